@@ -1,30 +1,37 @@
 use itertools::Itertools;
 mod metadata;
+mod native;
 mod serde_verbatim;
 mod settings;
 mod source_dist;
+mod version;
 mod wheel;
 
 pub(crate) use metadata::PyProjectToml;
-pub use metadata::check_direct_build;
+pub use metadata::{check_direct_build, dynamic_version};
 pub use settings::{BuildBackendSettings, WheelDataIncludes};
 pub use source_dist::{build_source_dist, list_source_dist};
 use uv_warnings::warn_user_once;
-pub use wheel::{build_editable, build_wheel, list_wheel, metadata};
+pub use wheel::{build_editable, build_wheel, list_wheel, metadata, pack_wheel};
 
 use rustc_hash::FxHashSet;
 use std::collections::HashSet;
+use std::env;
 use std::ffi::OsStr;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
 use std::str::FromStr;
 use thiserror::Error;
 use tracing::debug;
 
+use uv_cache_info::GitInfoError;
 use uv_fs::{Simplified, normalize_path};
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
-use uv_pypi_types::{Identifier, IdentifierParseError};
+use uv_platform_tags::ParsePlatformTagError;
+use uv_pypi_types::{Identifier, IdentifierParseError, MetadataError};
+use uv_static::EnvVars;
 
 use crate::metadata::ValidationError;
 use crate::settings::ModuleName;
@@ -93,6 +100,36 @@ pub enum Error {
     ),
     #[error("Failed to finish gzip stream for {}", _0.user_display())]
     GzipWrite(PathBuf, #[source] io::Error),
+    #[error("Invalid `SOURCE_DATE_EPOCH`, expected a number of seconds: `{0}`")]
+    InvalidSourceDateEpoch(String),
+    #[error("`tool.uv.build-backend.build-command` must not be empty")]
+    EmptyBuildCommand,
+    #[error("Failed to run build command `{0}`")]
+    BuildCommandSpawn(String, #[source] io::Error),
+    #[error("Build command `{0}` failed with {1}")]
+    BuildCommandFailed(String, ExitStatus),
+    #[error("No files in the module directory match `tool.uv.build-backend.native-include`")]
+    NoNativeArtifacts,
+    #[error(
+        "Building a wheel with native artifacts requires the `uv_build` Python package, which passes the interpreter to build for"
+    )]
+    MissingInterpreter,
+    #[error("Invalid interpreter description from `uv_build`: `{0}`")]
+    InvalidInterpreter(String),
+    #[error("Native artifacts are only supported for CPython, not `{0}`")]
+    UnsupportedImplementation(String),
+    #[error("Invalid platform tag for native artifacts: `{0}`")]
+    InvalidPlatformTag(String, #[source] ParsePlatformTagError),
+    #[error("Failed to read the Git repository to determine the dynamic version")]
+    GitInfo(#[source] GitInfoError),
+    #[error("Failed to run `git` to determine the dynamic version")]
+    GitSpawn(#[source] io::Error),
+    #[error("`git {0}` failed while determining the dynamic version")]
+    GitCommand(String),
+    #[error("Failed to read the version from: {}", _0.user_display())]
+    PkgInfo(PathBuf, #[source] MetadataError),
+    #[error("Invalid dynamic version `{0}`: {1}")]
+    InvalidDynamicVersion(String, String),
 }
 
 impl uv_errors::Hint for Error {
@@ -127,6 +164,24 @@ trait DirectoryWriter {
     fn close(self, dist_info_dir: &str) -> Result<(), Error>;
 }
 
+/// The modification time for the files in the archives, from `SOURCE_DATE_EPOCH`.
+///
+/// Without `SOURCE_DATE_EPOCH`, the archives use a fixed timestamp, so they are reproducible
+/// either way.
+///
+/// See <https://reproducible-builds.org/specs/source-date-epoch/>.
+fn source_date_epoch() -> Result<Option<u64>, Error> {
+    let Some(value) = env::var_os(EnvVars::SOURCE_DATE_EPOCH) else {
+        return Ok(None);
+    };
+    let value = value.to_string_lossy();
+    let epoch = value
+        .trim()
+        .parse()
+        .map_err(|_| Error::InvalidSourceDateEpoch(value.to_string()))?;
+    Ok(Some(epoch))
+}
+
 fn write_directory_once(
     writer: &mut impl DirectoryWriter,
     directories: &mut FxHashSet<PathBuf>,
@@ -490,11 +545,13 @@ mod tests {
     use sha2::Digest;
     use std::io::BufReader;
     use std::iter;
+    use std::str::FromStr;
     use tar_codec::{Archive as _, TarArchive, extract::ExtractPolicy};
     use tempfile::TempDir;
     use uv_distribution_filename::{SourceDistFilename, WheelFilename};
     use uv_errors::{ErrorWithHints, Hint};
     use uv_fs::{copy_dir_all, relative_to};
+    use uv_pep440::Version;
     use uv_preview::PreviewFeature;
 
     use crate::source_dist::SyncReader;
@@ -2164,4 +2221,156 @@ mod tests {
         build-backend = "uv_build"
         "#);
     }
+
+    /// Native artifacts are created by the build command, excluded from the source distribution,
+    /// and make the wheel specific to the interpreter.
+    #[test]
+    #[cfg(unix)]
+    fn native_artifacts() {
+        let _preview = uv_preview::test::with_features(&[PreviewFeature::NativeArtifacts]);
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "speedups"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            build-command = ["sh", "-c", "echo compiled > src/speedups/_native.so"]
+            native-include = ["src/speedups/*.so"]
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("speedups")).unwrap();
+        File::create(src.path().join("src").join("speedups").join("__init__.py")).unwrap();
+
+        let dist = TempDir::new().unwrap();
+        let build = temp_env::with_var(
+            EnvVars::UV_INTERNAL__BUILD_BACKEND_INTERPRETER,
+            Some("cpython 3.12 linux-x86_64"),
+            || build(src.path(), dist.path()),
+        )
+        .unwrap();
+        assert_snapshot!(build.source_dist_contents.join("\n"), @"
+        speedups-1.0.0/
+        speedups-1.0.0/PKG-INFO
+        speedups-1.0.0/pyproject.toml
+        speedups-1.0.0/pyproject.toml.orig
+        speedups-1.0.0/src
+        speedups-1.0.0/src/speedups
+        speedups-1.0.0/src/speedups/__init__.py
+        ");
+        assert_snapshot!(build.wheel_filename, @"speedups-1.0.0-cp312-cp312-linux_x86_64.whl");
+        assert_snapshot!(build.wheel_contents.join("\n"), @"
+        speedups-1.0.0.dist-info/
+        speedups-1.0.0.dist-info/METADATA
+        speedups-1.0.0.dist-info/RECORD
+        speedups-1.0.0.dist-info/WHEEL
+        speedups/
+        speedups/__init__.py
+        speedups/_native.so
+        ");
+        let wheel = wheel_entry(
+            &dist.path().join(build.wheel_filename.to_string()),
+            "speedups-1.0.0.dist-info/WHEEL",
+        );
+        assert_snapshot!(wheel, @"
+        Wheel-Version: 1.0
+        Generator: uv 1.0.0+test
+        Root-Is-Purelib: false
+        Tag: cp312-cp312-linux_x86_64
+        ");
+
+        // Without the build command, there are no native artifacts in the source distribution.
+        let pyproject_toml = pyproject_toml.replace("build-command", "unused-build-command");
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::remove_file(src.path().join("src").join("speedups").join("_native.so")).unwrap();
+        let err = temp_env::with_var(
+            EnvVars::UV_INTERNAL__BUILD_BACKEND_INTERPRETER,
+            Some("cpython 3.12 linux-x86_64"),
+            || build_err(src.path()),
+        );
+        assert_snapshot!(err, @"No files in the module directory match `tool.uv.build-backend.native-include`");
+    }
+
+    /// Derive the version from Git tags and preserve it when building from the source
+    /// distribution.
+    #[test]
+    fn dynamic_git_version() {
+        let _preview = uv_preview::test::with_features(&[PreviewFeature::DynamicVersion]);
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "tagged"
+            dynamic = ["version"]
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            version-source = "git"
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("tagged")).unwrap();
+        File::create(src.path().join("src").join("tagged").join("__init__.py")).unwrap();
+
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=uv",
+                    "-c",
+                    "user.email=uv@example.com",
+                    "-c",
+                    "commit.gpgsign=false",
+                    "-c",
+                    "tag.gpgsign=false",
+                ])
+                .args(args)
+                .current_dir(src.path())
+                .stdout(std::process::Stdio::null())
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        git(&["init", "--quiet"]);
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "Initial commit"]);
+        git(&["tag", "v1.0.0"]);
+
+        // `build` checks that the wheel built from the source distribution, which has no Git
+        // repository, has the same version.
+        let dist = TempDir::new().unwrap();
+        let build1 = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build1.source_dist_filename, @"tagged-1.0.0.tar.gz");
+        assert_snapshot!(build1.wheel_filename, @"tagged-1.0.0-py3-none-any.whl");
+        assert_eq!(
+            dynamic_version(src.path()).unwrap(),
+            Some(Version::from_str("1.0.0").unwrap())
+        );
+
+        // A commit after the tag is a development release of the next version.
+        fs_err::write(
+            src.path().join("src").join("tagged").join("__init__.py"),
+            "x = 1",
+        )
+        .unwrap();
+        let commit_filters = [(r"\+g[0-9a-f]{7}", "+g[COMMIT]")];
+        let dist = TempDir::new().unwrap();
+        let build2 = build(src.path(), dist.path()).unwrap();
+        insta::with_settings!({filters => commit_filters}, {
+            assert_snapshot!(build2.wheel_filename, @"tagged-1.0.1.dev0+g[COMMIT].dirty-py3-none-any.whl");
+        });
+        git(&["commit", "--quiet", "-am", "Change"]);
+        let dist = TempDir::new().unwrap();
+        let build3 = build(src.path(), dist.path()).unwrap();
+        insta::with_settings!({filters => commit_filters}, {
+            assert_snapshot!(build3.wheel_filename, @"tagged-1.0.1.dev1+g[COMMIT]-py3-none-any.whl");
+        });
+    }
 }
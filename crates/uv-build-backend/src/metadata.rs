@@ -27,7 +27,8 @@ use uv_pypi_types::{
 use uv_toml::deserialize_unique_map;
 
 use crate::serde_verbatim::SerdeVerbatim;
-use crate::{BuildBackendSettings, Error, error_on_venv};
+use crate::settings::VersionSource;
+use crate::{BuildBackendSettings, Error, error_on_venv, version};
 
 /// By default, we ignore generated python files.
 pub(crate) const DEFAULT_EXCLUDES: &[&str] = &["__pycache__", "*.pyc", "*.pyo"];
@@ -67,8 +68,14 @@ pub enum ValidationError {
     UnsupportedContentType(String),
     #[error("`project.description` must be a single line")]
     DescriptionNewlines,
-    #[error("Dynamic metadata is not supported")]
+    #[error("Dynamic metadata is not supported, except for `project.version`")]
     Dynamic,
+    #[error("`project.version` is missing, add a version or declare it as dynamic")]
+    MissingVersion,
+    #[error("`project.version` is dynamic, but there is no `tool.uv.build-backend.version-source`")]
+    MissingVersionSource,
+    #[error("`project.version` must not be set when it is declared as dynamic")]
+    StaticAndDynamicVersion,
     #[error(
         "When `project.license-files` is defined, `project.license` must be an SPDX expression string"
     )]
@@ -134,6 +141,8 @@ pub enum DirectBuildIncompatibility {
     UrlRequirement,
     #[error("`uv_build{0}` is not a known compatible range")]
     IncompatibleRange(VersionSpecifiers),
+    #[error("it builds native artifacts, which requires a Python interpreter")]
+    NativeArtifacts,
 }
 
 #[derive(Debug, Clone)]
@@ -256,6 +265,28 @@ pub fn check_direct_build(
     #[serde(rename_all = "kebab-case")]
     struct PyProjectToml {
         build_system: BuildSystem,
+        tool: Option<Tool>,
+    }
+
+    #[derive(Deserialize)]
+    struct Tool {
+        uv: Option<ToolUv>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct ToolUv {
+        build_backend: Option<NativeSettings>,
+    }
+
+    /// The subset of the build backend settings that requires a Python interpreter.
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct NativeSettings {
+        #[serde(default)]
+        build_command: Option<toml::Value>,
+        #[serde(default)]
+        native_include: Vec<toml::Value>,
     }
 
     let path = source_tree.join("pyproject.toml");
@@ -327,9 +358,62 @@ pub fn check_direct_build(
         Some(VersionOrUrl::VersionSpecifier(_)) => {}
     }
 
+    // The wheel tags for native artifacts come from the interpreter running the build backend.
+    if let Some(settings) = pyproject_toml
+        .tool
+        .and_then(|tool| tool.uv)
+        .and_then(|uv| uv.build_backend)
+    {
+        if settings.build_command.is_some() || !settings.native_include.is_empty() {
+            return Err(DirectBuildIncompatibility::NativeArtifacts);
+        }
+    }
+
     Ok(())
 }
 
+/// Determine the version of a project that declares a dynamic version with a
+/// `tool.uv.build-backend.version-source`.
+///
+/// Returns `None` if the project has no `version-source`.
+pub fn dynamic_version(source_tree: &Path) -> Result<Option<Version>, Error> {
+    #[derive(Deserialize)]
+    struct PyProjectToml {
+        tool: Option<Tool>,
+    }
+
+    #[derive(Deserialize)]
+    struct Tool {
+        uv: Option<ToolUv>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct ToolUv {
+        build_backend: Option<VersionSettings>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct VersionSettings {
+        version_source: Option<VersionSource>,
+    }
+
+    let path = source_tree.join("pyproject.toml");
+    let contents = fs_err::read_to_string(&path)?;
+    let pyproject_toml: PyProjectToml =
+        toml::from_str(&contents).map_err(|err| Error::Toml(path.clone(), err))?;
+    let Some(version_source) = pyproject_toml
+        .tool
+        .and_then(|tool| tool.uv)
+        .and_then(|uv| uv.build_backend)
+        .and_then(|build_backend| build_backend.version_source)
+    else {
+        return Ok(None);
+    };
+    Ok(Some(version::dynamic_version(source_tree, version_source)?))
+}
+
 /// A package name as provided in a `pyproject.toml`.
 #[derive(Debug, Clone)]
 struct VerbatimPackageName {
@@ -375,18 +459,49 @@ impl PyProjectToml {
     }
 
     pub(crate) fn version(&self) -> &Version {
-        &self.project.version
+        self.project
+            .version
+            .as_ref()
+            .expect("dynamic versions are determined when parsing")
     }
 
     pub(crate) fn parse(path: &Path) -> Result<Self, Error> {
         let contents = fs_err::read_to_string(path)?;
-        let pyproject_toml =
+        let mut pyproject_toml: Self =
             tracing::info_span!("toml::from_str uv build backend", path = %path.display())
                 .in_scope(|| toml::from_str(&contents))
                 .map_err(|err| Error::Toml(path.to_path_buf(), err))?;
+        pyproject_toml.resolve_version(path.parent().unwrap_or(Path::new("")))?;
         Ok(pyproject_toml)
     }
 
+    /// Whether `project.version` is declared as dynamic.
+    fn has_dynamic_version(&self) -> bool {
+        self.project
+            .dynamic
+            .as_ref()
+            .is_some_and(|dynamic| dynamic.iter().any(|field| field == "version"))
+    }
+
+    /// Determine the version from the `version-source` if it is dynamic.
+    fn resolve_version(&mut self, source_tree: &Path) -> Result<(), Error> {
+        let version_source = self.settings().and_then(|settings| settings.version_source);
+        match (
+            &self.project.version,
+            self.has_dynamic_version(),
+            version_source,
+        ) {
+            (Some(_), false, _) => Ok(()),
+            (Some(_), true, _) => Err(ValidationError::StaticAndDynamicVersion.into()),
+            (None, false, _) => Err(ValidationError::MissingVersion.into()),
+            (None, true, None) => Err(ValidationError::MissingVersionSource.into()),
+            (None, true, Some(version_source)) => {
+                self.project.version = Some(version::dynamic_version(source_tree, version_source)?);
+                Ok(())
+            }
+        }
+    }
+
     pub(crate) fn readme(&self) -> Option<&Readme> {
         self.project.readme.as_ref()
     }
@@ -496,11 +611,13 @@ impl PyProjectToml {
             None => (None, None),
         };
 
+        // A dynamic version is determined when parsing, all other fields must be static.
         if self
             .project
             .dynamic
-            .as_ref()
-            .is_some_and(|dynamic| !dynamic.is_empty())
+            .iter()
+            .flatten()
+            .any(|field| field != "version")
         {
             return Err(ValidationError::Dynamic.into());
         }
@@ -656,7 +773,7 @@ impl PyProjectToml {
         Ok(Metadata23 {
             metadata_version: metadata_version.to_string(),
             name: self.project.name.given.clone(),
-            version: self.project.version.to_string(),
+            version: self.version().to_string(),
             // Not supported.
             platforms: vec![],
             // Not supported.
@@ -955,7 +1072,10 @@ struct Project {
     /// The name of the project.
     name: VerbatimPackageName,
     /// The version of the project.
-    version: Version,
+    ///
+    /// For a dynamic version, this is the version determined from the `version-source` when
+    /// parsing the `pyproject.toml`.
+    version: Option<Version>,
     /// The summary description of the project in one line.
     description: Option<String>,
     /// The full description of the project (i.e. the README).
@@ -2023,7 +2143,7 @@ mod tests {
             .unwrap_err();
         assert_snapshot!(format_err(err), @"
         Invalid project metadata
-          Caused by: Dynamic metadata is not supported
+          Caused by: Dynamic metadata is not supported, except for `project.version`
         ");
     }
 
@@ -2214,6 +2334,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_direct_build_native_artifacts() {
+        let temp_dir = TempDir::new().unwrap();
+        fs_err::write(
+            temp_dir.path().join("pyproject.toml"),
+            indoc! {r#"
+                [project]
+                name = "hello-world"
+                version = "0.1.0"
+
+                [build-system]
+                requires = ["uv_build>=0.10.0,<0.11"]
+                build-backend = "uv_build"
+
+                [tool.uv.build-backend]
+                native-include = ["src/hello_world/*.so"]
+            "#},
+        )
+        .unwrap();
+        assert_snapshot!(
+            check_direct_build(temp_dir.path(), "0.10.0").unwrap_err(),
+            @"it builds native artifacts, which requires a Python interpreter"
+        );
+    }
+
     #[test]
     fn update_compatibility_for_breaking_release() {
         // Handle the case where we made a breaking change to the build backend.
@@ -0,0 +1,175 @@
+//! Support for wheels with prebuilt native artifacts, such as compiled extension modules.
+//!
+//! The uv build backend doesn't compile anything itself: A `build-command` creates the native
+//! artifacts in the module directory, and `native-include` marks them, so the wheel is tagged for
+//! the interpreter and platform instead of `py3-none-any`.
+
+use std::env;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use globset::{GlobSet, GlobSetBuilder};
+use itertools::Itertools;
+use tracing::debug;
+
+use uv_globfilter::PortableGlobParser;
+use uv_platform_tags::{AbiTag, CPythonAbiVariants, LanguageTag, PlatformTag};
+use uv_preview::PreviewFeature;
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+use crate::{BuildBackendSettings, Error};
+
+/// Run the `build-command` in the source tree, if there is one.
+pub(crate) fn run_build_command(
+    source_tree: &Path,
+    settings: &BuildBackendSettings,
+) -> Result<(), Error> {
+    let Some(build_command) = &settings.build_command else {
+        return Ok(());
+    };
+    if !uv_preview::is_enabled(PreviewFeature::NativeArtifacts) {
+        warn_user_once!(
+            "The `tool.uv.build-backend.build-command` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::NativeArtifacts
+        );
+    }
+    let Some((program, args)) = build_command.split_first() else {
+        return Err(Error::EmptyBuildCommand);
+    };
+
+    let command = build_command.iter().join(" ");
+    debug!("Running build command: `{command}`");
+    // The build frontend reads the filename of the built wheel from stdout, so the output of the
+    // build command is forwarded to stderr.
+    let status = Command::new(program)
+        .args(args)
+        .current_dir(source_tree)
+        .stdin(Stdio::null())
+        .stdout(std::io::stderr())
+        .status()
+        .map_err(|err| Error::BuildCommandSpawn(command.clone(), err))?;
+    if !status.success() {
+        return Err(Error::BuildCommandFailed(command, status));
+    }
+    Ok(())
+}
+
+/// Build a matcher for the `native-include` globs, or `None` if the wheel is pure Python.
+///
+/// Like source includes, the globs are anchored at the source tree.
+pub(crate) fn build_native_matcher(
+    settings: &BuildBackendSettings,
+) -> Result<Option<GlobSet>, Error> {
+    if settings.native_include.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for include in &settings.native_include {
+        let glob = PortableGlobParser::Uv
+            .parse(include)
+            .map_err(|err| Error::PortableGlob {
+                field: "tool.uv.build-backend.native-include".to_string(),
+                source: err,
+            })?;
+        builder.add(glob);
+    }
+    let matcher = builder.build().map_err(|err| Error::GlobSetTooLarge {
+        field: "tool.uv.build-backend.native-include".to_string(),
+        source: err,
+    })?;
+    Ok(Some(matcher))
+}
+
+/// The wheel tags for the interpreter the wheel is built for.
+///
+/// The uv build backend doesn't run in the interpreter, so the `uv_build` Python package passes
+/// a description of it through an environment variable.
+pub(crate) fn interpreter_tags() -> Result<(LanguageTag, AbiTag, PlatformTag), Error> {
+    if !uv_preview::is_enabled(PreviewFeature::NativeArtifacts) {
+        warn_user_once!(
+            "The `tool.uv.build-backend.native-include` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::NativeArtifacts
+        );
+    }
+    let Some(interpreter) = env::var_os(EnvVars::UV_INTERNAL__BUILD_BACKEND_INTERPRETER) else {
+        return Err(Error::MissingInterpreter);
+    };
+    parse_interpreter(&interpreter.to_string_lossy())
+}
+
+/// Parse the interpreter description, `<implementation> <major>.<minor>[t] <platform>`, where the
+/// platform is the value of `sysconfig.get_platform()`, into wheel tags.
+fn parse_interpreter(interpreter: &str) -> Result<(LanguageTag, AbiTag, PlatformTag), Error> {
+    let invalid = || Error::InvalidInterpreter(interpreter.to_string());
+
+    let Some((implementation, version, platform)) = interpreter.split_whitespace().collect_tuple()
+    else {
+        return Err(invalid());
+    };
+    let (version, gil_disabled) = match version.strip_suffix('t') {
+        Some(version) => (version, true),
+        None => (version, false),
+    };
+    let (major, minor) = version.split_once('.').ok_or_else(invalid)?;
+    let python_version = (
+        major.parse::<u8>().map_err(|_| invalid())?,
+        minor.parse::<u8>().map_err(|_| invalid())?,
+    );
+
+    if implementation != "cpython" {
+        return Err(Error::UnsupportedImplementation(implementation.to_string()));
+    }
+    let mut variant = CPythonAbiVariants::default();
+    if gil_disabled {
+        variant.insert(CPythonAbiVariants::Freethreading);
+    }
+    let language_tag = LanguageTag::CPython { python_version };
+    let abi_tag = AbiTag::CPython {
+        python_version,
+        variant,
+    };
+
+    // Same as `bdist_wheel`, e.g., `macosx-11.0-arm64` becomes `macosx_11_0_arm64`.
+    let platform = platform.replace(['-', '.'], "_");
+    let platform_tag = PlatformTag::from_str(&platform)
+        .map_err(|err| Error::InvalidPlatformTag(platform.clone(), err))?;
+
+    Ok((language_tag, abi_tag, platform_tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_interpreter;
+
+    fn tags(interpreter: &str) -> String {
+        match parse_interpreter(interpreter) {
+            Ok((language_tag, abi_tag, platform_tag)) => {
+                format!("{language_tag}-{abi_tag}-{platform_tag}")
+            }
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn interpreter_tags() {
+        assert_eq!(
+            tags("cpython 3.12 linux-x86_64"),
+            "cp312-cp312-linux_x86_64"
+        );
+        assert_eq!(
+            tags("cpython 3.14t macosx-11.0-arm64"),
+            "cp314-cp314t-macosx_11_0_arm64"
+        );
+        assert_eq!(tags("cpython 3.13 win-amd64"), "cp313-cp313-win_amd64");
+        assert_eq!(
+            tags("pypy 3.11 linux-x86_64"),
+            "Native artifacts are only supported for CPython, not `pypy`"
+        );
+        assert_eq!(
+            tags("cpython 3 linux-x86_64"),
+            "Invalid interpreter description from `uv_build`: `cpython 3 linux-x86_64`"
+        );
+    }
+}
@@ -171,6 +171,57 @@ pub struct BuildBackendSettings {
         example = r#"data = { headers = "include/headers", scripts = "bin" }"#
     )]
     pub(crate) data: WheelDataIncludes,
+
+    /// A command to run in the project root before building a wheel, e.g., to compile extension
+    /// modules.
+    ///
+    /// The first element is the program, the remaining elements are its arguments. The command
+    /// runs with the environment of the build, so `python` refers to the interpreter the wheel is
+    /// built for. The command also runs before building an editable, so that extension modules
+    /// are available in the source tree.
+    #[option(
+        default = r#"None"#,
+        value_type = "list[str]",
+        example = r#"build-command = ["make", "extensions"]"#
+    )]
+    pub(crate) build_command: Option<Vec<String>>,
+
+    /// Glob expressions which match prebuilt native artifacts, such as compiled extension modules.
+    ///
+    /// Native artifacts are included in the wheel like any other file in the module directory,
+    /// but they make the wheel specific to the Python interpreter and platform it is built with:
+    /// The wheel is tagged for the interpreter (e.g., `cp312-cp312-linux_x86_64`) and installed
+    /// into `platlib`. Native artifacts are excluded from the source distribution, use
+    /// `build-command` to build them from source.
+    ///
+    /// The globs are relative to the project root, and the matching files must be inside the
+    /// module directory. Building a wheel fails if no file matches.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"native-include = ["src/foo/*.so", "src/foo/*.pyd"]"#
+    )]
+    pub(crate) native_include: Vec<String>,
+
+    /// Where to read the project version from, for projects that declare
+    /// `dynamic = ["version"]` instead of a static `project.version`.
+    ///
+    /// With `"git"`, the version is derived from the nearest Git tag that is a valid version, with
+    /// an optional `v` prefix. If the current commit is tagged and there are no uncommitted
+    /// changes, the version is the version of the tag, e.g., `1.2.3`. Otherwise, the version is a
+    /// development release of the next version, with the distance from the tag and the commit as
+    /// local version, e.g., `1.2.4.dev5+g1a2b3c4` for the fifth commit after `v1.2.3`, and
+    /// `1.2.4.dev5+g1a2b3c4.dirty` with uncommitted changes. Without a tag, the version is
+    /// `0.1.dev<distance>+g<commit>`.
+    ///
+    /// The version is written to the source distribution, so building a wheel from the source
+    /// distribution doesn't require Git.
+    #[option(
+        default = r#"None"#,
+        value_type = "str",
+        example = r#"version-source = "git""#
+    )]
+    pub(crate) version_source: Option<VersionSource>,
 }
 
 impl Default for BuildBackendSettings {
@@ -184,10 +235,22 @@ impl Default for BuildBackendSettings {
             wheel_exclude: Vec::new(),
             namespace: false,
             data: WheelDataIncludes::default(),
+            build_command: None,
+            native_include: Vec::new(),
+            version_source: None,
         }
     }
 }
 
+/// The source of a dynamic project version.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum VersionSource {
+    /// Derive the version from the Git tags.
+    Git,
+}
+
 /// Whether to include a single module or multiple modules.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
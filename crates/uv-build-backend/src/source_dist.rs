@@ -49,11 +49,15 @@ pub fn build_source_dist(
     }
 
     let temp_file = uv_fs::tempfile_in(source_dist_directory)?;
-    if uv_preview::is_enabled(PreviewFeature::TarCodec) {
+    let source_date_epoch = crate::source_date_epoch()?;
+    // tar-codec always writes a modification time of zero, so we only use it without
+    // `SOURCE_DATE_EPOCH`.
+    if uv_preview::is_enabled(PreviewFeature::TarCodec) && source_date_epoch.is_none() {
         let writer = TarCodecGzWriter::new(temp_file.as_file(), &source_dist_path);
         write_source_dist(source_tree, writer, uv_version, show_warnings)?;
     } else {
-        let writer = TokioTarGzWriter::new(temp_file.as_file(), &source_dist_path);
+        let writer =
+            TokioTarGzWriter::new(temp_file.as_file(), &source_dist_path, source_date_epoch);
         write_source_dist(source_tree, writer, uv_version, show_warnings)?;
     }
     temp_file
@@ -193,6 +197,11 @@ fn source_dist_matcher(
             excludes.push(exclude);
         }
     }
+    // Native artifacts are built from source, they don't belong in the source distribution. Unlike
+    // excludes, native includes are anchored.
+    for native_include in settings.native_include {
+        excludes.push(format!("/{native_include}"));
+    }
     debug!("Source dist excludes: {:?}", excludes);
     let exclude_matcher = build_exclude_matcher(excludes)?;
     if exclude_matcher.is_match("pyproject.toml") {
@@ -412,14 +421,20 @@ impl<W: Write + Unpin> AsyncWrite for SyncWriter<W> {
 struct TokioTarGzWriter<W: Write + Unpin + Send> {
     path: PathBuf,
     tar: tokio_tar::Builder<SyncWriter<GzEncoder<W>>>,
+    /// The modification time of all entries.
+    mtime: u64,
 }
 
 impl<W: Write + Unpin + Send> TokioTarGzWriter<W> {
-    fn new(writer: W, path: impl Into<PathBuf>) -> Self {
+    fn new(writer: W, path: impl Into<PathBuf>, source_date_epoch: Option<u64>) -> Self {
         let path = path.into();
         let gzip = GzEncoder::new(writer, Compression::default());
         let tar = tokio_tar::Builder::new_non_terminated(SyncWriter::new(gzip));
-        Self { path, tar }
+        Self {
+            path,
+            tar,
+            mtime: source_date_epoch.unwrap_or_default(),
+        }
     }
 }
 
@@ -474,6 +489,7 @@ impl<W: Write + Unpin + Send> DirectoryWriter for TokioTarGzWriter<W> {
         // Reasonable default to avoid 0o000 permissions, the user's umask will be applied on
         // unpacking.
         header.set_mode(0o644);
+        header.set_mtime(self.mtime);
         block_on(
             self.tar
                 .append_data(&mut header, path, SyncReader::new(Cursor::new(bytes))),
@@ -498,6 +514,7 @@ impl<W: Write + Unpin + Send> DirectoryWriter for TokioTarGzWriter<W> {
 
         header.set_mode(if executable_bit { 0o755 } else { 0o644 });
         header.set_size(metadata.len());
+        header.set_mtime(self.mtime);
         let reader = BufReader::new(File::open(file)?);
         block_on(
             self.tar
@@ -513,6 +530,7 @@ impl<W: Write + Unpin + Send> DirectoryWriter for TokioTarGzWriter<W> {
         header.set_mode(0o755);
         header.set_entry_type(EntryType::Directory);
         header.set_size(0);
+        header.set_mtime(self.mtime);
         block_on(
             self.tar
                 .append_data(&mut header, directory, SyncReader::new(io::empty())),
@@ -0,0 +1,221 @@
+//! Dynamic project versions, for projects with `dynamic = ["version"]`.
+//!
+//! The version is derived from the Git tags of the repository. When building a wheel from a
+//! source distribution, there is no Git repository, so the version is read from the `PKG-INFO`
+//! the source distribution build wrote instead.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use tracing::debug;
+
+use uv_cache_info::{Commit, Tags};
+use uv_fs::Simplified;
+use uv_pep440::{Prerelease, Version};
+use uv_preview::PreviewFeature;
+use uv_pypi_types::Metadata10;
+use uv_warnings::warn_user_once;
+
+use crate::Error;
+use crate::settings::VersionSource;
+
+/// The number of hexadecimal characters of the commit in the local version.
+const SHORT_COMMIT_LENGTH: usize = 7;
+
+/// Determine the dynamic version of the project in the source tree.
+pub(crate) fn dynamic_version(
+    source_tree: &Path,
+    version_source: VersionSource,
+) -> Result<Version, Error> {
+    if !uv_preview::is_enabled(PreviewFeature::DynamicVersion) {
+        warn_user_once!(
+            "The `tool.uv.build-backend.version-source` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::DynamicVersion
+        );
+    }
+
+    // In an unpacked source distribution, the version was already determined by the source
+    // distribution build.
+    let pkg_info = source_tree.join("PKG-INFO");
+    if pkg_info.is_file() {
+        debug!("Reading dynamic version from: {}", pkg_info.user_display());
+        let metadata = Metadata10::parse_pkg_info(&fs_err::read(&pkg_info)?)
+            .map_err(|err| Error::PkgInfo(pkg_info.clone(), err))?;
+        return Version::from_str(&metadata.version)
+            .map_err(|err| Error::InvalidDynamicVersion(metadata.version, err.to_string()));
+    }
+
+    match version_source {
+        VersionSource::Git => git_version(source_tree),
+    }
+}
+
+/// Derive the version from the nearest Git tag that is a valid version.
+fn git_version(source_tree: &Path) -> Result<Version, Error> {
+    let commit = Commit::from_repository(source_tree).map_err(Error::GitInfo)?;
+    let tags = Tags::from_repository(source_tree).map_err(Error::GitInfo)?;
+
+    // Only consider tags that are versions, e.g., `v1.2.3` but not `nightly`.
+    let version_tags: Vec<&str> = tags
+        .iter()
+        .map(|(name, _)| name)
+        .filter(|name| parse_tag(name).is_some())
+        .collect();
+
+    let nearest = if version_tags.is_empty() {
+        None
+    } else {
+        let mut args = vec!["describe", "--tags", "--long"];
+        for tag in &version_tags {
+            args.extend(["--match", tag]);
+        }
+        // `git describe` fails if no matching tag is reachable from the current commit.
+        git(source_tree, &args)?.and_then(|describe| parse_describe(&describe))
+    };
+    let (tag, distance) = if let Some((tag, distance)) = nearest {
+        (Some(tag), distance)
+    } else {
+        let count = git(source_tree, &["rev-list", "--count", "HEAD"])?
+            .ok_or_else(|| Error::GitCommand("rev-list --count HEAD".to_string()))?;
+        let count = count
+            .trim()
+            .parse()
+            .map_err(|_| Error::GitCommand("rev-list --count HEAD".to_string()))?;
+        (None, count)
+    };
+
+    let status = git(
+        source_tree,
+        &["status", "--porcelain", "--untracked-files=no"],
+    )?
+    .ok_or_else(|| Error::GitCommand("status --porcelain".to_string()))?;
+    let dirty = !status.trim().is_empty();
+
+    let short_commit = &commit.as_str()[..SHORT_COMMIT_LENGTH];
+    let version = format_version(tag.as_ref(), distance, short_commit, dirty)?;
+    debug!("Derived version {version} from Git");
+    Ok(version)
+}
+
+/// Run a Git command in the source tree, returning its stdout, or `None` if it failed.
+fn git(source_tree: &Path, args: &[&str]) -> Result<Option<String>, Error> {
+    debug!("Running `git {}`", args.iter().join(" "));
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(source_tree)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(Error::GitSpawn)?;
+    if !output.status.success() {
+        debug!(
+            "`git {}` failed: {}",
+            args.iter().join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(&output.stdout).to_string()))
+}
+
+/// Parse a tag as version, with an optional `v` prefix.
+fn parse_tag(tag: &str) -> Option<Version> {
+    let version = Version::from_str(tag.strip_prefix('v').unwrap_or(tag)).ok()?;
+    // The local version is reserved for the commit.
+    if version.is_local() {
+        return None;
+    }
+    Some(version)
+}
+
+/// Parse the output of `git describe --long`, `<tag>-<distance>-g<commit>`, into the version of
+/// the tag and the distance.
+fn parse_describe(describe: &str) -> Option<(Version, u64)> {
+    let (_commit, distance, tag) = describe.trim().rsplitn(3, '-').collect_tuple()?;
+    Some((parse_tag(tag)?, distance.parse().ok()?))
+}
+
+/// Format the version for a tag, the distance from the tag, the current commit, and whether
+/// there are uncommitted changes.
+fn format_version(
+    tag: Option<&Version>,
+    distance: u64,
+    short_commit: &str,
+    dirty: bool,
+) -> Result<Version, Error> {
+    let version = match tag {
+        Some(tag) if distance == 0 && !dirty => return Ok(tag.clone()),
+        Some(tag) => {
+            let dirty = if dirty { ".dirty" } else { "" };
+            format!("{}.dev{distance}+g{short_commit}{dirty}", next_version(tag))
+        }
+        None => {
+            let dirty = if dirty { ".dirty" } else { "" };
+            format!("0.1.dev{distance}+g{short_commit}{dirty}")
+        }
+    };
+    Version::from_str(&version)
+        .map_err(|err| Error::InvalidDynamicVersion(version, err.to_string()))
+}
+
+/// The version a development release after the tag leads up to: The next pre-release for a
+/// pre-release, otherwise the next release, incrementing the last release segment.
+fn next_version(tag: &Version) -> String {
+    let epoch = if tag.epoch() > 0 {
+        format!("{}!", tag.epoch())
+    } else {
+        String::new()
+    };
+    if let Some(pre) = tag.pre() {
+        let next = tag.only_release().with_pre(Some(Prerelease {
+            kind: pre.kind,
+            number: pre.number + 1,
+        }));
+        return format!("{epoch}{next}");
+    }
+    let mut release = tag.release().to_vec();
+    if let Some(last) = release.last_mut() {
+        *last += 1;
+    }
+    format!("{epoch}{}", Version::new(release))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+
+    use super::{format_version, parse_describe};
+
+    fn version(tag: Option<&str>, distance: u64, dirty: bool) -> String {
+        let tag = tag.map(|tag| Version::from_str(tag).unwrap());
+        format_version(tag.as_ref(), distance, "1a2b3c4", dirty)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn git_versions() {
+        assert_eq!(version(Some("1.2.3"), 0, false), "1.2.3");
+        assert_eq!(version(Some("1.2.3"), 0, true), "1.2.4.dev0+g1a2b3c4.dirty");
+        assert_eq!(version(Some("1.2.3"), 5, false), "1.2.4.dev5+g1a2b3c4");
+        assert_eq!(version(Some("1.2"), 5, false), "1.3.dev5+g1a2b3c4");
+        assert_eq!(version(Some("2.0rc1"), 2, false), "2.0rc2.dev2+g1a2b3c4");
+        assert_eq!(version(Some("1.0.post1"), 1, false), "1.1.dev1+g1a2b3c4");
+        assert_eq!(version(Some("1!2.0"), 1, false), "1!2.1.dev1+g1a2b3c4");
+        assert_eq!(version(None, 12, false), "0.1.dev12+g1a2b3c4");
+        assert_eq!(version(None, 12, true), "0.1.dev12+g1a2b3c4.dirty");
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            parse_describe("v1.2.3-5-g1a2b3c4\n"),
+            Some((Version::from_str("1.2.3").unwrap(), 5))
+        );
+        assert_eq!(parse_describe("release-candidate-1.0-0-g1a2b3c4"), None);
+        assert_eq!(parse_describe("nightly"), None);
+    }
+}
@@ -1,5 +1,5 @@
 use async_zip::base::write::{EntrySeekableWriter, ZipFileWriter};
-use async_zip::{Compression, ZipEntryBuilder};
+use async_zip::{Compression, ZipDateTime, ZipDateTimeBuilder, ZipEntryBuilder};
 use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD as base64};
 use fs_err::File;
 use futures_lite::future::block_on;
@@ -27,7 +27,7 @@ use uv_warnings::warn_user_once;
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::{
     BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
-    error_on_venv, find_roots, write_directory_once, write_file_with_directories,
+    error_on_venv, find_roots, native, write_directory_once, write_file_with_directories,
 };
 
 // Files at or below this size are buffered and written with `write_entry_whole`,
@@ -54,16 +54,12 @@ pub fn build_wheel(
     }
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
+    native::run_build_command(source_tree, &settings)?;
+    let filename = wheel_filename(&pyproject_toml, &settings)?;
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
@@ -73,7 +69,8 @@ pub fn build_wheel(
     }
 
     let temp_file = uv_fs::tempfile_in(wheel_dir)?;
-    let wheel_writer = ZipDirectoryWriter::new_wheel(temp_file.as_file());
+    let wheel_writer =
+        ZipDirectoryWriter::new_wheel(temp_file.as_file(), crate::source_date_epoch()?);
 
     write_wheel(
         source_tree,
@@ -102,16 +99,13 @@ pub fn list_wheel(
         warn_user_once!("{warning}");
     }
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
+    // The native artifacts only exist after running the build command.
+    native::run_build_command(source_tree, &settings)?;
+    let filename = wheel_filename(&pyproject_toml, &settings)?;
 
     let mut files = FileList::new();
     let writer = ListWriter::new(&mut files);
@@ -126,6 +120,33 @@ pub fn list_wheel(
     Ok((filename, files))
 }
 
+/// The filename of the wheel: `py3-none-any` for pure Python wheels, or the tags of the
+/// interpreter the wheel is built for if it contains native artifacts.
+fn wheel_filename(
+    pyproject_toml: &PyProjectToml,
+    settings: &BuildBackendSettings,
+) -> Result<WheelFilename, Error> {
+    let (language_tag, abi_tag, platform_tag) = if settings.native_include.is_empty() {
+        (
+            LanguageTag::Python {
+                major: 3,
+                minor: None,
+            },
+            AbiTag::None,
+            PlatformTag::Any,
+        )
+    } else {
+        native::interpreter_tags()?
+    };
+    Ok(WheelFilename::new(
+        pyproject_toml.name().clone(),
+        pyproject_toml.version().clone(),
+        language_tag,
+        abi_tag,
+        platform_tag,
+    ))
+}
+
 fn write_wheel(
     source_tree: &Path,
     pyproject_toml: &PyProjectToml,
@@ -140,6 +161,7 @@ fn write_wheel(
         .unwrap_or_else(BuildBackendSettings::default);
 
     let exclude_matcher = build_wheel_exclude_matcher(&settings)?;
+    let native_matcher = native::build_native_matcher(&settings)?;
 
     debug!("Adding content files to wheel");
     let (src_root, module_relative) = find_roots(
@@ -152,6 +174,7 @@ fn write_wheel(
     )?;
 
     let mut files_visited = 0;
+    let mut native_artifacts = 0;
     let mut written_directories = FxHashSet::<PathBuf>::default();
     for module_relative in module_relative {
         for entry in WalkDir::new(src_root.join(module_relative))
@@ -193,7 +216,15 @@ fn write_wheel(
                 continue;
             }
 
-            debug!("Adding to wheel: {}", entry_path.user_display());
+            if native_matcher
+                .as_ref()
+                .is_some_and(|native_matcher| native_matcher.is_match(match_path))
+            {
+                debug!("Adding native artifact: {}", entry_path.user_display());
+                native_artifacts += 1;
+            } else {
+                debug!("Adding to wheel: {}", entry_path.user_display());
+            }
             write_file_with_directories(
                 &mut wheel_writer,
                 &mut written_directories,
@@ -204,6 +235,9 @@ fn write_wheel(
         }
     }
     debug!("Visited {files_visited} files for wheel build");
+    if native_matcher.is_some() && native_artifacts == 0 {
+        return Err(Error::NoNativeArtifacts);
+    }
 
     // Add the license files
     if pyproject_toml.license_files_wheel().next().is_some() {
@@ -265,6 +299,10 @@ pub fn build_editable(
 
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
+    // Editables link the module directory, so native artifacts must be built in place, but the
+    // editable itself is pure Python.
+    native::run_build_command(source_tree, &settings)?;
+
     let filename = WheelFilename::new(
         pyproject_toml.name().clone(),
         pyproject_toml.version().clone(),
@@ -284,7 +322,8 @@ pub fn build_editable(
     }
 
     let temp_file = uv_fs::tempfile_in(wheel_dir)?;
-    let mut wheel_writer = ZipDirectoryWriter::new_wheel(temp_file.as_file());
+    let mut wheel_writer =
+        ZipDirectoryWriter::new_wheel(temp_file.as_file(), crate::source_date_epoch()?);
 
     debug!("Adding pth file to {}", wheel_path.user_display());
     // Check that a module root exists in the directory we're linking from the `.pth` file
@@ -402,6 +441,66 @@ fn build_wheel_exclude_matcher(settings: &BuildBackendSettings) -> Result<GlobSe
     build_exclude_matcher(excludes)
 }
 
+/// Pack an unpacked wheel into a wheel in the output directory, regenerating the `RECORD`.
+///
+/// Other build tools use this to write a wheel they modified, e.g., when repairing a wheel with
+/// native dependencies.
+pub fn pack_wheel(
+    directory: &Path,
+    wheel_dir: &Path,
+    filename: &WheelFilename,
+) -> Result<(), Error> {
+    let dist_info_dir = format!(
+        "{}-{}.dist-info",
+        filename.name.as_dist_info_name(),
+        filename.version
+    );
+    let record_path = format!("{dist_info_dir}/RECORD");
+
+    let wheel_path = wheel_dir.join(filename.to_string());
+    debug!("Packing wheel at {}", wheel_path.user_display());
+
+    // Write the `.dist-info` directory last, so that the metadata comes after the content files.
+    let mut files = Vec::new();
+    let mut dist_info_files = Vec::new();
+    for entry in WalkDir::new(directory).sort_by_file_name() {
+        let entry = entry.map_err(|err| Error::WalkDir {
+            root: directory.to_path_buf(),
+            err,
+        })?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(directory)
+            .expect("walkdir starts with root");
+        let relative = relative.portable_display().to_string();
+        if relative == record_path {
+            continue;
+        }
+        if relative.starts_with(&format!("{dist_info_dir}/")) {
+            dist_info_files.push((relative, entry.into_path()));
+        } else {
+            files.push((relative, entry.into_path()));
+        }
+    }
+
+    let temp_file = uv_fs::tempfile_in(wheel_dir)?;
+    let mut wheel_writer =
+        ZipDirectoryWriter::new_wheel(temp_file.as_file(), crate::source_date_epoch()?);
+    for (relative, path) in files.iter().chain(&dist_info_files) {
+        wheel_writer.write_file(relative, path)?;
+    }
+    wheel_writer.close(&dist_info_dir)?;
+
+    temp_file
+        .persist(&wheel_path)
+        .map_err(|err| Error::Persist(wheel_path.clone(), err.error))?;
+
+    Ok(())
+}
+
 /// Write the dist-info directory to the output directory without building the wheel.
 pub fn metadata(
     source_tree: &Path,
@@ -413,16 +512,11 @@ pub fn metadata(
         warn_user_once!("{warning}");
     }
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
+    let filename = wheel_filename(&pyproject_toml, &settings)?;
 
     debug!(
         "Writing metadata files to {}",
@@ -727,7 +821,8 @@ impl WheelInfo {
         Self {
             wheel_version: "1.0".to_string(),
             generator: format!("uv {uv_version}"),
-            root_is_purelib: true,
+            // Wheels with native artifacts are installed into `platlib`.
+            root_is_purelib: filename.platform_tags().iter().all(PlatformTag::is_any),
             tags,
         }
     }
@@ -750,6 +845,8 @@ impl Display for WheelInfo {
 struct ZipDirectoryWriter<W: AsyncWrite + AsyncSeek + Unpin> {
     writer: ZipFileWriter<W>,
     compression: Compression,
+    /// The modification time of all entries.
+    timestamp: ZipDateTime,
     /// The entries in the `RECORD` file.
     record: Vec<RecordEntry>,
 }
@@ -762,8 +859,10 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> ZipDirectoryWriter<W> {
     const EXECUTABLE_FILE_MODE: u16 = 0o100_755;
     const DIRECTORY_MODE: u16 = 0o040_755;
 
-    fn entry(path: &str, compression: Compression, mode: u16) -> ZipEntryBuilder {
-        ZipEntryBuilder::new(path.to_string().into(), compression).unix_permissions(mode)
+    fn entry(&self, path: &str, compression: Compression, mode: u16) -> ZipEntryBuilder {
+        ZipEntryBuilder::new(path.to_string().into(), compression)
+            .unix_permissions(mode)
+            .last_modification_date(self.timestamp)
     }
 
     /// Add a file with the given name and return a writer for it.
@@ -778,7 +877,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> ZipDirectoryWriter<W> {
         } else {
             Self::REGULAR_FILE_MODE
         };
-        let entry = Self::entry(path, self.compression, mode);
+        let entry = self.entry(path, self.compression, mode);
         let writer = block_on(self.writer.write_entry_seekable(entry))?;
         Ok(EntryWriter::new(writer))
     }
@@ -824,10 +923,11 @@ impl<W: Seek + Unpin> AsyncSeek for SyncWriter<W> {
 
 impl<W: Write + Seek + Unpin> ZipDirectoryWriter<SyncWriter<W>> {
     /// A wheel writer with deflate compression.
-    fn new_wheel(writer: W) -> Self {
+    fn new_wheel(writer: W, source_date_epoch: Option<u64>) -> Self {
         Self {
             writer: ZipFileWriter::new(SyncWriter::new(writer)),
             compression: Compression::Deflate,
+            timestamp: zip_timestamp(source_date_epoch),
             record: Vec::new(),
         }
     }
@@ -840,11 +940,56 @@ impl<W: Write + Seek + Unpin> ZipDirectoryWriter<SyncWriter<W>> {
         Self {
             writer: ZipFileWriter::new(SyncWriter::new(writer)),
             compression: Compression::Stored,
+            timestamp: ZipDateTime::default(),
             record: Vec::new(),
         }
     }
 }
 
+/// Convert `SOURCE_DATE_EPOCH` to the MS-DOS date and time stored in ZIP archives.
+///
+/// The format can only represent the years 1980 to 2107 with a granularity of two seconds, so
+/// timestamps outside that range are clamped. Without a timestamp, we use the earliest date,
+/// 1980-01-01.
+fn zip_timestamp(source_date_epoch: Option<u64>) -> ZipDateTime {
+    /// 1980-01-01T00:00:00Z
+    const MIN: u64 = 315_532_800;
+    /// 2107-12-31T23:59:58Z
+    const MAX: u64 = 4_354_819_198;
+
+    let Some(epoch) = source_date_epoch else {
+        return ZipDateTime::default();
+    };
+    let epoch = epoch.clamp(MIN, MAX);
+    let (days, seconds) = (epoch / 86_400, epoch % 86_400);
+
+    // Convert days since the Unix epoch to a civil date, see
+    // <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    ZipDateTimeBuilder::new()
+        .year(i32::try_from(year).expect("year is clamped"))
+        .month(u32::try_from(month).expect("month is at most 12"))
+        .day(u32::try_from(day).expect("day is at most 31"))
+        .hour(u32::try_from(seconds / 3600).expect("hour is at most 23"))
+        .minute(u32::try_from(seconds % 3600 / 60).expect("minute is at most 59"))
+        .second(u32::try_from(seconds % 60).expect("second is at most 59"))
+        .build()
+}
+
 struct EntryWriter<'writer, W: AsyncWrite + AsyncSeek + Unpin> {
     writer: Option<EntrySeekableWriter<'writer, W>>,
 }
@@ -887,7 +1032,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         trace!("Adding {}", path);
         // Set appropriate permissions for metadata files (644 = rw-r--r--)
-        let entry = Self::entry(path, self.compression, Self::REGULAR_FILE_MODE);
+        let entry = self.entry(path, self.compression, Self::REGULAR_FILE_MODE);
         block_on(self.writer.write_entry_whole(entry, bytes))?;
 
         let hash = base64.encode(Sha256::new().chain_update(bytes).finalize());
@@ -920,7 +1065,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
 
         if metadata.len() <= WHOLE_FILE_ZIP_ENTRY_LIMIT {
             let bytes = fs_err::read(file)?;
-            let entry = Self::entry(path, self.compression, mode);
+            let entry = self.entry(path, self.compression, mode);
             block_on(self.writer.write_entry_whole(entry, &bytes))?;
 
             let hash = base64.encode(Sha256::new().chain_update(&bytes).finalize());
@@ -946,7 +1091,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
         } else {
             format!("{directory}/")
         };
-        let entry = Self::entry(&directory, Compression::Stored, Self::DIRECTORY_MODE);
+        let entry = self.entry(&directory, Compression::Stored, Self::DIRECTORY_MODE);
         block_on(self.writer.write_entry_whole(entry, &[]))?;
         Ok(())
     }
@@ -958,7 +1103,7 @@ impl<W: AsyncWrite + AsyncSeek + Unpin> DirectoryWriter for ZipDirectoryWriter<W
         let record = mem::take(&mut self.record);
         let mut record_bytes = Vec::new();
         write_record(&mut record_bytes, dist_info_dir, record)?;
-        let entry = Self::entry(&record_path, self.compression, Self::REGULAR_FILE_MODE);
+        let entry = self.entry(&record_path, self.compression, Self::REGULAR_FILE_MODE);
         block_on(self.writer.write_entry_whole(entry, &record_bytes))?;
 
         trace!("Adding central directory");
@@ -1081,6 +1226,32 @@ mod test {
         ");
     }
 
+    #[test]
+    fn test_zip_timestamp() {
+        let format = |timestamp: ZipDateTime| {
+            format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                timestamp.year(),
+                timestamp.month(),
+                timestamp.day(),
+                timestamp.hour(),
+                timestamp.minute(),
+                timestamp.second()
+            )
+        };
+        assert_eq!(format(zip_timestamp(None)), "1980-01-01T00:00:00");
+        assert_eq!(format(zip_timestamp(Some(0))), "1980-01-01T00:00:00");
+        assert_eq!(
+            format(zip_timestamp(Some(1_700_000_001))),
+            "2023-11-14T22:13:20"
+        );
+        assert_eq!(
+            format(zip_timestamp(Some(951_782_400))),
+            "2000-02-29T00:00:00"
+        );
+        assert_eq!(format(zip_timestamp(Some(u64::MAX))), "2107-12-31T23:59:58");
+    }
+
     /// Snapshot all files from the prepare metadata hook.
     #[test]
     fn test_prepare_metadata() {
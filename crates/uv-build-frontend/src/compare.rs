@@ -0,0 +1,337 @@
+//! Compare two builds of the same distribution, to verify that a build is reproducible.
+//!
+//! Comparing the archives member-by-member pinpoints the cause when two builds differ, e.g., an
+//! embedded timestamp, a file with different permissions, or members added in a different order.
+
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+
+use fs_err::tokio::File;
+use futures::StreamExt;
+use rustc_hash::FxHashMap;
+use thiserror::Error;
+use tokio::io::{AsyncRead, BufReader};
+use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
+use tracing::debug;
+
+use uv_distribution_filename::{DistFilename, SourceDistExtension};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+/// An error while reading the distributions to compare.
+#[derive(Error, Debug)]
+pub enum CompareError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to read wheel")]
+    Zip(#[source] async_zip::error::ZipError),
+    #[error("Failed to read: `{0}`")]
+    ZipRead(String, #[source] async_zip::error::ZipError),
+    #[error("Failed to read source distribution")]
+    TokioTar(#[source] io::Error),
+}
+
+/// A difference between two builds of the same distribution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// The archive member that differs, or `None` if the difference is in the archive itself.
+    pub path: Option<String>,
+    pub message: String,
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "`{path}`: {}", self.message)
+        } else {
+            f.write_str(&self.message)
+        }
+    }
+}
+
+/// The properties of an archive member that must be identical between two builds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ArchiveMember {
+    path: String,
+    /// The SHA-256 hash of the contents, or `None` for directories.
+    sha256: Option<String>,
+    /// The Unix permissions, if the archive records them.
+    mode: Option<u32>,
+    /// The modification time, as recorded in the archive.
+    mtime: String,
+}
+
+/// Compare two builds of the same wheel or source distribution.
+///
+/// Returns the differences in the contents, permissions, modification times, and order of the
+/// archive members. Archives that are byte-for-byte identical have no differences.
+pub async fn compare_distributions(
+    first: &Path,
+    second: &Path,
+    filename: &DistFilename,
+) -> Result<Vec<Difference>, CompareError> {
+    debug!("Comparing {} to {}", first.display(), second.display());
+    if sha256(File::open(first).await?).await? == sha256(File::open(second).await?).await? {
+        return Ok(Vec::new());
+    }
+
+    let (first_members, second_members) = match filename {
+        DistFilename::WheelFilename(_) => {
+            (wheel_members(first).await?, wheel_members(second).await?)
+        }
+        DistFilename::SourceDistFilename(source_dist)
+            if source_dist.extension == SourceDistExtension::TarGz =>
+        {
+            (
+                source_dist_members(first).await?,
+                source_dist_members(second).await?,
+            )
+        }
+        DistFilename::SourceDistFilename(_) => {
+            return Ok(vec![Difference {
+                path: None,
+                message: "The archives differ".to_string(),
+            }]);
+        }
+    };
+
+    let mut differences = compare_members(&first_members, &second_members);
+    if differences.is_empty() {
+        differences.push(Difference {
+            path: None,
+            message: "The archives differ in their compression or archive metadata, but their \
+                members are identical"
+                .to_string(),
+        });
+    }
+    Ok(differences)
+}
+
+/// Compare the members of two archives, in the order of the first archive.
+fn compare_members(first: &[ArchiveMember], second: &[ArchiveMember]) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    let second_by_path: FxHashMap<&str, &ArchiveMember> = second
+        .iter()
+        .map(|member| (member.path.as_str(), member))
+        .collect();
+    let first_by_path: FxHashMap<&str, &ArchiveMember> = first
+        .iter()
+        .map(|member| (member.path.as_str(), member))
+        .collect();
+
+    for member in first {
+        let Some(other) = second_by_path.get(member.path.as_str()) else {
+            differences.push(Difference {
+                path: Some(member.path.clone()),
+                message: "only in the first build".to_string(),
+            });
+            continue;
+        };
+        if member.sha256 != other.sha256 {
+            differences.push(Difference {
+                path: Some(member.path.clone()),
+                message: "the contents differ".to_string(),
+            });
+        }
+        if member.mode != other.mode {
+            let format_mode = |mode: Option<u32>| {
+                mode.map_or_else(|| "none".to_string(), |mode| format!("{mode:o}"))
+            };
+            differences.push(Difference {
+                path: Some(member.path.clone()),
+                message: format!(
+                    "the permissions differ: {} and {}",
+                    format_mode(member.mode),
+                    format_mode(other.mode)
+                ),
+            });
+        }
+        if member.mtime != other.mtime {
+            differences.push(Difference {
+                path: Some(member.path.clone()),
+                message: format!(
+                    "the modification times differ: {} and {}",
+                    member.mtime, other.mtime
+                ),
+            });
+        }
+    }
+    for member in second {
+        if !first_by_path.contains_key(member.path.as_str()) {
+            differences.push(Difference {
+                path: Some(member.path.clone()),
+                message: "only in the second build".to_string(),
+            });
+        }
+    }
+
+    // Compare the order of the members that are in both archives.
+    let first_order = first
+        .iter()
+        .filter(|member| second_by_path.contains_key(member.path.as_str()))
+        .map(|member| member.path.as_str());
+    let second_order = second
+        .iter()
+        .filter(|member| first_by_path.contains_key(member.path.as_str()))
+        .map(|member| member.path.as_str());
+    if !first_order.eq(second_order) {
+        differences.push(Difference {
+            path: None,
+            message: "The archive members are in a different order".to_string(),
+        });
+    }
+
+    differences
+}
+
+/// The hex-encoded SHA-256 hash of a reader.
+async fn sha256(reader: impl AsyncRead + Unpin) -> Result<String, CompareError> {
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    HashReader::new(reader, &mut hashers).finish().await?;
+    Ok(HashDigest::from(hashers.remove(0)).digest.to_string())
+}
+
+/// Read the members of a wheel, in the order of the central directory.
+async fn wheel_members(file: &Path) -> Result<Vec<ArchiveMember>, CompareError> {
+    let reader = futures::io::BufReader::new(File::open(file).await?.compat());
+    let mut zip = async_zip::base::read::seek::ZipFileReader::new(reader)
+        .await
+        .map_err(CompareError::Zip)?;
+
+    let entries = zip
+        .file()
+        .entries()
+        .iter()
+        .map(|entry| {
+            let timestamp = entry.last_modification_date();
+            Ok((
+                String::from_utf8_lossy(entry.filename().as_bytes()).to_string(),
+                entry.dir().map_err(CompareError::Zip)?,
+                entry.unix_permissions().map(u32::from),
+                format!(
+                    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                    timestamp.year(),
+                    timestamp.month(),
+                    timestamp.day(),
+                    timestamp.hour(),
+                    timestamp.minute(),
+                    timestamp.second()
+                ),
+            ))
+        })
+        .collect::<Result<Vec<_>, CompareError>>()?;
+
+    let mut members = Vec::with_capacity(entries.len());
+    for (index, (path, is_dir, mode, mtime)) in entries.into_iter().enumerate() {
+        let sha256 = if is_dir {
+            None
+        } else {
+            let reader = zip
+                .reader_with_entry(index)
+                .await
+                .map_err(|err| CompareError::ZipRead(path.clone(), err))?;
+            Some(sha256(reader.compat()).await?)
+        };
+        members.push(ArchiveMember {
+            path,
+            sha256,
+            mode,
+            mtime,
+        });
+    }
+    Ok(members)
+}
+
+/// Read the members of a `.tar.gz` source distribution, in archive order.
+///
+/// Unlike tar-codec, tokio-tar exposes the modification time and permissions of the members, so
+/// it's used regardless of the tar backend.
+async fn source_dist_members(file: &Path) -> Result<Vec<ArchiveMember>, CompareError> {
+    let reader = BufReader::new(File::open(&file).await?);
+    let decoded = async_compression::tokio::bufread::GzipDecoder::new(reader);
+    let mut archive = tokio_tar::Archive::new(decoded);
+    let mut entries = archive.entries().map_err(CompareError::TokioTar)?;
+
+    let mut members = Vec::new();
+    while let Some(mut entry) = entries
+        .next()
+        .await
+        .transpose()
+        .map_err(CompareError::TokioTar)?
+    {
+        let path = entry
+            .path()
+            .map_err(CompareError::TokioTar)?
+            .to_string_lossy()
+            .to_string();
+        let header = entry.header();
+        let mode = header.mode().ok();
+        let mtime = header
+            .mtime()
+            .map_or_else(|_| "invalid".to_string(), |mtime| mtime.to_string());
+        let sha256 = if header.entry_type().is_dir() {
+            None
+        } else {
+            // We have to read while iterating or the entry is empty as we're beyond it in the file.
+            Some(sha256(&mut entry).await?)
+        };
+        members.push(ArchiveMember {
+            path,
+            sha256,
+            mode,
+            mtime,
+        });
+    }
+    Ok(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+    use itertools::Itertools;
+
+    use super::{ArchiveMember, compare_members};
+
+    fn member(path: &str, sha256: &str, mode: u32, mtime: &str) -> ArchiveMember {
+        ArchiveMember {
+            path: path.to_string(),
+            sha256: Some(sha256.to_string()),
+            mode: Some(mode),
+            mtime: mtime.to_string(),
+        }
+    }
+
+    #[test]
+    fn identical_members() {
+        let members = [
+            member("example/__init__.py", "a", 0o644, "0"),
+            member("example/cli.py", "b", 0o755, "0"),
+        ];
+        assert!(compare_members(&members, &members).is_empty());
+    }
+
+    #[test]
+    fn differing_members() {
+        let first = [
+            member("example/__init__.py", "a", 0o644, "0"),
+            member("example/_version.py", "b", 0o644, "1700000000"),
+            member("example/cli.py", "c", 0o644, "0"),
+            member("example/old.py", "d", 0o644, "0"),
+        ];
+        let second = [
+            member("example/cli.py", "c", 0o755, "0"),
+            member("example/__init__.py", "a", 0o644, "0"),
+            member("example/_version.py", "e", 0o644, "1700000042"),
+            member("example/new.py", "d", 0o644, "0"),
+        ];
+        assert_snapshot!(compare_members(&first, &second).iter().join("\n"), @"
+        `example/_version.py`: the contents differ
+        `example/_version.py`: the modification times differ: 1700000000 and 1700000042
+        `example/cli.py`: the permissions differ: 644 and 755
+        `example/old.py`: only in the first build
+        `example/new.py`: only in the second build
+        The archive members are in a different order
+        ");
+    }
+}
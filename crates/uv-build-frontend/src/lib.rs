@@ -2,8 +2,10 @@
 //!
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
+mod compare;
 mod error;
 mod pipreqs;
+mod repair;
 
 use std::borrow::Cow;
 use std::ffi::OsString;
@@ -48,7 +50,9 @@ use uv_types::{
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
+pub use crate::compare::{CompareError, Difference, compare_distributions};
 pub use crate::error::{Error, MissingHeaderCause};
+pub use crate::repair::{RepairError, RepairedWheel, repair_wheel};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
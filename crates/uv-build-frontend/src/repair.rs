@@ -0,0 +1,863 @@
+//! Repair Linux wheels that link against shared libraries from the build machine.
+//!
+//! Wheels built on Linux are tagged `linux_<arch>` and may depend on shared libraries that are
+//! only installed on the build machine. Like `auditwheel repair`, we copy these libraries into a
+//! `<name>.libs` directory in the wheel, point the native modules at the copies with `patchelf`,
+//! and retag the wheel with the `manylinux` or `musllinux` tag matching the newest glibc symbol
+//! versions it requires.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::env;
+use std::ffi::OsStr;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use goblin::elf::Elf;
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_distribution_filename::{WheelFilename, WheelFilenameError};
+use uv_fs::Simplified;
+use uv_platform_tags::{Arch, PlatformTag};
+use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
+
+/// The shared libraries that every `manylinux` system provides, from
+/// [PEP 599](https://peps.python.org/pep-0599/#the-manylinux2014-policy), which are never
+/// vendored.
+const SYSTEM_LIBRARIES: &[&str] = &[
+    "libgcc_s.so.1",
+    "libstdc++.so.6",
+    "libm.so.6",
+    "libdl.so.2",
+    "librt.so.1",
+    "libc.so.6",
+    "libnsl.so.1",
+    "libutil.so.1",
+    "libpthread.so.0",
+    "libresolv.so.2",
+    "libX11.so.6",
+    "libXext.so.6",
+    "libXrender.so.1",
+    "libICE.so.6",
+    "libSM.so.6",
+    "libGL.so.1",
+    "libgobject-2.0.so.0",
+    "libgthread-2.0.so.0",
+    "libglib-2.0.so.0",
+];
+
+/// The newest symbol versions of the GCC runtime libraries that each `manylinux` policy provides,
+/// from the toolchain of its reference distribution, e.g., `CentOS 7` for `manylinux_2_17`.
+///
+/// Unlike glibc, these libraries are not versioned with the policy, so a wheel that requires a
+/// newer `libstdc++` than the policy's glibc version implies needs a newer policy.
+const MANYLINUX_POLICIES: &[ManylinuxPolicy] = &[
+    ManylinuxPolicy {
+        glibc_minor: 5,
+        glibcxx: &[3, 4, 8],
+        cxxabi: &[1, 3, 1],
+        gcc: &[4, 2, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 12,
+        glibcxx: &[3, 4, 13],
+        cxxabi: &[1, 3, 3],
+        gcc: &[4, 3, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 17,
+        glibcxx: &[3, 4, 19],
+        cxxabi: &[1, 3, 7],
+        gcc: &[4, 8, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 24,
+        glibcxx: &[3, 4, 22],
+        cxxabi: &[1, 3, 10],
+        gcc: &[4, 8, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 28,
+        glibcxx: &[3, 4, 25],
+        cxxabi: &[1, 3, 11],
+        gcc: &[7, 0, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 31,
+        glibcxx: &[3, 4, 28],
+        cxxabi: &[1, 3, 12],
+        gcc: &[7, 0, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 34,
+        glibcxx: &[3, 4, 29],
+        cxxabi: &[1, 3, 13],
+        gcc: &[7, 0, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 35,
+        glibcxx: &[3, 4, 30],
+        cxxabi: &[1, 3, 13],
+        gcc: &[12, 0, 0],
+    },
+    ManylinuxPolicy {
+        glibc_minor: 39,
+        glibcxx: &[3, 4, 33],
+        cxxabi: &[1, 3, 15],
+        gcc: &[14, 0, 0],
+    },
+];
+
+/// The directories the dynamic linker searches last.
+const DEFAULT_LIBRARY_DIRS: &[&str] = &["/lib64", "/usr/lib64", "/lib", "/usr/lib"];
+
+#[derive(Debug, Error)]
+pub enum RepairError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Extract(#[from] uv_extract::Error),
+    #[error("Failed to write the repaired wheel")]
+    Pack(#[from] uv_build_backend::Error),
+    #[error("The wheel has an invalid filename: `{0}`")]
+    InvalidFilename(String, #[source] WheelFilenameError),
+    #[error("Failed to parse ELF file: {}", _0.user_display())]
+    Elf(PathBuf, #[source] goblin::error::Error),
+    #[error("Failed to find shared library `{library}`, which is required by `{dependent}`")]
+    MissingLibrary { library: String, dependent: String },
+    #[error("There is no `manylinux` policy for the architecture `{0}`")]
+    UnsupportedArch(&'static str),
+    #[error(
+        "The wheel requires the symbol version `{0}`, which is newer than any `manylinux` policy provides"
+    )]
+    UnsupportedSymbolVersion(String),
+    #[error("The wheel is missing a `WHEEL` file")]
+    MissingWheelFile,
+    #[error(
+        "Failed to run `patchelf`, which is required to repair wheels (install it with `uv tool install patchelf`)"
+    )]
+    PatchelfSpawn(#[source] io::Error),
+    #[error("`patchelf {0}` failed:\n{1}")]
+    PatchelfFailed(String, String),
+}
+
+/// A repaired wheel.
+#[derive(Debug)]
+pub struct RepairedWheel {
+    /// The filename of the repaired wheel, which replaced the original wheel.
+    pub filename: WheelFilename,
+    /// The shared libraries that were copied into the wheel.
+    pub vendored: Vec<String>,
+}
+
+/// The newest GCC runtime symbol versions of a `manylinux` policy.
+#[derive(Debug)]
+struct ManylinuxPolicy {
+    glibc_minor: u16,
+    /// The newest `GLIBCXX_` version, from `libstdc++`.
+    glibcxx: &'static [u64],
+    /// The newest `CXXABI_` version, from `libstdc++`.
+    cxxabi: &'static [u64],
+    /// The newest `GCC_` version, from `libgcc_s`.
+    gcc: &'static [u64],
+}
+
+/// The C library the native modules of a wheel link against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Libc {
+    Glibc,
+    Musl,
+}
+
+/// The changes to a native module or vendored library in the wheel.
+#[derive(Debug)]
+struct ElfPatch {
+    path: PathBuf,
+    /// The `DT_NEEDED` entries to replace with the vendored libraries.
+    replacements: Vec<(String, String)>,
+    /// The new `DT_SONAME` of a vendored library.
+    soname: Option<String>,
+    /// The search path entries relative to the file, which are kept.
+    origin_rpaths: Vec<String>,
+}
+
+/// Repair a Linux wheel, replacing it with a wheel that includes its shared library dependencies
+/// and has a `manylinux` or `musllinux` tag.
+///
+/// Returns `None` if the wheel doesn't need repairs, e.g., because it is pure Python.
+pub fn repair_wheel(wheel: &Path) -> Result<Option<RepairedWheel>, RepairError> {
+    let raw_filename = wheel
+        .file_name()
+        .and_then(OsStr::to_str)
+        .unwrap_or_default();
+    let filename = WheelFilename::from_str(raw_filename)
+        .map_err(|err| RepairError::InvalidFilename(raw_filename.to_string(), err))?;
+
+    if filename.platform_tags().iter().all(PlatformTag::is_any) {
+        debug!("Skipping repair of pure Python wheel: {filename}");
+        return Ok(None);
+    }
+    let Some(arch) = filename
+        .platform_tags()
+        .iter()
+        .map(|tag| match tag {
+            PlatformTag::Linux { arch } => Some(*arch),
+            _ => None,
+        })
+        .all_equal_value()
+        .ok()
+        .flatten()
+    else {
+        warn_user_once!("Only `linux` wheels can be repaired, skipping: `{filename}`");
+        return Ok(None);
+    };
+    let Some(minimum_minor) = arch.get_minimum_manylinux_minor() else {
+        return Err(RepairError::UnsupportedArch(arch.name()));
+    };
+
+    let wheel_dir = wheel.parent().unwrap_or_else(|| Path::new("."));
+    let temp_dir = tempfile::tempdir_in(wheel_dir)?;
+    let root = temp_dir.path();
+    uv_extract::unzip(fs_err::File::open(wheel)?, root)?;
+
+    let native_modules = find_elf_files(root)?;
+    if native_modules.is_empty() {
+        debug!("Skipping repair of wheel without native modules: {filename}");
+        return Ok(None);
+    }
+
+    let libs_name = format!("{}.libs", filename.name.as_dist_info_name());
+    let libs_dir = root.join(&libs_name);
+
+    // Vendor the dependencies of the native modules, and then the dependencies of the vendored
+    // libraries, breadth-first.
+    let mut vendored: BTreeMap<String, String> = BTreeMap::new();
+    let mut patches = Vec::new();
+    let mut libc = Libc::Glibc;
+    let mut glibc_minor = minimum_minor;
+    let mut queue: VecDeque<(PathBuf, Option<String>)> = native_modules
+        .into_iter()
+        .map(|path| (path, None))
+        .collect();
+    while let Some((path, soname)) = queue.pop_front() {
+        let bytes = fs_err::read(&path)?;
+        let elf = Elf::parse(&bytes).map_err(|err| RepairError::Elf(path.clone(), err))?;
+        let origin = path.parent().unwrap_or(root);
+
+        for version in symbol_versions(&elf) {
+            if let Some(minor) = required_glibc_minor(version)? {
+                glibc_minor = glibc_minor.max(minor);
+            }
+        }
+
+        let mut replacements = Vec::new();
+        for library in &elf.libraries {
+            if is_musl_library(library) {
+                libc = Libc::Musl;
+            }
+            if is_system_library(library) {
+                continue;
+            }
+            if let Some(name) = vendored.get(*library) {
+                replacements.push(((*library).to_string(), name.clone()));
+                continue;
+            }
+            let Some(source) = find_library(library, &elf, origin) else {
+                return Err(RepairError::MissingLibrary {
+                    library: (*library).to_string(),
+                    dependent: relative_display(&path, root),
+                });
+            };
+            if source.starts_with(root) {
+                debug!("Found `{library}` in the wheel");
+                continue;
+            }
+
+            let name = hashed_name(library, &source)?;
+            debug!(
+                "Vendoring `{library}` from `{}` as `{libs_name}/{name}`",
+                source.user_display()
+            );
+            fs_err::create_dir_all(&libs_dir)?;
+            let target = libs_dir.join(&name);
+            fs_err::copy(&source, &target)?;
+            // Libraries may be installed read-only, but we need to patch the copy.
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                fs_err::set_permissions(&target, std::fs::Permissions::from_mode(0o755))?;
+            }
+            vendored.insert((*library).to_string(), name.clone());
+            replacements.push(((*library).to_string(), name.clone()));
+            queue.push_back((target, Some(name)));
+        }
+
+        if !replacements.is_empty() || soname.is_some() {
+            let origin_rpaths = elf
+                .runpaths
+                .iter()
+                .chain(&elf.rpaths)
+                .flat_map(|rpath| rpath.split(':'))
+                .filter(|entry| entry.starts_with("$ORIGIN") || entry.starts_with("${ORIGIN}"))
+                .map(ToString::to_string)
+                .collect();
+            patches.push(ElfPatch {
+                path,
+                replacements,
+                soname,
+                origin_rpaths,
+            });
+        }
+    }
+
+    for patch in &patches {
+        patch_elf(patch, &libs_dir)?;
+    }
+
+    let platform_tags = match libc {
+        Libc::Glibc => manylinux_tags(arch, glibc_minor),
+        // musl has no symbol versions, and musllinux_1_2 is the oldest supported baseline.
+        Libc::Musl => vec![PlatformTag::Musllinux {
+            major: 1,
+            minor: 2,
+            arch,
+        }],
+    };
+    let repaired_filename = retag(&filename, &platform_tags)?;
+    debug!("Retagging `{filename}` as `{repaired_filename}`");
+
+    let dist_info_dir = root.join(format!(
+        "{}-{}.dist-info",
+        filename.name.as_dist_info_name(),
+        filename.version
+    ));
+    let wheel_file = dist_info_dir.join("WHEEL");
+    let contents = match fs_err::read_to_string(&wheel_file) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(RepairError::MissingWheelFile);
+        }
+        Err(err) => return Err(err.into()),
+    };
+    fs_err::write(&wheel_file, retag_wheel_file(&contents, &platform_tags))?;
+
+    uv_build_backend::pack_wheel(root, wheel_dir, &repaired_filename)?;
+    fs_err::remove_file(wheel)?;
+
+    Ok(Some(RepairedWheel {
+        filename: repaired_filename,
+        vendored: vendored.into_keys().collect(),
+    }))
+}
+
+/// Find the ELF files in the unpacked wheel, i.e., the native modules and bundled libraries.
+fn find_elf_files(root: &Path) -> Result<Vec<PathBuf>, io::Error> {
+    let mut elf_files = Vec::new();
+    for entry in WalkDir::new(root).sort_by_file_name() {
+        let entry = entry.map_err(io::Error::from)?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let mut magic = [0u8; 4];
+        let mut file = fs_err::File::open(entry.path())?;
+        if file.read_exact(&mut magic).is_ok() && magic == *b"\x7fELF" {
+            elf_files.push(entry.into_path());
+        }
+    }
+    Ok(elf_files)
+}
+
+/// Whether the library is provided by every system with the wheel's libc.
+fn is_system_library(library: &str) -> bool {
+    SYSTEM_LIBRARIES.contains(&library)
+        || is_musl_library(library)
+        // The dynamic linker, e.g., `ld-linux-x86-64.so.2` or `ld64.so.2`.
+        || library.starts_with("ld-linux")
+        || library.starts_with("ld64.so")
+        // Extension modules must use the interpreter's `libpython`.
+        || library.starts_with("libpython")
+}
+
+/// Whether the library is the musl libc or its dynamic linker.
+fn is_musl_library(library: &str) -> bool {
+    library == "libc.so" || library.starts_with("libc.musl-") || library.starts_with("ld-musl-")
+}
+
+/// The symbol versions the ELF file requires, e.g., `GLIBC_2.17` or `GLIBCXX_3.4.19`.
+fn symbol_versions<'a>(elf: &'a Elf<'a>) -> impl Iterator<Item = &'a str> + 'a {
+    elf.verneed
+        .iter()
+        .flat_map(goblin::elf::VerneedSection::iter)
+        .flat_map(|need| need.iter().collect::<Vec<_>>())
+        .filter_map(|aux| elf.dynstrtab.get_at(aux.vna_name))
+}
+
+/// The glibc minor version of the oldest `manylinux` policy that provides a symbol version.
+///
+/// Returns `None` for symbol versions that don't constrain the policy.
+fn required_glibc_minor(version: &str) -> Result<Option<u16>, RepairError> {
+    if let Some(minor) = parse_glibc_version(version) {
+        return Ok(Some(minor));
+    }
+    let (limit, number): (fn(&ManylinuxPolicy) -> &[u64], _) =
+        if let Some(number) = version.strip_prefix("GLIBCXX_") {
+            (|policy| policy.glibcxx, number)
+        } else if let Some(number) = version.strip_prefix("CXXABI_") {
+            (|policy| policy.cxxabi, number)
+        } else if let Some(number) = version.strip_prefix("GCC_") {
+            (|policy| policy.gcc, number)
+        } else {
+            return Ok(None);
+        };
+    // Skip versions that aren't numeric, e.g., `CXXABI_TM_1`.
+    let Ok(number) = number
+        .split('.')
+        .map(str::parse::<u64>)
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return Ok(None);
+    };
+    MANYLINUX_POLICIES
+        .iter()
+        .find(|policy| number.as_slice() <= limit(policy))
+        .map(|policy| Some(policy.glibc_minor))
+        .ok_or_else(|| RepairError::UnsupportedSymbolVersion(version.to_string()))
+}
+
+/// Parse a glibc symbol version, e.g., `GLIBC_2.2.5`, into the minor version.
+fn parse_glibc_version(version: &str) -> Option<u16> {
+    let mut segments = version.strip_prefix("GLIBC_")?.split('.');
+    if segments.next()? != "2" {
+        return None;
+    }
+    segments.next()?.parse().ok()
+}
+
+/// Find a shared library like the dynamic linker does.
+fn find_library(library: &str, elf: &Elf, origin: &Path) -> Option<PathBuf> {
+    if library.contains('/') {
+        let path = PathBuf::from(library);
+        return path.is_file().then_some(path);
+    }
+
+    let search_path = |entries: &[&str]| -> Vec<PathBuf> {
+        entries
+            .iter()
+            .flat_map(|entry| entry.split(':'))
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                PathBuf::from(
+                    entry
+                        .replace("${ORIGIN}", &origin.to_string_lossy())
+                        .replace("$ORIGIN", &origin.to_string_lossy()),
+                )
+            })
+            .collect()
+    };
+
+    let mut dirs = Vec::new();
+    // `DT_RPATH` is ignored if there is a `DT_RUNPATH`.
+    if elf.runpaths.is_empty() {
+        dirs.extend(search_path(&elf.rpaths));
+    }
+    if let Some(ld_library_path) = env::var_os(EnvVars::LD_LIBRARY_PATH) {
+        dirs.extend(env::split_paths(&ld_library_path));
+    }
+    dirs.extend(search_path(&elf.runpaths));
+    dirs.extend(ld_so_conf_dirs());
+    dirs.extend(DEFAULT_LIBRARY_DIRS.iter().map(PathBuf::from));
+
+    dirs.into_iter()
+        .map(|dir| dir.join(library))
+        .find(|candidate| is_compatible_library(candidate, elf))
+}
+
+/// The library directories configured in `/etc/ld.so.conf` and `/etc/ld.so.conf.d`.
+fn ld_so_conf_dirs() -> Vec<PathBuf> {
+    let mut conf_files = vec![PathBuf::from("/etc/ld.so.conf")];
+    if let Ok(entries) = fs_err::read_dir("/etc/ld.so.conf.d") {
+        conf_files.extend(
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "conf"))
+                .sorted(),
+        );
+    }
+    conf_files
+        .iter()
+        .filter_map(|conf_file| fs_err::read_to_string(conf_file).ok())
+        .flat_map(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                // `include` directives are covered by reading `/etc/ld.so.conf.d`.
+                .filter(|line| {
+                    !line.is_empty() && !line.starts_with('#') && !line.starts_with("include ")
+                })
+                .map(PathBuf::from)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Whether the candidate is a shared library for the same architecture as the dependent.
+fn is_compatible_library(candidate: &Path, dependent: &Elf) -> bool {
+    let Ok(bytes) = fs_err::read(candidate) else {
+        return false;
+    };
+    let Ok(elf) = Elf::parse(&bytes) else {
+        return false;
+    };
+    elf.is_64 == dependent.is_64 && elf.header.e_machine == dependent.header.e_machine
+}
+
+/// The filename of a vendored library, with a hash of its contents to avoid conflicts with other
+/// wheels that vendor a different build of the same library, e.g., `libfoo-1a2b3c4d.so.1`.
+fn hashed_name(library: &str, source: &Path) -> Result<String, io::Error> {
+    let digest = hex::encode(Sha256::digest(fs_err::read(source)?));
+    let (stem, suffix) = match library.find(".so") {
+        Some(index) => library.split_at(index),
+        None => (library, ""),
+    };
+    Ok(format!("{stem}-{}{suffix}", &digest[..8]))
+}
+
+/// Rewrite the dependencies, soname, and search path of an ELF file with `patchelf`.
+fn patch_elf(patch: &ElfPatch, libs_dir: &Path) -> Result<(), RepairError> {
+    let mut args = Vec::new();
+    for (old, new) in &patch.replacements {
+        args.extend(["--replace-needed".to_string(), old.clone(), new.clone()]);
+    }
+    if let Some(soname) = &patch.soname {
+        args.extend(["--set-soname".to_string(), soname.clone()]);
+    }
+    if !patch.replacements.is_empty() {
+        let origin = patch.path.parent().unwrap_or(libs_dir);
+        let relative = uv_fs::relative_to(libs_dir, origin)?;
+        let libs_rpath = if relative.as_os_str().is_empty() {
+            "$ORIGIN".to_string()
+        } else {
+            format!("$ORIGIN/{}", relative.portable_display())
+        };
+        let rpath = std::iter::once(libs_rpath)
+            .chain(patch.origin_rpaths.iter().cloned())
+            .unique()
+            .join(":");
+        // Use `DT_RPATH` over `DT_RUNPATH`, which also applies to the dependencies of the
+        // vendored libraries.
+        args.extend([
+            "--force-rpath".to_string(),
+            "--set-rpath".to_string(),
+            rpath,
+        ]);
+    }
+
+    debug!(
+        "Running `patchelf {}` on `{}`",
+        args.join(" "),
+        patch.path.user_display()
+    );
+    let output = Command::new("patchelf")
+        .args(&args)
+        .arg(&patch.path)
+        .output()
+        .map_err(RepairError::PatchelfSpawn)?;
+    if !output.status.success() {
+        return Err(RepairError::PatchelfFailed(
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// The `manylinux` tags for a glibc version, including the legacy alias from PEP 513, PEP 571,
+/// or PEP 599, if there is one.
+fn manylinux_tags(arch: Arch, minor: u16) -> Vec<PlatformTag> {
+    let mut tags = vec![PlatformTag::Manylinux {
+        major: 2,
+        minor,
+        arch,
+    }];
+    let legacy_x86 = matches!(arch, Arch::X86 | Arch::X86_64);
+    match minor {
+        5 if legacy_x86 => tags.push(PlatformTag::Manylinux1 { arch }),
+        12 if legacy_x86 => tags.push(PlatformTag::Manylinux2010 { arch }),
+        17 if legacy_x86
+            || matches!(
+                arch,
+                Arch::Aarch64 | Arch::Armv7L | Arch::Powerpc64 | Arch::Powerpc64Le | Arch::S390X
+            ) =>
+        {
+            tags.push(PlatformTag::Manylinux2014 { arch });
+        }
+        _ => {}
+    }
+    tags
+}
+
+/// The wheel filename with the platform tags replaced.
+fn retag(
+    filename: &WheelFilename,
+    platform_tags: &[PlatformTag],
+) -> Result<WheelFilename, RepairError> {
+    let build_tag = filename
+        .build_tag()
+        .map(|build_tag| format!("-{build_tag}"))
+        .unwrap_or_default();
+    let repaired = format!(
+        "{}-{}{build_tag}-{}-{}-{}.whl",
+        filename.name.as_dist_info_name(),
+        filename.version,
+        filename.python_tags().iter().join("."),
+        filename.abi_tags().iter().join("."),
+        platform_tags.iter().join("."),
+    );
+    WheelFilename::from_str(&repaired).map_err(|err| RepairError::InvalidFilename(repaired, err))
+}
+
+/// Replace the platform in the `Tag` entries of a `WHEEL` file.
+fn retag_wheel_file(contents: &str, platform_tags: &[PlatformTag]) -> String {
+    let mut lines = Vec::new();
+    let mut tagged = Vec::new();
+    for line in contents.lines() {
+        if let Some(tag) = line.strip_prefix("Tag:") {
+            let tag = tag.trim();
+            let prefix = tag
+                .rsplit_once('-')
+                .map_or(tag, |(prefix, _platform)| prefix);
+            if tagged.contains(&prefix) {
+                continue;
+            }
+            tagged.push(prefix);
+            for platform_tag in platform_tags {
+                lines.push(format!("Tag: {prefix}-{platform_tag}"));
+            }
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+/// Display a path in the unpacked wheel relative to the wheel root.
+fn relative_display(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .portable_display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+    use std::str::FromStr;
+
+    use goblin::elf::Elf;
+    use indoc::indoc;
+    use insta::assert_snapshot;
+    use uv_distribution_filename::WheelFilename;
+    use uv_platform_tags::{Arch, PlatformTag};
+
+    use super::{
+        hashed_name, manylinux_tags, parse_glibc_version, repair_wheel, required_glibc_minor,
+        retag, retag_wheel_file, symbol_versions,
+    };
+
+    #[test]
+    fn glibc_version() {
+        assert_eq!(parse_glibc_version("GLIBC_2.17"), Some(17));
+        assert_eq!(parse_glibc_version("GLIBC_2.2.5"), Some(2));
+        assert_eq!(parse_glibc_version("GLIBC_PRIVATE"), None);
+        assert_eq!(parse_glibc_version("GLIBCXX_3.4.21"), None);
+    }
+
+    #[test]
+    fn symbol_version_policy() {
+        assert_eq!(required_glibc_minor("GLIBC_2.28").unwrap(), Some(28));
+        // CentOS 7 ships `libstdc++` from GCC 4.8.
+        assert_eq!(required_glibc_minor("GLIBCXX_3.4.19").unwrap(), Some(17));
+        assert_eq!(required_glibc_minor("GLIBCXX_3.4.20").unwrap(), Some(24));
+        assert_eq!(required_glibc_minor("GLIBCXX_3.4.30").unwrap(), Some(35));
+        assert_eq!(required_glibc_minor("CXXABI_1.3.8").unwrap(), Some(24));
+        assert_eq!(required_glibc_minor("GCC_3.0").unwrap(), Some(5));
+        assert_eq!(required_glibc_minor("GCC_7.0.0").unwrap(), Some(28));
+        assert_eq!(required_glibc_minor("CXXABI_TM_1").unwrap(), None);
+        assert_eq!(required_glibc_minor("GLIBC_PRIVATE").unwrap(), None);
+        assert_snapshot!(
+            required_glibc_minor("GLIBCXX_3.4.99").unwrap_err(),
+            @"The wheel requires the symbol version `GLIBCXX_3.4.99`, which is newer than any `manylinux` policy provides"
+        );
+    }
+
+    #[test]
+    fn retag_manylinux() {
+        let filename = WheelFilename::from_str("foo-1.0-cp312-cp312-linux_x86_64.whl").unwrap();
+        let tags = manylinux_tags(Arch::X86_64, 17);
+        assert_snapshot!(
+            retag(&filename, &tags).unwrap(),
+            @"foo-1.0-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl"
+        );
+        let tags = manylinux_tags(Arch::Aarch64, 28);
+        assert_snapshot!(
+            retag(&filename, &tags).unwrap(),
+            @"foo-1.0-cp312-cp312-manylinux_2_28_aarch64.whl"
+        );
+
+        let wheel_file = "Wheel-Version: 1.0\nGenerator: setuptools\nRoot-Is-Purelib: false\nTag: cp312-cp312-linux_x86_64\n";
+        assert_snapshot!(retag_wheel_file(wheel_file, &manylinux_tags(Arch::X86_64, 17)), @r"
+        Wheel-Version: 1.0
+        Generator: setuptools
+        Root-Is-Purelib: false
+        Tag: cp312-cp312-manylinux_2_17_x86_64
+        Tag: cp312-cp312-manylinux2014_x86_64
+        ");
+    }
+
+    #[test]
+    fn vendored_name() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let library = temp_dir.path().join("libfoo.so.1.2");
+        fs_err::write(&library, "library").unwrap();
+        assert_snapshot!(hashed_name("libfoo.so.1.2", &library).unwrap(), @"libfoo-b718f135.so.1.2");
+    }
+
+    /// Build a wheel with a C++ extension module that links against a library outside the wheel,
+    /// and check that the library is vendored, the module is patched to load it, and the wheel is
+    /// retagged for the newest symbol versions it requires.
+    #[test]
+    #[cfg(target_os = "linux")]
+    #[expect(clippy::print_stderr)]
+    fn repair_vendors_libraries() {
+        if Command::new("patchelf").arg("--version").output().is_err() {
+            eprintln!("Skipping test: `patchelf` is not installed");
+            return;
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let lib_dir = temp_dir.path().join("lib");
+        let unpacked = temp_dir.path().join("unpacked");
+        let dist = temp_dir.path().join("dist");
+        fs_err::create_dir_all(&lib_dir).unwrap();
+        fs_err::create_dir_all(unpacked.join("project")).unwrap();
+        fs_err::create_dir_all(unpacked.join("project-0.1.0.dist-info")).unwrap();
+        fs_err::create_dir_all(&dist).unwrap();
+
+        fs_err::write(
+            temp_dir.path().join("foo.c"),
+            "int foo(void) { return 42; }\n",
+        )
+        .unwrap();
+        let status = Command::new("gcc")
+            .args(["-shared", "-fPIC", "-Wl,-soname,libfoo.so.1", "-o"])
+            .arg(lib_dir.join("libfoo.so.1"))
+            .arg(temp_dir.path().join("foo.c"))
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        fs_err::write(
+            temp_dir.path().join("ext.cpp"),
+            indoc! {r#"
+                #include <string>
+                extern "C" int foo(void);
+                extern "C" int ext(void) { return std::to_string(foo()).size(); }
+            "#},
+        )
+        .unwrap();
+        let status = Command::new("g++")
+            .args(["-shared", "-fPIC", "-o"])
+            .arg(unpacked.join("project").join("_ext.so"))
+            .arg(temp_dir.path().join("ext.cpp"))
+            .arg(format!("-L{}", lib_dir.display()))
+            .arg("-l:libfoo.so.1")
+            .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let arch = std::env::consts::ARCH;
+        fs_err::write(
+            unpacked.join("project-0.1.0.dist-info").join("METADATA"),
+            "Metadata-Version: 2.4\nName: project\nVersion: 0.1.0\n",
+        )
+        .unwrap();
+        fs_err::write(
+            unpacked.join("project-0.1.0.dist-info").join("WHEEL"),
+            format!(
+                "Wheel-Version: 1.0\nGenerator: test\nRoot-Is-Purelib: false\nTag: cp312-cp312-linux_{arch}\n"
+            ),
+        )
+        .unwrap();
+        let filename =
+            WheelFilename::from_str(&format!("project-0.1.0-cp312-cp312-linux_{arch}.whl"))
+                .unwrap();
+        uv_build_backend::pack_wheel(&unpacked, &dist, &filename).unwrap();
+
+        let repaired = repair_wheel(&dist.join(filename.to_string()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(repaired.vendored, ["libfoo.so.1"]);
+        assert!(!dist.join(filename.to_string()).exists());
+
+        // The tag covers the newest glibc and `libstdc++` symbol versions the module requires.
+        let repaired_dir = temp_dir.path().join("repaired");
+        uv_extract::unzip(
+            fs_err::File::open(dist.join(repaired.filename.to_string())).unwrap(),
+            &repaired_dir,
+        )
+        .unwrap();
+        let module = fs_err::read(repaired_dir.join("project").join("_ext.so")).unwrap();
+        let module = Elf::parse(&module).unwrap();
+        let required = symbol_versions(&module)
+            .filter_map(|version| required_glibc_minor(version).unwrap())
+            .max()
+            .unwrap();
+        assert!(
+            symbol_versions(&module).any(|version| version.starts_with("GLIBCXX_")),
+            "Expected the module to require `libstdc++` symbol versions"
+        );
+        let [
+            PlatformTag::Manylinux {
+                major: 2, minor, ..
+            },
+            ..,
+        ] = repaired.filename.platform_tags()
+        else {
+            panic!("Expected a `manylinux` tag: {}", repaired.filename);
+        };
+        assert_eq!(*minor, required);
+        let wheel_file =
+            fs_err::read_to_string(repaired_dir.join("project-0.1.0.dist-info").join("WHEEL"))
+                .unwrap();
+        assert!(wheel_file.contains(&format!("Tag: cp312-cp312-manylinux_2_{minor}_{arch}")));
+        assert!(!wheel_file.contains(&format!("-linux_{arch}")));
+
+        // The module loads the vendored copy, found relative to the module.
+        let libs = fs_err::read_dir(repaired_dir.join("project.libs"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        let [vendored] = libs.as_slice() else {
+            panic!("Expected one vendored library: {libs:?}");
+        };
+        assert!(vendored.starts_with("libfoo-") && vendored.ends_with(".so.1"));
+        assert!(module.libraries.contains(&vendored.as_str()));
+        assert!(!module.libraries.contains(&"libfoo.so.1"));
+        assert_eq!(module.rpaths, ["$ORIGIN/../project.libs"]);
+        assert!(module.runpaths.is_empty());
+
+        let library = fs_err::read(repaired_dir.join("project.libs").join(vendored)).unwrap();
+        let library = Elf::parse(&library).unwrap();
+        assert_eq!(library.soname, Some(vendored.as_str()));
+    }
+}
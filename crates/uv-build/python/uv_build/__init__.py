@@ -34,8 +34,26 @@ def warn_config_settings(config_settings: "Mapping[Any, Any] | None" = None) ->
         print("Warning: Config settings are not supported", file=sys.stderr)
 
 
+def interpreter_env() -> "dict[str, str]":
+    """The environment for building a wheel, including a description of the interpreter.
+
+    The uv build backend uses the description for the tags of wheels with native artifacts.
+    """
+    import os
+    import sys
+    import sysconfig
+
+    version = f"{sys.version_info.major}.{sys.version_info.minor}"
+    if sysconfig.get_config_var("Py_GIL_DISABLED"):
+        version += "t"
+    interpreter = f"{sys.implementation.name} {version} {sysconfig.get_platform()}"
+    return {**os.environ, "UV_INTERNAL__BUILD_BACKEND_INTERPRETER": interpreter}
+
+
 def call(
-    args: "Sequence[str]", config_settings: "Mapping[Any, Any] | None" = None
+    args: "Sequence[str]",
+    config_settings: "Mapping[Any, Any] | None" = None,
+    env: "Mapping[str, str] | None" = None,
 ) -> str:
     """Invoke a uv subprocess and return the filename from stdout."""
     import shutil
@@ -52,7 +70,10 @@ def call(
     build_backend_args = ["build-backend"] if USE_UV_EXECUTABLE else []
     # Forward stderr, capture stdout for the filename
     result = subprocess.run(
-        [uv_bin, *build_backend_args, *args], stdout=subprocess.PIPE, check=False
+        [uv_bin, *build_backend_args, *args],
+        stdout=subprocess.PIPE,
+        check=False,
+        env=env,
     )
     if result.returncode != 0:
         sys.exit(result.returncode)
@@ -86,7 +107,7 @@ def build_wheel(
     args = ["build-wheel", wheel_directory]
     if metadata_directory:
         args.extend([metadata_directory])
-    return call(args, config_settings)
+    return call(args, config_settings, interpreter_env())
 
 
 def get_requires_for_build_sdist(
@@ -110,7 +131,7 @@ def prepare_metadata_for_build_wheel(
 ) -> str:
     """PEP 517 hook `prepare_metadata_for_build_wheel`."""
     args = ["prepare-metadata-for-build-wheel", metadata_directory]
-    return call(args, config_settings)
+    return call(args, config_settings, interpreter_env())
 
 
 def build_editable(
@@ -7,7 +7,7 @@ use uv_fs::find_git_repository_root;
 use walkdir::WalkDir;
 
 #[derive(Debug, thiserror::Error)]
-pub(crate) enum GitInfoError {
+pub enum GitInfoError {
     #[error("The repository at {0} is missing a `.git` directory")]
     MissingGitDir(PathBuf),
     #[error("The repository at {0} is missing a `HEAD` file")]
@@ -26,11 +26,11 @@ pub(crate) enum GitInfoError {
 
 /// The current commit for a repository (i.e., a 40-character hexadecimal string).
 #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
-pub(crate) struct Commit(String);
+pub struct Commit(String);
 
 impl Commit {
     /// Return the [`Commit`] for the repository at the given path.
-    pub(crate) fn from_repository(path: &Path) -> Result<Self, GitInfoError> {
+    pub fn from_repository(path: &Path) -> Result<Self, GitInfoError> {
         let repository = GitRepository::find(path)?;
 
         let git_head_path = repository.git_dir.join("HEAD");
@@ -58,15 +58,20 @@ impl Commit {
 
         Ok(Self(commit))
     }
+
+    /// The full commit hash.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 /// The set of tags visible in a repository.
 #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
-pub(crate) struct Tags(BTreeMap<String, String>);
+pub struct Tags(BTreeMap<String, String>);
 
 impl Tags {
     /// Return the [`Tags`] for the repository at the given path.
-    pub(crate) fn from_repository(path: &Path) -> Result<Self, GitInfoError> {
+    pub fn from_repository(path: &Path) -> Result<Self, GitInfoError> {
         let repository = GitRepository::find(path)?;
         let git_tags_path = repository.common_dir.join("refs").join("tags");
 
@@ -102,6 +107,15 @@ impl Tags {
 
         Ok(Self(tags))
     }
+
+    /// Iterate over the tag names and the objects they point to.
+    ///
+    /// Lightweight tags point to a commit, annotated tags point to the tag object.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, object)| (name.as_str(), object.as_str()))
+    }
 }
 
 struct GitRepository {
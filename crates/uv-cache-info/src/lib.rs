@@ -1,4 +1,5 @@
 pub use crate::cache_info::*;
+pub use crate::git_info::{Commit, GitInfoError, Tags};
 pub use crate::timestamp::*;
 
 mod cache_info;
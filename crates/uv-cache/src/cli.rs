@@ -2,7 +2,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use uv_static::EnvVars;
 
-use crate::Cache;
+use crate::{Cache, SharedCache};
 use clap::{Parser, ValueHint};
 use tracing::{debug, warn};
 
@@ -29,6 +29,14 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR, value_hint = ValueHint::DirPath)]
     pub cache_dir: Option<PathBuf>,
+
+    /// A shared cache for wheels built from source, consulted before building and populated after
+    /// a successful build.
+    ///
+    /// Accepts either a directory (e.g., on a network file system) or an HTTP(S) URL that supports
+    /// `GET` and `PUT` requests.
+    #[arg(global = true, long, env = EnvVars::UV_SHARED_CACHE, value_name = "LOCATION")]
+    pub shared_cache: Option<SharedCache>,
 }
 
 impl Cache {
@@ -0,0 +1,401 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use tracing::{debug, trace};
+
+use crate::{Cache, CacheBucket, ENVIRONMENTS_EXTENSION, Removal};
+
+/// The limits enforced by [`Cache::evict`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EvictionPolicy {
+    /// The maximum total size of the cache, in bytes.
+    pub max_size: Option<u64>,
+    /// The maximum time since an archive was last used.
+    pub max_age: Option<Duration>,
+}
+
+impl EvictionPolicy {
+    /// Returns `true` if the policy doesn't impose any limits.
+    pub fn is_empty(&self) -> bool {
+        self.max_size.is_none() && self.max_age.is_none()
+    }
+}
+
+/// An entry in the archive bucket that is a candidate for eviction.
+#[derive(Debug)]
+struct Candidate {
+    /// The path to the archive.
+    path: PathBuf,
+    /// The time at which the archive was last used.
+    last_access: SystemTime,
+    /// The logical size of the archive, in bytes.
+    size: u64,
+}
+
+impl Cache {
+    /// Record that an unzipped wheel in the archive bucket was used, for least-recently-used
+    /// eviction.
+    ///
+    /// Access times are tracked via the modification time of the archive directory, since
+    /// filesystem access times are commonly disabled or coarse (e.g., `noatime` or `relatime`).
+    /// Paths outside the archive bucket are ignored.
+    pub fn touch_archive(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        if !path.starts_with(self.bucket(CacheBucket::Archive)) {
+            return;
+        }
+        if let Err(err) = filetime::set_file_mtime(path, filetime::FileTime::now()) {
+            // The cache may be read-only; failing to record an access is not fatal.
+            trace!("Failed to record access for {}: {err}", path.display());
+        }
+    }
+
+    /// Record that an unzipped wheel in the archive bucket was linked into the `site-packages`
+    /// directory of an environment.
+    ///
+    /// Symlinked files (and, on Windows, hardlinked files) can't be traced back to the environment
+    /// from the archive, so the environments are recorded alongside the archive to retain it
+    /// during eviction for as long as they link to it. Paths outside the archive bucket are
+    /// ignored.
+    pub fn record_archive_link(&self, path: impl AsRef<Path>, site_packages: &Path) {
+        let path = path.as_ref();
+        if !path.starts_with(self.bucket(CacheBucket::Archive)) {
+            return;
+        }
+        let environments = path.with_extension(ENVIRONMENTS_EXTENSION);
+        let site_packages = site_packages.to_string_lossy();
+        if fs_err::read_to_string(&environments)
+            .is_ok_and(|contents| contents.lines().any(|line| line == site_packages))
+        {
+            return;
+        }
+        let result = fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&environments)
+            .and_then(|mut file| file.write_all(format!("{site_packages}\n").as_bytes()));
+        if let Err(err) = result {
+            // The cache may be read-only; failing to record a link is not fatal.
+            trace!("Failed to record link for {}: {err}", path.display());
+        }
+    }
+
+    /// Evict least-recently-used archives until the cache satisfies the given [`EvictionPolicy`].
+    ///
+    /// Archives that are older than the maximum age are removed first; then, the
+    /// least-recently-used archives are removed until the cache fits within the maximum size,
+    /// along with any links to them from the wheel and source distribution buckets.
+    ///
+    /// Archives that are referenced by a live environment (i.e., that contain files hardlinked
+    /// or symlinked into an environment) are retained, as removing them would not reclaim any
+    /// space, or would break the environment.
+    pub fn evict(&self, policy: EvictionPolicy) -> Result<Removal, io::Error> {
+        let mut summary = self.removal();
+        if policy.is_empty() {
+            return Ok(summary);
+        }
+
+        // Collect the archives, along with their last access time and size.
+        let mut candidates = Vec::new();
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let metadata = entry.metadata()?;
+                    if !metadata.is_dir() {
+                        continue;
+                    }
+                    let (size, in_use) = archive_usage(&path)?;
+                    if in_use {
+                        trace!(
+                            "Retaining archive in use by an environment: {}",
+                            path.display()
+                        );
+                        continue;
+                    }
+                    candidates.push(Candidate {
+                        path,
+                        last_access: metadata.modified()?,
+                        size,
+                    });
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+            Err(err) => return Err(err),
+        }
+
+        // Evict the least-recently-used archives first.
+        candidates.sort_by_key(|candidate| candidate.last_access);
+
+        let mut total = if policy.max_size.is_some() {
+            cache_size(&self.root)?
+        } else {
+            0
+        };
+
+        let now = SystemTime::now();
+        let references = self.find_archive_references()?;
+        for candidate in candidates {
+            let expired = policy.max_age.is_some_and(|max_age| {
+                now.duration_since(candidate.last_access)
+                    .is_ok_and(|age| age > max_age)
+            });
+            let oversized = policy.max_size.is_some_and(|max_size| total > max_size);
+            if !expired && !oversized {
+                // Candidates are sorted by access time, so all remaining candidates are more
+                // recent than this one.
+                break;
+            }
+
+            if expired {
+                debug!(
+                    "Evicting expired cache archive: {}",
+                    candidate.path.display()
+                );
+            } else {
+                debug!(
+                    "Evicting least-recently-used cache archive: {}",
+                    candidate.path.display()
+                );
+            }
+
+            // Remove any links to the archive, to avoid leaving dangling entries behind.
+            let target = fs_err::canonicalize(&candidate.path)?;
+            for link in references.get(&target).into_iter().flatten() {
+                summary += self.remove_path(link)?;
+            }
+            summary += self.remove_archive(&candidate.path)?;
+            total = total.saturating_sub(candidate.size);
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Return the logical size of an archive, and whether any of its files are linked into an
+/// environment.
+fn archive_usage(path: &Path) -> Result<(u64, bool), io::Error> {
+    let (size, in_use) = archive_size(path)?;
+    Ok((size, in_use || is_linked(path)?))
+}
+
+/// Returns `true` if any of the environments recorded for the archive (see
+/// [`Cache::record_archive_link`]) still link to its files.
+///
+/// The distribution metadata of each wheel in the archive is compared to the installed copy,
+/// which is the same file if the environment symlinks or hardlinks the archive.
+fn is_linked(path: &Path) -> Result<bool, io::Error> {
+    let environments = match fs_err::read_to_string(path.with_extension(ENVIRONMENTS_EXTENSION)) {
+        Ok(environments) => environments,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err),
+    };
+    let mut dist_infos = Vec::new();
+    for entry in fs_err::read_dir(path)? {
+        let entry = entry?;
+        if Path::new(&entry.file_name())
+            .extension()
+            .is_some_and(|ext| ext == "dist-info")
+        {
+            dist_infos.push(entry.file_name());
+        }
+    }
+    Ok(environments.lines().any(|site_packages| {
+        dist_infos.iter().any(|dist_info| {
+            let metadata = Path::new(dist_info).join("METADATA");
+            same_file::is_same_file(
+                path.join(&metadata),
+                Path::new(site_packages).join(&metadata),
+            )
+            .unwrap_or(false)
+        })
+    }))
+}
+
+/// Return the logical size of an archive, and whether any of its files are hardlinked into an
+/// environment.
+fn archive_size(path: &Path) -> Result<(u64, bool), io::Error> {
+    let mut size = 0;
+    let mut in_use = false;
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        size += metadata.len();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            if metadata.nlink() > 1 {
+                in_use = true;
+            }
+        }
+    }
+    Ok((size, in_use))
+}
+
+/// Return the logical size of all files in the cache.
+fn cache_size(root: &Path) -> Result<u64, io::Error> {
+    let mut size = 0;
+    for entry in walkdir::WalkDir::new(root) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::{Duration, SystemTime};
+
+    use filetime::FileTime;
+
+    use crate::{ArchiveId, Cache, CacheBucket, ENVIRONMENTS_EXTENSION, EvictionPolicy};
+
+    /// Create an archive with a single file of the given size, last used `age` ago.
+    fn archive(cache: &Cache, id: &str, size: usize, age: Duration) -> std::path::PathBuf {
+        let path = cache.archive(&ArchiveId::from_str(id).unwrap());
+        fs_err::create_dir_all(&path).unwrap();
+        fs_err::write(path.join("module.py"), vec![b'x'; size]).unwrap();
+        filetime::set_file_mtime(&path, FileTime::from_system_time(SystemTime::now() - age))
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn evict_max_age() {
+        let cache = Cache::temp().unwrap();
+        let old = archive(&cache, "old", 10, Duration::from_hours(40 * 24));
+        let new = archive(&cache, "new", 10, Duration::from_mins(1));
+
+        let removal = cache
+            .evict(EvictionPolicy {
+                max_size: None,
+                max_age: Some(Duration::from_hours(30 * 24)),
+            })
+            .unwrap();
+
+        assert!(!old.exists());
+        assert!(new.exists());
+        assert_eq!(removal.num_files, 1);
+        assert_eq!(removal.logical_bytes, 10);
+    }
+
+    #[test]
+    fn evict_max_size() {
+        let cache = Cache::temp().unwrap();
+        let oldest = archive(&cache, "oldest", 100, Duration::from_secs(300));
+        let older = archive(&cache, "older", 100, Duration::from_secs(200));
+        let newest = archive(&cache, "newest", 100, Duration::from_secs(100));
+
+        cache
+            .evict(EvictionPolicy {
+                max_size: Some(150),
+                max_age: None,
+            })
+            .unwrap();
+
+        assert!(!oldest.exists());
+        assert!(!older.exists());
+        assert!(newest.exists());
+    }
+
+    #[test]
+    fn touch_archive() {
+        let cache = Cache::temp().unwrap();
+        let first = archive(&cache, "first", 100, Duration::from_secs(300));
+        let second = archive(&cache, "second", 100, Duration::from_secs(200));
+
+        // Using the older archive makes it the most recently used.
+        cache.touch_archive(&first);
+
+        cache
+            .evict(EvictionPolicy {
+                max_size: Some(150),
+                max_age: None,
+            })
+            .unwrap();
+
+        assert!(first.exists());
+        assert!(!second.exists());
+
+        // Paths outside the archive bucket are ignored.
+        cache.touch_archive(cache.bucket(CacheBucket::Wheels));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn evict_retains_linked_archives() {
+        let cache = Cache::temp().unwrap();
+        let linked = archive(&cache, "linked", 100, Duration::from_hours(40 * 24));
+
+        // Simulate an environment that hardlinks the archive's files.
+        let environment = tempfile::tempdir().unwrap();
+        fs_err::hard_link(
+            linked.join("module.py"),
+            environment.path().join("module.py"),
+        )
+        .unwrap();
+
+        cache
+            .evict(EvictionPolicy {
+                max_size: Some(0),
+                max_age: Some(Duration::from_mins(1)),
+            })
+            .unwrap();
+
+        assert!(linked.exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn evict_retains_symlinked_archives() {
+        let cache = Cache::temp().unwrap();
+        let linked = archive(&cache, "linked", 100, Duration::from_hours(40 * 24));
+        let unlinked = archive(&cache, "unlinked", 100, Duration::from_hours(40 * 24));
+        for archive in [&linked, &unlinked] {
+            fs_err::create_dir_all(archive.join("foo-1.0.0.dist-info")).unwrap();
+            fs_err::write(archive.join("foo-1.0.0.dist-info").join("METADATA"), "").unwrap();
+        }
+
+        // Simulate an environment that symlinks the first archive's files, and an environment
+        // that used to symlink the second archive, but no longer does.
+        let site_packages = tempfile::tempdir().unwrap();
+        fs_err::create_dir_all(site_packages.path().join("foo-1.0.0.dist-info")).unwrap();
+        fs_err::os::unix::fs::symlink(
+            linked.join("foo-1.0.0.dist-info").join("METADATA"),
+            site_packages
+                .path()
+                .join("foo-1.0.0.dist-info")
+                .join("METADATA"),
+        )
+        .unwrap();
+        cache.record_archive_link(&linked, site_packages.path());
+        cache.record_archive_link(&unlinked, site_packages.path());
+        // Recording the same environment again is a no-op.
+        cache.record_archive_link(&linked, site_packages.path());
+        assert_eq!(
+            fs_err::read_to_string(linked.with_extension(ENVIRONMENTS_EXTENSION)).unwrap(),
+            format!("{}\n", site_packages.path().display())
+        );
+
+        cache
+            .evict(EvictionPolicy {
+                max_size: Some(0),
+                max_age: Some(Duration::from_mins(1)),
+            })
+            .unwrap();
+
+        assert!(linked.exists());
+        assert!(!unlinked.exists());
+        assert!(!unlinked.with_extension(ENVIRONMENTS_EXTENSION).exists());
+    }
+}
@@ -0,0 +1,679 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::FxHashSet;
+use sha2::{Digest, Sha256};
+use tracing::{debug, trace};
+
+use uv_cache_key::{RepositoryUrl, cache_digest};
+use uv_distribution_types::IndexUrl;
+use uv_fs::directories;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::wheel::WheelCacheKind;
+use crate::{ArchiveId, Cache, CacheBucket, DIRHASH_EXTENSION, WheelCache, is_built_wheel};
+
+/// The version of the [`CacheManifest`] format.
+const MANIFEST_VERSION: u32 = 1;
+
+/// A set of cache entries to export, as paths relative to the cache root.
+#[derive(Debug, Default)]
+pub struct CacheSelection {
+    paths: BTreeSet<PathBuf>,
+}
+
+impl CacheSelection {
+    /// Select the cache entries for a distribution from a registry (or `--find-links` location),
+    /// at the given version.
+    pub fn registry(
+        &mut self,
+        cache: &Cache,
+        index: &IndexUrl,
+        name: &PackageName,
+        version: &Version,
+    ) -> io::Result<()> {
+        let root = WheelCache::Index(index).root();
+
+        // The Simple API response for the package.
+        self.insert(
+            cache,
+            Path::new(CacheBucket::Simple.to_str())
+                .join(&root)
+                .join(format!("{name}.rkyv")),
+        );
+
+        // Downloaded wheels, whose entries are prefixed by the version (e.g., `1.0.0-py3-none-any`).
+        let wheels = Path::new(CacheBucket::Wheels.to_str()).join(root.join(name.as_ref()));
+        for entry in entries(&cache.root().join(&wheels))? {
+            let Some(filename) = entry.to_str() else {
+                continue;
+            };
+            let matches = filename
+                .split_once('-')
+                .and_then(|(prefix, _)| Version::from_str(prefix).ok())
+                .is_some_and(|candidate| candidate == *version);
+            if matches {
+                self.insert(cache, wheels.join(filename));
+            }
+        }
+
+        // Source distributions, and the wheels built from them.
+        let sdists =
+            Path::new(CacheBucket::SourceDistributions.to_str()).join(root.join(name.as_ref()));
+        for directory in directories(cache.root().join(&sdists))? {
+            let Some(dirname) = directory.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if Version::from_str(dirname).is_ok_and(|candidate| candidate == *version) {
+                self.insert(cache, sdists.join(dirname));
+            }
+        }
+
+        // Any provenance for the package.
+        let provenance = cache.bucket(CacheBucket::Provenance);
+        for directory in directories(&provenance)? {
+            if let Ok(relative) = directory.join(name.as_ref()).strip_prefix(cache.root()) {
+                self.insert(cache, relative.to_path_buf());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Select the cache entries for a direct URL or local archive dependency.
+    ///
+    /// Since these entries are keyed by URL, they're identified by the name of the package in any
+    /// wheel built from them.
+    pub fn direct(&mut self, cache: &Cache, name: &PackageName) -> io::Result<()> {
+        // Downloaded wheels, indexed by URL and then by package name.
+        let root = cache.bucket(CacheBucket::Wheels).join(WheelCacheKind::Url);
+        for directory in directories(root)? {
+            self.insert_absolute(cache, &directory.join(name.as_ref()));
+        }
+
+        // Source distributions, indexed by URL (or path) and then by version.
+        for kind in [WheelCacheKind::Url, WheelCacheKind::Path] {
+            let root = cache.bucket(CacheBucket::SourceDistributions).join(kind);
+            for directory in directories(root)? {
+                if directories(&directory)?.any(|version| is_built_wheel(&version, name)) {
+                    self.insert_absolute(cache, &directory);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Select the cache entries for a Git dependency at the given commit, including the Git
+    /// repository itself.
+    pub fn git(&mut self, cache: &Cache, repository: &RepositoryUrl, sha: &str) -> io::Result<()> {
+        let short_sha = &sha[..sha.len().min(16)];
+        let ident = cache_digest(repository);
+        let git = Path::new(CacheBucket::Git.to_str());
+        self.insert(cache, git.join("db").join(&ident));
+        self.insert(cache, git.join("checkouts").join(&ident).join(short_sha));
+
+        // Wheels built from the repository, indexed by URL and then by commit.
+        let root = cache
+            .bucket(CacheBucket::SourceDistributions)
+            .join(WheelCacheKind::Git);
+        for directory in directories(root)? {
+            self.insert_absolute(cache, &directory.join(short_sha));
+        }
+
+        Ok(())
+    }
+
+    /// Select the cached Python download metadata.
+    pub fn python(&mut self, cache: &Cache) {
+        self.insert(cache, PathBuf::from(CacheBucket::Python.to_str()));
+    }
+
+    /// Select the cached `--find-links` responses.
+    pub fn flat_index(&mut self, cache: &Cache) {
+        self.insert(cache, PathBuf::from(CacheBucket::FlatIndex.to_str()));
+    }
+
+    /// Select an unzipped wheel in the archive bucket, along with its recorded dirhash.
+    pub fn archive(&mut self, cache: &Cache, id: &ArchiveId) {
+        for path in archive_paths(id) {
+            self.insert(cache, path);
+        }
+    }
+
+    /// Return the absolute paths of the selected entries.
+    pub fn paths<'a>(&'a self, cache: &'a Cache) -> impl Iterator<Item = PathBuf> + 'a {
+        self.paths.iter().map(|path| cache.root().join(path))
+    }
+
+    /// Returns `true` if no entries are selected.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Select an entry, given as a path relative to the cache root, if it exists.
+    fn insert(&mut self, cache: &Cache, path: PathBuf) {
+        if cache.root().join(&path).symlink_metadata().is_ok() {
+            trace!("Selecting cache entry: {}", path.display());
+            self.paths.insert(path);
+        }
+    }
+
+    /// Select an entry, given as an absolute path within the cache, if it exists.
+    fn insert_absolute(&mut self, cache: &Cache, path: &Path) {
+        if let Ok(relative) = path.strip_prefix(cache.root()) {
+            self.insert(cache, relative.to_path_buf());
+        }
+    }
+}
+
+/// A manifest describing the contents of an exported cache archive.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CacheManifest {
+    /// The version of the manifest format.
+    pub version: u32,
+    /// The exported entries, with parents preceding their children.
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl CacheManifest {
+    /// The name of the manifest within an exported archive.
+    pub const FILENAME: &'static str = "uv-cache-manifest.json";
+}
+
+/// An entry in a [`CacheManifest`], with a `/`-separated path relative to the cache root.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ManifestEntry {
+    /// A directory.
+    Directory { path: String },
+    /// A regular file, with its size and SHA-256 digest.
+    File {
+        path: String,
+        size: u64,
+        sha256: String,
+    },
+    /// A link to an unzipped wheel in the archive bucket.
+    Link { path: String, archive: ArchiveId },
+}
+
+impl ManifestEntry {
+    /// Return the path of the entry, relative to the cache root.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Directory { path } | Self::File { path, .. } | Self::Link { path, .. } => path,
+        }
+    }
+}
+
+/// The result of [`Cache::import`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ImportSummary {
+    /// The number of files added to the cache.
+    pub num_files: u64,
+    /// The total size of the files added to the cache, in bytes.
+    pub num_bytes: u64,
+    /// The number of entries that were already present in the cache.
+    pub num_skipped: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheImportError {
+    #[error("Unsupported cache manifest version: {0} (expected {MANIFEST_VERSION})")]
+    UnsupportedVersion(u32),
+    #[error("Invalid path in cache manifest: `{0}`")]
+    InvalidPath(String),
+    #[error("Cache entry `{0}` is listed in the manifest, but missing from the archive")]
+    Missing(String),
+    #[error("Cache entry `{0}` is not listed in the manifest")]
+    Unexpected(String),
+    #[error("Cache entry `{path}` has size {actual}, but the manifest expects {expected}")]
+    SizeMismatch {
+        path: String,
+        expected: u64,
+        actual: u64,
+    },
+    #[error(
+        "Cache entry `{path}` has SHA-256 digest `{actual}`, but the manifest expects `{expected}`"
+    )]
+    HashMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("Cache entry `{0}` links to an unzipped wheel that is missing from the archive")]
+    MissingArchive(String),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl Cache {
+    /// Build a [`CacheManifest`] for the selected entries, including any unzipped wheels that they
+    /// link to.
+    pub fn export_manifest(&self, selection: &CacheSelection) -> io::Result<CacheManifest> {
+        let archive_bucket = fs_err::canonicalize(self.bucket(CacheBucket::Archive)).ok();
+
+        let mut entries = Vec::new();
+        let mut seen = FxHashSet::default();
+        let mut queue = selection.paths.iter().cloned().collect::<Vec<_>>();
+        while let Some(relative) = queue.pop() {
+            // Include the parent directories, such that they're created with the same layout.
+            for ancestor in relative
+                .ancestors()
+                .skip(1)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+            {
+                if ancestor.as_os_str().is_empty() {
+                    continue;
+                }
+                let path = portable(ancestor);
+                if seen.insert(path.clone()) {
+                    entries.push(ManifestEntry::Directory { path });
+                }
+            }
+
+            for entry in walkdir::WalkDir::new(self.root.join(&relative))
+                .follow_root_links(false)
+                .sort_by_file_name()
+            {
+                let entry = entry?;
+                let Ok(relative) = entry.path().strip_prefix(&self.root) else {
+                    continue;
+                };
+                let path = portable(relative);
+                if !seen.insert(path.clone()) {
+                    continue;
+                }
+
+                // Record links to unzipped wheels by archive ID, to recreate them on import.
+                if let Some(id) = self.archive_link(entry.path(), &entry, archive_bucket.as_deref())
+                {
+                    queue.extend(
+                        archive_paths(&id)
+                            .into_iter()
+                            .filter(|path| self.root.join(path).symlink_metadata().is_ok()),
+                    );
+                    entries.push(ManifestEntry::Link { path, archive: id });
+                    continue;
+                }
+
+                if entry.file_type().is_dir() {
+                    entries.push(ManifestEntry::Directory { path });
+                } else if entry.file_type().is_file() {
+                    let (size, sha256) = hash_file(entry.path())?;
+                    entries.push(ManifestEntry::File { path, size, sha256 });
+                } else {
+                    debug!(
+                        "Skipping unsupported cache entry: {}",
+                        entry.path().display()
+                    );
+                }
+            }
+        }
+
+        Ok(CacheManifest {
+            version: MANIFEST_VERSION,
+            entries,
+        })
+    }
+
+    /// Import the entries in an unpacked cache archive into the cache.
+    ///
+    /// The unpacked archive must be on the same file system as the cache. Every entry is verified
+    /// against the [`CacheManifest`] before the cache is modified. Entries that already exist in
+    /// the cache are retained, as cache entries are immutable once written.
+    pub fn import(
+        &self,
+        staging: &Path,
+        manifest: &CacheManifest,
+    ) -> Result<ImportSummary, CacheImportError> {
+        if manifest.version != MANIFEST_VERSION {
+            return Err(CacheImportError::UnsupportedVersion(manifest.version));
+        }
+
+        // Verify the unpacked archive against the manifest.
+        let mut expected = FxHashSet::default();
+        let mut archives = FxHashSet::default();
+        for entry in &manifest.entries {
+            let relative = validate_path(entry.path())?;
+            let path = staging.join(&relative);
+            match entry {
+                ManifestEntry::Directory { .. } => {
+                    if !path
+                        .symlink_metadata()
+                        .is_ok_and(|metadata| metadata.is_dir())
+                    {
+                        return Err(CacheImportError::Missing(entry.path().to_string()));
+                    }
+                    if let Ok(id) = relative.strip_prefix(CacheBucket::Archive.to_str()) {
+                        archives.insert(id.to_path_buf());
+                    }
+                }
+                ManifestEntry::File { size, sha256, .. } => {
+                    if !path
+                        .symlink_metadata()
+                        .is_ok_and(|metadata| metadata.is_file())
+                    {
+                        return Err(CacheImportError::Missing(entry.path().to_string()));
+                    }
+                    let (actual_size, actual_sha256) = hash_file(&path)?;
+                    if actual_size != *size {
+                        return Err(CacheImportError::SizeMismatch {
+                            path: entry.path().to_string(),
+                            expected: *size,
+                            actual: actual_size,
+                        });
+                    }
+                    if !actual_sha256.eq_ignore_ascii_case(sha256) {
+                        return Err(CacheImportError::HashMismatch {
+                            path: entry.path().to_string(),
+                            expected: sha256.clone(),
+                            actual: actual_sha256,
+                        });
+                    }
+                }
+                ManifestEntry::Link { .. } => {}
+            }
+            expected.insert(relative);
+        }
+        for entry in &manifest.entries {
+            if let ManifestEntry::Link { path, archive } = entry {
+                if !archives.contains(Path::new(archive.as_ref()))
+                    && !self.archive(archive).is_dir()
+                {
+                    return Err(CacheImportError::MissingArchive(path.clone()));
+                }
+            }
+        }
+        for entry in walkdir::WalkDir::new(staging).min_depth(1) {
+            let entry = entry.map_err(io::Error::from)?;
+            let Ok(relative) = entry.path().strip_prefix(staging) else {
+                continue;
+            };
+            if relative == Path::new(CacheManifest::FILENAME) {
+                continue;
+            }
+            if !expected.contains(relative) {
+                return Err(CacheImportError::Unexpected(portable(relative)));
+            }
+        }
+
+        // Merge the entries into the cache. Unzipped wheels are moved first, such that entries
+        // that point to them are never visible before their targets. Directories that are absent
+        // from the cache are moved in their entirety.
+        let mut summary = ImportSummary::default();
+        let mut moved = Vec::<PathBuf>::new();
+        let (archive_entries, other_entries): (Vec<_>, Vec<_>) = manifest
+            .entries
+            .iter()
+            .partition(|entry| Path::new(entry.path()).starts_with(CacheBucket::Archive.to_str()));
+        for entry in archive_entries.into_iter().chain(other_entries) {
+            let relative = validate_path(entry.path())?;
+            if moved.iter().any(|parent| relative.starts_with(parent)) {
+                if let ManifestEntry::File { size, .. } = entry {
+                    summary.num_files += 1;
+                    summary.num_bytes += size;
+                }
+                continue;
+            }
+            let source = staging.join(&relative);
+            let target = self.root.join(&relative);
+            match entry {
+                ManifestEntry::Directory { .. } => {
+                    if target.symlink_metadata().is_err() {
+                        if let Some(parent) = target.parent() {
+                            fs_err::create_dir_all(parent)?;
+                        }
+                        fs_err::rename(&source, &target)?;
+                        moved.push(relative);
+                    }
+                }
+                ManifestEntry::File { size, .. } => {
+                    if target.symlink_metadata().is_ok() {
+                        summary.num_skipped += 1;
+                    } else {
+                        if let Some(parent) = target.parent() {
+                            fs_err::create_dir_all(parent)?;
+                        }
+                        fs_err::rename(&source, &target)?;
+                        summary.num_files += 1;
+                        summary.num_bytes += size;
+                    }
+                }
+                ManifestEntry::Link { .. } => {}
+            }
+        }
+
+        // Recreate the links to unzipped wheels.
+        for entry in &manifest.entries {
+            if let ManifestEntry::Link { path, archive } = entry {
+                let target = self.root.join(validate_path(path)?);
+                if target.symlink_metadata().is_ok() {
+                    summary.num_skipped += 1;
+                    continue;
+                }
+                if let Some(parent) = target.parent() {
+                    fs_err::create_dir_all(parent)?;
+                }
+                self.create_link(archive, &target)?;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// If the given entry is a link to an unzipped wheel, return the ID of the linked archive.
+    fn archive_link(
+        &self,
+        path: &Path,
+        entry: &walkdir::DirEntry,
+        archive_bucket: Option<&Path>,
+    ) -> Option<ArchiveId> {
+        // On Unix, archive references use symlinks; on Windows, they're files containing
+        // structured data.
+        if cfg!(unix) && !entry.file_type().is_symlink() {
+            return None;
+        }
+        if cfg!(windows)
+            && (!entry.file_type().is_file() || path.starts_with(self.bucket(CacheBucket::Archive)))
+        {
+            return None;
+        }
+        let target = self.resolve_link(path).ok()?;
+        if target.parent() != archive_bucket {
+            return None;
+        }
+        let id = target.file_name()?.to_str()?;
+        ArchiveId::from_str(id).ok()
+    }
+}
+
+/// Return the names of the entries in a directory, or an empty list if it doesn't exist.
+fn entries(path: &Path) -> io::Result<Vec<std::ffi::OsString>> {
+    match fs_err::read_dir(path) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns the paths of an archive and its recorded dirhash, relative to the cache root.
+fn archive_paths(id: &ArchiveId) -> [PathBuf; 2] {
+    let bucket = Path::new(CacheBucket::Archive.to_str());
+    [
+        bucket.join(id),
+        bucket.join(format!("{id}.{DIRHASH_EXTENSION}")),
+    ]
+}
+
+/// Render a relative path with `/` separators.
+fn portable(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Validate that a manifest path is a relative path that stays within the cache.
+fn validate_path(path: &str) -> Result<PathBuf, CacheImportError> {
+    let relative = PathBuf::from(path);
+    if path
+        .split('/')
+        .any(|part| part.is_empty() || part == "." || part == "..")
+        || path.contains('\\')
+        || !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(CacheImportError::InvalidPath(path.to_string()));
+    }
+    Ok(relative)
+}
+
+/// Return the size and SHA-256 digest of a file.
+fn hash_file(path: &Path) -> io::Result<(u64, String)> {
+    let mut file = fs_err::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+    let mut size = 0;
+    loop {
+        let len = match file.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        hasher.update(&buffer[..len]);
+        size += len as u64;
+    }
+    Ok((size, hex::encode(hasher.finalize())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_distribution_types::IndexUrl;
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::{CacheImportError, CacheManifest, CacheSelection, ManifestEntry, validate_path};
+    use crate::{ArchiveId, Cache, CacheBucket, WheelCache};
+
+    #[test]
+    fn validate_paths() {
+        assert!(validate_path("wheels-v6/pypi/anyio/4.0.0-py3-none-any.http").is_ok());
+        assert!(validate_path("").is_err());
+        assert!(validate_path("/etc/passwd").is_err());
+        assert!(validate_path("wheels-v6/../../etc/passwd").is_err());
+        assert!(validate_path("wheels-v6/./pypi").is_err());
+        assert!(validate_path("wheels-v6\\pypi").is_err());
+    }
+
+    #[test]
+    fn export_import() {
+        let source = Cache::temp().unwrap();
+        let index = IndexUrl::from_str("https://pypi.org/simple").unwrap();
+        let name = PackageName::from_str("anyio").unwrap();
+        let wheels = source
+            .bucket(CacheBucket::Wheels)
+            .join(WheelCache::Index(&index).wheel_dir("anyio"));
+        fs_err::create_dir_all(&wheels).unwrap();
+        fs_err::write(wheels.join("4.0.0-py3-none-any.http"), "4.0.0").unwrap();
+        fs_err::write(wheels.join("3.0.0-py3-none-any.http"), "3.0.0").unwrap();
+
+        // Link the wheel to an unzipped archive.
+        let id = ArchiveId::from_str("abc").unwrap();
+        fs_err::create_dir_all(source.archive(&id)).unwrap();
+        fs_err::write(source.archive(&id).join("anyio.py"), "").unwrap();
+        source
+            .create_link(&id, wheels.join("4.0.0-py3-none-any"))
+            .unwrap();
+
+        let mut selection = CacheSelection::default();
+        selection
+            .registry(&source, &index, &name, &Version::from_str("4.0").unwrap())
+            .unwrap();
+        let manifest = source.export_manifest(&selection).unwrap();
+        let paths = manifest
+            .entries
+            .iter()
+            .map(ManifestEntry::path)
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&"wheels-v6/pypi/anyio/4.0.0-py3-none-any.http"));
+        assert!(!paths.contains(&"wheels-v6/pypi/anyio/3.0.0-py3-none-any.http"));
+        assert!(paths.contains(&"archive-v0/abc/anyio.py"));
+
+        // Stage the exported entries, as if unpacked from an archive.
+        let staging = tempfile::tempdir().unwrap();
+        for entry in &manifest.entries {
+            match entry {
+                ManifestEntry::Directory { path } => {
+                    fs_err::create_dir_all(staging.path().join(path)).unwrap();
+                }
+                ManifestEntry::File { path, .. } => {
+                    fs_err::copy(source.root().join(path), staging.path().join(path)).unwrap();
+                }
+                ManifestEntry::Link { .. } => {}
+            }
+        }
+
+        let target = Cache::temp().unwrap();
+        let summary = target.import(staging.path(), &manifest).unwrap();
+        assert_eq!(summary.num_files, 2);
+        assert!(
+            target
+                .root()
+                .join("wheels-v6/pypi/anyio/4.0.0-py3-none-any.http")
+                .is_file()
+        );
+        assert_eq!(
+            target
+                .resolve_link(
+                    target
+                        .root()
+                        .join("wheels-v6/pypi/anyio/4.0.0-py3-none-any")
+                )
+                .unwrap(),
+            fs_err::canonicalize(target.archive(&id)).unwrap()
+        );
+    }
+
+    #[test]
+    fn import_rejects_corrupt_entries() {
+        let cache = Cache::temp().unwrap();
+        let staging = tempfile::tempdir().unwrap();
+        fs_err::write(staging.path().join("entry"), "contents").unwrap();
+
+        let manifest = CacheManifest {
+            version: 1,
+            entries: vec![ManifestEntry::File {
+                path: "entry".to_string(),
+                size: 8,
+                sha256: "0".repeat(64),
+            }],
+        };
+        assert!(matches!(
+            cache.import(staging.path(), &manifest),
+            Err(CacheImportError::HashMismatch { .. })
+        ));
+
+        let manifest = CacheManifest {
+            version: 1,
+            entries: vec![],
+        };
+        assert!(matches!(
+            cache.import(staging.path(), &manifest),
+            Err(CacheImportError::Unexpected(..))
+        ));
+        assert!(!cache.root().join("entry").exists());
+    }
+}
@@ -17,8 +17,16 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::eviction::EvictionPolicy;
+pub use crate::export::{
+    CacheImportError, CacheManifest, CacheSelection, ImportSummary, ManifestEntry,
+};
 use crate::removal::Remover;
 pub use crate::removal::{Removal, RemovalMode};
+pub use crate::shared::SharedCache;
+pub use crate::stats::{
+    ArchiveUsage, BucketUsage, CacheStats, CacheUsage, PackageUsage, PhysicalSpace,
+};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
@@ -27,7 +35,11 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod eviction;
+mod export;
 mod removal;
+mod shared;
+mod stats;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -35,6 +47,20 @@ mod wheel;
 /// Must be kept in-sync with the version in [`CacheBucket::to_str`].
 pub const ARCHIVE_VERSION: u8 = 0;
 
+/// The extension of the file that records the dirhash of an archive, alongside the archive.
+pub const DIRHASH_EXTENSION: &str = "dirhash";
+
+/// The extension of the file that records the environments an archive was linked into, alongside
+/// the archive.
+pub const ENVIRONMENTS_EXTENSION: &str = "environments";
+
+/// Returns `true` if the path is a file stored alongside an archive in the archive bucket, rather
+/// than an archive, e.g., the recorded dirhash of an archive.
+pub fn is_archive_sidecar(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext == DIRHASH_EXTENSION || ext == ENVIRONMENTS_EXTENSION)
+}
+
 /// Error locking a cache entry or shard
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -176,6 +202,8 @@ pub struct Cache {
     lock_file: Option<Arc<LockedFile>>,
     /// The storage accounting used when removing cache entries.
     removal_mode: RemovalMode,
+    /// A secondary cache tier for wheels built from source, shared between machines.
+    shared: Option<Arc<SharedCache>>,
 }
 
 impl Cache {
@@ -187,6 +215,7 @@ impl Cache {
             temp_dir: None,
             lock_file: None,
             removal_mode: RemovalMode::Logical,
+            shared: None,
         }
     }
 
@@ -199,6 +228,7 @@ impl Cache {
             temp_dir: Some(Arc::new(temp_dir)),
             lock_file: None,
             removal_mode: RemovalMode::Logical,
+            shared: None,
         })
     }
 
@@ -223,6 +253,20 @@ impl Cache {
         }
     }
 
+    /// Set the [`SharedCache`] tier to consult for wheels built from source.
+    #[must_use]
+    pub fn with_shared_cache(self, shared: Option<SharedCache>) -> Self {
+        Self {
+            shared: shared.map(Arc::new),
+            ..self
+        }
+    }
+
+    /// Return the [`SharedCache`] tier, if any.
+    pub fn shared_cache(&self) -> Option<&SharedCache> {
+        self.shared.as_deref()
+    }
+
     /// Create an empty removal summary using the cache's configured accounting mode.
     pub fn removal(&self) -> Removal {
         Removal::new(self.removal_mode)
@@ -236,6 +280,7 @@ impl Cache {
             temp_dir,
             lock_file,
             removal_mode,
+            shared,
         } = self;
 
         // Release the existing lock, avoid deadlocks from a cloned cache.
@@ -259,6 +304,7 @@ impl Cache {
             temp_dir,
             lock_file: Some(Arc::new(lock_file)),
             removal_mode,
+            shared,
         })
     }
 
@@ -272,6 +318,7 @@ impl Cache {
             temp_dir,
             lock_file,
             removal_mode,
+            shared,
         } = self;
 
         match LockedFile::acquire_no_wait(
@@ -285,6 +332,7 @@ impl Cache {
                 temp_dir,
                 lock_file: Some(Arc::new(lock_file)),
                 removal_mode,
+                shared,
             }),
             None => Err(Self {
                 root,
@@ -292,6 +340,7 @@ impl Cache {
                 temp_dir,
                 lock_file,
                 removal_mode,
+                shared,
             }),
         }
     }
@@ -326,6 +375,15 @@ impl Cache {
         self.bucket(CacheBucket::Archive).join(id)
     }
 
+    /// Return the path to the recorded dirhash of an archive in the cache.
+    ///
+    /// The dirhash is stored alongside the archive, rather than within it, since the contents of
+    /// an unzipped wheel are installed as-is.
+    pub fn archive_dirhash(&self, id: &ArchiveId) -> PathBuf {
+        self.bucket(CacheBucket::Archive)
+            .join(format!("{id}.{DIRHASH_EXTENSION}"))
+    }
+
     /// Create a temporary directory to be used as a Python virtual environment.
     pub fn venv_dir(&self) -> io::Result<tempfile::TempDir> {
         fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
@@ -405,6 +463,26 @@ impl Cache {
         &self,
         temp_dir: impl AsRef<Path>,
         path: impl AsRef<Path>,
+    ) -> io::Result<ArchiveId> {
+        self.persist_archive(temp_dir, path, None).await
+    }
+
+    /// Persist a temporary directory to the artifact store, recording its dirhash (as computed by
+    /// `uv_extract::dirhash`) such that the archive can later be verified.
+    pub async fn persist_with_dirhash(
+        &self,
+        temp_dir: impl AsRef<Path>,
+        path: impl AsRef<Path>,
+        dirhash: &str,
+    ) -> io::Result<ArchiveId> {
+        self.persist_archive(temp_dir, path, Some(dirhash)).await
+    }
+
+    async fn persist_archive(
+        &self,
+        temp_dir: impl AsRef<Path>,
+        path: impl AsRef<Path>,
+        dirhash: Option<&str>,
     ) -> io::Result<ArchiveId> {
         // Create a unique ID for the artifact.
         // TODO(charlie): Support content-addressed persistence via SHAs.
@@ -415,6 +493,12 @@ impl Cache {
         fs_err::create_dir_all(archive_entry.dir())?;
         uv_fs::rename_with_retry(temp_dir.as_ref(), archive_entry.path()).await?;
 
+        // Record the dirhash before the archive is linked, such that any reachable archive with a
+        // dirhash has a complete one.
+        if let Some(dirhash) = dirhash {
+            uv_fs::write_atomic(self.archive_dirhash(&id), dirhash).await?;
+        }
+
         // Create a symlink to the directory store.
         fs_err::create_dir_all(path.as_ref().parent().expect("Cache entry to have parent"))?;
         self.create_link(&id, path.as_ref())?;
@@ -605,7 +689,7 @@ impl Cache {
         for (target, references) in references {
             if target.starts_with(&archive_root) && references.iter().all(|path| !path.exists()) {
                 debug!("Removing dangling cache entry: {}", target.display());
-                summary += self.remove_path(target)?;
+                summary += self.remove_archive(target)?;
             }
         }
 
@@ -728,10 +812,21 @@ impl Cache {
                 for entry in entries {
                     let entry = entry?;
                     let path = entry.path();
+
+                    // Retain the recorded dirhash and environments of any archive that remains;
+                    // they're removed alongside a removed archive.
+                    if is_archive_sidecar(&path) {
+                        if path.exists() && !path.with_extension("").is_dir() {
+                            debug!("Removing dangling archive sidecar: {}", path.display());
+                            summary += self.remove_path(path)?;
+                        }
+                        continue;
+                    }
+
                     let target = fs_err::canonicalize(&path)?;
                     if !references.contains_key(&target) {
                         debug!("Removing dangling cache archive: {}", path.display());
-                        summary += self.remove_path(path)?;
+                        summary += self.remove_archive(path)?;
                     }
                 }
             }
@@ -749,6 +844,34 @@ impl Cache {
             .rm_rf(path, false)
     }
 
+    /// Remove an archive from the cache, along with its recorded dirhash.
+    pub fn remove_archive(&self, path: impl AsRef<Path>) -> io::Result<Removal> {
+        let path = path.as_ref();
+        let mut summary = self.remove_path(path)?;
+        summary += self.remove_path(path.with_extension(DIRHASH_EXTENSION))?;
+        summary += self.remove_path(path.with_extension(ENVIRONMENTS_EXTENSION))?;
+        Ok(summary)
+    }
+
+    /// Remove archives from the cache, along with their recorded dirhashes and any links to them
+    /// from the wheel and source distribution buckets.
+    pub fn remove_archives(&self, paths: &[PathBuf]) -> io::Result<Removal> {
+        let mut summary = self.removal();
+        if paths.is_empty() {
+            return Ok(summary);
+        }
+
+        let references = self.find_archive_references()?;
+        for path in paths {
+            let target = fs_err::canonicalize(path)?;
+            for link in references.get(&target).into_iter().flatten() {
+                summary += self.remove_path(link)?;
+            }
+            summary += self.remove_archive(path)?;
+        }
+        Ok(summary)
+    }
+
     /// Find all references to entries in the archive bucket.
     ///
     /// Archive entries are often referenced by symlinks in other cache buckets. This method
@@ -903,6 +1026,18 @@ impl Cache {
     }
 }
 
+/// Returns `true` if the [`Path`] represents a built wheel for the given package.
+fn is_built_wheel(path: &Path, name: &PackageName) -> bool {
+    built_wheel_name(path).is_some_and(|built| built == *name)
+}
+
+/// Return the name of the built wheel in the given directory, if any.
+fn built_wheel_name(path: &Path) -> Option<PackageName> {
+    let metadata = fs_err::read(path.join("metadata.msgpack")).ok()?;
+    let metadata = rmp_serde::from_slice::<ResolutionMetadata>(&metadata).ok()?;
+    Some(metadata.name)
+}
+
 /// An archive (unzipped wheel) that exists in the local cache.
 #[derive(Debug, Clone)]
 #[allow(unused)]
@@ -1226,6 +1361,11 @@ pub enum CacheBucket {
     /// Cache structure:
     ///  * `osv-v0/vulnerability/<vuln_id>.msgpack` — cached full vulnerability records
     Osv,
+    /// Cached PEP 740 provenance objects, fetched from a package index's integrity API.
+    ///
+    /// Cache structure:
+    ///  * `provenance-v0/<digest(index-url)>/<package-name>/<filename>.msgpack`
+    Provenance,
 }
 
 impl CacheBucket {
@@ -1241,7 +1381,7 @@ impl CacheBucket {
             Self::Interpreter => "interpreter-v4",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/build/cache_clean.rs`.
-            Self::Simple => "simple-v24",
+            Self::Simple => "simple-v25",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/build/cache_prune.rs`.
             Self::Wheels => "wheels-v6",
@@ -1253,6 +1393,7 @@ impl CacheBucket {
             Self::Python => "python-v0",
             Self::Binaries => "binaries-v0",
             Self::Osv => "osv-v0",
+            Self::Provenance => "provenance-v0",
         }
     }
 
@@ -1260,17 +1401,6 @@ impl CacheBucket {
     ///
     /// Returns the number of entries removed from the cache.
     fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
-        /// Returns `true` if the [`Path`] represents a built wheel for the given package.
-        fn is_match(path: &Path, name: &PackageName) -> bool {
-            let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
-                return false;
-            };
-            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
-                return false;
-            };
-            metadata.name == *name
-        }
-
         let mut summary = cache.removal();
         match self {
             Self::Wheels => {
@@ -1309,7 +1439,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
-                    if directories(&url)?.any(|version| is_match(&version, name)) {
+                    if directories(&url)?.any(|version| is_built_wheel(&version, name)) {
                         summary += cache.remove_path(url)?;
                     }
                 }
@@ -1319,7 +1449,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
-                    if directories(&path)?.any(|version| is_match(&version, name)) {
+                    if directories(&path)?.any(|version| is_built_wheel(&version, name)) {
                         summary += cache.remove_path(path)?;
                     }
                 }
@@ -1330,7 +1460,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Git);
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
-                        if is_match(&sha, name) {
+                        if is_built_wheel(&sha, name) {
                             summary += cache.remove_path(sha)?;
                         }
                     }
@@ -1348,6 +1478,14 @@ impl CacheBucket {
                     summary += cache.remove_path(directory.join(format!("{name}.rkyv")))?;
                 }
             }
+            Self::Provenance => {
+                // For provenance, we expect a directory for every index, followed by a directory
+                // per package (indexed by name).
+                let root = cache.bucket(self);
+                for directory in directories(root)? {
+                    summary += cache.remove_path(directory.join(name.as_ref()))?;
+                }
+            }
             Self::FlatIndex => {
                 // We can't know if the flat index includes a package, so we just remove the entire
                 // cache entry.
@@ -1383,6 +1521,7 @@ impl CacheBucket {
             Self::Python,
             Self::Binaries,
             Self::Osv,
+            Self::Provenance,
         ]
         .iter()
         .copied()
@@ -1575,4 +1714,36 @@ mod tests {
         assert!(victim_dir.join("payload.txt").is_file());
         assert!(fs_err::symlink_metadata(symlink).is_err());
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn prune_removes_dirhash_with_archive() {
+        use super::{Cache, CacheBucket};
+
+        let cache_root = tempfile::tempdir().unwrap();
+        let cache = Cache::from_path(cache_root.path());
+        let wheels = cache_root.path().join(CacheBucket::Wheels.to_str());
+        fs_err::create_dir_all(&wheels).unwrap();
+
+        // A referenced archive, an unreferenced archive, and a dirhash without an archive.
+        let referenced = ArchiveId::from_str("referenced").unwrap();
+        let unreferenced = ArchiveId::from_str("unreferenced").unwrap();
+        let missing = ArchiveId::from_str("missing").unwrap();
+        for id in [&referenced, &unreferenced] {
+            fs_err::create_dir_all(cache.archive(id)).unwrap();
+            fs_err::write(cache.archive(id).join("module.py"), "").unwrap();
+        }
+        for id in [&referenced, &unreferenced, &missing] {
+            fs_err::write(cache.archive_dirhash(id), "0".repeat(64)).unwrap();
+        }
+        fs_err::os::unix::fs::symlink(cache.archive(&referenced), wheels.join("link")).unwrap();
+
+        cache.prune(false).unwrap();
+
+        assert!(cache.archive(&referenced).is_dir());
+        assert!(cache.archive_dirhash(&referenced).is_file());
+        assert!(!cache.archive(&unreferenced).exists());
+        assert!(!cache.archive_dirhash(&unreferenced).exists());
+        assert!(!cache.archive_dirhash(&missing).exists());
+    }
 }
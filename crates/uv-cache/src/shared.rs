@@ -0,0 +1,100 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
+
+/// A secondary cache tier for wheels built from source, shared between machines.
+///
+/// The shared cache is consulted before building a source distribution, and populated after a
+/// successful build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SharedCache {
+    /// A directory, e.g., on a network file system.
+    Directory(PathBuf),
+    /// An HTTP server that supports `GET` and `PUT` requests.
+    Url(DisplaySafeUrl),
+}
+
+impl FromStr for SharedCache {
+    type Err = DisplaySafeUrlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            Ok(Self::Url(DisplaySafeUrl::parse(s)?))
+        } else if let Some(path) = s.strip_prefix("file://") {
+            Ok(Self::Directory(PathBuf::from(path)))
+        } else {
+            Ok(Self::Directory(PathBuf::from(s)))
+        }
+    }
+}
+
+impl Display for SharedCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Directory(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SharedCache {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("SharedCache")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A directory path, or an HTTP(S) URL that supports `GET` and `PUT` requests."
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SharedCache {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = SharedCache;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                SharedCache::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use super::SharedCache;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            SharedCache::from_str("/mnt/uv-cache").unwrap(),
+            SharedCache::Directory(PathBuf::from("/mnt/uv-cache"))
+        );
+        assert_eq!(
+            SharedCache::from_str("file:///mnt/uv-cache").unwrap(),
+            SharedCache::Directory(PathBuf::from("/mnt/uv-cache"))
+        );
+        assert!(matches!(
+            SharedCache::from_str("https://cache.example.com/uv").unwrap(),
+            SharedCache::Url(url) if url.as_str() == "https://cache.example.com/uv"
+        ));
+    }
+}
@@ -0,0 +1,541 @@
+use std::io;
+use std::ops::AddAssign;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_fs::{PhysicalSpaceError, directories, files};
+use uv_normalize::PackageName;
+
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket, built_wheel_name, is_archive_sidecar};
+
+/// The disk usage of a set of cache entries.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CacheUsage {
+    /// The number of files.
+    pub num_files: u64,
+    /// The logical size of the files, in bytes.
+    ///
+    /// Files that are hardlinked into an environment are counted in full.
+    pub logical_bytes: u64,
+    /// The exclusively owned physical storage of the files, in bytes.
+    ///
+    /// Files that are hardlinked into an environment (or otherwise share storage) are excluded,
+    /// as removing them from the cache would not reclaim any space. Only meaningful if
+    /// [`CacheStats::physical_space`] is supported.
+    pub physical_bytes: u64,
+    /// The time at which the least-recently-used entry was last used.
+    pub oldest: Option<SystemTime>,
+    /// The time at which the most-recently-used entry was last used.
+    pub newest: Option<SystemTime>,
+}
+
+impl CacheUsage {
+    /// Account for a file that was last used at the given time.
+    fn add_file(&mut self, logical_bytes: u64, physical_bytes: u64, last_used: SystemTime) {
+        self.num_files += 1;
+        self.logical_bytes += logical_bytes;
+        self.physical_bytes += physical_bytes;
+        self.add_time(last_used);
+    }
+
+    fn add_time(&mut self, last_used: SystemTime) {
+        self.oldest = Some(
+            self.oldest
+                .map_or(last_used, |oldest| oldest.min(last_used)),
+        );
+        self.newest = Some(
+            self.newest
+                .map_or(last_used, |newest| newest.max(last_used)),
+        );
+    }
+}
+
+impl AddAssign for CacheUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.num_files += other.num_files;
+        self.logical_bytes += other.logical_bytes;
+        self.physical_bytes += other.physical_bytes;
+        if let Some(oldest) = other.oldest {
+            self.add_time(oldest);
+        }
+        if let Some(newest) = other.newest {
+            self.add_time(newest);
+        }
+    }
+}
+
+/// The disk usage of a [`CacheBucket`].
+#[derive(Debug, Clone, Copy)]
+pub struct BucketUsage {
+    pub bucket: CacheBucket,
+    pub usage: CacheUsage,
+}
+
+/// The disk usage of the cache entries for a package, across all buckets.
+#[derive(Debug, Clone)]
+pub struct PackageUsage {
+    pub name: PackageName,
+    pub usage: CacheUsage,
+}
+
+/// The disk usage of an unzipped wheel (or cached environment) in the archive bucket.
+#[derive(Debug, Clone)]
+pub struct ArchiveUsage {
+    /// The path to the archive.
+    pub path: PathBuf,
+    /// The package that links to the archive, if any.
+    pub package: Option<PackageName>,
+    /// The time at which the archive was last used.
+    pub last_used: SystemTime,
+    pub usage: CacheUsage,
+}
+
+/// The accuracy of the physical storage reported in [`CacheStats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PhysicalSpace {
+    /// Physical storage was measured for every file.
+    #[default]
+    Exact,
+    /// Some files could not be measured; physical storage is a lower bound.
+    Incomplete,
+    /// The filesystem does not support physical space accounting.
+    Unsupported,
+}
+
+/// Disk usage statistics for the cache.
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    /// The usage of each bucket that exists in the cache.
+    pub buckets: Vec<BucketUsage>,
+    /// The usage of any entries outside the current buckets (e.g., outdated bucket versions).
+    pub other: CacheUsage,
+    /// The usage of each package with entries in the cache, sorted by name.
+    pub packages: Vec<PackageUsage>,
+    /// The usage of each archive in the archive bucket.
+    pub archives: Vec<ArchiveUsage>,
+    /// The accuracy of the reported physical storage.
+    pub physical_space: PhysicalSpace,
+}
+
+impl CacheStats {
+    /// The total usage of the cache.
+    pub fn total(&self) -> CacheUsage {
+        let mut total = self.other;
+        for bucket in &self.buckets {
+            total += bucket.usage;
+        }
+        total
+    }
+}
+
+impl Cache {
+    /// Compute the disk usage of the cache, by bucket and by package.
+    ///
+    /// Usage is attributed to a package if it's stored under a per-package entry (e.g., the
+    /// downloaded wheels or the Simple API response for the package), or if it's an archive that
+    /// such an entry links to. Archives are considered last used at the modification time of the
+    /// archive directory (see [`Cache::touch_archive`]); all other files at their own modification
+    /// time.
+    pub fn stats(&self) -> io::Result<CacheStats> {
+        let mut stats = CacheStats::default();
+        let entries = match fs_err::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(stats),
+            Err(err) => return Err(err),
+        };
+
+        let roots = self.package_roots()?;
+        let owners = self.archive_owners(&roots)?;
+
+        let mut physical = PhysicalMeasure::default();
+        let mut buckets = FxHashMap::<CacheBucket, CacheUsage>::default();
+        let mut packages = FxHashMap::<PackageName, CacheUsage>::default();
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            let bucket = CacheBucket::iter().find(|bucket| entry.file_name() == bucket.to_str());
+            let mut usage = CacheUsage::default();
+
+            if bucket == Some(CacheBucket::Archive) {
+                // Resolve archives to their canonical path, to match the archive references.
+                let canonical = fs_err::canonicalize(&path)?;
+                for entry in fs_err::read_dir(&path)? {
+                    let entry = entry?;
+                    let path = entry.path();
+                    let Some(metadata) = metadata(&path)? else {
+                        continue;
+                    };
+
+                    // Sidecars are attributed to the package that owns the archive.
+                    let id = if is_archive_sidecar(&path) {
+                        path.with_extension("")
+                    } else {
+                        path.clone()
+                    };
+                    let package = id
+                        .file_name()
+                        .and_then(|id| owners.get(&canonical.join(id)))
+                        .cloned();
+
+                    let mut archive = CacheUsage::default();
+                    if metadata.is_dir() {
+                        let last_used = metadata.modified()?;
+                        archive.add_time(last_used);
+                        walk(
+                            &path,
+                            &mut physical,
+                            |_, logical_bytes, physical_bytes, _| {
+                                archive.add_file(logical_bytes, physical_bytes, last_used);
+                            },
+                        )?;
+                        stats.archives.push(ArchiveUsage {
+                            path,
+                            package: package.clone(),
+                            last_used,
+                            usage: archive,
+                        });
+                    } else if metadata.is_file() {
+                        archive.add_file(
+                            metadata.len(),
+                            physical.measure(&path, &metadata),
+                            metadata.modified()?,
+                        );
+                    }
+
+                    usage += archive;
+                    if let Some(package) = package {
+                        *packages.entry(package).or_default() += archive;
+                    }
+                }
+            } else {
+                walk(
+                    &path,
+                    &mut physical,
+                    |file, logical_bytes, physical_bytes, modified| {
+                        usage.add_file(logical_bytes, physical_bytes, modified);
+                        if bucket.is_none() {
+                            return;
+                        }
+                        let package = file
+                            .ancestors()
+                            .take_while(|ancestor| *ancestor != path)
+                            .find_map(|ancestor| roots.get(ancestor));
+                        if let Some(package) = package {
+                            packages.entry(package.clone()).or_default().add_file(
+                                logical_bytes,
+                                physical_bytes,
+                                modified,
+                            );
+                        }
+                    },
+                )?;
+            }
+
+            match bucket {
+                Some(bucket) => *buckets.entry(bucket).or_default() += usage,
+                None => stats.other += usage,
+            }
+        }
+
+        stats.buckets = CacheBucket::iter()
+            .filter_map(|bucket| {
+                buckets
+                    .remove(&bucket)
+                    .map(|usage| BucketUsage { bucket, usage })
+            })
+            .collect();
+        stats.packages = packages
+            .into_iter()
+            .map(|(name, usage)| PackageUsage { name, usage })
+            .collect();
+        stats.packages.sort_by(|a, b| a.name.cmp(&b.name));
+        stats
+            .archives
+            .sort_by(|a, b| a.last_used.cmp(&b.last_used).then(a.path.cmp(&b.path)));
+        stats.physical_space = physical.accuracy();
+
+        Ok(stats)
+    }
+
+    /// Return the per-package entries in the cache, mapped to the package they belong to.
+    ///
+    /// Mirrors the layout of each bucket, as in [`CacheBucket::remove`].
+    fn package_roots(&self) -> io::Result<FxHashMap<PathBuf, PackageName>> {
+        let mut roots = FxHashMap::default();
+
+        // For wheels, we expect a directory per package (indexed by name), under a directory for
+        // every index or URL (other than for PyPI).
+        let root = self.bucket(CacheBucket::Wheels);
+        named_directories(&root.join(WheelCacheKind::Pypi), &mut roots)?;
+        for kind in [
+            WheelCacheKind::Index,
+            WheelCacheKind::Url,
+            WheelCacheKind::Path,
+        ] {
+            for directory in directories(root.join(kind))? {
+                named_directories(&directory, &mut roots)?;
+            }
+        }
+
+        // For source distributions from an index, we expect the same layout as for wheels.
+        let root = self.bucket(CacheBucket::SourceDistributions);
+        named_directories(&root.join(WheelCacheKind::Pypi), &mut roots)?;
+        for directory in directories(root.join(WheelCacheKind::Index))? {
+            named_directories(&directory, &mut roots)?;
+        }
+
+        // For direct URLs and local paths, we expect a directory for every URL or path, followed
+        // by a directory per version, which contains the metadata of the built wheel.
+        for kind in [WheelCacheKind::Url, WheelCacheKind::Path] {
+            for directory in directories(root.join(kind))? {
+                let name = directories(&directory)?.find_map(|version| built_wheel_name(&version));
+                if let Some(name) = name {
+                    roots.insert(directory, name);
+                }
+            }
+        }
+
+        // For Git dependencies, we expect a directory for every repository, followed by a
+        // directory for every SHA, which contains the metadata of the built wheel.
+        for repository in directories(root.join(WheelCacheKind::Git))? {
+            for sha in directories(repository)? {
+                if let Some(name) = built_wheel_name(&sha) {
+                    roots.insert(sha, name);
+                }
+            }
+        }
+
+        // For Simple API responses, we expect a rkyv file per package (indexed by name).
+        let root = self.bucket(CacheBucket::Simple);
+        named_files(&root.join(WheelCacheKind::Pypi), &mut roots)?;
+        for directory in directories(root.join(WheelCacheKind::Index))? {
+            named_files(&directory, &mut roots)?;
+        }
+
+        // For provenance, we expect a directory for every index, followed by a directory per
+        // package (indexed by name).
+        for directory in directories(self.bucket(CacheBucket::Provenance))? {
+            named_directories(&directory, &mut roots)?;
+        }
+
+        Ok(roots)
+    }
+
+    /// Return the package that links to each archive, keyed by the canonical archive path.
+    fn archive_owners(
+        &self,
+        roots: &FxHashMap<PathBuf, PackageName>,
+    ) -> io::Result<FxHashMap<PathBuf, PackageName>> {
+        let mut owners = FxHashMap::default();
+        for (target, links) in self.find_archive_references()? {
+            let package = links.iter().find_map(|link| {
+                link.ancestors()
+                    .take_while(|ancestor| *ancestor != self.root)
+                    .find_map(|ancestor| roots.get(ancestor))
+            });
+            if let Some(package) = package {
+                owners.insert(target, package.clone());
+            }
+        }
+        Ok(owners)
+    }
+}
+
+/// Insert the directories in `path` that are named after a package.
+fn named_directories(path: &Path, roots: &mut FxHashMap<PathBuf, PackageName>) -> io::Result<()> {
+    for directory in directories(path)? {
+        let name = directory
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| PackageName::from_str(name).ok());
+        if let Some(name) = name {
+            roots.insert(directory, name);
+        }
+    }
+    Ok(())
+}
+
+/// Insert the `.rkyv` files in `path` that are named after a package.
+fn named_files(path: &Path, roots: &mut FxHashMap<PathBuf, PackageName>) -> io::Result<()> {
+    for file in files(path)? {
+        if file.extension().is_none_or(|ext| ext != "rkyv") {
+            continue;
+        }
+        let name = file
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .and_then(|name| PackageName::from_str(name).ok());
+        if let Some(name) = name {
+            roots.insert(file, name);
+        }
+    }
+    Ok(())
+}
+
+/// Return the metadata for a path without following symlinks, or `None` if it was removed
+/// concurrently.
+fn metadata(path: &Path) -> io::Result<Option<std::fs::Metadata>> {
+    match fs_err::symlink_metadata(path) {
+        Ok(metadata) => Ok(Some(metadata)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Call `f` with the path, logical size, physical size, and modification time of every regular
+/// file under `root`.
+///
+/// Symlinks (e.g., links into the archive bucket) are not followed, such that each file is only
+/// counted once.
+fn walk(
+    root: &Path,
+    physical: &mut PhysicalMeasure,
+    mut f: impl FnMut(&Path, u64, u64, SystemTime),
+) -> io::Result<()> {
+    for entry in walkdir::WalkDir::new(root) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            // Entries may be removed by a concurrent uv process.
+            Err(err)
+                if err
+                    .io_error()
+                    .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+            {
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Some(metadata) = metadata(entry.path())? else {
+            continue;
+        };
+        let physical_bytes = physical.measure(entry.path(), &metadata);
+        f(
+            entry.path(),
+            metadata.len(),
+            physical_bytes,
+            metadata.modified()?,
+        );
+    }
+    Ok(())
+}
+
+/// Measures the exclusively owned physical storage of files.
+#[derive(Debug, Default)]
+struct PhysicalMeasure {
+    unsupported: bool,
+    incomplete: bool,
+}
+
+impl PhysicalMeasure {
+    fn measure(&mut self, path: &Path, metadata: &std::fs::Metadata) -> u64 {
+        if self.unsupported {
+            return 0;
+        }
+        match uv_fs::physical_space(path, metadata) {
+            Ok(physical) => physical,
+            Err(PhysicalSpaceError::UnsupportedFilesystem) => {
+                debug!(
+                    "Physical space accounting is unsupported for {}; reporting logical space only",
+                    path.display()
+                );
+                self.unsupported = true;
+                0
+            }
+            Err(PhysicalSpaceError::UnmeasurableFile(error)) => {
+                debug!(
+                    "Failed to measure physical space for {}: {error}",
+                    path.display()
+                );
+                self.incomplete = true;
+                0
+            }
+        }
+    }
+
+    fn accuracy(&self) -> PhysicalSpace {
+        if self.unsupported {
+            PhysicalSpace::Unsupported
+        } else if self.incomplete {
+            PhysicalSpace::Incomplete
+        } else {
+            PhysicalSpace::Exact
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use crate::{ArchiveId, Cache, CacheBucket};
+
+    #[test]
+    #[cfg(unix)]
+    fn stats_by_package() {
+        let cache = Cache::temp().unwrap();
+
+        // A downloaded wheel for `foo`, linked into the archive bucket.
+        let id = ArchiveId::from_str("foo").unwrap();
+        fs_err::create_dir_all(cache.archive(&id)).unwrap();
+        fs_err::write(cache.archive(&id).join("foo.py"), vec![b'x'; 100]).unwrap();
+        let wheels = cache.bucket(CacheBucket::Wheels).join("pypi").join("foo");
+        fs_err::create_dir_all(&wheels).unwrap();
+        fs_err::os::unix::fs::symlink(cache.archive(&id), wheels.join("1.0.0-py3-none-any"))
+            .unwrap();
+        fs_err::write(wheels.join("1.0.0-py3-none-any.http"), vec![b'x'; 10]).unwrap();
+
+        // The Simple API responses for `foo` and `bar`.
+        let simple = cache.bucket(CacheBucket::Simple).join("pypi");
+        fs_err::create_dir_all(&simple).unwrap();
+        fs_err::write(simple.join("foo.rkyv"), vec![b'x'; 20]).unwrap();
+        fs_err::write(simple.join("bar.rkyv"), vec![b'x'; 5]).unwrap();
+
+        // An archive that isn't linked to any package.
+        let orphan = ArchiveId::from_str("orphan").unwrap();
+        fs_err::create_dir_all(cache.archive(&orphan)).unwrap();
+        fs_err::write(cache.archive(&orphan).join("orphan.py"), vec![b'x'; 1]).unwrap();
+
+        let stats = cache.stats().unwrap();
+
+        let packages = stats
+            .packages
+            .iter()
+            .map(|package| (package.name.to_string(), package.usage.logical_bytes))
+            .collect::<Vec<_>>();
+        assert_eq!(packages, [("bar".to_string(), 5), ("foo".to_string(), 130)]);
+
+        let buckets = stats
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.bucket, bucket.usage.logical_bytes))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            buckets,
+            [
+                (CacheBucket::Wheels, 10),
+                (CacheBucket::Simple, 25),
+                (CacheBucket::Archive, 101),
+            ]
+        );
+
+        assert_eq!(stats.archives.len(), 2);
+        let foo = PackageName::from_str("foo").unwrap();
+        assert!(
+            stats
+                .archives
+                .iter()
+                .any(|archive| archive.package.as_ref() == Some(&foo))
+        );
+        assert_eq!(stats.total().logical_bytes, 136);
+    }
+}
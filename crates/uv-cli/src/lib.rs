@@ -1,5 +1,6 @@
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -15,8 +16,9 @@ use uv_audit::VulnerabilityServiceFormat;
 use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    CacheAge, CacheSize, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    PipCompileFormat, ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexName, IndexSourceError, IndexUrl,
@@ -69,6 +71,15 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PublishFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum AuditOutputFormat {
     /// Display the result in a human-readable format.
@@ -91,6 +102,15 @@ pub enum CacheSizeOutputFormat {
     Machine,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheStatsFormat {
+    /// Display the statistics in a human-readable format.
+    #[default]
+    Text,
+    /// Display the statistics in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum TreeFormat {
     /// Display the dependency graph as a human-readable tree.
@@ -567,6 +587,12 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage package indexes.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
     /// Inspect uv workspaces.
     #[command(
         after_help = "Use `uv help workspace` for more details.",
@@ -939,6 +965,30 @@ pub enum CacheCommand {
     /// wheels, source distributions, and other cached data. By default, displays a human-readable
     /// size when the output is a terminal and raw bytes otherwise.
     Size(SizeArgs),
+    /// Export the cache entries needed to sync a lockfile into an archive.
+    ///
+    /// The archive includes the cached package metadata, downloaded and built wheels, Git
+    /// repositories, and Python download metadata for the packages in the lockfile, and can be
+    /// merged into another cache with `uv cache import` (e.g., to pass the cache between stages
+    /// of a continuous integration pipeline).
+    Export(CacheExportArgs),
+    /// Import cache entries from an archive created by `uv cache export`.
+    ///
+    /// Every entry is verified against the archive's manifest before the cache is modified.
+    /// Entries that already exist in the cache are retained.
+    Import(CacheImportArgs),
+    /// Verify the integrity of the cache, removing any corrupt entries.
+    ///
+    /// Each unzipped wheel is checked against the hash recorded when it was added to the cache,
+    /// and each metadata entry is checked to deserialize. Corrupt entries are removed, such that
+    /// they're re-downloaded or rebuilt on next use.
+    Verify(CacheVerifyArgs),
+    /// Show the disk usage of the cache, by bucket and by package.
+    ///
+    /// Reports the logical size of each cache bucket and of the largest packages, along with the
+    /// physical storage that removing them would reclaim (excluding files that are hardlinked into
+    /// an environment), and how recently the unzipped wheels in the cache were last used.
+    Stats(CacheStatsArgs),
 }
 
 #[derive(Args, Debug)]
@@ -973,6 +1023,23 @@ pub struct PruneArgs {
     #[arg(long)]
     pub ci: bool,
 
+    /// Evict the least-recently-used cache entries until the cache fits within the given size.
+    ///
+    /// Accepts a size with an optional binary unit, e.g., `512M` or `20G`.
+    ///
+    /// Unzipped wheels are evicted in order of last use, along with any links to them from other
+    /// cache buckets. Unzipped wheels that are linked into an existing environment are retained.
+    #[arg(long, env = EnvVars::UV_CACHE_MAX_SIZE, value_name = "SIZE")]
+    pub max_size: Option<CacheSize>,
+
+    /// Evict cache entries that have not been used within the given duration.
+    ///
+    /// Accepts a duration, e.g., `30d`, `2w`, or `12h`.
+    ///
+    /// Unzipped wheels that are linked into an existing environment are retained.
+    #[arg(long, env = EnvVars::UV_CACHE_MAX_AGE, value_name = "AGE")]
+    pub max_age: Option<CacheAge>,
+
     /// Force removal of the cache, ignoring in-use checks.
     ///
     /// By default, `uv cache prune` will block until no process is reading the cache. When
@@ -997,6 +1064,52 @@ pub struct SizeArgs {
     pub human: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct CacheExportArgs {
+    /// The lockfile to export cache entries for.
+    #[arg(long, default_value = "uv.lock", value_hint = ValueHint::FilePath)]
+    pub lock: PathBuf,
+
+    /// Write the archive to the given `.tar.zst` file.
+    #[arg(long, short, value_hint = ValueHint::FilePath)]
+    pub output_file: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheImportArgs {
+    /// The `.tar.zst` archive to import.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub archive: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheVerifyArgs {
+    /// Only check the structure of each cache entry.
+    ///
+    /// In `--quick` mode, uv checks that each unzipped wheel is well-formed, but doesn't hash its
+    /// contents, and checks that each metadata entry is non-empty, but doesn't deserialize it.
+    #[arg(long)]
+    pub quick: bool,
+
+    /// Force verification of the cache, ignoring in-use checks.
+    ///
+    /// By default, `uv cache verify` will block until no process is reading the cache. When
+    /// `--force` is used, `uv cache verify` will proceed without taking a lock.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheStatsArgs {
+    /// The number of packages to display, ordered by size.
+    #[arg(long, default_value_t = 10, value_name = "N")]
+    pub top: usize,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = CacheStatsFormat::default())]
+    pub output_format: CacheStatsFormat,
+}
+
 #[derive(Args)]
 pub struct PipNamespace {
     #[command(subcommand)]
@@ -3071,6 +3184,37 @@ pub struct BuildArgs {
     #[arg(long)]
     pub clear: bool,
 
+    /// Check the built distributions for problems.
+    ///
+    /// Each wheel and source distribution is opened and its filename, core metadata, long
+    /// description content type, license files, and archive paths are validated, as well as the
+    /// `RECORD` hashes and `WHEEL` tags of wheels. The build fails if any distribution has an
+    /// error.
+    #[arg(long, conflicts_with = "list")]
+    pub check: bool,
+
+    /// Build each distribution twice and verify that the builds are identical.
+    ///
+    /// Both builds run in separate temporary directories. The archives are compared member by
+    /// member, and differences in the contents, permissions, modification times, or order of
+    /// the members are reported. The build fails if any distribution is not reproducible.
+    ///
+    /// Set `SOURCE_DATE_EPOCH` to use a fixed timestamp in build backends that embed the current
+    /// time.
+    #[arg(long, conflicts_with = "list")]
+    pub verify_reproducible: bool,
+
+    /// Repair Linux wheels that depend on shared libraries from the build machine.
+    ///
+    /// The shared libraries that are not part of the `manylinux` policy are copied into a
+    /// `<name>.libs` directory in the wheel, the native modules are patched to load the copies,
+    /// and the `linux_<arch>` tag is replaced with the `manylinux` or `musllinux` tag that matches
+    /// the glibc symbol versions the wheel requires, similar to `auditwheel repair`.
+    ///
+    /// Requires `patchelf`.
+    #[arg(long, conflicts_with = "list")]
+    pub repair: bool,
+
     #[arg(long, overrides_with("no_create_gitignore"), hide = true)]
     pub create_gitignore: bool,
 
@@ -3106,6 +3250,10 @@ pub struct BuildArgs {
     /// will be used to create those environments, and will be symlinked or copied in depending on
     /// the platform.
     ///
+    /// May be provided multiple times to build wheels with each interpreter, e.g., for each
+    /// supported Python version. The source distribution is built once, and identical wheels
+    /// are only kept once.
+    ///
     /// See `uv help python` to view supported request formats.
     #[arg(
         long,
@@ -3116,7 +3264,7 @@ pub struct BuildArgs {
         value_parser = parse_maybe_string,
         value_hint = ValueHint::Other,
     )]
-    pub python: Option<Maybe<String>>,
+    pub python: Vec<Maybe<String>>,
 
     #[command(flatten)]
     pub resolver: ResolverArgs,
@@ -3276,6 +3424,22 @@ pub struct VenvArgs {
     #[arg(long, overrides_with("relocatable"), hide = true)]
     pub no_relocatable: bool,
 
+    /// Layer the virtual environment on top of a read-only parent environment.
+    ///
+    /// The `site-packages` directory of the parent environment is appended to the virtual
+    /// environment's `sys.path`, such that packages installed in the parent are importable, but
+    /// packages installed in the virtual environment itself take precedence. When syncing, uv
+    /// treats packages provided by a parent environment as installed if they match the requested
+    /// version; otherwise, the requested version is installed into the virtual environment,
+    /// shadowing the parent. uv never modifies a parent environment.
+    ///
+    /// The parent must be a virtual environment using the same Python minor version. Parents
+    /// declared by the parent environment are included transitively.
+    ///
+    /// May be provided multiple times, in order of precedence.
+    #[arg(long, value_hint = ValueHint::DirPath)]
+    pub parent: Vec<PathBuf>,
+
     #[command(flatten)]
     pub index_args: IndexArgs,
 
@@ -3582,6 +3746,17 @@ pub struct RunArgs {
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_env_file: bool,
 
+    /// Print the environment variables that would be set for the command, instead of running it.
+    ///
+    /// Includes the variables read from `--env-file` and those declared in
+    /// `[tool.uv.environment]`, along with the `PATH` and `VIRTUAL_ENV` variables set by uv.
+    #[arg(long)]
+    pub print_env: bool,
+
+    /// List the tasks declared in `[tool.uv.scripts]`, instead of running a command.
+    #[arg(long, conflicts_with = "print_env")]
+    pub list: bool,
+
     /// The command to run.
     ///
     /// If the path to a Python script (i.e., ending in `.py`), it will be
@@ -5765,6 +5940,17 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub lfs: bool,
 
+    /// Install the tool into an environment shared with other tools.
+    ///
+    /// The tool is installed into an existing shared environment if its requirements are
+    /// compatible with those of the tools already installed there, i.e., if installing it
+    /// wouldn't change any package they depend on. Otherwise, a new shared environment is created.
+    ///
+    /// When a shared tool is upgraded and its requirements diverge from those of the other tools
+    /// in its environment, it's moved into a separate shared environment.
+    #[arg(long)]
+    pub shared: bool,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
@@ -5840,6 +6026,12 @@ pub struct ToolListArgs {
     #[arg(long)]
     pub show_python: bool,
 
+    /// Whether to display the environment each tool is installed into.
+    ///
+    /// Tools installed with `--shared` may share an environment with other tools.
+    #[arg(long)]
+    pub show_environments: bool,
+
     /// List outdated tools.
     ///
     /// The latest version of each tool will be shown alongside the installed version. Up-to-date
@@ -7630,15 +7822,19 @@ pub struct PublishArgs {
     /// uv publish --index pypi
     /// uv publish --publish-url https://upload.pypi.org/legacy/ --check-url https://pypi.org/simple
     /// ```
+    ///
+    /// May be provided multiple times, or as a comma-separated list, to publish to multiple
+    /// indexes. All indexes are checked for existing files before any file is uploaded.
     #[arg(
         long,
         verbatim_doc_comment,
         env = EnvVars::UV_PUBLISH_INDEX,
+        value_delimiter = ',',
         conflicts_with = "publish_url",
         conflicts_with = "check_url",
         value_hint = ValueHint::Other,
     )]
-    pub index: Option<String>,
+    pub index: Vec<String>,
 
     /// The username for the upload.
     #[arg(
@@ -7719,6 +7915,23 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL, hide_env_values = true)]
     pub check_url: Option<IndexUrl>,
 
+    /// Publish to a directory on the local file system instead of uploading to a registry.
+    ///
+    /// The files are copied into a PEP 503 directory layout, with a directory for each project,
+    /// and the project pages are regenerated in both the HTML and the PEP 691 JSON format, with
+    /// hashes and PEP 658 metadata files. The directory can be used directly as a `file://` index,
+    /// or served as a static index, e.g., from a file share or an object storage bucket.
+    ///
+    /// Publish URLs with the `file://` scheme are handled the same way.
+    #[arg(
+        long,
+        conflicts_with = "publish_url",
+        conflicts_with = "check_url",
+        conflicts_with = "index",
+        value_hint = ValueHint::DirPath,
+    )]
+    pub to_directory: Option<PathBuf>,
+
     #[arg(long, hide = true)]
     pub skip_existing: bool,
 
@@ -7736,12 +7949,122 @@ pub struct PublishArgs {
     #[arg(long, env = EnvVars::UV_PUBLISH_NO_ATTESTATIONS)]
     pub no_attestations: bool,
 
+    /// Generate PEP 740 publish attestations for the published files.
+    ///
+    /// Each file is signed with Sigstore, using an ephemeral key that is certified for the OIDC
+    /// identity of the CI environment (e.g., the GitHub Actions workflow), and the signature is
+    /// recorded in Sigstore's public transparency log. The attestations are written next to the
+    /// files, as `<file>.publish.attestation`, and uploaded alongside them. Files that already
+    /// have a publish attestation are uploaded with the existing attestation.
+    ///
+    /// Requires a supported trusted publishing environment, like GitHub Actions or GitLab CI/CD,
+    /// with permission to request an OIDC token.
+    #[arg(long, conflicts_with = "no_attestations")]
+    pub attest: bool,
+
+    /// The URL of the Sigstore certificate authority (Fulcio) used to sign attestations.
+    ///
+    /// Defaults to Sigstore's public-good instance (<https://fulcio.sigstore.dev>), which is the
+    /// instance that PyPI trusts.
+    #[arg(
+        long,
+        env = EnvVars::UV_PUBLISH_FULCIO_URL,
+        requires = "attest",
+        hide_env_values = true
+    )]
+    pub fulcio_url: Option<DisplaySafeUrl>,
+
+    /// The URL of the Sigstore transparency log (Rekor) in which signatures are recorded.
+    ///
+    /// Defaults to Sigstore's public-good instance (<https://rekor.sigstore.dev>), which is the
+    /// instance that PyPI trusts.
+    #[arg(
+        long,
+        env = EnvVars::UV_PUBLISH_REKOR_URL,
+        requires = "attest",
+        hide_env_values = true
+    )]
+    pub rekor_url: Option<DisplaySafeUrl>,
+
+    /// Check the files for problems before uploading any of them.
+    ///
+    /// Each wheel and source distribution is opened and its filename, core metadata, long
+    /// description content type, license files, and archive paths are validated, as well as the
+    /// `RECORD` hashes and `WHEEL` tags of wheels. If any file has an error, nothing is uploaded.
+    #[arg(long)]
+    pub check: bool,
+
     /// Use direct upload to the registry.
     ///
     /// When enabled, the publish command will use a direct two-phase upload protocol
     /// that uploads files directly to storage, bypassing the registry's upload endpoint.
     #[arg(long, hide = true)]
     pub direct: bool,
+
+    /// The format in which to display the result of the upload.
+    ///
+    /// The JSON report lists the status of each file on each index, e.g., whether it was uploaded
+    /// or already existed, so that a partially failed publish can be retried.
+    #[arg(long, value_enum, default_value_t = PublishFormat::default())]
+    pub output_format: PublishFormat,
+}
+
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Serve a directory of distributions as a package index on localhost.
+    ///
+    /// The wheels and source distributions in the directory, e.g., a directory used with
+    /// `--find-links`, are served under `/simple/` with generated project pages in the HTML and
+    /// JSON formats of the Simple API, including their hashes and the core metadata of wheels.
+    /// Alternatively, the wheels that uv built from source distributions can be served from the
+    /// cache.
+    ///
+    /// The files are read once on startup. The server runs until interrupted.
+    Serve(IndexServeArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct IndexServeArgs {
+    /// The directory with the wheels and source distributions to serve.
+    #[arg(
+        value_hint = ValueHint::DirPath,
+        required_unless_present = "from_cache",
+        conflicts_with = "from_cache"
+    )]
+    pub path: Option<PathBuf>,
+
+    /// Serve the wheels that were built from source distributions and stored in the cache.
+    #[arg(long)]
+    pub from_cache: bool,
+
+    /// The address to listen on.
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: std::net::IpAddr,
+
+    /// The port to listen on.
+    ///
+    /// Use `0` to select an unused port.
+    #[arg(long, default_value_t = 8000)]
+    pub port: u16,
+
+    /// Require HTTP basic authentication with this username.
+    #[arg(long, env = EnvVars::UV_SERVE_USERNAME, requires = "password")]
+    pub username: Option<String>,
+
+    /// Require HTTP basic authentication with this password.
+    #[arg(
+        long,
+        env = EnvVars::UV_SERVE_PASSWORD,
+        hide_env_values = true,
+        requires = "username"
+    )]
+    pub password: Option<String>,
 }
 
 #[derive(Args)]
@@ -7767,6 +8090,12 @@ pub enum WorkspaceCommand {
     ///
     /// Displays newline separated names of workspace members.
     List(WorkspaceListArgs),
+    /// Run a command in each member of a workspace.
+    ///
+    /// The workspace environment is synced to include the selected members, then the command is
+    /// run with each member's directory as the working directory. A summary of the members in
+    /// which the command succeeded or failed is displayed once all runs have completed.
+    Exec(Box<WorkspaceExecArgs>),
 }
 #[derive(Args)]
 pub struct MetadataArgs {
@@ -7866,10 +8195,95 @@ pub struct WorkspaceListArgs {
     pub paths: bool,
 
     /// List all standalone scripts with inline metadata in the workspace.
+    ///
+    /// The tasks declared in `[tool.uv.scripts]` by each workspace member are listed as
+    /// `<member>:<task>`.
     #[arg(long)]
     pub scripts: bool,
 }
 
+#[derive(Args)]
+pub struct WorkspaceExecArgs {
+    /// Only run the command in the specified workspace members.
+    ///
+    /// May be provided multiple times. By default, the command is run in all workspace members.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub package: Vec<PackageName>,
+
+    /// Only run the command in workspace members with changes since the given Git reference.
+    ///
+    /// A member is considered changed if any file in its directory differs from the given
+    /// reference, including uncommitted and untracked files. Files in nested members are
+    /// attributed to the innermost member.
+    #[arg(long, value_name = "REF", value_hint = ValueHint::Other)]
+    pub changed_since: Option<String>,
+
+    /// The number of workspace members to run the command in concurrently.
+    ///
+    /// When greater than one, the output of each run is captured and displayed once the run
+    /// completes.
+    #[arg(long, value_name = "N", default_value = "1")]
+    pub parallel: NonZeroUsize,
+
+    /// Sync dependencies to the active virtual environment.
+    ///
+    /// Instead of creating or updating the virtual environment for the workspace, the active
+    /// virtual environment will be preferred, if the `VIRTUAL_ENV` environment variable is set.
+    #[arg(long, overrides_with = "no_active")]
+    pub active: bool,
+
+    /// Prefer project virtual environment over an active environment.
+    ///
+    /// By default, uv prefers to use the project's virtual environment, even if another is active.
+    #[arg(long, overrides_with = "active", hide = true)]
+    pub no_active: bool,
+
+    /// Avoid syncing the virtual environment [env: UV_NO_SYNC=]
+    #[arg(long, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_sync: bool,
+
+    /// Assert that the `uv.lock` will remain unchanged [env: UV_LOCKED=]
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Run without updating the `uv.lock` file [env: UV_FROZEN=]
+    ///
+    /// Instead of checking if the lockfile is up-to-date, uses the versions in the lockfile as the
+    /// source of truth. If the lockfile is missing, uv will exit with an error.
+    #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use for the workspace environment.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// The command to run in each workspace member.
+    #[command(subcommand)]
+    pub command: ExternalCommand,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
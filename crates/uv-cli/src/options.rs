@@ -824,6 +824,8 @@ pub fn resolver_installer_options(
         } else {
             Some(no_sources_package)
         },
+        require_provenance: None,
+        require_provenance_package: None,
         torch_backend: None,
     }
     .relative_to(&env::current_dir()?)
@@ -1,6 +1,7 @@
 use std::env;
 use std::fmt::{Debug, Write};
 use std::num::ParseIntError;
+use std::path::PathBuf;
 use std::sync::{Arc, OnceLock};
 use std::time::{Duration, SystemTimeError};
 
@@ -25,7 +26,7 @@ use uv_auth::{
     AuthMiddleware, Credentials, CredentialsCache, CredentialsFromUrlError, Indexes, PyxTokenStore,
 };
 use uv_configuration::ProxyUrlKind;
-use uv_configuration::{Concurrency, KeyringProviderType, ProxyUrl, TrustedHost};
+use uv_configuration::{Concurrency, GitMirror, KeyringProviderType, ProxyUrl, TrustedHost};
 use uv_distribution_types::IndexCredentialsError;
 use uv_git::GitHttpSettings;
 use uv_pep508::MarkerEnvironment;
@@ -92,6 +93,8 @@ pub struct BaseClientBuilder<'a> {
     keyring: KeyringProviderType,
     preview: Preview,
     allow_insecure_host: Vec<TrustedHost>,
+    git_mirrors: Vec<GitMirror>,
+    git_allowed_signers: Option<PathBuf>,
     system_certs: bool,
     custom_certificates: Option<Certificates>,
     retries: u32,
@@ -203,6 +206,8 @@ impl Default for BaseClientBuilder<'_> {
             keyring: KeyringProviderType::default(),
             preview: Preview::default(),
             allow_insecure_host: vec![],
+            git_mirrors: vec![],
+            git_allowed_signers: None,
             system_certs: false,
             custom_certificates: None,
             connectivity: Connectivity::Online,
@@ -275,6 +280,18 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn git_mirrors(mut self, git_mirrors: Vec<GitMirror>) -> Self {
+        self.git_mirrors = git_mirrors;
+        self
+    }
+
+    #[must_use]
+    pub fn git_allowed_signers(mut self, git_allowed_signers: Option<PathBuf>) -> Self {
+        self.git_allowed_signers = git_allowed_signers;
+        self
+    }
+
     #[must_use]
     pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
         self.connectivity = connectivity;
@@ -474,6 +491,8 @@ impl<'a> BaseClientBuilder<'a> {
         Ok(BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
+            git_mirrors: self.git_mirrors.clone(),
+            git_allowed_signers: self.git_allowed_signers.clone(),
             retries: self.retries,
             no_retry_delay: self.no_retry_delay,
             client,
@@ -505,6 +524,8 @@ impl<'a> BaseClientBuilder<'a> {
         BaseClient {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
+            git_mirrors: self.git_mirrors.clone(),
+            git_allowed_signers: self.git_allowed_signers.clone(),
             retries: self.retries,
             no_retry_delay: self.no_retry_delay,
             client,
@@ -751,6 +772,10 @@ pub struct BaseClient {
     connect_timeout: Duration,
     /// Hosts that are trusted to use the insecure client.
     allow_insecure_host: Vec<TrustedHost>,
+    /// Rules for fetching Git repositories from mirrors.
+    git_mirrors: Vec<GitMirror>,
+    /// The file of trusted SSH keys against which to verify Git signatures.
+    git_allowed_signers: Option<PathBuf>,
     /// The number of retries to attempt on transient errors.
     retries: u32,
     /// Whether to disable retry delays (for testing).
@@ -813,9 +838,12 @@ impl BaseClient {
 
     /// Return the [`GitHttpSettings`] for fetching from the given URL.
     pub fn git_http_settings(&self, url: &DisplaySafeUrl) -> GitHttpSettings {
+        let mirror = GitMirror::rewrite_with(&self.git_mirrors, url);
         GitHttpSettings::default()
-            .with_disabled_ssl(self.disable_ssl(url))
+            .with_disabled_ssl(self.disable_ssl(mirror.as_ref().unwrap_or(url)))
             .with_offline(self.connectivity().is_offline())
+            .with_mirror(mirror)
+            .with_allowed_signers(self.git_allowed_signers.clone())
     }
 
     /// The configured client read timeout.
@@ -694,6 +694,7 @@ impl RegistryClient {
                             data.project_status,
                             &url,
                         )
+                        .with_project_versions(data.versions)
                     }
                     MediaType::PypiV1Html | MediaType::TextHtml => {
                         let text = response.text().await.map_err(|err| {
@@ -725,15 +726,38 @@ impl RegistryClient {
 
     /// Fetch the [`SimpleDetailMetadata`] from a local file, using a PEP 503-compatible directory
     /// structure.
+    ///
+    /// Like a remote index, a PEP 691 JSON page (`index.json`) is preferred over the HTML page
+    /// (`index.html`) when both are present.
     async fn fetch_local_simple_detail(
         &self,
         package_name: &PackageName,
         url: &DisplaySafeUrl,
     ) -> Result<OwnedArchive<SimpleDetailMetadata>, Error> {
-        let path = url
+        let directory = url
             .to_file_path()
-            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?
-            .join("index.html");
+            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+
+        match fs_err::tokio::read(directory.join("index.json")).await {
+            Ok(bytes) => {
+                let data: PypiSimpleDetail = serde_json::from_slice(&bytes)
+                    .map_err(|err| Error::from_json_err(err, url.clone()))?;
+                let metadata = SimpleDetailMetadata::from_pypi_files(
+                    data.files,
+                    package_name,
+                    data.project_status,
+                    url,
+                )
+                .with_project_versions(data.versions);
+                return OwnedArchive::from_unarchived(&metadata);
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(Error::from(ErrorKind::Io(err)));
+            }
+        }
+
+        let path = directory.join("index.html");
         let text = match fs_err::tokio::read_to_string(&path).await {
             Ok(text) => text,
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -910,15 +934,30 @@ impl RegistryClient {
         Ok(index)
     }
 
-    /// Fetch the list of projects from a local Simple API index.
+    /// Fetch the list of projects from a local Simple API index, preferring a PEP 691 JSON page
+    /// (`index.json`) over the HTML page (`index.html`).
     async fn fetch_local_simple_index(
         &self,
         url: &DisplaySafeUrl,
     ) -> Result<OwnedArchive<SimpleIndexMetadata>, Error> {
-        let path = url
+        let directory = url
             .to_file_path()
-            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?
-            .join("index.html");
+            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+
+        match fs_err::tokio::read(directory.join("index.json")).await {
+            Ok(bytes) => {
+                let data: PypiSimpleIndex = serde_json::from_slice(&bytes)
+                    .map_err(|err| Error::from_json_err(err, url.clone()))?;
+                let metadata = SimpleIndexMetadata::from_pypi_index(data);
+                return OwnedArchive::from_unarchived(&metadata);
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(Error::from(ErrorKind::Io(err)));
+            }
+        }
+
+        let path = directory.join("index.html");
         let text = match fs_err::tokio::read_to_string(&path).await {
             Ok(text) => text,
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
@@ -1026,6 +1065,7 @@ impl RegistryClient {
                 let fetch = git
                     .fetch(
                         &wheel.git,
+                        wheel.install_path.parent(),
                         self.git_http_settings(wheel.git.url()),
                         self.cache.bucket(CacheBucket::Git),
                         reporter,
@@ -1660,6 +1700,10 @@ impl SimpleIndexMetadata {
 pub struct SimpleDetailMetadata {
     project_status: ProjectStatus,
     versions: Vec<SimpleDetailMetadatum>,
+    /// The versions of the project listed by a [PEP 700] index, including those without files.
+    ///
+    /// [PEP 700]: https://peps.python.org/pep-0700/
+    project_versions: Vec<Version>,
 }
 
 #[derive(Debug, rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
@@ -1676,6 +1720,38 @@ impl SimpleDetailMetadata {
         self.versions.iter()
     }
 
+    /// Return the versions of the project listed by a [PEP 700] index, including those without
+    /// files, in ascending order.
+    ///
+    /// Empty if the index doesn't list the versions (e.g., for HTML pages).
+    ///
+    /// [PEP 700]: https://peps.python.org/pep-0700/
+    pub fn project_versions(&self) -> &[Version] {
+        &self.project_versions
+    }
+
+    /// Set the versions of the project listed by a [PEP 700] index, skipping any that are
+    /// invalid.
+    ///
+    /// [PEP 700]: https://peps.python.org/pep-0700/
+    #[must_use]
+    fn with_project_versions(mut self, versions: Vec<SmallString>) -> Self {
+        let mut versions = versions
+            .into_iter()
+            .filter_map(|version| match version.parse::<Version>() {
+                Ok(version) => Some(version),
+                Err(err) => {
+                    debug!("Skipping invalid project version {version:?}: {err}");
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        versions.sort_unstable();
+        versions.dedup();
+        self.project_versions = versions;
+        self
+    }
+
     fn from_pypi_files(
         files: Vec<uv_pypi_types::PypiFile>,
         package_name: &PackageName,
@@ -1740,6 +1816,7 @@ impl SimpleDetailMetadata {
                 })
                 .collect(),
             project_status,
+            project_versions: Vec::new(),
         }
     }
 
@@ -1810,6 +1887,7 @@ impl SimpleDetailMetadata {
                 })
                 .collect(),
             project_status,
+            project_versions: Vec::new(),
         }
     }
 
@@ -1859,10 +1937,19 @@ impl ArchivedSimpleDetailMetadata {
     pub fn project_status(&self) -> &rkyv::Archived<ProjectStatus> {
         &self.project_status
     }
+
+    /// Return the versions of the project listed by a [PEP 700] index, including those without
+    /// files, in ascending order.
+    ///
+    /// [PEP 700]: https://peps.python.org/pep-0700/
+    pub fn project_versions(&self) -> &[rkyv::Archived<Version>] {
+        &self.project_versions
+    }
 }
 
-#[derive(Debug)]
-enum MediaType {
+/// A media type of a Simple API response.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MediaType {
     PyxV1Msgpack,
     PyxV1Json,
     PypiV1Json,
@@ -2380,7 +2467,8 @@ mod tests {
             &PackageName::from_str("pepy").unwrap(),
             data.project_status,
             &base,
-        );
+        )
+        .with_project_versions(data.versions);
 
         insta::assert_debug_snapshot!(simple_metadata, @r#"
         SimpleDetailMetadata {
@@ -2427,6 +2515,9 @@ mod tests {
                     metadata: None,
                 },
             ],
+            project_versions: [
+                "2.1.1",
+            ],
         }
         "#);
     }
@@ -2499,10 +2590,107 @@ mod tests {
                     metadata: None,
                 },
             ],
+            project_versions: [],
         }
         "#);
     }
 
+    /// Fetch the project list, the files for `ok`, and its listed versions from a local Simple API
+    /// index, rendering each file with its PEP 700 metadata.
+    async fn local_index_files(
+        format: &str,
+    ) -> Result<(Vec<String>, Vec<String>, Vec<String>), Error> {
+        let directory = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../test/simple-index")
+            .join(format);
+        let index = IndexUrl::parse(directory.to_string_lossy().as_ref(), None)?;
+        let package_name = PackageName::from_str("ok")?;
+        let registry_client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), Cache::temp()?)
+                .index_locations(IndexLocations::new(
+                    vec![Index::from_index_url(index.clone())],
+                    vec![],
+                    false,
+                ))
+                .build()?;
+
+        let projects = registry_client
+            .fetch_simple_index(&index)
+            .await?
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        let results = registry_client
+            .simple_detail(
+                &package_name,
+                Some(IndexMetadataRef {
+                    url: &index,
+                    format: IndexFormat::Simple,
+                }),
+                &IndexCapabilities::default(),
+                &Semaphore::new(1),
+            )
+            .await?;
+        let [(_, super::MetadataFormat::Simple(archive))] = results.as_slice() else {
+            panic!("expected a single Simple API response");
+        };
+        let simple_metadata = super::OwnedArchive::deserialize(archive);
+        let versions = simple_metadata
+            .project_versions()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let files = simple_metadata
+            .versions
+            .into_iter()
+            .flat_map(|datum| datum.files.all(&package_name))
+            .map(|(filename, file)| {
+                let url = file.url.to_url().expect("file URL is valid");
+                format!(
+                    "{filename} size={:?} upload_time_utc_ms={:?} requires_python={:?} url={}",
+                    file.size,
+                    file.upload_time_utc_ms,
+                    file.requires_python
+                        .map(|specifiers| specifiers.to_string()),
+                    url.as_str()
+                        .rsplit_once("/test/")
+                        .map_or(url.as_str(), |(_, path)| path),
+                )
+            })
+            .collect();
+
+        Ok((projects, files, versions))
+    }
+
+    /// Local indexes are read from the PEP 691 JSON page when present, and from the HTML page
+    /// otherwise, with the same PEP 700 file fields surfaced from either format. Only the JSON page
+    /// lists the project's versions.
+    #[tokio::test]
+    async fn local_index_json_and_html() -> Result<(), Error> {
+        let (json_projects, json_files, json_versions) = local_index_files("json").await?;
+        let (html_projects, html_files, html_versions) = local_index_files("html").await?;
+        let json = (json_projects, json_files);
+        let html = (html_projects, html_files);
+
+        insta::assert_debug_snapshot!(json, @r#"
+        (
+            [
+                "ok",
+            ],
+            [
+                "ok-1.0.0-py3-none-any.whl size=Some(875) upload_time_utc_ms=Some(1711324800000) requires_python=Some(\">=3.8\") url=links/ok-1.0.0-py3-none-any.whl",
+                "ok-2.0.0-py3-none-any.whl size=Some(871) upload_time_utc_ms=Some(1713184200000) requires_python=Some(\">=3.8\") url=links/ok-2.0.0-py3-none-any.whl",
+            ],
+        )
+        "#);
+        assert_eq!(json, html);
+        assert_eq!(json_versions, ["1.0.0", "2.0.0"]);
+        assert!(html_versions.is_empty());
+
+        Ok(())
+    }
+
     /// Test for AWS Code Artifact registry
     ///
     /// See: <https://github.com/astral-sh/uv/issues/1388>
@@ -0,0 +1,241 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheLimitError {
+    #[error(
+        "`{0}` is not a valid cache size (expected a number followed by an optional unit, e.g., `512M` or `20G`)"
+    )]
+    InvalidSize(String),
+    #[error("`{0}` is not a valid cache age (expected a duration, e.g., `30d` or `12h`)")]
+    InvalidAge(String),
+    #[error("Cache age must be positive, but got `{0}`")]
+    NonPositiveAge(String),
+}
+
+/// An upper bound on the size of the cache, e.g., `20G` or `512MiB`.
+///
+/// Units are binary (powers of 1024), and are case-insensitive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CacheSize(u64);
+
+impl CacheSize {
+    /// Returns the size in bytes.
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+const SIZE_UNITS: [(&str, u32); 5] = [("", 0), ("K", 1), ("M", 2), ("G", 3), ("T", 4)];
+
+impl FromStr for CacheSize {
+    type Err = CacheLimitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || CacheLimitError::InvalidSize(s.to_string());
+
+        let trimmed = s.trim();
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(trimmed.len());
+        let (number, unit) = trimmed.split_at(split);
+        let number = f64::from_str(number).map_err(|_| err())?;
+        if !number.is_finite() || number < 0.0 {
+            return Err(err());
+        }
+
+        // Accept `G`, `GB`, and `GiB` (and a trailing `B` on its own for bytes).
+        let unit = unit.trim().to_ascii_uppercase();
+        let unit = unit
+            .strip_suffix("IB")
+            .or_else(|| unit.strip_suffix('B'))
+            .unwrap_or(&unit);
+        let (_, exponent) = SIZE_UNITS
+            .iter()
+            .find(|(suffix, _)| *suffix == unit)
+            .ok_or_else(err)?;
+
+        #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bytes = (number * 1024f64.powi(i32::try_from(*exponent).unwrap_or(0))).round() as u64;
+        Ok(Self(bytes))
+    }
+}
+
+impl std::fmt::Display for CacheSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Render with the largest unit that represents the size exactly.
+        for (suffix, exponent) in SIZE_UNITS.iter().rev() {
+            let factor = 1024u64.pow(*exponent);
+            if *exponent > 0 && self.0 != 0 && self.0.is_multiple_of(factor) {
+                return write!(f, "{}{suffix}iB", self.0 / factor);
+            }
+        }
+        write!(f, "{}B", self.0)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheSize {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("CacheSize")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A size, with an optional binary unit, e.g. `512M` or `20G`."
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheSize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = CacheSize;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                CacheSize::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// A maximum age for cache entries, e.g., `30d` or `12h`.
+///
+/// Calendar units are interpreted as fixed durations (i.e., a day is 24 hours).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheAge {
+    duration: Duration,
+    raw: String,
+}
+
+impl CacheAge {
+    /// Returns the maximum age as a [`Duration`].
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl FromStr for CacheAge {
+    type Err = CacheLimitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let span = jiff::Span::from_str(s.trim())
+            .map_err(|_| CacheLimitError::InvalidAge(s.to_string()))?;
+        let signed = span
+            .to_duration(jiff::SpanRelativeTo::days_are_24_hours())
+            .map_err(|_| CacheLimitError::InvalidAge(s.to_string()))?;
+        if signed.is_negative() || signed.is_zero() {
+            return Err(CacheLimitError::NonPositiveAge(s.to_string()));
+        }
+        let duration =
+            Duration::try_from(signed).map_err(|_| CacheLimitError::InvalidAge(s.to_string()))?;
+        Ok(Self {
+            duration,
+            raw: s.trim().to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for CacheAge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheAge {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("CacheAge")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A duration, e.g. `30d`, `2w`, or `12h`."
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheAge {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = CacheAge;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                CacheAge::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::{CacheAge, CacheSize};
+
+    #[test]
+    fn parse_size() {
+        assert_eq!(CacheSize::from_str("0").unwrap().bytes(), 0);
+        assert_eq!(CacheSize::from_str("512").unwrap().bytes(), 512);
+        assert_eq!(CacheSize::from_str("512B").unwrap().bytes(), 512);
+        assert_eq!(CacheSize::from_str("4k").unwrap().bytes(), 4096);
+        assert_eq!(CacheSize::from_str("512M").unwrap().bytes(), 512 << 20);
+        assert_eq!(CacheSize::from_str("20G").unwrap().bytes(), 20 << 30);
+        assert_eq!(CacheSize::from_str("20GB").unwrap().bytes(), 20 << 30);
+        assert_eq!(CacheSize::from_str("20GiB").unwrap().bytes(), 20 << 30);
+        assert_eq!(CacheSize::from_str("1.5G").unwrap().bytes(), 3 << 29);
+        assert!(CacheSize::from_str("").is_err());
+        assert!(CacheSize::from_str("G").is_err());
+        assert!(CacheSize::from_str("20X").is_err());
+        assert!(CacheSize::from_str("-1G").is_err());
+    }
+
+    #[test]
+    fn display_size() {
+        assert_eq!(CacheSize::from_str("20G").unwrap().to_string(), "20GiB");
+        assert_eq!(CacheSize::from_str("1.5G").unwrap().to_string(), "1536MiB");
+        assert_eq!(CacheSize::from_str("1000").unwrap().to_string(), "1000B");
+        assert_eq!(CacheSize::from_str("0").unwrap().to_string(), "0B");
+    }
+
+    #[test]
+    fn parse_age() {
+        assert_eq!(
+            CacheAge::from_str("30d").unwrap().duration(),
+            Duration::from_hours(30 * 24)
+        );
+        assert_eq!(
+            CacheAge::from_str("12h").unwrap().duration(),
+            Duration::from_hours(12)
+        );
+        assert_eq!(
+            CacheAge::from_str("P1W").unwrap().duration(),
+            Duration::from_hours(7 * 24)
+        );
+        assert!(CacheAge::from_str("0d").is_err());
+        assert!(CacheAge::from_str("-1d").is_err());
+        assert!(CacheAge::from_str("30 parsecs").is_err());
+    }
+}
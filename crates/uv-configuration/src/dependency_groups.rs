@@ -130,6 +130,14 @@ impl DependencyGroups {
         })
     }
 
+    /// Include the given groups, in addition to those already requested.
+    #[must_use]
+    pub fn with_groups(&self, groups: impl IntoIterator<Item = GroupName>) -> Self {
+        let mut history = self.0.history.clone();
+        history.group.extend(groups);
+        Self::from_history(history)
+    }
+
     /// Apply defaults to a base [`DependencyGroups`].
     ///
     /// This is appropriate in projects, where the `dev` group is synced by default.
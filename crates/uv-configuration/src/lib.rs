@@ -1,5 +1,6 @@
 pub use authentication::*;
 pub use build_options::*;
+pub use cache_limits::*;
 pub use concurrency::*;
 pub use constraints::*;
 pub use dependency_groups::*;
@@ -9,12 +10,14 @@ pub use env_file::*;
 pub use excludes::*;
 pub use export_format::*;
 pub use extras::*;
+pub use git_mirror::*;
 pub use hash::*;
 pub use install_options::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
 pub use project_build_backend::*;
+pub use provenance::*;
 pub use proxy_url::*;
 pub use required_version::*;
 pub use sources::*;
@@ -27,6 +30,7 @@ pub use vcs::*;
 
 mod authentication;
 mod build_options;
+mod cache_limits;
 mod concurrency;
 mod constraints;
 mod dependency_groups;
@@ -36,12 +40,14 @@ mod env_file;
 mod excludes;
 mod export_format;
 mod extras;
+mod git_mirror;
 mod hash;
 mod install_options;
 mod name_specifiers;
 mod overrides;
 mod package_options;
 mod project_build_backend;
+mod provenance;
 mod proxy_url;
 mod required_version;
 mod sources;
@@ -0,0 +1,67 @@
+use uv_normalize::PackageName;
+
+/// Whether to require verified [PEP 740] provenance for registry distributions.
+///
+/// [PEP 740]: https://peps.python.org/pep-0740/
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub enum RequireProvenance {
+    /// Install distributions without verifying their provenance.
+    #[default]
+    None,
+
+    /// Require verified provenance for all registry distributions.
+    All,
+
+    /// Require verified provenance for the distributions of specific packages.
+    Packages(Vec<PackageName>),
+}
+
+impl RequireProvenance {
+    /// Determine the provenance policy to use for the given arguments.
+    pub fn from_args(
+        require_provenance: Option<bool>,
+        require_provenance_package: Vec<PackageName>,
+    ) -> Self {
+        match require_provenance {
+            Some(true) => Self::All,
+            Some(false) => Self::None,
+            None => {
+                if require_provenance_package.is_empty() {
+                    Self::None
+                } else {
+                    Self::Packages(require_provenance_package)
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if provenance is required for the given package.
+    pub fn for_package(&self, package_name: &PackageName) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Packages(packages) => packages.contains(package_name),
+        }
+    }
+
+    /// Combine a set of [`RequireProvenance`] values.
+    #[must_use]
+    pub fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::None, Self::None) => Self::None,
+            (Self::All, _) | (_, Self::All) => Self::All,
+            (Self::Packages(a), Self::None) => Self::Packages(a),
+            (Self::None, Self::Packages(b)) => Self::Packages(b),
+            (Self::Packages(mut a), Self::Packages(b)) => {
+                a.extend(b);
+                Self::Packages(a)
+            }
+        }
+    }
+
+    /// Returns `true` if provenance isn't required for any package.
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
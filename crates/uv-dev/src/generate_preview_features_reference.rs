@@ -112,27 +112,42 @@ mod tests {
         - `audit-command`: Allows using `uv audit` and `uv tool audit`.
         - `auth-helper`: Allows using `uv auth helper` as a credential helper for external tools.
         - `azure-endpoint`: Allows signing requests to Azure Blob Storage endpoints with Azure credentials.
+        - `build-matrix`: Allows building with multiple Python interpreters with `uv build --python`.
+        - `cache-eviction`: Allows evicting cache entries by size and age with `uv cache prune --max-size` and `--max-age`.
+        - `cache-export`: Allows moving cache entries between machines with `uv cache export` and `uv cache import`.
         - `cache-physical-space`: Reports the physical disk space reclaimed by cache cleanup, accounting for hardlinks and copy-on-write clones.
         - `cache-size`: Allows using `uv cache size`.
+        - `cache-stats`: Allows reporting cache disk usage by bucket and by package with `uv cache stats`.
+        - `cache-verify`: Allows checking the cache for corrupt entries with `uv cache verify`.
         - `centralized-project-envs`: Stores [project virtual environments](./projects/layout.md#centralized-project-environments)
           in the uv cache.
         - `check-command`: Allows using `uv check`.
         - `detect-module-conflicts`: Warns when multiple packages would install conflicting Python modules into the same
           environment.
         - `direct-publish`: Allows publishing directly to a package index.
+        - `directory-publish`: Allows publishing to a local index directory with `uv publish --to-directory`.
+        - `distribution-checks`: Allows checking distributions for problems with `uv publish --check` and
+          `uv build --check`.
+        - `dynamic-version`: Allows deriving the project version from Git tags in the uv build backend.
         - `extra-build-dependencies`: Allows specifying additional dependencies for package builds.
         - `format-command`: Allows using `uv format`.
         - `gcs-endpoint`: Allows signing requests to configured Google Cloud Storage endpoints.
+        - `git-mirror`: Allows fetching Git dependencies from mirrors with `[[tool.uv.git-mirror]]`.
         - `index-by-name`: Allows selecting configured package indexes by name with `--index` and `--default-index`.
         - `index-exclude-newer`: Allows setting `exclude-newer` on configured package indexes.
         - `index-hash-algorithm`: Allows requiring a hash algorithm for configured package indexes.
+        - `index-serve`: Allows serving a directory of distributions as a package index with `uv index serve`.
         - `init-project-flag`: Rejects the deprecated `--project` option in `uv init`.
         - `json-output`: Allows `--output-format json` for various uv commands.
+        - `layered-environments`: Allows layering virtual environments on top of read-only parent environments with
+          `uv venv --parent`.
         - `lock-without-metadata`: Omit `package.metadata` from `uv.lock`.
         - `lockfile-format-check`: Rejects non-canonical lockfile formatting when using `--locked` or `--check`.
         - `malware-check`: Allows `uv sync` and other commands to check for malware using [OSV](https://osv.dev) before
           installing packages.
         - `metadata-json`: Includes JSON metadata files in built wheels.
+        - `multi-index-publish`: Allows publishing to multiple indexes with `uv publish --index`.
+        - `native-artifacts`: Allows including native artifacts in wheels built with the uv build backend.
         - `native-auth`: Enables storage of credentials in a [system-native location](../concepts/authentication/http.md#the-uv-credentials-store).
         - `no-distutils-patch`: Stops installing the `_virtualenv.py` / `_virtualenv.pth` distutils configuration monkeypatch
           in virtual environments for Python 3.10 and later.
@@ -142,13 +157,21 @@ mod tests {
         - `project-directory-must-exist`: Rejects an invalid `--project` path instead of warning and continuing. Except for `uv init`,
           the path must already exist as a directory or point to a `pyproject.toml` file. This feature
           takes effect before configuration is loaded.
+        - `project-environment-variables`: Allows declaring environment variables for the project environment in
+          `[tool.uv.environment]`.
+        - `project-tasks`: Allows declaring named tasks in `[tool.uv.scripts]` to run with `uv run <task>`.
+        - `provenance-verification`: Allows requiring verified PEP 740 provenance for registry distributions with
+          `require-provenance`.
+        - `publish-attestations`: Allows generating PEP 740 attestations during `uv publish` with `--attest`.
         - `publish-require-normalized`: Requires normalized distribution filenames when publishing, skipping files whose names are
           not normalized.
         - `pylock`: Allows installing from `pylock.toml` files.
         - `python-install-default`: Allows [installing `python` and `python3` executables](./python-versions.md#installing-python-executables).
         - `relocatable-envs-default`: Creates relocatable virtual environments by default.
+        - `reproducible-builds`: Allows verifying that builds are reproducible with `uv build --verify-reproducible`.
         - `s3-endpoint`: Allows signing requests to configured S3-compatible endpoints.
         - `sbom-export`: Allows using `uv export --format=cyclonedx1.5`.
+        - `shared-cache`: Allows sharing wheels built from source between machines with `--shared-cache`.
         - `special-conda-env-names`: Stops treating Conda environments named `base` or `root` as special.
         - `tar-codec`: Uses the new `tar-codec` encoding/decoding backend, instead of `astral-tokio-tar`.
         - `target-workspace-discovery`: Uses the directory containing a local `uv run` target, rather than the current working
@@ -158,9 +181,13 @@ mod tests {
           original as `pyproject.toml.orig` to ensure compatibility with older build tools.
         - `tool-install-locks`: Stores a `uv.lock` alongside each installed tool and reuses it for reproducible installations,
           upgrades, and audits.
+        - `tool-shared-environments`: Allows installing tools into environments shared with other tools with
+          `uv tool install --shared`.
         - `venv-safe-clear`: Prevents `uv venv --clear` from clearing a directory that does not contain a `pyvenv.cfg` file
           unless `--force` is provided.
+        - `wheel-repair`: Allows repairing Linux wheels with `uv build --repair`.
         - `workspace-dir`: Allows using `uv workspace dir`.
+        - `workspace-exec`: Allows running a command in each workspace member with `uv workspace exec`.
         - `workspace-list`: Allows using `uv workspace list`.
         - `workspace-list-scripts`: Allows using `uv workspace list --scripts`.
         - `workspace-metadata`: Allows using `uv workspace metadata`.
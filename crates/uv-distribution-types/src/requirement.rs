@@ -738,10 +738,27 @@ impl RequirementSource {
     /// Convert the source to a [`RequirementSource`] relative to the given path.
     fn relative_to(self, path: &Path) -> Result<Self, io::Error> {
         match self {
-            Self::Registry { .. }
-            | Self::Url { .. }
-            | Self::GitPath { .. }
-            | Self::GitDirectory { .. } => Ok(self),
+            Self::Registry { .. } | Self::Url { .. } => Ok(self),
+            Self::GitDirectory {
+                git,
+                subdirectory,
+                url,
+            } => Ok(Self::GitDirectory {
+                git: git_relative_to(git, path)?,
+                subdirectory,
+                url,
+            }),
+            Self::GitPath {
+                git,
+                install_path,
+                ext,
+                url,
+            } => Ok(Self::GitPath {
+                git: git_relative_to(git, path)?,
+                install_path,
+                ext,
+                url,
+            }),
             Self::Path {
                 install_path,
                 ext,
@@ -772,10 +789,27 @@ impl RequirementSource {
     #[must_use]
     fn into_absolute(self, root: &Path) -> Self {
         match self {
-            Self::Registry { .. }
-            | Self::Url { .. }
-            | Self::GitPath { .. }
-            | Self::GitDirectory { .. } => self,
+            Self::Registry { .. } | Self::Url { .. } => self,
+            Self::GitDirectory {
+                git,
+                subdirectory,
+                url,
+            } => Self::GitDirectory {
+                git: git_into_absolute(git, root),
+                subdirectory,
+                url,
+            },
+            Self::GitPath {
+                git,
+                install_path,
+                ext,
+                url,
+            } => Self::GitPath {
+                git: git_into_absolute(git, root),
+                install_path,
+                ext,
+                url,
+            },
             Self::Path {
                 install_path,
                 ext,
@@ -805,6 +839,24 @@ impl RequirementSource {
     }
 }
 
+/// Convert the allowed signers of a [`GitUrl`] to a path relative to the given path.
+fn git_relative_to(git: GitUrl, path: &Path) -> Result<GitUrl, io::Error> {
+    let Some(allowed_signers) = git.allowed_signers() else {
+        return Ok(git);
+    };
+    let allowed_signers = try_relative_to_if(allowed_signers, path, true)?;
+    Ok(git.with_allowed_signers(Some(allowed_signers)))
+}
+
+/// Convert the allowed signers of a [`GitUrl`] to an absolute path based on the given root.
+fn git_into_absolute(git: GitUrl, root: &Path) -> GitUrl {
+    let Some(allowed_signers) = git.allowed_signers() else {
+        return git;
+    };
+    let allowed_signers = normalize_path(root.join(allowed_signers)).into_owned();
+    git.with_allowed_signers(Some(allowed_signers))
+}
+
 impl Display for RequirementSource {
     /// Display the [`RequirementSource`], with the intention of being shown directly to a user,
     /// rather than for inclusion in a `requirements.txt` file.
@@ -952,6 +1004,21 @@ impl From<RequirementSource> for RequirementSourceWire {
                     url.query_pairs_mut().append_pair("lfs", "true");
                 }
 
+                // Persist the signature requirement in the distribution metadata.
+                if git.verify_signature() {
+                    url.query_pairs_mut()
+                        .append_pair("verify-signature", "true");
+                }
+                if let Some(allowed_signers) = git
+                    .allowed_signers()
+                    .map(PortablePath::from)
+                    .as_ref()
+                    .map(PortablePath::to_string)
+                {
+                    url.query_pairs_mut()
+                        .append_pair("allowed-signers", &allowed_signers);
+                }
+
                 // Put the requested reference in the query.
                 match git.reference() {
                     GitReference::Branch(branch) => {
@@ -1018,6 +1085,21 @@ impl From<RequirementSource> for RequirementSourceWire {
                     url.query_pairs_mut().append_pair("lfs", "true");
                 }
 
+                // Persist the signature requirement in the distribution metadata.
+                if git.verify_signature() {
+                    url.query_pairs_mut()
+                        .append_pair("verify-signature", "true");
+                }
+                if let Some(allowed_signers) = git
+                    .allowed_signers()
+                    .map(PortablePath::from)
+                    .as_ref()
+                    .map(PortablePath::to_string)
+                {
+                    url.query_pairs_mut()
+                        .append_pair("allowed-signers", &allowed_signers);
+                }
+
                 // Put the precise commit in the fragment.
                 if let Some(precise) = git.precise() {
                     url.set_fragment(Some(&precise.to_string()));
@@ -1079,6 +1161,8 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                 let mut reference = GitReference::DefaultBranch;
                 let mut subdirectory: Option<PortablePathBuf> = None;
                 let mut lfs = GitLfs::Disabled;
+                let mut verify_signature = false;
+                let mut allowed_signers: Option<PortablePathBuf> = None;
                 let mut path: Option<PortablePathBuf> = None;
                 for (key, val) in repository.query_pairs() {
                     match &*key {
@@ -1089,6 +1173,12 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                             subdirectory = Some(PortablePathBuf::from(val.as_ref()));
                         }
                         "lfs" => lfs = GitLfs::from(val.eq_ignore_ascii_case("true")),
+                        "verify-signature" => {
+                            verify_signature = val.eq_ignore_ascii_case("true");
+                        }
+                        "allowed-signers" => {
+                            allowed_signers = Some(PortablePathBuf::from(val.as_ref()));
+                        }
                         "path" => {
                             path = Some(PortablePathBuf::from(val.as_ref()));
                         }
@@ -1126,7 +1216,11 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                     url.set_fragment(Some(&frags.join("&")));
                 }
                 let url = VerbatimUrl::from_url(url);
-                let git = GitUrl::from_fields(repository, reference, precise, lfs)?;
+                let git = GitUrl::from_fields(repository, reference, precise, lfs)?
+                    .with_verify_signature(verify_signature)
+                    .with_allowed_signers(
+                        allowed_signers.map(Box::<Path>::from).map(PathBuf::from),
+                    );
 
                 if let Some(install_path) = path.map(Box::<Path>::from).map(PathBuf::from) {
                     Ok(Self::GitPath {
@@ -1,6 +1,6 @@
 use std::future::Future;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
@@ -9,10 +9,10 @@ use futures::{FutureExt, TryStreamExt};
 use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
-use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{ArchiveId, Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
@@ -22,8 +22,9 @@ use uv_distribution_types::{
     BuildInfo, BuildableSource, BuiltDist, Dist, DistRef, File, HashPolicy, Hashed, IndexUrl,
     InstalledDist, Name, SourceDist, ToUrlError,
 };
+use uv_extract::dirhash::DirhashTree;
 use uv_extract::hash::Hasher;
-use uv_fs::write_atomic;
+use uv_fs::{PortablePath, write_atomic};
 use uv_git::{GIT_LFS, GitError};
 use uv_install_wheel::validate_and_heal_record;
 use uv_platform_tags::Tags;
@@ -366,6 +367,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .git()
                     .fetch(
                         &wheel.git,
+                        wheel.install_path.parent(),
                         self.client.unmanaged.git_http_settings(wheel.git.url()),
                         self.build_context.cache().bucket(CacheBucket::Git),
                         self.reporter.clone().map(<dyn Reporter>::into_git_reporter),
@@ -716,33 +718,35 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
 
-                let files = match progress {
+                let (files, dirhash) = match progress {
                     Some((reporter, progress)) => {
                         let mut reader = ProgressReader::new(&mut hasher, progress, &**reporter);
                         match extension {
                             WheelExtension::Whl => {
-                                uv_extract::stream::unzip(&mut reader, temp_dir.path())
+                                uv_extract::stream::unzip_with_dirhash(&mut reader, temp_dir.path())
                                     .await
                                     .map_err(|err| Error::Extract(filename.to_string(), err))?
                             }
-                            WheelExtension::WhlZst => {
+                            WheelExtension::WhlZst => (
                                 uv_extract::stream::untar_zst(&mut reader, temp_dir.path())
                                     .await
-                                    .map_err(|err| Error::Extract(filename.to_string(), err))?
-                            }
+                                    .map_err(|err| Error::Extract(filename.to_string(), err))?,
+                                None,
+                            ),
                         }
                     }
                     None => match extension {
                         WheelExtension::Whl => {
-                            uv_extract::stream::unzip(&mut hasher, temp_dir.path())
+                            uv_extract::stream::unzip_with_dirhash(&mut hasher, temp_dir.path())
                                 .await
                                 .map_err(|err| Error::Extract(filename.to_string(), err))?
                         }
-                        WheelExtension::WhlZst => {
+                        WheelExtension::WhlZst => (
                             uv_extract::stream::untar_zst(&mut hasher, temp_dir.path())
                                 .await
-                                .map_err(|err| Error::Extract(filename.to_string(), err))?
-                        }
+                                .map_err(|err| Error::Extract(filename.to_string(), err))?,
+                            None,
+                        ),
                     },
                 };
                 // Exhaust the reader to compute the hashes.
@@ -764,12 +768,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(Error::InstallWheelError)?;
 
                 // Persist the temporary directory to the directory store.
-                let id = self
-                    .build_context
-                    .cache()
-                    .persist(temp_dir.keep(), wheel_entry.path())
-                    .await
-                    .map_err(Error::CacheRead)?;
+                let id = persist_wheel(
+                    self.build_context.cache(),
+                    temp_dir.keep(),
+                    wheel_entry.path(),
+                    &files,
+                    dirhash,
+                )
+                .await
+                .map_err(Error::CacheRead)?;
 
                 if let Some((reporter, progress)) = progress {
                     reporter.on_download_complete(dist.name(), progress);
@@ -958,13 +965,17 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(Error::CacheWrite)?;
 
                 let target = temp_dir.path().to_owned();
-                let files = match extension {
+                let (files, dirhash) = match extension {
                     WheelExtension::Whl => {
                         let file = file.into_std().await;
-                        tokio::task::spawn_blocking(move || uv_extract::unzip(file, &target))
-                            .await?
+                        tokio::task::spawn_blocking(move || {
+                            uv_extract::unzip_with_dirhash(file, &target)
+                        })
+                        .await?
                     }
-                    WheelExtension::WhlZst => uv_extract::stream::untar_zst(file, &target).await,
+                    WheelExtension::WhlZst => uv_extract::stream::untar_zst(file, &target)
+                        .await
+                        .map(|files| (files, None)),
                 }
                 .map_err(|err| Error::Extract(filename.to_string(), err))?;
                 let hashes = hashers.into_iter().map(HashDigest::from).collect();
@@ -975,12 +986,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     .map_err(Error::InstallWheelError)?;
 
                 // Persist the temporary directory to the directory store.
-                let id = self
-                    .build_context
-                    .cache()
-                    .persist(temp_dir.keep(), wheel_entry.path())
-                    .await
-                    .map_err(Error::CacheRead)?;
+                let id = persist_wheel(
+                    self.build_context.cache(),
+                    temp_dir.keep(),
+                    wheel_entry.path(),
+                    &files,
+                    dirhash,
+                )
+                .await
+                .map_err(Error::CacheRead)?;
 
                 if let Some((reporter, progress)) = progress {
                     reporter.on_download_complete(dist.name(), progress);
@@ -1162,15 +1176,18 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             let mut hasher = uv_extract::hash::HashReader::new(file, &mut hashers);
 
             // Unzip the wheel to a temporary directory.
-            let files = match extension {
-                WheelExtension::Whl => uv_extract::stream::unzip(&mut hasher, temp_dir.path())
-                    .await
-                    .map_err(|err| Error::Extract(filename.to_string(), err))?,
-                WheelExtension::WhlZst => {
-                    uv_extract::stream::untar_zst(&mut hasher, temp_dir.path())
+            let (files, dirhash) = match extension {
+                WheelExtension::Whl => {
+                    uv_extract::stream::unzip_with_dirhash(&mut hasher, temp_dir.path())
                         .await
                         .map_err(|err| Error::Extract(filename.to_string(), err))?
                 }
+                WheelExtension::WhlZst => (
+                    uv_extract::stream::untar_zst(&mut hasher, temp_dir.path())
+                        .await
+                        .map_err(|err| Error::Extract(filename.to_string(), err))?,
+                    None,
+                ),
             };
 
             // Exhaust the reader to compute the hash.
@@ -1184,12 +1201,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 .map_err(Error::InstallWheelError)?;
 
             // Persist the temporary directory to the directory store.
-            let id = self
-                .build_context
-                .cache()
-                .persist(temp_dir.keep(), wheel_entry.path())
-                .await
-                .map_err(Error::CacheWrite)?;
+            let id = persist_wheel(
+                self.build_context.cache(),
+                temp_dir.keep(),
+                wheel_entry.path(),
+                &files,
+                dirhash,
+            )
+            .await
+            .map_err(Error::CacheWrite)?;
 
             // Create an archive.
             let archive = Archive::new(id, hashes, filename.clone(), None);
@@ -1223,14 +1243,14 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         target: &Path,
         dist: DistRef<'_>,
     ) -> Result<ArchiveId, Error> {
-        let (temp_dir, files) = tokio::task::spawn_blocking({
+        let (temp_dir, (files, dirhash)) = tokio::task::spawn_blocking({
             let path = path.to_owned();
             let root = self.build_context.cache().root().to_path_buf();
             move || -> Result<_, Error> {
                 // Unzip the wheel into a temporary directory.
                 let temp_dir = tempfile::tempdir_in(root).map_err(Error::CacheWrite)?;
                 let reader = fs_err::File::open(&path).map_err(Error::CacheWrite)?;
-                let files = uv_extract::unzip(reader, temp_dir.path())
+                let files = uv_extract::unzip_with_dirhash(reader, temp_dir.path())
                     .map_err(|err| Error::Extract(path.to_string_lossy().into_owned(), err))?;
                 Ok((temp_dir, files))
             }
@@ -1242,12 +1262,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             .map_err(Error::InstallWheelError)?;
 
         // Persist the temporary directory to the directory store.
-        let id = self
-            .build_context
-            .cache()
-            .persist(temp_dir.keep(), target)
-            .await
-            .map_err(Error::CacheWrite)?;
+        let id = persist_wheel(
+            self.build_context.cache(),
+            temp_dir.keep(),
+            target,
+            &files,
+            dirhash,
+        )
+        .await
+        .map_err(Error::CacheWrite)?;
 
         Ok(id)
     }
@@ -1361,6 +1384,53 @@ where
     }
 }
 
+/// Persist an unzipped wheel to the archive bucket, recording its dirhash (as computed while
+/// unzipping it) such that the archive can be verified by `uv cache verify`.
+///
+/// The dirhash is computed from the archive entries as they're written, rather than by re-reading
+/// the unzipped wheel; if the `RECORD` was rewritten by [`validate_and_heal_record`], its hash is
+/// updated here.
+async fn persist_wheel(
+    cache: &Cache,
+    temp_dir: PathBuf,
+    path: impl AsRef<Path>,
+    files: &[(PathBuf, u64)],
+    dirhash: Option<DirhashTree>,
+) -> io::Result<ArchiveId> {
+    let Some(mut dirhash) = dirhash else {
+        return cache.persist(temp_dir, path).await;
+    };
+
+    // Re-hash the `RECORD`, which is the only file that may have changed since it was unzipped.
+    let record = files.iter().map(|(file, _)| file).find(|file| {
+        let mut components = file.components();
+        match (components.next(), components.next(), components.next()) {
+            (Some(dist_info), Some(record), None) => {
+                dist_info
+                    .as_os_str()
+                    .to_string_lossy()
+                    .ends_with(".dist-info")
+                    && record.as_os_str() == "RECORD"
+            }
+            _ => false,
+        }
+    });
+    if let Some(record) = record {
+        let hash = blake3::hash(&fs_err::tokio::read(temp_dir.join(record)).await?);
+        if let Err(err) = dirhash.update_file(&PortablePath::from(record).to_string(), hash) {
+            debug!(
+                "Failed to compute dirhash for `{}`: {err}",
+                temp_dir.display()
+            );
+            return cache.persist(temp_dir, path).await;
+        }
+    }
+
+    cache
+        .persist_with_dirhash(temp_dir, path, &dirhash.hash().to_hex())
+        .await
+}
+
 /// A pointer to an archive in the cache, fetched from an HTTP archive.
 ///
 /// Encoded with `MsgPack`, and represented on disk by a `.http` file.
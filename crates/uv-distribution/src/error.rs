@@ -68,6 +68,8 @@ pub enum Error {
     CacheWalk(#[source] walkdir::Error),
     #[error(transparent)]
     CacheInfo(#[from] uv_cache_info::CacheInfoError),
+    #[error("Invalid shared cache URL")]
+    SharedCacheUrl(#[source] uv_redacted::DisplaySafeUrlError),
 
     // Build error
     #[error(transparent)]
@@ -9,6 +9,7 @@ pub use metadata::{
 };
 pub use reporter::Reporter;
 pub use source::{StaticMetadataDatabase, prune};
+pub use verify::{CacheVerification, CorruptEntry, repair, verify};
 
 mod archive;
 mod distribution_database;
@@ -19,3 +20,4 @@ mod index;
 mod metadata;
 mod reporter;
 mod source;
+mod verify;
@@ -191,9 +191,15 @@ impl LoweredRequirement {
                             tag,
                             branch,
                             lfs,
+                            verify_signature,
+                            allowed_signers,
                             marker,
                             ..
                         } => {
+                            let base = match origin {
+                                RequirementOrigin::Project => project_dir,
+                                RequirementOrigin::Workspace => workspace.install_path(),
+                            };
                             let source = git_source(
                                 git,
                                 subdirectory.map(Box::<Path>::from),
@@ -202,6 +208,10 @@ impl LoweredRequirement {
                                 tag,
                                 branch,
                                 lfs,
+                                verify_signature,
+                                allowed_signers.map(|allowed_signers| {
+                                    normalize_path(base.join(allowed_signers)).into_owned()
+                                }),
                             )?;
                             (source, marker)
                         }
@@ -387,6 +397,8 @@ impl LoweredRequirement {
                             tag,
                             branch,
                             lfs,
+                            verify_signature,
+                            allowed_signers,
                             marker,
                             ..
                         } => {
@@ -398,6 +410,10 @@ impl LoweredRequirement {
                                 tag,
                                 branch,
                                 lfs,
+                                verify_signature,
+                                allowed_signers.map(|allowed_signers| {
+                                    normalize_path(dir.join(allowed_signers)).into_owned()
+                                }),
                             )?;
                             (source, marker)
                         }
@@ -681,6 +697,8 @@ fn git_source(
     tag: Option<String>,
     branch: Option<String>,
     lfs: Option<bool>,
+    verify_signature: Option<bool>,
+    allowed_signers: Option<PathBuf>,
 ) -> Result<RequirementSource, LoweringError> {
     let reference = match (rev, tag, branch) {
         (None, None, None) => GitReference::DefaultBranch,
@@ -722,7 +740,9 @@ fn git_source(
     }
     let url = VerbatimUrl::from_url(url);
 
-    let git = GitUrl::from_fields(git, reference, None, lfs)?;
+    let git = GitUrl::from_fields(git, reference, None, lfs)?
+        .with_verify_signature(verify_signature.unwrap_or(false))
+        .with_allowed_signers(allowed_signers);
 
     if let Some(path) = path {
         let ext = match DistExtension::from_path(&path) {
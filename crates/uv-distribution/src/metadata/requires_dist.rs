@@ -587,7 +587,7 @@ mod test {
           |
         8 | tqdm = { git = "https://github.com/tqdm/tqdm", ref = "baaaaaab" }
           |                                                ^^^
-        unknown field `ref`, expected one of `git`, `subdirectory`, `rev`, `tag`, `branch`, `lfs`, `url`, `path`, `editable`, `package`, `index`, `workspace`, `marker`, `extra`, `group`
+        unknown field `ref`, expected one of `git`, `subdirectory`, `rev`, `tag`, `branch`, `lfs`, `verify-signature`, `allowed-signers`, `url`, `path`, `editable`, `package`, `index`, `workspace`, `marker`, `extra`, `group`
         "#);
     }
 
@@ -636,6 +636,52 @@ mod test {
         "#);
     }
 
+    #[tokio::test]
+    async fn verify_signature_requires_git() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+            dependencies = [
+              "tqdm",
+            ]
+            [tool.uv.sources]
+            tqdm = { url = "https://files.pythonhosted.org/tqdm-4.66.0.tar.gz", verify-signature = true }
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r#"
+        error: Failed to parse: `[PATH]/pyproject.toml`
+          Caused by: TOML parse error at line 8, column 8
+          |
+        8 | tqdm = { url = "https://files.pythonhosted.org/tqdm-4.66.0.tar.gz", verify-signature = true }
+          |        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+        cannot specify both `url` and `verify-signature`
+        "#);
+    }
+
+    #[tokio::test]
+    async fn allowed_signers_requires_verify_signature() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+            dependencies = [
+              "tqdm",
+            ]
+            [tool.uv.sources]
+            tqdm = { git = "https://github.com/tqdm/tqdm", allowed-signers = "allowed_signers" }
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r#"
+        error: Failed to parse: `[PATH]/pyproject.toml`
+          Caused by: TOML parse error at line 8, column 8
+          |
+        8 | tqdm = { git = "https://github.com/tqdm/tqdm", allowed-signers = "allowed_signers" }
+          |        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+        cannot specify `allowed-signers` without `verify-signature = true`
+        "#);
+    }
+
     #[tokio::test]
     async fn missing_constraint() {
         let input = indoc! {r#"
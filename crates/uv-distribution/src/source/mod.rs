@@ -54,10 +54,12 @@ use crate::hash::http_hash_algorithms;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
 use crate::source::revision::Revision;
+use crate::source::shared::{SharedWheel, SharedWheelKey};
 use crate::{Reporter, RequiresDist};
 
 mod built_wheel_metadata;
 mod revision;
+mod shared;
 
 /// Access distribution metadata without requiring a build interpreter.
 ///
@@ -181,7 +183,13 @@ async fn fetch_git_source_tree(
     reporter: Option<Arc<dyn uv_git::Reporter>>,
 ) -> Result<Fetch, Error> {
     let fetch = git_resolver
-        .fetch(git, http_settings, cache.bucket(CacheBucket::Git), reporter)
+        .fetch(
+            git,
+            subdirectory,
+            http_settings,
+            cache.bucket(CacheBucket::Git),
+            reporter,
+        )
         .await?;
 
     if let Some(subdirectory) = subdirectory
@@ -293,6 +301,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                             &cache_shard,
                             tags,
                             hashes,
+                            client,
                         )
                         .boxed_local()
                         .await;
@@ -350,9 +359,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Dist(SourceDist::Directory(dist)) => {
-                self.source_tree(source, &DirectorySourceUrl::from(dist), tags, hashes)
-                    .boxed_local()
-                    .await?
+                self.source_tree(
+                    source,
+                    &DirectorySourceUrl::from(dist),
+                    tags,
+                    hashes,
+                    client,
+                )
+                .boxed_local()
+                .await?
             }
             BuildableSource::Dist(SourceDist::Path(dist)) => {
                 let cache_shard = self.build_context.cache().shard(
@@ -365,6 +380,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     &cache_shard,
                     tags,
                     hashes,
+                    client,
                 )
                 .boxed_local()
                 .await?
@@ -401,7 +417,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Url(SourceUrl::Directory(resource)) => {
-                self.source_tree(source, resource, tags, hashes)
+                self.source_tree(source, resource, tags, hashes, client)
                     .boxed_local()
                     .await?
             }
@@ -410,7 +426,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     CacheBucket::SourceDistributions,
                     WheelCache::Path(resource.url).root(),
                 );
-                self.archive(source, resource, &cache_shard, tags, hashes)
+                self.archive(source, resource, &cache_shard, tags, hashes, client)
                     .boxed_local()
                     .await?
             }
@@ -455,6 +471,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                             },
                             &cache_shard,
                             hashes,
+                            client,
                         )
                         .boxed_local()
                         .await;
@@ -510,23 +527,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Dist(SourceDist::Directory(dist)) => {
-                self.source_tree_metadata(
-                    source,
-                    &DirectorySourceUrl::from(dist),
-                    hashes,
-                    client.unmanaged.credentials_cache(),
-                )
-                .boxed_local()
-                .await?
+                self.source_tree_metadata(source, &DirectorySourceUrl::from(dist), hashes, client)
+                    .boxed_local()
+                    .await?
             }
             BuildableSource::Dist(SourceDist::Path(dist)) => {
                 let cache_shard = self.build_context.cache().shard(
                     CacheBucket::SourceDistributions,
                     WheelCache::Path(&dist.url).root(),
                 );
-                self.archive_metadata(source, &PathSourceUrl::from(dist), &cache_shard, hashes)
-                    .boxed_local()
-                    .await?
+                self.archive_metadata(
+                    source,
+                    &PathSourceUrl::from(dist),
+                    &cache_shard,
+                    hashes,
+                    client,
+                )
+                .boxed_local()
+                .await?
             }
             BuildableSource::Url(SourceUrl::Direct(resource)) => {
                 // For direct URLs, cache directly under the hash of the URL itself.
@@ -565,21 +583,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .await?
             }
             BuildableSource::Url(SourceUrl::Directory(resource)) => {
-                self.source_tree_metadata(
-                    source,
-                    resource,
-                    hashes,
-                    client.unmanaged.credentials_cache(),
-                )
-                .boxed_local()
-                .await?
+                self.source_tree_metadata(source, resource, hashes, client)
+                    .boxed_local()
+                    .await?
             }
             BuildableSource::Url(SourceUrl::Path(resource)) => {
                 let cache_shard = self.build_context.cache().shard(
                     CacheBucket::SourceDistributions,
                     WheelCache::Path(resource.url).root(),
                 );
-                self.archive_metadata(source, resource, &cache_shard, hashes)
+                self.archive_metadata(source, resource, &cache_shard, hashes, client)
                     .boxed_local()
                     .await?
             }
@@ -720,6 +733,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         // Build the source distribution.
+        let shared = self.shared_wheel(
+            SharedWheelKey::archive(revision.hashes(), subdirectory, &build_info),
+            client,
+        );
         let (disk_filename, wheel_filename, metadata) = self
             .build_distribution(
                 source,
@@ -727,6 +744,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 subdirectory,
                 &cache_shard,
                 NoSources::None,
+                shared,
             )
             .await?;
 
@@ -903,6 +921,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         // Build the source distribution.
+        let shared = self.shared_wheel(
+            SharedWheelKey::archive(revision.hashes(), subdirectory, &build_info),
+            client,
+        );
         let (_disk_filename, _wheel_filename, metadata) = self
             .build_distribution(
                 source,
@@ -910,6 +932,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 subdirectory,
                 &cache_shard,
                 NoSources::None,
+                shared,
             )
             .await?;
 
@@ -1059,6 +1082,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         cache_shard: &CacheShard,
         tags: &Tags,
         hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
 
@@ -1125,6 +1149,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self.shared_wheel(
+            SharedWheelKey::archive(revision.hashes(), None, &build_info),
+            client,
+        );
         let (disk_filename, filename, metadata) = self
             .build_distribution(
                 source,
@@ -1132,6 +1160,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 NoSources::None,
+                shared,
             )
             .await?;
 
@@ -1167,6 +1196,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         resource: &PathSourceUrl<'_>,
         cache_shard: &CacheShard,
         hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
 
@@ -1278,6 +1308,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self.shared_wheel(
+            SharedWheelKey::archive(revision.hashes(), None, &build_info),
+            client,
+        );
         let (_disk_filename, _filename, metadata) = self
             .build_distribution(
                 source,
@@ -1285,6 +1319,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 NoSources::None,
+                shared,
             )
             .await?;
 
@@ -1333,12 +1368,20 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // Read the existing metadata from the cache.
         let revision_entry = cache_shard.entry(LOCAL_REVISION);
+        let shared = self.build_context.cache().shared_cache().is_some();
 
         // If the revision already exists, return it. There's no need to check for freshness, since
         // we use an exact timestamp.
         if let Some(pointer) = LocalRevisionPointer::read_from(&revision_entry)? {
             if *pointer.cache_info() == cache_info {
-                if pointer.revision().has_digests(hashes) {
+                if pointer.revision().has_digests(hashes)
+                    && (!shared
+                        || pointer
+                            .revision()
+                            .hashes()
+                            .iter()
+                            .any(|digest| digest.algorithm() == HashAlgorithm::Sha256))
+                {
                     return Ok(pointer);
                 }
             }
@@ -1347,10 +1390,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // Otherwise, we need to create a new revision.
         let revision = Revision::new();
 
-        // Unzip the archive to a temporary directory.
+        // Unzip the archive to a temporary directory. If a shared cache is configured, always
+        // compute the SHA-256 digest, which identifies the archive in the shared cache.
         debug!("Unpacking source distribution: {source}");
         let entry = cache_shard.shard(revision.id()).entry(SOURCE);
-        let algorithms = hashes.algorithms();
+        let algorithms = if shared {
+            http_hash_algorithms(hashes)
+        } else {
+            hashes.algorithms()
+        };
         let hashes = self
             .persist_archive(&resource.path, resource.ext, entry.path(), &algorithms)
             .await?;
@@ -1376,6 +1424,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         resource: &DirectorySourceUrl<'_>,
         tags: &Tags,
         hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
         // Before running the build, check that the hashes match.
         if hashes.requires_validation() {
@@ -1440,6 +1489,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self
+            .shared_tree_wheel(source, resource, &build_info, client)
+            .await;
         let (disk_filename, filename, metadata) = self
             .build_distribution(
                 source,
@@ -1447,6 +1499,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 self.build_context.sources().clone(),
+                shared,
             )
             .await?;
 
@@ -1482,12 +1535,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         source: &BuildableSource<'_>,
         resource: &DirectorySourceUrl<'_>,
         hashes: HashPolicy<'_>,
-        credentials_cache: &CredentialsCache,
+        client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
         // Before running the build, check that the hashes match.
         if hashes.requires_validation() {
             return Err(Error::HashesNotSupportedSourceTree(source.to_string()));
         }
+        let credentials_cache = client.unmanaged.credentials_cache();
 
         // Project-style resolution always lowers workspace members as editable. Tool-style
         // resolution preserves an explicit local requirement choice instead, defaulting implicit
@@ -1644,6 +1698,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self
+            .shared_tree_wheel(source, resource, &build_info, client)
+            .await;
         let (_disk_filename, _filename, metadata) = self
             .build_distribution(
                 source,
@@ -1651,6 +1708,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 self.build_context.sources().clone(),
+                shared,
             )
             .await?;
 
@@ -1856,6 +1914,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 resource.git,
+                resource.path.parent(),
                 client.unmanaged.git_http_settings(resource.git.url()),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
@@ -1920,6 +1979,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self.shared_wheel(
+            Some(SharedWheelKey::git(resource.url, git_sha, &build_info)),
+            client,
+        );
         let (disk_filename, filename, metadata) = self
             .build_distribution(
                 source,
@@ -1927,6 +1990,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 NoSources::None,
+                shared,
             )
             .await?;
 
@@ -1966,6 +2030,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 resource.git,
+                resource.path.parent(),
                 client.unmanaged.git_http_settings(resource.git.url()),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
@@ -2077,6 +2142,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self.shared_wheel(
+            Some(SharedWheelKey::git(resource.url, git_sha, &build_info)),
+            client,
+        );
         let (_disk_filename, _filename, metadata) = self
             .build_distribution(
                 source,
@@ -2084,6 +2153,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 None,
                 &cache_shard,
                 NoSources::None,
+                shared,
             )
             .await?;
 
@@ -2189,6 +2259,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self.shared_wheel(
+            Some(SharedWheelKey::git(resource.url, git_sha, &build_info)),
+            client,
+        );
         let (disk_filename, filename, metadata) = self
             .build_distribution(
                 source,
@@ -2196,6 +2270,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 resource.subdirectory,
                 &cache_shard,
                 self.build_context.sources().clone(),
+                shared,
             )
             .await?;
 
@@ -2264,6 +2339,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .git()
                 .github_fast_path(
                     resource.git,
+                    &client.unmanaged.git_http_settings(resource.git.url()),
                     client
                         .unmanaged
                         .uncached_client(resource.git.url())
@@ -2491,6 +2567,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .as_ref()
             .map(|reporter| reporter.on_build_start(source));
 
+        let shared = self.shared_wheel(
+            Some(SharedWheelKey::git(resource.url, git_sha, &build_info)),
+            client,
+        );
         let (_disk_filename, _filename, metadata) = self
             .build_distribution(
                 source,
@@ -2498,6 +2578,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 resource.subdirectory,
                 &cache_shard,
                 self.build_context.sources().clone(),
+                shared,
             )
             .await?;
 
@@ -2546,11 +2627,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         source: &BuildableSource<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<Option<GitOid>, Error> {
-        let git = match source {
-            BuildableSource::Dist(SourceDist::GitDirectory(source)) => &*source.git,
-            BuildableSource::Dist(SourceDist::GitPath(source)) => &*source.git,
-            BuildableSource::Url(SourceUrl::GitDirectory(source)) => source.git,
-            BuildableSource::Url(SourceUrl::GitPath(source)) => source.git,
+        let (git, subdirectory) = match source {
+            BuildableSource::Dist(SourceDist::GitDirectory(source)) => {
+                (&*source.git, source.subdirectory.as_deref())
+            }
+            BuildableSource::Dist(SourceDist::GitPath(source)) => {
+                (&*source.git, source.install_path.parent())
+            }
+            BuildableSource::Url(SourceUrl::GitDirectory(source)) => {
+                (source.git, source.subdirectory)
+            }
+            BuildableSource::Url(SourceUrl::GitPath(source)) => (source.git, source.path.parent()),
             _ => {
                 return Ok(None);
             }
@@ -2568,6 +2655,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .github_fast_path(
                 git,
+                &client.unmanaged.git_http_settings(git.url()),
                 client.unmanaged.uncached_client(git.url()).raw_client(),
             )
             .await?
@@ -2582,6 +2670,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 git,
+                subdirectory,
                 client.unmanaged.git_http_settings(git.url()),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter
@@ -2998,7 +3087,20 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         subdirectory: Option<&Path>,
         cache_shard: &CacheShard,
         no_sources: NoSources,
+        shared: Option<SharedWheel<'_>>,
     ) -> Result<(String, WheelFilename, ResolutionMetadata), Error> {
+        // Before building, check whether the wheel was already built on another machine.
+        if let Some(shared) = shared.as_ref() {
+            match self.fetch_shared_wheel(source, shared, cache_shard).await {
+                Ok(Some(built)) => return Ok(built),
+                Ok(None) => {}
+                Err(err) => warn!(
+                    "Failed to fetch `{source}` from the shared cache: {}",
+                    err.to_string().trim()
+                ),
+            }
+        }
+
         debug!("Building: {source}");
 
         // Guard against build of source distributions when disabled.
@@ -3148,9 +3250,137 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         .map_err(Error::CacheWrite)?;
 
         debug!("Built `{source}` into `{disk_filename}`");
+
+        // Share the built wheel with other machines. Failures are non-fatal, since the wheel is
+        // already available locally.
+        if let Some(shared) = shared.as_ref() {
+            if let Err(err) = self
+                .store_shared_wheel(shared, &cache_shard.join(&disk_filename), &filename)
+                .await
+            {
+                warn!(
+                    "Failed to store `{source}` in the shared cache: {}",
+                    err.to_string().trim()
+                );
+            }
+        }
+
         Ok((disk_filename, filename, metadata))
     }
 
+    /// Returns the shared cache entry for a source distribution, if a shared cache is configured.
+    ///
+    /// The shared cache is consulted before building a source distribution, and populated after a
+    /// successful build.
+    fn shared_wheel<'client>(
+        &self,
+        key: Option<SharedWheelKey>,
+        client: &'client ManagedClient<'client>,
+    ) -> Option<SharedWheel<'client>> {
+        self.build_context.cache().shared_cache()?;
+        Some(SharedWheel { key: key?, client })
+    }
+
+    /// Returns the shared cache entry for a local source tree, if a shared cache is configured.
+    ///
+    /// Source trees are keyed by their contents, which requires hashing the tree. Editable builds
+    /// are never shared, since they refer to the source tree on the local machine.
+    async fn shared_tree_wheel<'client>(
+        &self,
+        source: &BuildableSource<'_>,
+        resource: &DirectorySourceUrl<'_>,
+        build_info: &BuildInfo,
+        client: &'client ManagedClient<'client>,
+    ) -> Option<SharedWheel<'client>> {
+        if resource.editable.unwrap_or(false) {
+            return None;
+        }
+        self.build_context.cache().shared_cache()?;
+        let key = tokio::task::spawn_blocking({
+            let root = resource.install_path.to_path_buf();
+            let build_info = build_info.clone();
+            move || SharedWheelKey::tree(&root, &build_info)
+        })
+        .await
+        .map_err(std::io::Error::other)
+        .and_then(|key| key);
+        match key {
+            Ok(key) => self.shared_wheel(Some(key), client),
+            Err(err) => {
+                warn!("Failed to hash `{source}` for the shared cache: {err}");
+                None
+            }
+        }
+    }
+
+    /// Fetch a wheel built from the given source distribution from the shared cache, moving it
+    /// into the local cache shard.
+    async fn fetch_shared_wheel(
+        &self,
+        source: &BuildableSource<'_>,
+        shared: &SharedWheel<'_>,
+        cache_shard: &CacheShard,
+    ) -> Result<Option<(String, WheelFilename, ResolutionMetadata)>, Error> {
+        let Some(tier) = self.build_context.cache().shared_cache() else {
+            return Ok(None);
+        };
+        let interpreter = self.build_context.interpreter().await;
+        let Some(platform) = interpreter.tags().ok().and_then(shared::platform) else {
+            return Ok(None);
+        };
+
+        let temp_dir = self
+            .build_context
+            .cache()
+            .build_dir()
+            .map_err(Error::CacheWrite)?;
+        let Some(filename) =
+            shared::fetch(tier, &shared.key, &platform, temp_dir.path(), shared.client).await?
+        else {
+            debug!("No wheel for `{source}` in the shared cache");
+            return Ok(None);
+        };
+        let disk_filename = filename.to_string();
+
+        // Validate the wheel, as for a local build.
+        let metadata = read_wheel_metadata(&filename, &temp_dir.path().join(&disk_filename))?;
+        validate_metadata(source, &metadata)?;
+        validate_filename(&filename, &metadata)?;
+
+        // Move the wheel to the cache.
+        fs::create_dir_all(&cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        rename_with_retry(
+            temp_dir.path().join(&disk_filename),
+            cache_shard.join(&disk_filename),
+        )
+        .await
+        .map_err(Error::CacheWrite)?;
+
+        debug!("Fetched `{source}` from the shared cache as `{disk_filename}`");
+        Ok(Some((disk_filename, filename, metadata)))
+    }
+
+    /// Store a wheel built from a source distribution in the shared cache.
+    async fn store_shared_wheel(
+        &self,
+        shared: &SharedWheel<'_>,
+        wheel: &Path,
+        filename: &WheelFilename,
+    ) -> Result<(), Error> {
+        let Some(tier) = self.build_context.cache().shared_cache() else {
+            return Ok(());
+        };
+        let interpreter = self.build_context.interpreter().await;
+        let Some(platform) = interpreter.tags().ok().and_then(shared::platform) else {
+            return Ok(());
+        };
+        shared::store(tier, &shared.key, &platform, wheel, filename, shared.client).await?;
+        debug!("Stored `{filename}` in the shared cache");
+        Ok(())
+    }
+
     /// Build the metadata for a source distribution.
     #[instrument(skip_all, fields(dist = %source))]
     async fn build_metadata(
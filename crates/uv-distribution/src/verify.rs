@@ -0,0 +1,329 @@
+//! Verify the integrity of the cache.
+//!
+//! Unzipped wheels in the archive bucket are checked against the dirhash recorded when they were
+//! persisted, and metadata entries (e.g., cached HTTP responses and archive pointers) are checked
+//! to deserialize. Corrupt entries can then be removed, such that they're re-populated on next use.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+use tracing::{debug, trace};
+
+use uv_cache::{ArchiveId, Cache, CacheBucket, DIRHASH_EXTENSION, Removal, is_archive_sidecar};
+use uv_client::{DataWithCachePolicy, OwnedArchive, SimpleDetailMetadata, SimpleIndexMetadata};
+
+use crate::{HttpArchivePointer, PathArchivePointer};
+
+/// The buckets that contain metadata entries.
+///
+/// The Git bucket is omitted, as checkouts may contain arbitrary files.
+const METADATA_BUCKETS: [CacheBucket; 8] = [
+    CacheBucket::Wheels,
+    CacheBucket::SourceDistributions,
+    CacheBucket::FlatIndex,
+    CacheBucket::Interpreter,
+    CacheBucket::Simple,
+    CacheBucket::Python,
+    CacheBucket::Osv,
+    CacheBucket::Provenance,
+];
+
+/// The result of verifying the cache.
+#[derive(Debug, Default)]
+pub struct CacheVerification {
+    /// The number of archives that were checked.
+    pub num_archives: usize,
+    /// The number of archives without a recorded dirhash, for which only the structure was
+    /// checked.
+    pub num_unhashed: usize,
+    /// The number of metadata entries that were checked.
+    pub num_metadata: usize,
+    /// The entries that failed verification.
+    pub corrupt: Vec<CorruptEntry>,
+}
+
+/// An entry in the cache that failed verification.
+#[derive(Debug)]
+pub struct CorruptEntry {
+    /// The path to the entry.
+    pub path: PathBuf,
+    /// The reason the entry failed verification.
+    pub reason: String,
+    /// Whether the entry is an archive, rather than a metadata entry.
+    archive: bool,
+}
+
+/// Verify the archives and metadata entries in the cache.
+///
+/// If `quick` is set, only the structure of each entry is checked: archives are not hashed, and
+/// metadata entries are not deserialized.
+pub fn verify(cache: &Cache, quick: bool) -> io::Result<CacheVerification> {
+    let mut verification = CacheVerification::default();
+
+    // Verify the unzipped wheels (and cached environments) in the archive bucket.
+    match fs_err::read_dir(cache.bucket(CacheBucket::Archive)) {
+        Ok(entries) => {
+            for entry in entries {
+                let path = entry?.path();
+                if is_archive_sidecar(&path) {
+                    continue;
+                }
+
+                verification.num_archives += 1;
+                match verify_archive(&path, quick) {
+                    Ok(hashed) => {
+                        if !hashed {
+                            verification.num_unhashed += 1;
+                        }
+                    }
+                    Err(reason) => {
+                        debug!("Corrupt cache archive `{}`: {reason}", path.display());
+                        verification.corrupt.push(CorruptEntry {
+                            path,
+                            reason,
+                            archive: true,
+                        });
+                    }
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+
+    // Verify the metadata entries in the remaining buckets.
+    for bucket in METADATA_BUCKETS {
+        let root = cache.bucket(bucket);
+        if !root.is_dir() {
+            continue;
+        }
+        // Skip unpacked source distributions, which may contain arbitrary files.
+        let walker = walkdir::WalkDir::new(&root)
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "src");
+        for entry in walker {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Some(format) = MetadataFormat::from_path(entry.path()) else {
+                continue;
+            };
+
+            verification.num_metadata += 1;
+            if let Err(reason) = verify_metadata(entry.path(), format, quick) {
+                debug!("Corrupt cache entry `{}`: {reason}", entry.path().display());
+                verification.corrupt.push(CorruptEntry {
+                    path: entry.into_path(),
+                    reason,
+                    archive: false,
+                });
+            }
+        }
+    }
+
+    Ok(verification)
+}
+
+/// Remove the corrupt entries found by [`verify`] from the cache.
+///
+/// Corrupt archives are removed along with any links and pointers to them, such that the affected
+/// wheels are re-downloaded (or rebuilt) on next use.
+pub fn repair(cache: &Cache, verification: &CacheVerification) -> io::Result<Removal> {
+    let archives = verification
+        .corrupt
+        .iter()
+        .filter(|entry| entry.archive)
+        .map(|entry| entry.path.clone())
+        .collect::<Vec<_>>();
+    let mut summary = cache.remove_archives(&archives)?;
+    summary += remove_pointers(cache, &archives)?;
+    for entry in verification.corrupt.iter().filter(|entry| !entry.archive) {
+        summary += cache.remove_path(&entry.path)?;
+    }
+    Ok(summary)
+}
+
+/// Remove the archive pointers in the wheel bucket that refer to any of the given archives.
+fn remove_pointers(cache: &Cache, archives: &[PathBuf]) -> io::Result<Removal> {
+    let mut summary = cache.removal();
+    if archives.is_empty() {
+        return Ok(summary);
+    }
+
+    let ids = archives
+        .iter()
+        .filter_map(|path| ArchiveId::from_str(path.file_name()?.to_str()?).ok())
+        .collect::<FxHashSet<_>>();
+
+    let root = cache.bucket(CacheBucket::Wheels);
+    if !root.is_dir() {
+        return Ok(summary);
+    }
+    for entry in walkdir::WalkDir::new(&root) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let archive = match entry.path().extension().and_then(|ext| ext.to_str()) {
+            Some("http") => HttpArchivePointer::read_from(entry.path())
+                .ok()
+                .flatten()
+                .map(HttpArchivePointer::into_archive),
+            Some("rev") => PathArchivePointer::read_from(entry.path())
+                .ok()
+                .flatten()
+                .map(PathArchivePointer::into_archive),
+            _ => continue,
+        };
+        if archive.is_some_and(|archive| ids.contains(&archive.id)) {
+            debug!(
+                "Removing pointer to corrupt archive: {}",
+                entry.path().display()
+            );
+            summary += cache.remove_path(entry.path())?;
+        }
+    }
+    Ok(summary)
+}
+
+/// Verify an archive, returning `true` if its contents were checked against a recorded dirhash.
+fn verify_archive(path: &Path, quick: bool) -> Result<bool, String> {
+    let metadata = fs_err::symlink_metadata(path).map_err(|err| err.to_string())?;
+    if !metadata.is_dir() {
+        return Err("expected a directory".to_string());
+    }
+
+    // Every archive is either an unzipped wheel, or a cached environment.
+    if !path.join("pyvenv.cfg").is_file() && !has_record(path).map_err(|err| err.to_string())? {
+        return Err("missing `.dist-info/RECORD`".to_string());
+    }
+
+    let expected = match fs_err::read_to_string(path.with_extension(DIRHASH_EXTENSION)) {
+        Ok(expected) => expected.trim().to_string(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err.to_string()),
+    };
+    if expected.len() != 64 || !expected.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err("invalid recorded dirhash".to_string());
+    }
+    if quick {
+        return Ok(true);
+    }
+
+    trace!("Hashing cache archive: {}", path.display());
+    let actual = uv_extract::dirhash::dirhash_path(path).map_err(|err| err.to_string())?;
+    if !actual.to_hex().eq_ignore_ascii_case(&expected) {
+        return Err(format!(
+            "expected dirhash `{expected}`, found `{}`",
+            actual.to_hex()
+        ));
+    }
+    Ok(true)
+}
+
+/// Returns `true` if the directory contains a `.dist-info` directory with a `RECORD` file.
+fn has_record(path: &Path) -> io::Result<bool> {
+    for entry in fs_err::read_dir(path)? {
+        let entry = entry?;
+        if entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.ends_with(".dist-info"))
+            && entry.path().join("RECORD").is_file()
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// The serialization format of a metadata entry.
+#[derive(Debug, Clone, Copy)]
+enum MetadataFormat {
+    /// A cached HTTP response containing an rkyv-serialized simple API response.
+    Rkyv,
+    /// `MsgPack` data, optionally wrapped in a cached HTTP response.
+    MsgPack,
+}
+
+impl MetadataFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "rkyv" => Some(Self::Rkyv),
+            "msgpack" | "http" | "rev" => Some(Self::MsgPack),
+            _ => None,
+        }
+    }
+}
+
+/// Verify that a metadata entry deserializes.
+fn verify_metadata(path: &Path, format: MetadataFormat, quick: bool) -> Result<(), String> {
+    let bytes = fs_err::read(path).map_err(|err| err.to_string())?;
+    if bytes.is_empty() {
+        return Err("empty file".to_string());
+    }
+    if quick {
+        return Ok(());
+    }
+
+    match format {
+        MetadataFormat::Rkyv => {
+            let data = DataWithCachePolicy::from_reader(bytes.as_slice())
+                .map_err(|err| err.to_string())?
+                .data;
+            if path
+                .file_name()
+                .is_some_and(|name| name == "index.html.rkyv")
+            {
+                OwnedArchive::<SimpleIndexMetadata>::new(data).map_err(|err| err.to_string())?;
+            } else {
+                OwnedArchive::<SimpleDetailMetadata>::new(data).map_err(|err| err.to_string())?;
+            }
+            Ok(())
+        }
+        MetadataFormat::MsgPack => {
+            // Entries are either plain `MsgPack` (e.g., archive pointers for local wheels), or
+            // `MsgPack` wrapped in a cached HTTP response.
+            let wrapped = DataWithCachePolicy::from_reader(bytes.as_slice())
+                .is_ok_and(|response| is_msgpack(&response.data));
+            if wrapped || is_msgpack(&bytes) {
+                Ok(())
+            } else {
+                Err("invalid MsgPack data".to_string())
+            }
+        }
+    }
+}
+
+/// Returns `true` if the bytes contain exactly one `MsgPack` value.
+fn is_msgpack(bytes: &[u8]) -> bool {
+    let mut reader = bytes;
+    let valid =
+        serde::de::IgnoredAny::deserialize(&mut rmp_serde::Deserializer::new(&mut reader)).is_ok();
+    valid && reader.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_msgpack;
+
+    #[test]
+    fn msgpack() {
+        let bytes = rmp_serde::to_vec(&("metadata", 1, [true, false])).unwrap();
+        assert!(is_msgpack(&bytes));
+
+        // Truncated values are invalid.
+        assert!(!is_msgpack(&bytes[..bytes.len() - 1]));
+
+        // Trailing data is invalid.
+        let mut trailing = bytes.clone();
+        trailing.push(0xc0);
+        assert!(!is_msgpack(&trailing));
+
+        assert!(!is_msgpack(&[]));
+    }
+}
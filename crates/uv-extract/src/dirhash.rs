@@ -70,6 +70,7 @@ use std::pin::{Pin, pin};
 
 use rayon::prelude::*;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tracing::debug;
 
 // Read repeatedly until the whole buffer is full, similar to `read_exact`. But if EOF is
 // encountered, return `Ok(n)` with a short length instead of reporting an error.
@@ -438,6 +439,27 @@ impl DirhashTree {
     }
 }
 
+/// Add an unpacked entry to the dirhash of an archive: a file if `hash` is provided, and otherwise
+/// a directory. If the entry can't be represented (e.g., a file conflicts with a directory), the
+/// dirhash is abandoned, such that we never record a dirhash that disagrees with the unpacked files.
+pub(crate) fn add_to_dirhash(
+    dirhash: &mut Option<DirhashTree>,
+    path: Option<&str>,
+    hash: Option<blake3::Hash>,
+) {
+    let (Some(tree), Some(path)) = (dirhash.as_mut(), path) else {
+        return;
+    };
+    let result = match hash {
+        Some(hash) => tree.add_file(path, hash),
+        None => tree.add_empty_dir(path),
+    };
+    if let Err(err) = result {
+        debug!("Skipping dirhash for unpacked archive: {err}");
+        *dirhash = None;
+    }
+}
+
 fn component_needs_normalization(component: &str) -> bool {
     matches!(component, "" | "." | "..")
 }
@@ -760,6 +782,40 @@ mod tests {
         Ok(())
     }
 
+    /// The dirhash computed while unzipping a wheel matches the dirhash of the unzipped files.
+    #[tokio::test]
+    async fn test_unzip_with_dirhash() -> anyhow::Result<()> {
+        let links = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test/links");
+        for entry in fs_err::read_dir(links)? {
+            let wheel = entry?.path();
+            if wheel.extension().is_none_or(|ext| ext != "whl") {
+                continue;
+            }
+
+            let target = tempfile::tempdir()?;
+            let reader = tokio::io::BufReader::new(fs_err::tokio::File::open(&wheel).await?);
+            let (_, tree) = crate::stream::unzip_with_dirhash(reader, target.path()).await?;
+            let tree = tree.expect("the dirhash to be computed");
+            assert_eq!(
+                tree.hash(),
+                dirhash_path(target.path())?,
+                "{}",
+                wheel.display()
+            );
+
+            let target = tempfile::tempdir()?;
+            let (_, tree) = crate::unzip_with_dirhash(fs_err::File::open(&wheel)?, target.path())?;
+            let tree = tree.expect("the dirhash to be computed");
+            assert_eq!(
+                tree.hash(),
+                dirhash_path(target.path())?,
+                "{}",
+                wheel.display()
+            );
+        }
+        Ok(())
+    }
+
     #[derive(Debug, serde::Deserialize)]
     struct JsonTestVector {
         // A tree of directories and the files they contain.
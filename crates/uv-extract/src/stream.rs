@@ -16,6 +16,7 @@ use tracing::{debug, warn};
 use uv_distribution_filename::{LegacySourceDistExtension, SourceDistExtension};
 use uv_preview::PreviewFeature;
 
+use crate::dirhash::{DirhashTree, add_to_dirhash, blake3_copy};
 use crate::{Error, insecure_no_validate, validate_archive_member_name};
 
 const DEFAULT_BUF_SIZE: usize = 128 * 1024;
@@ -75,10 +76,35 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
     reader: R,
     target: impl AsRef<Path>,
 ) -> Result<Vec<(PathBuf, u64)>, Error> {
+    let (files, _) = unzip_inner(reader, target.as_ref(), None).await?;
+    Ok(files)
+}
+
+/// Unpack a `.zip` archive into the target directory, without requiring `Seek`, computing the
+/// dirhash of the unpacked files as they're written.
+///
+/// Returns the list of unpacked files and their sizes, along with the [`DirhashTree`] of the
+/// unpacked files, if every entry could be hashed.
+pub async fn unzip_with_dirhash<R: tokio::io::AsyncRead + Unpin>(
+    reader: R,
+    target: impl AsRef<Path>,
+) -> Result<(Vec<(PathBuf, u64)>, Option<DirhashTree>), Error> {
+    Box::pin(unzip_inner(
+        reader,
+        target.as_ref(),
+        Some(DirhashTree::new()),
+    ))
+    .await
+}
+
+async fn unzip_inner<R: tokio::io::AsyncRead + Unpin>(
+    reader: R,
+    target: &Path,
+    mut dirhash: Option<DirhashTree>,
+) -> Result<(Vec<(PathBuf, u64)>, Option<DirhashTree>), Error> {
     // Determine whether ZIP validation is disabled.
     let skip_validation = insecure_no_validate();
 
-    let target = target.as_ref();
     let mut reader = futures::io::BufReader::with_capacity(DEFAULT_BUF_SIZE, reader.compat());
     let mut zip = async_zip::base::read::stream::ZipFileReader::new(&mut reader);
 
@@ -123,6 +149,9 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
         let expected_uncompressed_size = zip_entry.uncompressed_size();
         let expected_data_descriptor = zip_entry.data_descriptor();
 
+        // Retain the name of the entry, to record it in the dirhash.
+        let name = dirhash.is_some().then(|| path.to_string());
+
         // Either create the directory or write the file to disk.
         let path = target.join(&relpath);
         let is_dir = zip_entry.dir()?;
@@ -132,6 +161,7 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
                     .await
                     .map_err(Error::Io)?;
             }
+            add_to_dirhash(&mut dirhash, name.as_deref(), None);
 
             // If this is a directory, we expect the CRC32 to be 0.
             if zip_entry.crc32() != 0 {
@@ -182,9 +212,19 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
                         tokio::io::BufWriter::new(file)
                     };
                     let mut reader = entry.reader_mut().compat();
-                    let bytes_read = tokio::io::copy(&mut reader, &mut writer)
-                        .await
-                        .map_err(Error::io_or_zip)?;
+                    let bytes_read = if dirhash.is_some() {
+                        // Hash the file as it's written, rather than reading it back later. The
+                        // copy buffer is part of the future, so box it to keep it off the stack.
+                        let (bytes_read, hash) = Box::pin(blake3_copy(&mut reader, &mut writer))
+                            .await
+                            .map_err(Error::io_or_zip)?;
+                        add_to_dirhash(&mut dirhash, name.as_deref(), Some(hash));
+                        bytes_read
+                    } else {
+                        tokio::io::copy(&mut reader, &mut writer)
+                            .await
+                            .map_err(Error::io_or_zip)?
+                    };
                     let reader = reader.into_inner();
 
                     (bytes_read, reader)
@@ -571,7 +611,7 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
         }
     }
 
-    Ok(files)
+    Ok((files, dirhash))
 }
 
 /// Unpack the given tar archive into the destination directory.
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use crate::dirhash::{DirhashTree, add_to_dirhash};
 use crate::vendor::CloneableSeekableReader;
 use crate::{Error, insecure_no_validate, validate_archive_member_name};
 use async_zip::base::read::seek::ZipFileReader;
@@ -12,10 +13,60 @@ use rustc_hash::FxHashSet;
 use tracing::warn;
 use uv_configuration::initialize_rayon_once;
 
+/// An entry unpacked from a `.zip` archive.
+enum Unpacked {
+    /// A file, with its size and, if requested, its name and the BLAKE3 hash of its contents.
+    File {
+        path: PathBuf,
+        size: u64,
+        hashed: Option<(String, blake3::Hash)>,
+    },
+    /// A directory, by name. Only reported if hashes are requested.
+    Directory(String),
+}
+
 /// Unzip a `.zip` archive into the target directory.
 ///
 /// Returns the list of unpacked files and their sizes.
 pub fn unzip(reader: fs_err::File, target: &Path) -> Result<Vec<(PathBuf, u64)>, Error> {
+    let entries = unzip_inner(reader, target, false)?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Unpacked::File { path, size, .. } => Some((path, size)),
+            Unpacked::Directory(_) => None,
+        })
+        .collect())
+}
+
+/// Unzip a `.zip` archive into the target directory, computing the dirhash of the unpacked files
+/// as they're written.
+///
+/// Returns the list of unpacked files and their sizes, along with the [`DirhashTree`] of the
+/// unpacked files, if every entry could be hashed.
+#[expect(clippy::type_complexity)]
+pub fn unzip_with_dirhash(
+    reader: fs_err::File,
+    target: &Path,
+) -> Result<(Vec<(PathBuf, u64)>, Option<DirhashTree>), Error> {
+    let entries = unzip_inner(reader, target, true)?;
+    let mut files = Vec::with_capacity(entries.len());
+    let mut dirhash = Some(DirhashTree::new());
+    for entry in entries {
+        match entry {
+            Unpacked::File { path, size, hashed } => {
+                if let Some((name, hash)) = hashed {
+                    add_to_dirhash(&mut dirhash, Some(&name), Some(hash));
+                }
+                files.push((path, size));
+            }
+            Unpacked::Directory(name) => add_to_dirhash(&mut dirhash, Some(&name), None),
+        }
+    }
+    Ok((files, dirhash))
+}
+
+fn unzip_inner(reader: fs_err::File, target: &Path, hash: bool) -> Result<Vec<Unpacked>, Error> {
     let (reader, _) = reader.into_parts();
 
     // Parse the central directory once, then clone the archive reader per Rayon worker so
@@ -61,7 +112,7 @@ pub fn unzip(reader: fs_err::File, target: &Path) -> Result<Vec<(PathBuf, u64)>,
                 if directories.insert(path.clone()) {
                     fs_err::create_dir_all(path).map_err(Error::Io)?;
                 }
-                return Ok(None);
+                return Ok(hash.then(|| Unpacked::Directory(file_name.to_string())));
             }
 
             if let Some(parent) = path.parent() {
@@ -79,9 +130,10 @@ pub fn unzip(reader: fs_err::File, target: &Path) -> Result<Vec<(PathBuf, u64)>,
             } else {
                 std::io::BufWriter::new(outfile)
             };
-            let (copied, computed_crc32) = block_on(async {
+            let (copied, computed_crc32, computed_hash) = block_on(async {
                 let mut file = archive.reader_with_entry(file_number).await?;
                 let mut writer = AllowStdIo::new(writer);
+                let mut hasher = hash.then(blake3::Hasher::new);
                 let mut copied = 0;
                 let mut buffer = vec![0; 128 * 1024];
                 loop {
@@ -89,11 +141,18 @@ pub fn unzip(reader: fs_err::File, target: &Path) -> Result<Vec<(PathBuf, u64)>,
                     if read == 0 {
                         break;
                     }
+                    if let Some(hasher) = hasher.as_mut() {
+                        hasher.update(&buffer[..read]);
+                    }
                     writer.write_all(&buffer[..read]).await.map_err(Error::Io)?;
                     copied += read as u64;
                 }
                 writer.flush().await.map_err(Error::Io)?;
-                Ok::<_, Error>((copied, file.compute_hash()))
+                Ok::<_, Error>((
+                    copied,
+                    file.compute_hash(),
+                    hasher.map(|hasher| hasher.finalize()),
+                ))
             })?;
 
             if copied != size && !skip_validation {
@@ -135,9 +194,13 @@ pub fn unzip(reader: fs_err::File, target: &Path) -> Result<Vec<(PathBuf, u64)>,
                 }
             }
 
-            Ok(Some((enclosed_name, size)))
+            Ok(Some(Unpacked::File {
+                path: enclosed_name,
+                size,
+                hashed: computed_hash.map(|hash| (file_name.to_string(), hash)),
+            }))
         })
-        // Filter out directories and skipped dangerous paths, we only want to collect the files.
+        // Filter out skipped dangerous paths.
         .filter_map(Result::transpose)
         .collect::<Result<_, Error>>()
 }
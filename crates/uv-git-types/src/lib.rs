@@ -2,6 +2,7 @@ pub use crate::github::GitHubRepository;
 pub use crate::oid::{GitOid, OidParseError};
 pub use crate::reference::GitReference;
 use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 use percent_encoding::percent_decode_str;
@@ -108,6 +109,10 @@ pub struct GitUrl {
     precise: Option<GitOid>,
     /// Git LFS configuration for this repository.
     lfs: GitLfs,
+    /// Whether to require a trusted signature on the commit (or tag) before using it.
+    verify_signature: bool,
+    /// The file of SSH keys that are trusted to sign the commit (or tag), if not the default.
+    allowed_signers: Option<PathBuf>,
 }
 
 impl GitUrl {
@@ -153,6 +158,8 @@ impl GitUrl {
             reference,
             precise: None,
             lfs,
+            verify_signature: false,
+            allowed_signers: None,
         };
         match precise {
             Some(precise) => git.with_precise(precise),
@@ -218,6 +225,31 @@ impl GitUrl {
         self.lfs = lfs;
         self
     }
+
+    /// Return `true` if the signature of the commit (or tag) must be verified before use.
+    pub fn verify_signature(&self) -> bool {
+        self.verify_signature
+    }
+
+    /// Set whether the signature of the commit (or tag) must be verified before use.
+    #[must_use]
+    pub fn with_verify_signature(mut self, verify_signature: bool) -> Self {
+        self.verify_signature = verify_signature;
+        self
+    }
+
+    /// Return the file of SSH keys that are trusted to sign the commit (or tag), if not the
+    /// default.
+    pub fn allowed_signers(&self) -> Option<&Path> {
+        self.allowed_signers.as_deref()
+    }
+
+    /// Set the file of SSH keys that are trusted to sign the commit (or tag).
+    #[must_use]
+    pub fn with_allowed_signers(mut self, allowed_signers: Option<PathBuf>) -> Self {
+        self.allowed_signers = allowed_signers;
+        self
+    }
 }
 
 impl PartialEq for GitUrl {
@@ -226,6 +258,8 @@ impl PartialEq for GitUrl {
             && self.reference == other.reference
             && self.precise == other.precise
             && self.lfs == other.lfs
+            && self.verify_signature == other.verify_signature
+            && self.allowed_signers == other.allowed_signers
     }
 }
 
@@ -244,6 +278,8 @@ impl Ord for GitUrl {
             .then_with(|| self.reference.cmp(&other.reference))
             .then_with(|| self.precise.cmp(&other.precise))
             .then_with(|| self.lfs.cmp(&other.lfs))
+            .then_with(|| self.verify_signature.cmp(&other.verify_signature))
+            .then_with(|| self.allowed_signers.cmp(&other.allowed_signers))
     }
 }
 
@@ -253,6 +289,8 @@ impl std::hash::Hash for GitUrl {
         self.reference.hash(state);
         self.precise.hash(state);
         self.lfs.hash(state);
+        self.verify_signature.hash(state);
+        self.allowed_signers.hash(state);
     }
 }
 
@@ -1,6 +1,7 @@
 //! Git support is derived from Cargo's implementation.
 //! Cargo is dual-licensed under either Apache 2.0 or MIT, at the user's choice.
 //! Source: <https://github.com/rust-lang/cargo/blob/23eb492cf920ce051abfc56bbaf838514dc8365c/src/cargo/sources/git/utils.rs>
+use std::collections::BTreeSet;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
 use std::str::{self};
@@ -38,6 +39,24 @@ pub enum GitError {
     TransportNotAllowed,
 }
 
+/// An error that occurs when the signature of a Git commit (or tag) can't be verified.
+#[derive(Debug, thiserror::Error)]
+pub enum GitSignatureError {
+    #[error("Git source `{url}` requires a signature, but {subject} is not signed")]
+    Unsigned {
+        url: DisplaySafeUrl,
+        subject: String,
+    },
+    #[error(
+        "Git source `{url}` requires a signature, but {subject} is not signed by a trusted key:\n{output}"
+    )]
+    Untrusted {
+        url: DisplaySafeUrl,
+        subject: String,
+        output: String,
+    },
+}
+
 /// A global cache of the result of `which git` as a command
 ///
 /// Caching the command allows us to avoid needing to remove environment
@@ -66,7 +85,79 @@ pub static GIT: LazyLock<Result<ProcessBuilder, GitError>> = LazyLock::new(|| {
     Ok(cmd)
 });
 
+/// The minimum Git version with which partial clones and sparse checkouts are used.
+const PARTIAL_CLONE_MIN_VERSION: (u32, u32) = (2, 39);
+
+/// The header of the sparse checkout patterns written by uv.
+const SPARSE_CHECKOUT_HEADER: &str = "# Managed by uv.";
+
+/// A global cache of whether the `git` CLI supports partial clones and sparse checkouts.
+///
+/// Partial clones fetch commits and trees up-front, and file contents on demand, such that
+/// checking out a single subdirectory of a large repository avoids downloading the entire tree.
+static GIT_PARTIAL_CLONE: LazyLock<bool> = LazyLock::new(|| {
+    if std::env::var_os(EnvVars::UV_NO_GIT_PARTIAL_CLONE).is_some() {
+        debug!("Partial Git clones are disabled via `UV_NO_GIT_PARTIAL_CLONE`");
+        return false;
+    }
+
+    let Ok(git) = GIT.as_ref() else {
+        return false;
+    };
+    let version = git
+        .clone()
+        .arg("--version")
+        .exec_with_output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    match version.as_deref().and_then(parse_git_version) {
+        Some(version) if version >= PARTIAL_CLONE_MIN_VERSION => true,
+        Some((major, minor)) => {
+            debug!("Partial Git clones are not supported by Git {major}.{minor}");
+            false
+        }
+        None => {
+            debug!("Failed to determine the Git version; disabling partial Git clones");
+            false
+        }
+    }
+});
+
+/// Returns `true` if partial clones and sparse checkouts should be used for Git fetches.
+pub(crate) fn partial_clone_enabled() -> bool {
+    *GIT_PARTIAL_CLONE
+}
+
+/// Parse the major and minor version from the output of `git --version`, e.g.,
+/// `git version 2.39.5` or `git version 2.45.1.windows.1`.
+fn parse_git_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.trim().strip_prefix("git version ")?;
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts
+        .next()?
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .ok()?;
+    Some((major, minor))
+}
+
+/// The objects to request when fetching refspecs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FetchObjects {
+    /// Fetch all objects reachable from the refspecs.
+    All,
+    /// Omit file contents, which are fetched on demand when checking out a revision.
+    Partial,
+    /// Fetch all objects reachable from the refspecs, including those omitted by an earlier
+    /// partial fetch.
+    Refetch,
+}
+
 /// Strategy when fetching refspecs for a [`GitReference`]
+#[derive(Debug, Copy, Clone)]
 enum RefspecStrategy {
     /// All refspecs should be fetched, if any fail then the fetch will fail.
     All,
@@ -235,6 +326,131 @@ impl GitRepository {
         Ok(result.parse()?)
     }
 
+    /// Returns the type of the object named by `refname`, e.g., `commit` or `tag`.
+    fn object_type(&self, refname: &str) -> Result<String> {
+        let result = GIT
+            .as_ref()
+            .cloned()?
+            .arg("cat-file")
+            .arg("-t")
+            .arg(refname)
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        let mut result = String::from_utf8(result.stdout)?;
+        result.truncate(result.trim_end().len());
+        Ok(result)
+    }
+
+    /// Sets a configuration value in the repository's local Git configuration.
+    fn set_config(&self, key: &str, value: &str) -> Result<()> {
+        GIT.as_ref()
+            .cloned()?
+            .arg("config")
+            .arg(key)
+            .arg(value)
+            .cwd(&self.path)
+            .exec_with_output()?;
+        Ok(())
+    }
+
+    /// Returns `true` if this repository was populated by a partial (blob-less) fetch, such that
+    /// file contents may need to be fetched from the remote on demand.
+    ///
+    /// Git records the filter of a partial fetch in the configuration of the remote.
+    fn is_partial(&self) -> bool {
+        let Ok(git) = GIT.as_ref() else {
+            return false;
+        };
+        git.clone()
+            .arg("config")
+            .arg("--get-regexp")
+            .arg(r"^remote\..*\.partialclonefilter$")
+            .cwd(&self.path)
+            .exec_with_output()
+            .is_ok()
+    }
+
+    /// Removes the partial fetch configuration of all remotes, after the omitted objects have
+    /// been refetched.
+    ///
+    /// Git retains the configuration on a refetch, such that later fetches would omit file
+    /// contents again.
+    fn clear_partial_filter(&self) -> Result<()> {
+        let output = GIT
+            .as_ref()
+            .cloned()?
+            .arg("config")
+            .arg("--name-only")
+            .arg("--get-regexp")
+            .arg(r"^remote\..*\.(promisor|partialclonefilter)$")
+            .cwd(&self.path)
+            .exec_with_output();
+        let Ok(output) = output else {
+            return Ok(());
+        };
+        for name in String::from_utf8_lossy(&output.stdout).lines() {
+            GIT.as_ref()
+                .cloned()?
+                .arg("config")
+                .arg("--unset-all")
+                .arg(name)
+                .cwd(&self.path)
+                .exec_with_output()?;
+        }
+        Ok(())
+    }
+
+    /// Configures the `upstream` remote from which objects missing in a checkout of a partial
+    /// database are fetched on demand.
+    ///
+    /// The credential-free URL is persisted; credentials are passed to each command that may
+    /// fetch objects via [`submodule_auth_config`].
+    fn set_promisor_remote(&self, url: &DisplaySafeUrl) -> Result<()> {
+        self.set_config("remote.upstream.url", url.without_credentials().as_str())?;
+        self.set_config("remote.upstream.promisor", "true")?;
+        self.set_config("remote.upstream.partialclonefilter", "blob:none")?;
+        Ok(())
+    }
+
+    /// Returns the directories included in the sparse checkout of this repository, or `None` if
+    /// the repository is fully checked out.
+    fn sparse_directories(&self) -> Option<BTreeSet<String>> {
+        let patterns =
+            fs_err::read_to_string(self.path.join(".git").join("info").join("sparse-checkout"))
+                .ok()?;
+        if patterns.lines().next() != Some(SPARSE_CHECKOUT_HEADER) {
+            return None;
+        }
+        Some(
+            patterns
+                .lines()
+                .filter_map(|line| line.strip_prefix('/')?.strip_suffix('/'))
+                .map(unescape_sparse_pattern)
+                .collect(),
+        )
+    }
+
+    /// Limits the checkout of this repository to the given directories (along with any files in
+    /// their parent directories and any `pyproject.toml` files), or restores a full checkout if
+    /// `directories` is `None`.
+    ///
+    /// Takes effect on the next `git reset`.
+    fn set_sparse_directories(&self, directories: Option<&BTreeSet<String>>) -> Result<()> {
+        let path = self.path.join(".git").join("info").join("sparse-checkout");
+        fs_err::create_dir_all(path.parent().unwrap())?;
+        if let Some(directories) = directories {
+            fs_err::write(&path, sparse_checkout_patterns(directories))?;
+        } else {
+            // Disabling sparse checkouts retains the files excluded from the working tree, so
+            // instead match all files, which restores them on reset.
+            fs_err::write(&path, "/*\n")?;
+        }
+        self.set_config("core.sparseCheckout", "true")?;
+        self.set_config("core.sparseCheckoutCone", "false")?;
+        Ok(())
+    }
+
     /// Verifies LFS artifacts have been initialized for a given `refname`.
     #[instrument(skip_all, fields(path = %self.path.user_display(), refname = %refname))]
     fn lfs_fsck_objects(&self, refname: &str) -> bool {
@@ -296,6 +512,10 @@ impl GitRemote {
     /// if we can. If that can successfully load our revision then we've
     /// populated the database with the latest version of `reference`, so
     /// return that database and the rev we resolve to.
+    ///
+    /// When `partial` is set, file contents are omitted from the fetch (if supported by the
+    /// remote), and fetched on demand when checking out a revision.
+    #[expect(clippy::fn_params_excessive_bools)]
     pub(crate) fn checkout(
         self,
         into: &Path,
@@ -305,6 +525,7 @@ impl GitRemote {
         disable_ssl: bool,
         offline: bool,
         with_lfs: bool,
+        partial: bool,
     ) -> Result<(GitDatabase, GitOid)> {
         let reference = locked_rev
             .or_else(|| {
@@ -317,8 +538,15 @@ impl GitRemote {
             .map(ReferenceOrOid::Oid)
             .unwrap_or(ReferenceOrOid::Reference(reference));
         if let Some(mut db) = db {
-            fetch(&mut db.repo, &self.url, reference, disable_ssl, offline)
-                .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
+            fetch(
+                &mut db.repo,
+                &self.url,
+                reference,
+                disable_ssl,
+                offline,
+                partial,
+            )
+            .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
 
             let resolved_commit_hash = match locked_rev {
                 Some(rev) => db.contains(rev).then_some(rev),
@@ -346,8 +574,15 @@ impl GitRemote {
 
         fs_err::create_dir_all(into)?;
         let mut repo = GitRepository::init(into)?;
-        fetch(&mut repo, &self.url, reference, disable_ssl, offline)
-            .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
+        fetch(
+            &mut repo,
+            &self.url,
+            reference,
+            disable_ssl,
+            offline,
+            partial,
+        )
+        .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
             Some(rev) => rev,
             None => reference.resolve(&repo)?,
@@ -382,7 +617,16 @@ impl GitRemote {
 
 impl GitDatabase {
     /// Checkouts to a revision at `destination` from this database.
-    pub(crate) fn copy_to(&self, rev: GitOid, destination: &Path) -> Result<GitCheckout> {
+    ///
+    /// If `sparse` is provided, the checkout is limited to the given directory (see
+    /// [`sparse_directory`]); otherwise, the full repository is checked out.
+    pub(crate) fn copy_to(
+        &self,
+        rev: GitOid,
+        destination: &Path,
+        sparse: Option<&str>,
+        offline: bool,
+    ) -> Result<GitCheckout> {
         // If the existing checkout exists, and it is fresh, use it.
         // A non-fresh checkout can happen if the checkout operation was
         // interrupted. In that case, the checkout gets deleted and a new
@@ -392,8 +636,13 @@ impl GitDatabase {
             .map(|repo| GitCheckout::new(rev, repo))
             .filter(GitCheckout::is_fresh)
         {
-            Some(co) => co.with_lfs_ready(self.lfs_ready),
-            None => GitCheckout::clone_into(destination, self, rev, self.remote.url())?,
+            Some(co) => {
+                let co = co.with_lfs_ready(self.lfs_ready);
+                co.widen(sparse, self.remote.url(), offline)?
+            }
+            None => {
+                GitCheckout::clone_into(destination, self, rev, self.remote.url(), sparse, offline)?
+            }
         };
         Ok(checkout)
     }
@@ -414,6 +663,11 @@ impl GitDatabase {
         Ok(result)
     }
 
+    /// Returns `true` if this database is missing file contents, as populated by a partial fetch.
+    pub(crate) fn is_partial(&self) -> bool {
+        self.repo.is_partial()
+    }
+
     /// Checks if `oid` resolves to a commit in this database.
     pub(crate) fn contains(&self, oid: GitOid) -> bool {
         self.repo.rev_parse(&format!("{oid}^0")).is_ok()
@@ -430,6 +684,114 @@ impl GitDatabase {
         self.lfs_ready = lfs;
         self
     }
+
+    /// Checks if the tag `tag` has been fetched into this database, and points to `oid`.
+    pub(crate) fn contains_tag(&self, tag: &str, oid: GitOid) -> bool {
+        self.repo
+            .rev_parse(&format!("refs/remotes/origin/tags/{tag}^0"))
+            .is_ok_and(|rev| rev == oid)
+    }
+
+    /// Fetches the tag `tag` into this database.
+    pub(crate) fn fetch_tag(
+        &self,
+        tag: &GitReference,
+        disable_ssl: bool,
+        offline: bool,
+        partial: bool,
+    ) -> Result<()> {
+        fetch(
+            &mut GitRepository::open(&self.repo.path)?,
+            &self.remote.url,
+            ReferenceOrOid::Reference(tag),
+            disable_ssl,
+            offline,
+            partial,
+        )
+    }
+
+    /// Verifies that the commit `oid` is signed by a trusted key.
+    ///
+    /// If a `tag` that points to the commit is provided, a trusted signature on the (annotated)
+    /// tag is accepted in lieu of a signature on the commit.
+    ///
+    /// SSH signatures are verified against the `allowed_signers` file, if provided, or Git's
+    /// `gpg.ssh.allowedSignersFile` otherwise. GPG signatures are verified against the user's
+    /// keyring.
+    pub(crate) fn verify_signature(
+        &self,
+        url: &DisplaySafeUrl,
+        oid: GitOid,
+        tag: Option<&str>,
+        allowed_signers: Option<&Path>,
+    ) -> Result<()> {
+        // Returns the output of Git if the signature could not be verified.
+        let verify = |command: &str, object: &str| -> Result<Option<String>> {
+            let mut cmd = GIT.as_ref().cloned()?;
+            if let Some(allowed_signers) = allowed_signers {
+                cmd.arg("-c").arg(format!(
+                    "gpg.ssh.allowedSignersFile={}",
+                    allowed_signers.display()
+                ));
+            }
+            let output = cmd.arg(command).arg(object).cwd(&self.repo.path).output()?;
+            if output.status.success() {
+                debug!("Verified signature of `{object}` for: {url}");
+                Ok(None)
+            } else {
+                Ok(Some(
+                    String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                ))
+            }
+        };
+
+        // Accept a trusted signature on the tag, then on the commit. Lightweight tags can't be
+        // signed.
+        let tag = tag.filter(|tag| self.contains_tag(tag, oid));
+        let mut tag_output = None;
+        if let Some(tag) = tag {
+            let refname = format!("refs/remotes/origin/tags/{tag}");
+            if self.repo.object_type(&refname)? == "tag" {
+                match verify("verify-tag", &refname)? {
+                    None => return Ok(()),
+                    Some(output) => {
+                        debug!("Failed to verify signature of tag `{tag}`: {output}");
+                        tag_output = Some(output);
+                    }
+                }
+            }
+        }
+        let Some(mut output) = verify("verify-commit", oid.as_str())? else {
+            return Ok(());
+        };
+
+        // If the commit is unsigned, report why the tag's signature couldn't be verified.
+        if output.is_empty() {
+            output = tag_output.unwrap_or_default();
+        }
+
+        let subject = if let Some(tag) = tag {
+            format!("tag `{tag}` (commit `{oid}`)")
+        } else {
+            format!("commit `{oid}`")
+        };
+
+        // Git doesn't report anything when verifying an unsigned commit.
+        if output.is_empty() {
+            Err(GitSignatureError::Unsigned {
+                url: url.clone(),
+                subject,
+            }
+            .into())
+        } else {
+            Err(GitSignatureError::Untrusted {
+                url: url.clone(),
+                subject,
+                output,
+            }
+            .into())
+        }
+    }
 }
 
 impl GitCheckout {
@@ -452,6 +814,8 @@ impl GitCheckout {
         database: &GitDatabase,
         revision: GitOid,
         original_remote_url: &DisplaySafeUrl,
+        sparse: Option<&str>,
+        offline: bool,
     ) -> Result<Self> {
         let dirname = into.parent().unwrap();
         fs_err::create_dir_all(dirname)?;
@@ -489,11 +853,51 @@ impl GitCheckout {
         }
 
         let repo = GitRepository::open(into)?;
+
+        // If the database is missing file contents, fetch them from the remote on demand.
+        if database.repo.is_partial() {
+            repo.set_promisor_remote(original_remote_url)?;
+        }
+
+        if let Some(directory) = sparse {
+            debug!("Using a sparse checkout of `{directory}`");
+            repo.set_sparse_directories(Some(&BTreeSet::from([directory.to_string()])))?;
+        }
+
         let checkout = Self::new(revision, repo);
-        let lfs_ready = checkout.reset(database.lfs_ready, original_remote_url)?;
+        let lfs_ready = checkout.reset(database.lfs_ready, original_remote_url, offline)?;
         Ok(checkout.with_lfs_ready(lfs_ready))
     }
 
+    /// Extends a sparse checkout to include the `sparse` directory, or to include the full
+    /// repository if `sparse` is `None`.
+    fn widen(
+        self,
+        sparse: Option<&str>,
+        original_remote_url: &DisplaySafeUrl,
+        offline: bool,
+    ) -> Result<Self> {
+        let Some(mut directories) = self.repo.sparse_directories() else {
+            return Ok(self);
+        };
+        if let Some(directory) = sparse {
+            if directories
+                .iter()
+                .any(|included| sparse_directory_includes(included, directory))
+            {
+                return Ok(self);
+            }
+            debug!("Extending sparse checkout to include `{directory}`");
+            directories.insert(directory.to_string());
+            self.repo.set_sparse_directories(Some(&directories))?;
+        } else {
+            debug!("Extending sparse checkout to the full repository");
+            self.repo.set_sparse_directories(None)?;
+        }
+        let lfs_ready = self.reset(self.lfs_ready, original_remote_url, offline)?;
+        Ok(self.with_lfs_ready(lfs_ready))
+    }
+
     /// Checks if the `HEAD` of this checkout points to the expected revision.
     fn is_fresh(&self) -> bool {
         match self.repo.rev_parse("HEAD") {
@@ -532,10 +936,14 @@ impl GitCheckout {
     /// [`.ok`]: CHECKOUT_READY_LOCK
     /// `git reset --hard [<commit>]` can break relative submodule URLs, so we update submodules
     /// using the original remote URL.
+    ///
+    /// In a checkout of a partial database, the reset fetches any missing file contents from the
+    /// remote.
     fn reset(
         &self,
         with_lfs: Option<bool>,
         original_remote_url: &DisplaySafeUrl,
+        offline: bool,
     ) -> Result<Option<bool>> {
         let ok_file = self.repo.path.join(CHECKOUT_READY_LOCK);
         let _ = paths::remove_file(&ok_file);
@@ -548,14 +956,22 @@ impl GitCheckout {
         debug!("Reset {} to {}", self.repo.path.display(), self.revision);
 
         // Perform the hard reset.
-        GIT.as_ref()
-            .cloned()?
+        let mut reset = GIT.as_ref().cloned()?;
+        for config in submodule_auth_config(original_remote_url) {
+            reset.arg("-c").arg(config);
+        }
+        if offline {
+            reset.env(EnvVars::GIT_ALLOW_PROTOCOL, "file");
+        }
+        reset
             .arg("reset")
             .arg("--hard")
             .arg(self.revision.as_str())
+            .env(EnvVars::GIT_TERMINAL_PROMPT, "0")
             .env(EnvVars::GIT_LFS_SKIP_SMUDGE, lfs_skip_smudge)
             .cwd(&self.repo.path)
-            .exec_with_output()?;
+            .exec_with_output()
+            .map_err(|err| redact_git_error(err, original_remote_url))?;
 
         // Initialize direct submodules using the original remote URL so Git can resolve relative
         // submodule URLs, but don't write it to `remote.origin.url`. Git persists resolved submodule
@@ -618,6 +1034,98 @@ impl GitCheckout {
     }
 }
 
+/// Normalize a `subdirectory` within a repository to a sparse checkout directory, e.g.,
+/// `pkgs/a`.
+///
+/// Returns `None` if the subdirectory is the repository root, or can't be represented as a
+/// sparse checkout directory (e.g., it traverses outside the repository), in which case the
+/// repository should be checked out in full.
+pub(crate) fn sparse_directory(subdirectory: &Path) -> Option<String> {
+    let mut components = Vec::new();
+    for component in subdirectory.components() {
+        match component {
+            std::path::Component::Normal(component) => components.push(component.to_str()?),
+            std::path::Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    if components.is_empty() {
+        return None;
+    }
+    Some(components.join("/"))
+}
+
+/// Returns `true` if the sparse checkout `directory` includes the `subdirectory`.
+fn sparse_directory_includes(directory: &str, subdirectory: &str) -> bool {
+    subdirectory
+        .strip_prefix(directory)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Return the (non-cone) sparse checkout patterns for the given directories.
+///
+/// Like Git's cone mode, the files (but not the directories) at the repository root and in each
+/// parent of an included directory are checked out, such that a build can reference files like a
+/// shared `README.md` or `LICENSE`. All `pyproject.toml` files are checked out too, since they're
+/// needed to discover workspace members.
+fn sparse_checkout_patterns(directories: &BTreeSet<String>) -> String {
+    let mut parents = BTreeSet::new();
+    for directory in directories {
+        let mut parent = directory.as_str();
+        while let Some((head, _)) = parent.rsplit_once('/') {
+            parents.insert(head);
+            parent = head;
+        }
+    }
+
+    let mut patterns = vec![
+        SPARSE_CHECKOUT_HEADER.to_string(),
+        "/*".to_string(),
+        "!/*/".to_string(),
+        "pyproject.toml".to_string(),
+    ];
+    for parent in parents {
+        let parent = escape_sparse_pattern(parent);
+        patterns.push(format!("/{parent}/*"));
+        patterns.push(format!("!/{parent}/*/"));
+    }
+    for directory in directories {
+        patterns.push(format!("/{}/", escape_sparse_pattern(directory)));
+    }
+
+    let mut patterns = patterns.join("\n");
+    patterns.push('\n');
+    patterns
+}
+
+/// Escape the wildcard characters in a path, for use in a sparse checkout pattern.
+fn escape_sparse_pattern(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for char in path.chars() {
+        if matches!(char, '*' | '?' | '[' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+    escaped
+}
+
+/// Reverse [`escape_sparse_pattern`].
+fn unescape_sparse_pattern(pattern: &str) -> String {
+    let mut unescaped = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(char) = chars.next() {
+        if char == '\\' {
+            if let Some(next) = chars.next() {
+                unescaped.push(next);
+            }
+        } else {
+            unescaped.push(char);
+        }
+    }
+    unescaped
+}
+
 /// Return command-local Git configuration for initializing direct submodules in a checkout.
 ///
 /// Relative submodule URLs are resolved from `remote.origin.url`, but writing the original remote
@@ -633,7 +1141,8 @@ fn submodule_update_config(original_remote_url: &DisplaySafeUrl) -> Vec<String>
     config
 }
 
-/// Return command-local Git authentication configuration for updating submodules.
+/// Return command-local Git authentication configuration for updating submodules and fetching
+/// objects missing from a partial clone.
 ///
 /// Unlike `remote.origin.url`, these rewrites are safe to inherit during recursive submodule
 /// updates: they rewrite transport URLs for authentication, but do not change the base URL that Git
@@ -678,17 +1187,30 @@ fn remote_url_root(mut url: Url) -> Url {
 /// * Dispatches `git fetch` using the git CLI.
 ///
 /// The `remote_url` argument is the git remote URL where we want to fetch from.
+///
+/// If `partial` is set, a blob-less fetch is attempted first, falling back to a full fetch on
+/// failure. Otherwise, any file contents omitted by an earlier partial fetch are fetched too.
 fn fetch(
     repo: &mut GitRepository,
     remote_url: &DisplaySafeUrl,
     reference: ReferenceOrOid<'_>,
     disable_ssl: bool,
     offline: bool,
+    partial: bool,
 ) -> Result<()> {
+    // A full fetch into a partial repository must request the omitted objects explicitly, since
+    // Git otherwise reuses the filter of the partial fetch.
+    let full = if repo.is_partial() {
+        FetchObjects::Refetch
+    } else {
+        FetchObjects::All
+    };
+    let objects = if partial { FetchObjects::Partial } else { full };
+
     let oid_to_fetch = if let ReferenceOrOid::Oid(rev) = reference {
         let local_object = reference.resolve(repo).ok();
         if let Some(local_object) = local_object {
-            if rev == local_object {
+            if rev == local_object && objects != FetchObjects::Refetch {
                 return Ok(());
             }
         }
@@ -763,14 +1285,69 @@ fn fetch(
     }
 
     debug!("Performing a Git fetch for: {remote_url}");
-    let result = match refspec_strategy {
+    let mut result = fetch_refspecs(
+        repo,
+        remote_url,
+        &refspecs,
+        refspec_strategy,
+        tags,
+        disable_ssl,
+        offline,
+        objects,
+    );
+    let mut fetched = objects;
+    if objects == FetchObjects::Partial && !offline {
+        if let Err(err) = &result {
+            debug!("Partial Git fetch failed, retrying with a full fetch: {err}");
+            fetched = full;
+            result = fetch_refspecs(
+                repo,
+                remote_url,
+                &refspecs,
+                refspec_strategy,
+                tags,
+                disable_ssl,
+                offline,
+                full,
+            );
+        }
+    }
+    if fetched == FetchObjects::Refetch && result.is_ok() {
+        result = repo.clear_partial_filter();
+    }
+    match reference {
+        // With the default branch, adding context is confusing
+        ReferenceOrOid::Reference(GitReference::DefaultBranch) => result,
+        _ => result.with_context(|| {
+            format!(
+                "failed to fetch {} `{}`",
+                reference.kind_str(),
+                reference.as_rev()
+            )
+        }),
+    }
+}
+
+/// Fetch the given `refspecs` according to the [`RefspecStrategy`].
+fn fetch_refspecs(
+    repo: &mut GitRepository,
+    remote_url: &DisplaySafeUrl,
+    refspecs: &[String],
+    refspec_strategy: RefspecStrategy,
+    tags: bool,
+    disable_ssl: bool,
+    offline: bool,
+    objects: FetchObjects,
+) -> Result<()> {
+    match refspec_strategy {
         RefspecStrategy::All => fetch_with_cli(
             repo,
             remote_url,
-            refspecs.as_slice(),
+            refspecs,
             tags,
             disable_ssl,
             offline,
+            objects,
         ),
         RefspecStrategy::First => {
             // Try each refspec
@@ -784,6 +1361,7 @@ fn fetch(
                         tags,
                         disable_ssl,
                         offline,
+                        objects,
                     );
 
                     // Stop after the first success and log failures
@@ -809,17 +1387,6 @@ fn fetch(
                 Ok(())
             }
         }
-    };
-    match reference {
-        // With the default branch, adding context is confusing
-        ReferenceOrOid::Reference(GitReference::DefaultBranch) => result,
-        _ => result.with_context(|| {
-            format!(
-                "failed to fetch {} `{}`",
-                reference.kind_str(),
-                reference.as_rev()
-            )
-        }),
     }
 }
 
@@ -831,6 +1398,7 @@ fn fetch_with_cli(
     tags: bool,
     disable_ssl: bool,
     offline: bool,
+    objects: FetchObjects,
 ) -> Result<()> {
     let mut cmd = GIT.as_ref().cloned()?;
     // Disable interactive prompts in the terminal, as they'll be erased by the progress bar
@@ -838,10 +1406,32 @@ fn fetch_with_cli(
     // are still usable.
     cmd.env(EnvVars::GIT_TERMINAL_PROMPT, "0");
 
+    // A partial fetch records the remote URL in the repository configuration, so that missing
+    // objects can be fetched on demand (and a refetch updates it). Fetch from the credential-free
+    // URL and pass any credentials via a command-local rewrite, to avoid persisting them.
+    let remote_url = if objects == FetchObjects::All {
+        std::borrow::Cow::Borrowed(&**url)
+    } else {
+        for config in submodule_auth_config(url) {
+            cmd.arg("-c").arg(config);
+        }
+        url.without_credentials()
+    };
+
     cmd.arg("fetch");
     if tags {
         cmd.arg("--tags");
     }
+    match objects {
+        FetchObjects::All => {}
+        FetchObjects::Partial => {
+            cmd.arg("--filter=blob:none");
+        }
+        FetchObjects::Refetch => {
+            debug!("Refetching objects omitted by a partial Git fetch");
+            cmd.arg("--refetch").arg("--no-filter");
+        }
+    }
     if disable_ssl {
         debug!("Disabling SSL verification for Git fetch via `GIT_SSL_NO_VERIFY`");
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
@@ -852,7 +1442,7 @@ fn fetch_with_cli(
     }
     cmd.arg("--force") // handle force pushes
         .arg("--update-head-ok") // see discussion in #2078
-        .arg(url.as_str())
+        .arg(remote_url.as_str())
         .args(refspecs)
         .cwd(&repo.path);
 
@@ -976,6 +1566,183 @@ mod tests {
         );
     }
 
+    /// Run `git` in the given directory, returning its standard output.
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args([
+                "-c",
+                "user.name=ferris",
+                "-c",
+                "user.email=ferris@example.com",
+            ])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    /// Generate an SSH key at the given path, returning the public key.
+    fn ssh_keygen(path: &Path) -> String {
+        let status = std::process::Command::new("ssh-keygen")
+            .args(["-q", "-t", "ed25519", "-N", "", "-C", "ferris", "-f"])
+            .arg(path)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        fs_err::read_to_string(path.with_extension("pub"))
+            .unwrap()
+            .trim()
+            .to_string()
+    }
+
+    /// Commit to the repository, signing the commit with the given SSH key, if any.
+    fn commit(repo: &Path, message: &str, key: Option<&Path>) -> GitOid {
+        match key {
+            Some(key) => git(
+                repo,
+                &[
+                    "-c",
+                    "gpg.format=ssh",
+                    "-c",
+                    &format!("user.signingkey={}", key.display()),
+                    "commit",
+                    "--allow-empty",
+                    "-S",
+                    "-m",
+                    message,
+                ],
+            ),
+            None => git(
+                repo,
+                &["commit", "--allow-empty", "--no-gpg-sign", "-m", message],
+            ),
+        };
+        git(repo, &["rev-parse", "HEAD"]).parse().unwrap()
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn verify_signature_ssh() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let trusted_key = temp_dir.path().join("trusted");
+        let trusted_public_key = ssh_keygen(&trusted_key);
+        let unknown_key = temp_dir.path().join("unknown");
+        ssh_keygen(&unknown_key);
+
+        let allowed_signers = temp_dir.path().join("allowed_signers");
+        fs_err::write(
+            &allowed_signers,
+            format!("ferris@example.com {trusted_public_key}\n"),
+        )
+        .unwrap();
+
+        let repo = temp_dir.path().join("repo");
+        fs_err::create_dir(&repo).unwrap();
+        git(&repo, &["init"]);
+        let unsigned = commit(&repo, "Unsigned", None);
+        let untrusted = commit(&repo, "Signed by an unknown key", Some(&unknown_key));
+        let trusted = commit(&repo, "Signed by a trusted key", Some(&trusted_key));
+
+        let url = DisplaySafeUrl::from_file_path(&repo).unwrap();
+        let (db, _) = GitRemote::new(url.clone())
+            .checkout(
+                &temp_dir.path().join("db"),
+                None,
+                &GitReference::DefaultBranch,
+                None,
+                false,
+                false,
+                false,
+                false,
+            )
+            .unwrap();
+
+        let err = db
+            .verify_signature(&url, unsigned, None, Some(&allowed_signers))
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<GitSignatureError>(),
+            Some(GitSignatureError::Unsigned { .. })
+        ));
+
+        let err = db
+            .verify_signature(&url, untrusted, None, Some(&allowed_signers))
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<GitSignatureError>(),
+            Some(GitSignatureError::Untrusted { .. })
+        ));
+
+        db.verify_signature(&url, trusted, None, Some(&allowed_signers))
+            .unwrap();
+    }
+
+    #[test]
+    fn parse_git_version_variants() {
+        assert_eq!(parse_git_version("git version 2.39.5\n"), Some((2, 39)));
+        assert_eq!(
+            parse_git_version("git version 2.45.1.windows.1"),
+            Some((2, 45))
+        );
+        assert_eq!(
+            parse_git_version("git version 2.39.3 (Apple Git-146)"),
+            Some((2, 39))
+        );
+        assert_eq!(parse_git_version("git version 2.40rc0"), Some((2, 40)));
+        assert_eq!(parse_git_version("not git"), None);
+    }
+
+    #[test]
+    fn sparse_directory_normalization() {
+        assert_eq!(
+            sparse_directory(Path::new("./pkgs/a/")),
+            Some("pkgs/a".to_string())
+        );
+        assert_eq!(sparse_directory(Path::new(".")), None);
+        assert_eq!(sparse_directory(Path::new("")), None);
+        assert_eq!(sparse_directory(Path::new("../a")), None);
+        assert_eq!(sparse_directory(Path::new("/a")), None);
+
+        assert!(sparse_directory_includes("pkgs", "pkgs/a"));
+        assert!(sparse_directory_includes("pkgs/a", "pkgs/a"));
+        assert!(!sparse_directory_includes("pkgs/a", "pkgs/ab"));
+        assert!(!sparse_directory_includes("pkgs/a", "pkgs"));
+    }
+
+    #[test]
+    fn sparse_checkout_patterns_round_trip() -> Result<()> {
+        let directories = BTreeSet::from(["pkgs/a".to_string(), "tools/[x]*".to_string()]);
+        let patterns = sparse_checkout_patterns(&directories);
+        insta::assert_snapshot!(patterns, @r"
+        # Managed by uv.
+        /*
+        !/*/
+        pyproject.toml
+        /pkgs/*
+        !/pkgs/*/
+        /tools/*
+        !/tools/*/
+        /pkgs/a/
+        /tools/\[x]\*/
+        ");
+
+        let temp_dir = tempfile::tempdir()?;
+        let repo = GitRepository::init(temp_dir.path())?;
+        assert_eq!(repo.sparse_directories(), None);
+        repo.set_sparse_directories(Some(&directories))?;
+        assert_eq!(repo.sparse_directories(), Some(directories));
+        repo.set_sparse_directories(None)?;
+        assert_eq!(repo.sparse_directories(), None);
+        Ok(())
+    }
+
     #[test]
     fn submodule_update_config_preserves_git_ssh_user() {
         let url = DisplaySafeUrl::parse("ssh://git@example.com/org/repo.git").unwrap();
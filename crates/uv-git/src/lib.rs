@@ -1,5 +1,5 @@
 pub use crate::credentials::{store_credentials, store_credentials_from_url};
-pub use crate::git::{GIT, GIT_LFS, GitError};
+pub use crate::git::{GIT, GIT_LFS, GitError, GitSignatureError};
 pub use crate::resolver::{
     GitHttpSettings, GitResolver, GitResolverError, RepositoryReference,
     ResolvedRepositoryReference,
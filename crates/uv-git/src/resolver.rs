@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -11,6 +11,7 @@ use tracing::debug;
 use uv_cache_key::{RepositoryUrl, cache_digest};
 use uv_fs::{LockedFile, LockedFileError, LockedFileMode};
 use uv_git_types::{GitHubRepository, GitOid, GitReference, GitUrl};
+use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 use uv_version::version;
 
@@ -36,10 +37,12 @@ pub enum GitResolverError {
 }
 
 /// HTTP settings for fetching a Git repository.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct GitHttpSettings {
     disable_ssl: bool,
     offline: bool,
+    mirror: Option<DisplaySafeUrl>,
+    allowed_signers: Option<PathBuf>,
 }
 
 impl GitHttpSettings {
@@ -56,6 +59,20 @@ impl GitHttpSettings {
         self.offline = offline;
         self
     }
+
+    /// Configure the mirror URL from which to fetch the repository, in lieu of its own URL.
+    #[must_use]
+    pub fn with_mirror(mut self, mirror: Option<DisplaySafeUrl>) -> Self {
+        self.mirror = mirror;
+        self
+    }
+
+    /// Configure the file of trusted SSH keys against which to verify signatures, if required.
+    #[must_use]
+    pub fn with_allowed_signers(mut self, allowed_signers: Option<PathBuf>) -> Self {
+        self.allowed_signers = allowed_signers;
+        self
+    }
 }
 
 /// A resolver for Git repositories.
@@ -104,12 +121,23 @@ impl GitResolver {
     pub async fn github_fast_path(
         &self,
         url: &GitUrl,
+        http_settings: &GitHttpSettings,
         client: &ClientWithMiddleware,
     ) -> Result<Option<GitOid>, GitResolverError> {
         if std::env::var_os(EnvVars::UV_NO_GITHUB_FAST_PATH).is_some() {
             return Ok(None);
         }
 
+        // If the repository is fetched from a mirror, GitHub may not be reachable.
+        if http_settings.mirror.is_some() {
+            return Ok(None);
+        }
+
+        // If the signature must be verified, the commit must be fetched.
+        if url.verify_signature() {
+            return Ok(None);
+        }
+
         // If the URL is already precise or we know the precise commit, return it.
         if let Some(precise) = self.get_precise(url) {
             return Ok(Some(precise));
@@ -177,9 +205,13 @@ impl GitResolver {
     }
 
     /// Fetch a remote Git repository.
+    ///
+    /// If a `subdirectory` is provided, only that subdirectory (and the files required to build
+    /// it) may be checked out.
     pub async fn fetch(
         &self,
         url: &GitUrl,
+        subdirectory: Option<&Path>,
         http_settings: GitHttpSettings,
         cache: PathBuf,
         reporter: Option<Arc<dyn Reporter>>,
@@ -228,6 +260,15 @@ impl GitResolver {
             source
         };
 
+        // Limit the checkout to the required subdirectory, if supported.
+        let source = source.with_subdirectory(subdirectory.map(Path::to_path_buf));
+
+        // Fetch from the mirror, if configured.
+        let source = source.with_mirror(http_settings.mirror);
+
+        // Verify signatures against the trusted keys, if configured.
+        let source = source.with_allowed_signers(http_settings.allowed_signers);
+
         let fetch = tokio::task::spawn_blocking(move || source.fetch())
             .await?
             .map_err(GitResolverError::Git)?;
@@ -14,7 +14,7 @@ use uv_git_types::{GitOid, GitReference, GitUrl};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::credentials::GIT_STORE;
-use crate::git::{GitDatabase, GitRemote};
+use crate::git::{GitDatabase, GitRemote, partial_clone_enabled, sparse_directory};
 
 /// A remote Git source that can be checked out locally.
 pub(crate) struct GitSource {
@@ -26,6 +26,12 @@ pub(crate) struct GitSource {
     offline: bool,
     /// The path to the Git source database.
     cache: PathBuf,
+    /// The subdirectory within the repository that's required, if not the full repository.
+    subdirectory: Option<PathBuf>,
+    /// The mirror URL to fetch the repository from, in lieu of its own URL.
+    mirror: Option<DisplaySafeUrl>,
+    /// The file of trusted SSH keys against which to verify signatures, if required.
+    allowed_signers: Option<PathBuf>,
     /// The reporter to use for this source.
     reporter: Option<Arc<dyn Reporter>>,
 }
@@ -38,6 +44,9 @@ impl GitSource {
             disable_ssl: false,
             offline,
             cache: cache.into(),
+            subdirectory: None,
+            mirror: None,
+            allowed_signers: None,
             reporter: None,
         }
     }
@@ -51,6 +60,30 @@ impl GitSource {
         }
     }
 
+    /// Limit the checkout to the given subdirectory of the repository, if supported.
+    #[must_use]
+    pub(crate) fn with_subdirectory(self, subdirectory: Option<PathBuf>) -> Self {
+        Self {
+            subdirectory,
+            ..self
+        }
+    }
+
+    /// Fetch the repository from the given mirror URL, in lieu of its own URL.
+    #[must_use]
+    pub(crate) fn with_mirror(self, mirror: Option<DisplaySafeUrl>) -> Self {
+        Self { mirror, ..self }
+    }
+
+    /// Verify SSH signatures against the given file of trusted keys, if required.
+    #[must_use]
+    pub(crate) fn with_allowed_signers(self, allowed_signers: Option<PathBuf>) -> Self {
+        Self {
+            allowed_signers,
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for the [`GitSource`].
     #[must_use]
     pub(crate) fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
@@ -65,12 +98,25 @@ impl GitSource {
     pub(crate) fn fetch(self) -> Result<Fetch> {
         let lfs_requested = self.git.lfs().enabled();
 
+        // If a subdirectory is requested, omit file contents from the fetch, and limit the
+        // checkout to the subdirectory, if supported. Git LFS requires the full tree.
+        let sparse = self
+            .subdirectory
+            .as_deref()
+            .filter(|_| !lfs_requested && partial_clone_enabled())
+            .and_then(sparse_directory);
+        let partial = sparse.is_some();
+
         // The path to the repo, within the Git database.
         let ident = cache_digest(self.git.repository());
         let db_path = self.cache.join("db").join(&ident);
 
-        // Authenticate the URL, if necessary.
-        let remote = if let Some(credentials) = GIT_STORE.get(self.git.repository()) {
+        // Authenticate the URL, if necessary. Credentials for the repository aren't applied to a
+        // mirror, which may be hosted elsewhere.
+        let remote = if let Some(mirror) = &self.mirror {
+            debug!("Fetching `{}` from mirror `{mirror}`", self.git.url());
+            Cow::Borrowed(mirror)
+        } else if let Some(credentials) = GIT_STORE.get(self.git.repository()) {
             Cow::Owned(credentials.apply(self.git.url().clone()))
         } else {
             Cow::Borrowed(self.git.url())
@@ -82,11 +128,18 @@ impl GitSource {
             let git_remote = GitRemote::new(remote.clone().into_owned());
             let maybe_db = git_remote.db_at(&db_path).ok();
 
+            // A full checkout from a partial database fetches the omitted file contents up-front,
+            // rather than on demand, such that the database can later be checked out offline.
+            let complete = |db: &GitDatabase| partial || self.offline || !db.is_partial();
+
             // If we have a locked revision, and we have a pre-existing database which has that
             // revision, then no update needs to happen.
             // When requested, we also check if LFS artifacts have been fetched and validated.
             if let (Some(rev), Some(db)) = (self.git.precise(), &maybe_db) {
-                if db.contains(rev) && (!lfs_requested || db.contains_lfs_artifacts(rev)) {
+                if db.contains(rev)
+                    && (!lfs_requested || db.contains_lfs_artifacts(rev))
+                    && complete(db)
+                {
                     debug!("Using existing Git source `{}`", self.git.url());
                     return Ok((
                         maybe_db
@@ -105,7 +158,10 @@ impl GitSource {
             if let Some(db) = &maybe_db {
                 if let GitReference::BranchOrTagOrCommit(maybe_commit) = self.git.reference() {
                     if let Ok(oid) = maybe_commit.parse::<GitOid>() {
-                        if db.contains(oid) && (!lfs_requested || db.contains_lfs_artifacts(oid)) {
+                        if db.contains(oid)
+                            && (!lfs_requested || db.contains_lfs_artifacts(oid))
+                            && complete(db)
+                        {
                             // This reference is an exact commit. Treat it like it's locked.
                             debug!("Using existing Git source `{}`", self.git.url());
                             return Ok((
@@ -138,6 +194,7 @@ impl GitSource {
                 self.disable_ssl,
                 self.offline,
                 lfs_requested,
+                partial,
             )?;
 
             Ok((db, actual_rev, task))
@@ -146,6 +203,38 @@ impl GitSource {
         // Validate the resolved commit before checking out its contents.
         let git = self.git.clone().with_precise(actual_rev)?;
 
+        // Verify the signature of the commit (or tag), if required.
+        if self.git.verify_signature() {
+            let tag = match self.git.reference() {
+                GitReference::Tag(tag) => Some(tag.as_str()),
+                _ => None,
+            };
+
+            // A locked commit is fetched without its tag, so fetch the tag separately.
+            if let Some(tag) = tag {
+                if !db.contains_tag(tag, actual_rev) {
+                    if let Err(err) = db.fetch_tag(
+                        self.git.reference(),
+                        self.disable_ssl,
+                        self.offline,
+                        partial,
+                    ) {
+                        debug!(
+                            "Failed to fetch tag `{tag}` for `{}`: {err}",
+                            self.git.url()
+                        );
+                    }
+                }
+            }
+
+            // Prefer the trusted keys of the source over the global setting.
+            let allowed_signers = self
+                .git
+                .allowed_signers()
+                .or(self.allowed_signers.as_deref());
+            db.verify_signature(self.git.url(), actual_rev, tag, allowed_signers)?;
+        }
+
         // Don’t use the full hash, in order to contribute less to reaching the
         // path length limit on Windows.
         let short_id = db.to_short_id(actual_rev)?;
@@ -168,7 +257,7 @@ impl GitSource {
         // Check out `actual_rev` from the database to a scoped location on the
         // filesystem. This will use hard links and such to ideally make the
         // checkout operation here pretty fast.
-        let checkout = db.copy_to(actual_rev, &checkout_path)?;
+        let checkout = db.copy_to(actual_rev, &checkout_path, sparse.as_deref(), self.offline)?;
 
         // Report the checkout operation to the reporter.
         if let Some(task) = maybe_task {
@@ -103,9 +103,15 @@ impl<'a> Installer<'a> {
             }
         }
 
+        let layout = venv.interpreter().layout();
+
+        // Record that the cached wheels were used, for least-recently-used cache eviction.
+        if let Some(cache) = cache {
+            record_archive_use(cache, &wheels, &layout, link_mode);
+        }
+
         let (tx, rx) = oneshot::channel();
 
-        let layout = venv.interpreter().layout();
         let relocatable = venv.relocatable();
         // Initialize the threadpool with the user settings.
         initialize_rayon_once();
@@ -141,9 +147,16 @@ impl<'a> Installer<'a> {
             }
         }
 
+        let layout = self.venv.interpreter().layout();
+
+        // Record that the cached wheels were used, for least-recently-used cache eviction.
+        if let Some(cache) = self.cache {
+            record_archive_use(cache, &wheels, &layout, self.link_mode);
+        }
+
         install(
             wheels,
-            &self.venv.interpreter().layout(),
+            &layout,
             self.name.as_deref(),
             self.link_mode,
             self.reporter.as_ref(),
@@ -156,6 +169,23 @@ impl<'a> Installer<'a> {
 
 /// Install a set of wheels into a Python virtual environment synchronously.
 #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
+/// Record that the cached wheels were used, and which environment links to them, for cache
+/// eviction.
+fn record_archive_use(cache: &Cache, wheels: &[CachedDist], layout: &Layout, link_mode: LinkMode) {
+    // Hardlinks into an environment are detected from the link count of the archive's files,
+    // except on Windows, where it's not available.
+    let record_link = link_mode.is_symlink() || (cfg!(windows) && link_mode == LinkMode::Hardlink);
+    for wheel in wheels {
+        cache.touch_archive(wheel.path());
+        if record_link {
+            cache.record_archive_link(wheel.path(), &layout.scheme.purelib);
+            if layout.scheme.platlib != layout.scheme.purelib {
+                cache.record_archive_link(wheel.path(), &layout.scheme.platlib);
+            }
+        }
+    }
+}
+
 fn install(
     wheels: Vec<CachedDist>,
     layout: &Layout,
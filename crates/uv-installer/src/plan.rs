@@ -324,7 +324,35 @@ impl<'a> Planner<'a> {
                 reinstalls.extend(installed_dists);
             } else {
                 match installed_dists.as_slice() {
-                    [] => {}
+                    [] => {
+                        // If the package isn't installed directly, it may be provided by a parent
+                        // environment. Only the first (i.e., importable) distribution is considered;
+                        // if it doesn't match, we install into the environment itself, shadowing
+                        // the parent.
+                        if let Some(installed) =
+                            site_packages.get_parent_packages(dist.name()).first()
+                        {
+                            let source = RequirementSource::from(dist);
+                            if matches!(
+                                RequirementSatisfaction::check(
+                                    dist.name(),
+                                    installed,
+                                    &source,
+                                    dist.version(),
+                                    installation,
+                                    tags,
+                                    config_settings,
+                                    config_settings_package,
+                                    extra_build_requires,
+                                    extra_build_variables,
+                                ),
+                                RequirementSatisfaction::Satisfied
+                            ) {
+                                debug!("Requirement provided by parent environment: {installed}");
+                                continue;
+                            }
+                        }
+                    }
                     [installed] => {
                         let source = RequirementSource::from(dist);
                         match RequirementSatisfaction::check(
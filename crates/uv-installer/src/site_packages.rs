@@ -43,6 +43,9 @@ pub struct SitePackages {
     by_name: FxHashMap<PackageName, Vec<usize>>,
     /// The installed editable distributions, keyed by URL.
     by_url: FxHashMap<DisplaySafeUrl, Vec<usize>>,
+    /// The distributions provided by read-only parent environments, keyed by name. The first
+    /// distribution for a given name takes precedence at runtime.
+    parents: FxHashMap<PackageName, Vec<InstalledDist>>,
 }
 
 impl SitePackages {
@@ -75,57 +78,8 @@ impl SitePackages {
         let mut by_url: FxHashMap<DisplaySafeUrl, Vec<usize>> = FxHashMap::default();
 
         for site_packages in interpreter.site_packages() {
-            // Read the site-packages directory.
-            let site_packages = match fs::read_dir(site_packages.as_ref()) {
-                Ok(read_dir) => sorted_dist_like_paths(read_dir).with_context(|| {
-                    format!(
-                        "Failed to read site-packages directory contents: {}",
-                        site_packages.user_display()
-                    )
-                })?,
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                    continue;
-                }
-                Err(err) => return Err(err).context("Failed to read site-packages directory"),
-            };
-
             // Index all installed packages by name.
-            for path in site_packages {
-                if let Some(package_names) = package_names
-                    && let Some(package_name) = installed_dist_name(&path)
-                    && !package_names.contains(&package_name)
-                {
-                    continue;
-                }
-
-                let dist_info = match InstalledDist::try_from_path(&path) {
-                    Ok(Some(dist_info)) => dist_info,
-                    Ok(None) => continue,
-                    Err(_)
-                        if path.file_name().is_some_and(|name| {
-                            name.to_str().is_some_and(|name| name.starts_with('~'))
-                        }) =>
-                    {
-                        warn_user!(
-                            "Ignoring dangling temporary directory: `{}`",
-                            path.simplified_display().cyan()
-                        );
-                        continue;
-                    }
-                    Err(err) => {
-                        return Err(err).context(format!(
-                            "Failed to read metadata from: `{}`",
-                            path.simplified_display()
-                        ));
-                    }
-                };
-
-                if let Some(package_names) = package_names
-                    && !package_names.contains(dist_info.name())
-                {
-                    continue;
-                }
-
+            for dist_info in read_site_packages(site_packages.as_ref(), package_names)? {
                 let idx = distributions.len();
 
                 // Index the distribution by name.
@@ -144,11 +98,24 @@ impl SitePackages {
             }
         }
 
+        // Index the packages provided by any parent environments. These are tracked separately,
+        // as they're read-only: they can satisfy requirements, but are never removed.
+        let mut parents: FxHashMap<PackageName, Vec<InstalledDist>> = FxHashMap::default();
+        for site_packages in interpreter.parent_site_packages() {
+            for dist_info in read_site_packages(&site_packages, package_names)? {
+                parents
+                    .entry(dist_info.name().clone())
+                    .or_default()
+                    .push(dist_info);
+            }
+        }
+
         Ok(Self {
             interpreter: interpreter.clone(),
             distributions,
             by_name,
             by_url,
+            parents,
         })
     }
 
@@ -173,6 +140,27 @@ impl SitePackages {
             .collect()
     }
 
+    /// Returns the distributions provided by parent environments for a given package.
+    ///
+    /// Only the first distribution is importable at runtime, as earlier parents shadow later ones.
+    pub fn get_parent_packages(&self, name: &PackageName) -> &[InstalledDist] {
+        self.parents
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the installed distributions for a given package, falling back to the distribution
+    /// provided by a parent environment if the package is not installed directly.
+    fn get_packages_or_parent(&self, name: &PackageName) -> Vec<&InstalledDist> {
+        let installed = self.get_packages(name);
+        if installed.is_empty() {
+            self.get_parent_packages(name).iter().take(1).collect()
+        } else {
+            installed
+        }
+    }
+
     /// Remove the given packages from the index, returning all installed versions, if any.
     pub(crate) fn remove_packages(&mut self, name: &PackageName) -> Vec<InstalledDist> {
         let Some(indexes) = self.by_name.get(name) else {
@@ -286,7 +274,7 @@ impl SitePackages {
                         continue;
                     }
 
-                    let installed = self.get_packages(&dependency.name);
+                    let installed = self.get_packages_or_parent(&dependency.name);
                     match installed.as_slice() {
                         [] => {
                             // No version installed.
@@ -493,7 +481,7 @@ impl SitePackages {
         // Verify that all non-editable requirements are met.
         while let Some(requirement) = stack.pop() {
             let name = &requirement.name;
-            let installed = self.get_packages(name);
+            let installed = self.get_packages_or_parent(name);
             match installed.as_slice() {
                 [] => {
                     // The package isn't installed.
@@ -623,6 +611,71 @@ pub enum SatisfiesResult {
     Unsatisfied(String),
 }
 
+/// Read the installed distributions from a `site-packages` directory, optionally limited to the
+/// given package names.
+///
+/// Returns an empty list if the directory does not exist.
+fn read_site_packages(
+    site_packages: &Path,
+    package_names: Option<&FxHashSet<&PackageName>>,
+) -> Result<Vec<InstalledDist>> {
+    // Read the site-packages directory.
+    let paths = match fs::read_dir(site_packages) {
+        Ok(read_dir) => sorted_dist_like_paths(read_dir).with_context(|| {
+            format!(
+                "Failed to read site-packages directory contents: {}",
+                site_packages.user_display()
+            )
+        })?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(Vec::new());
+        }
+        Err(err) => return Err(err).context("Failed to read site-packages directory"),
+    };
+
+    let mut distributions = Vec::new();
+    for path in paths {
+        if let Some(package_names) = package_names
+            && let Some(package_name) = installed_dist_name(&path)
+            && !package_names.contains(&package_name)
+        {
+            continue;
+        }
+
+        let dist_info = match InstalledDist::try_from_path(&path) {
+            Ok(Some(dist_info)) => dist_info,
+            Ok(None) => continue,
+            Err(_)
+                if path.file_name().is_some_and(|name| {
+                    name.to_str().is_some_and(|name| name.starts_with('~'))
+                }) =>
+            {
+                warn_user!(
+                    "Ignoring dangling temporary directory: `{}`",
+                    path.simplified_display().cyan()
+                );
+                continue;
+            }
+            Err(err) => {
+                return Err(err).context(format!(
+                    "Failed to read metadata from: `{}`",
+                    path.simplified_display()
+                ));
+            }
+        };
+
+        if let Some(package_names) = package_names
+            && !package_names.contains(dist_info.name())
+        {
+            continue;
+        }
+
+        distributions.push(dist_info);
+    }
+
+    Ok(distributions)
+}
+
 /// Infer the package name from an installed distribution path without reading its metadata.
 ///
 /// Returns `None` when the name cannot safely be derived from the filename alone.
@@ -226,7 +226,7 @@ pub mod test {
     clippy::use_self,
     reason = "enumflags2 refers to the enum by name when inferring bits"
 )]
-#[repr(u64)]
+#[repr(u128)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PreviewMetadata)]
 pub enum PreviewFeature {
     /// Allows [installing `python` and `python3` executables](./python-versions.md#installing-python-executables).
@@ -335,6 +335,55 @@ pub enum PreviewFeature {
     IndexByName,
     /// Restricts generated requirement hashes to artifacts allowed by binary and build policies.
     ArtifactHashFiltering,
+    /// Allows layering virtual environments on top of read-only parent environments with
+    /// `uv venv --parent`.
+    LayeredEnvironments,
+    /// Allows declaring environment variables for the project environment in
+    /// `[tool.uv.environment]`.
+    ProjectEnvironmentVariables,
+    /// Allows declaring named tasks in `[tool.uv.scripts]` to run with `uv run <task>`.
+    ProjectTasks,
+    /// Allows running a command in each workspace member with `uv workspace exec`.
+    WorkspaceExec,
+    /// Allows installing tools into environments shared with other tools with
+    /// `uv tool install --shared`.
+    ToolSharedEnvironments,
+    /// Allows requiring verified PEP 740 provenance for registry distributions with
+    /// `require-provenance`.
+    ProvenanceVerification,
+    /// Allows generating PEP 740 attestations during `uv publish` with `--attest`.
+    PublishAttestations,
+    /// Allows checking distributions for problems with `uv publish --check` and
+    /// `uv build --check`.
+    DistributionChecks,
+    /// Allows publishing to multiple indexes with `uv publish --index`.
+    MultiIndexPublish,
+    /// Allows publishing to a local index directory with `uv publish --to-directory`.
+    DirectoryPublish,
+    /// Allows building with multiple Python interpreters with `uv build --python`.
+    BuildMatrix,
+    /// Allows verifying that builds are reproducible with `uv build --verify-reproducible`.
+    ReproducibleBuilds,
+    /// Allows including native artifacts in wheels built with the uv build backend.
+    NativeArtifacts,
+    /// Allows deriving the project version from Git tags in the uv build backend.
+    DynamicVersion,
+    /// Allows repairing Linux wheels with `uv build --repair`.
+    WheelRepair,
+    /// Allows evicting cache entries by size and age with `uv cache prune --max-size` and `--max-age`.
+    CacheEviction,
+    /// Allows sharing wheels built from source between machines with `--shared-cache`.
+    SharedCache,
+    /// Allows moving cache entries between machines with `uv cache export` and `uv cache import`.
+    CacheExport,
+    /// Allows checking the cache for corrupt entries with `uv cache verify`.
+    CacheVerify,
+    /// Allows reporting cache disk usage by bucket and by package with `uv cache stats`.
+    CacheStats,
+    /// Allows fetching Git dependencies from mirrors with `[[tool.uv.git-mirror]]`.
+    GitMirror,
+    /// Allows serving a directory of distributions as a package index with `uv index serve`.
+    IndexServe,
 }
 
 impl Display for PreviewFeature {
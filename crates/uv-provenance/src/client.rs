@@ -0,0 +1,157 @@
+use std::sync::LazyLock;
+
+use tracing::debug;
+use uv_cache::{Cache, CacheBucket, CacheEntry};
+use uv_cache_key::cache_digest;
+use uv_client::{CacheControl, CachedClient, CachedClientError};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
+
+use crate::Provenance;
+
+/// The media type of the integrity API, per PEP 740.
+const INTEGRITY_MEDIA_TYPE: &str = "application/vnd.pypi.integrity.v1+json";
+
+/// Provenance objects are immutable once published, so they can be cached indefinitely.
+static PROVENANCE_CACHE_CONTROL: LazyLock<http::HeaderValue> = LazyLock::new(|| {
+    "max-age=31536000, immutable"
+        .parse()
+        .expect("valid header value")
+});
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to construct the integrity API URL for index: {0}")]
+    Url(DisplaySafeUrl, #[source] Option<DisplaySafeUrlError>),
+    #[error("Failed to fetch provenance from: {0}")]
+    Fetch(DisplaySafeUrl, #[source] uv_client::Error),
+    #[error("Received a malformed provenance object from: {0}")]
+    Malformed(DisplaySafeUrl, #[source] reqwest_middleware::Error),
+}
+
+/// A client for fetching PEP 740 provenance objects from a package index's integrity API.
+pub struct ProvenanceClient<'a> {
+    client: &'a CachedClient,
+    cache: &'a Cache,
+}
+
+impl<'a> ProvenanceClient<'a> {
+    /// Create a new [`ProvenanceClient`].
+    pub fn new(client: &'a CachedClient, cache: &'a Cache) -> Self {
+        Self { client, cache }
+    }
+
+    /// Fetch the provenance object for a distribution file from the given index.
+    pub async fn fetch(
+        &self,
+        index: &DisplaySafeUrl,
+        name: &PackageName,
+        version: &Version,
+        filename: &str,
+    ) -> Result<Provenance, Error> {
+        let url = integrity_url(index, name, version, filename)?;
+        debug!("Fetching provenance for `{filename}` from: {url}");
+
+        let cache_entry = CacheEntry::new(
+            self.cache
+                .bucket(CacheBucket::Provenance)
+                .join(cache_digest(&index.to_string()))
+                .join(name.as_ref()),
+            format!("{filename}.msgpack"),
+        );
+        let request = self
+            .client
+            .uncached()
+            .for_host(&url)
+            .raw_client()
+            .get(url.as_ref())
+            .header(http::header::ACCEPT, INTEGRITY_MEDIA_TYPE)
+            .build()
+            .map_err(|err| Error::Malformed(url.clone(), err.into()))?;
+
+        self.client
+            .get_serde_with_retry(
+                request,
+                &cache_entry,
+                CacheControl::Override(PROVENANCE_CACHE_CONTROL.clone()),
+                async |response| response.error_for_status()?.json::<Provenance>().await,
+            )
+            .await
+            .map_err(|err| match err {
+                CachedClientError::Client(err) => Error::Fetch(url.clone(), err),
+                CachedClientError::Callback { err, .. } => {
+                    Error::Malformed(url.clone(), reqwest_middleware::Error::Reqwest(err))
+                }
+            })
+    }
+}
+
+/// Return the integrity API URL for the provenance of a distribution file.
+///
+/// The integrity API is rooted alongside the index's simple API, e.g., the provenance for a file
+/// on `https://pypi.org/simple` is served from
+/// `https://pypi.org/integrity/<project>/<version>/<filename>/provenance`.
+pub fn integrity_url(
+    index: &DisplaySafeUrl,
+    name: &PackageName,
+    version: &Version,
+    filename: &str,
+) -> Result<DisplaySafeUrl, Error> {
+    let mut url = index.clone();
+    {
+        let mut segments = url
+            .path_segments_mut()
+            .map_err(|()| Error::Url(index.clone(), None))?;
+        segments.pop_if_empty();
+        if index
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+            == Some("simple")
+        {
+            segments.pop();
+        }
+        segments.extend([
+            "integrity",
+            name.as_ref(),
+            &version.to_string(),
+            filename,
+            "provenance",
+        ]);
+    }
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn url(index: &str) -> String {
+        integrity_url(
+            &DisplaySafeUrl::parse(index).unwrap(),
+            &PackageName::from_str("sampleproject").unwrap(),
+            &Version::from_str("4.0.0").unwrap(),
+            "sampleproject-4.0.0-py3-none-any.whl",
+        )
+        .unwrap()
+        .to_string()
+    }
+
+    #[test]
+    fn integrity_urls() {
+        assert_eq!(
+            url("https://pypi.org/simple"),
+            "https://pypi.org/integrity/sampleproject/4.0.0/sampleproject-4.0.0-py3-none-any.whl/provenance"
+        );
+        assert_eq!(
+            url("https://pypi.org/simple/"),
+            "https://pypi.org/integrity/sampleproject/4.0.0/sampleproject-4.0.0-py3-none-any.whl/provenance"
+        );
+        assert_eq!(
+            url("https://example.com/pypi/"),
+            "https://example.com/pypi/integrity/sampleproject/4.0.0/sampleproject-4.0.0-py3-none-any.whl/provenance"
+        );
+    }
+}
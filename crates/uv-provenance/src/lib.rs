@@ -0,0 +1,27 @@
+//! `uv-provenance` verifies [PEP 740] provenance for distributions served by a package index.
+//!
+//! A provenance object contains one or more attestations, each of which is an in-toto statement
+//! about a distribution file, signed with a short-lived certificate issued by Sigstore's Fulcio
+//! CA to a Trusted Publisher, and recorded in Sigstore's Rekor transparency log. Verification is
+//! performed offline, against a bundled Sigstore trust root.
+//!
+//! Attestations for a distribution file can also be produced, by signing with an ephemeral key
+//! that Sigstore certifies for the OIDC identity of a CI workflow.
+//!
+//! [PEP 740]: https://peps.python.org/pep-0740/
+
+pub use client::{Error, ProvenanceClient, integrity_url};
+pub use provenance::{
+    Attestation, AttestationBundle, Envelope, Provenance, Publisher, TransparencyLogEntry,
+    VerificationMaterial,
+};
+pub use sign::{Signer, SigningConfig, SigningError, SigstoreSigner, attest_publish};
+pub use trust_root::{TrustRoot, TrustRootError};
+pub use verify::VerificationError;
+
+mod client;
+mod provenance;
+mod sign;
+mod tlog;
+mod trust_root;
+mod verify;
@@ -0,0 +1,177 @@
+//! Types for the provenance objects served by a package index's integrity API.
+//!
+//! See: <https://peps.python.org/pep-0740/#provenance-objects>
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A PEP 740 provenance object, attesting to a single distribution file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provenance {
+    /// The version of the provenance object format. Only version 1 is defined.
+    pub version: u8,
+    /// The attestations for the file, grouped by the publisher that produced them.
+    pub attestation_bundles: Vec<AttestationBundle>,
+}
+
+/// A set of attestations produced by a single Trusted Publisher.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttestationBundle {
+    /// The Trusted Publisher that produced the attestations.
+    pub publisher: Publisher,
+    /// The attestations produced by the publisher.
+    pub attestations: Vec<Attestation>,
+}
+
+/// The identity of a Trusted Publisher.
+///
+/// The claims depend on the kind of publisher, e.g., GitHub publishers are identified by a
+/// repository and a workflow, while Google publishers are identified by an email address.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Publisher {
+    /// The kind of publisher, e.g., `GitHub` or `GitLab`.
+    pub kind: String,
+    /// The repository slug, e.g., `owner/repo`, for GitHub and GitLab publishers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// The workflow filename, e.g., `release.yml`, for GitHub publishers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<String>,
+    /// The path to the CI configuration, e.g., `.gitlab-ci.yml`, for GitLab publishers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_filepath: Option<String>,
+    /// The deployment environment, if the publisher is restricted to one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    /// The service account email, for Google publishers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+impl Display for Publisher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.repository, &self.email) {
+            (Some(repository), _) => write!(f, "{} repository `{repository}`", self.kind),
+            (None, Some(email)) => write!(f, "{} identity `{email}`", self.kind),
+            (None, None) => write!(f, "{} publisher", self.kind),
+        }
+    }
+}
+
+/// A single PEP 740 attestation: a signed in-toto statement about a distribution file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attestation {
+    /// The version of the attestation format. Only version 1 is defined.
+    pub version: u8,
+    /// The material needed to verify the attestation's signature.
+    pub verification_material: VerificationMaterial,
+    /// The signed statement.
+    pub envelope: Envelope,
+}
+
+/// The material needed to verify an [`Attestation`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationMaterial {
+    /// The base64-encoded DER signing certificate, issued by Sigstore's Fulcio CA.
+    pub certificate: String,
+    /// The transparency log entries recording the signature.
+    pub transparency_entries: Vec<TransparencyLogEntry>,
+}
+
+/// A DSSE envelope, with the payload type fixed to `application/vnd.in-toto+json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Envelope {
+    /// The base64-encoded in-toto statement.
+    pub statement: String,
+    /// The base64-encoded signature over the statement.
+    pub signature: String,
+}
+
+/// A Rekor transparency log entry, in the JSON encoding of Sigstore's `TransparencyLogEntry`
+/// message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransparencyLogEntry {
+    /// The global index of the entry in the log.
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub log_index: i64,
+    /// The ID of the log that recorded the entry.
+    pub log_id: LogId,
+    /// The kind and version of the entry body.
+    pub kind_version: KindVersion,
+    /// The time at which the entry was integrated into the log, as a Unix timestamp.
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub integrated_time: i64,
+    /// The log's signed promise to include the entry.
+    pub inclusion_promise: Option<InclusionPromise>,
+    /// The proof that the entry is included in the log.
+    pub inclusion_proof: Option<InclusionProof>,
+    /// The base64-encoded canonicalized entry body.
+    pub canonicalized_body: String,
+}
+
+/// The ID of a transparency log, i.e., the SHA-256 digest of its public key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogId {
+    /// The base64-encoded key ID.
+    pub key_id: String,
+}
+
+/// The kind and version of a transparency log entry body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KindVersion {
+    pub kind: String,
+    pub version: String,
+}
+
+/// A signed entry timestamp, committing the log to including an entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionPromise {
+    /// The base64-encoded signature over the entry.
+    pub signed_entry_timestamp: String,
+}
+
+/// A Merkle tree inclusion proof for a transparency log entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionProof {
+    /// The index of the entry in the log shard.
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub log_index: i64,
+    /// The base64-encoded root hash of the tree.
+    pub root_hash: String,
+    /// The size of the tree.
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub tree_size: i64,
+    /// The base64-encoded hashes along the path from the entry to the root.
+    pub hashes: Vec<String>,
+    /// The signed checkpoint committing the log to the root hash.
+    pub checkpoint: Checkpoint,
+}
+
+/// A signed note committing a transparency log to a tree head.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub envelope: String,
+}
+
+/// Deserialize a 64-bit integer, which Sigstore's JSON encoding represents as a string.
+fn deserialize_int64<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Int64 {
+        Number(i64),
+        String(String),
+    }
+
+    match Int64::deserialize(deserializer)? {
+        Int64::Number(value) => Ok(value),
+        Int64::String(value) => value.parse().map_err(serde::de::Error::custom),
+    }
+}
@@ -0,0 +1,509 @@
+//! Keyless signing of PEP 740 attestations with Sigstore.
+//!
+//! A [`SigstoreSigner`] generates an ephemeral key pair and exchanges an OIDC identity token
+//! (e.g., from GitHub Actions) for a short-lived certificate from Sigstore's Fulcio CA that binds
+//! the public key to the token's identity. Each signature is then recorded in Sigstore's Rekor
+//! transparency log, which attests that the signature was made while the certificate was valid.
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use aws_lc_rs::encoding::AsDer;
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair};
+use base64::Engine;
+use base64::prelude::{BASE64_STANDARD, BASE64_URL_SAFE_NO_PAD};
+use reqwest_middleware::ClientWithMiddleware;
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+use x509_parser::pem::parse_x509_pem;
+
+use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
+
+use crate::provenance::{
+    Attestation, Checkpoint, Envelope, InclusionPromise, InclusionProof, KindVersion, LogId,
+    TransparencyLogEntry, VerificationMaterial,
+};
+use crate::verify::{IN_TOTO_PAYLOAD_TYPE, IN_TOTO_STATEMENT_TYPE, PUBLISH_PREDICATE_TYPE, pae};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SigningError {
+    #[error(transparent)]
+    Url(#[from] DisplaySafeUrlError),
+    #[error("The OIDC identity token is malformed")]
+    MalformedIdentityToken,
+    #[error("Failed to generate an ephemeral signing key")]
+    KeyGeneration,
+    #[error("Failed to sign with the ephemeral signing key")]
+    Signature,
+    #[error("Failed to request a signing certificate from: {0}")]
+    Fulcio(DisplaySafeUrl, #[source] reqwest_middleware::Error),
+    #[error("Received a malformed certificate chain from: {0}")]
+    MalformedCertificateChain(DisplaySafeUrl),
+    #[error("Failed to record the signature in the transparency log at: {0}")]
+    Rekor(DisplaySafeUrl, #[source] reqwest_middleware::Error),
+    #[error("Received a malformed transparency log entry from: {0}")]
+    MalformedLogEntry(DisplaySafeUrl),
+}
+
+/// The endpoints of a Sigstore instance that issue certificates and record signatures.
+#[derive(Debug, Clone)]
+pub struct SigningConfig {
+    /// The base URL of the Fulcio certificate authority.
+    pub fulcio_url: DisplaySafeUrl,
+    /// The base URL of the Rekor transparency log.
+    pub rekor_url: DisplaySafeUrl,
+}
+
+impl SigningConfig {
+    /// The public-good Sigstore instance, i.e., the instance that PyPI trusts.
+    pub fn public_good() -> Self {
+        Self {
+            fulcio_url: DisplaySafeUrl::parse("https://fulcio.sigstore.dev").expect("valid URL"),
+            rekor_url: DisplaySafeUrl::parse("https://rekor.sigstore.dev").expect("valid URL"),
+        }
+    }
+}
+
+/// A signer of in-toto statements, producing PEP 740 attestations.
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// Sign an in-toto statement.
+    async fn sign(&self, statement: &[u8]) -> Result<Attestation, SigningError>;
+}
+
+/// Create a PEP 740 publish attestation for a distribution file.
+pub async fn attest_publish(
+    signer: &dyn Signer,
+    filename: &str,
+    sha256: &str,
+) -> Result<Attestation, SigningError> {
+    let statement = json!({
+        "_type": IN_TOTO_STATEMENT_TYPE,
+        "subject": [{"name": filename, "digest": {"sha256": sha256}}],
+        "predicateType": PUBLISH_PREDICATE_TYPE,
+        "predicate": {},
+    })
+    .to_string();
+    signer.sign(statement.as_bytes()).await
+}
+
+/// A [`Signer`] that uses an ephemeral key certified by Sigstore for an OIDC identity.
+pub struct SigstoreSigner {
+    client: ClientWithMiddleware,
+    rekor_url: DisplaySafeUrl,
+    key: EcdsaKeyPair,
+    /// The PEM-encoded signing certificate.
+    certificate_pem: String,
+    /// The DER-encoded signing certificate.
+    certificate_der: Vec<u8>,
+}
+
+impl SigstoreSigner {
+    /// Generate an ephemeral key pair and request a signing certificate for it from Fulcio,
+    /// authenticating with the given OIDC identity token.
+    pub async fn new(
+        client: ClientWithMiddleware,
+        config: &SigningConfig,
+        identity_token: &str,
+    ) -> Result<Self, SigningError> {
+        let key = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING)
+            .map_err(|_| SigningError::KeyGeneration)?;
+        let public_key = key
+            .public_key()
+            .as_der()
+            .map_err(|_| SigningError::KeyGeneration)?;
+
+        // Fulcio requires proof that we hold the private key, in the form of a signature over the
+        // subject of the identity token.
+        let subject = token_subject(identity_token)?;
+        let proof_of_possession = key
+            .sign(&SystemRandom::new(), subject.as_bytes())
+            .map_err(|_| SigningError::Signature)?;
+
+        let url = config.fulcio_url.join("api/v2/signingCert")?;
+        debug!("Requesting a signing certificate from: {url}");
+        let request = json!({
+            "credentials": {"oidcIdentityToken": identity_token},
+            "publicKeyRequest": {
+                "publicKey": {
+                    "algorithm": "ECDSA",
+                    "content": pem("PUBLIC KEY", public_key.as_ref()),
+                },
+                "proofOfPossession": BASE64_STANDARD.encode(proof_of_possession),
+            },
+        });
+        let response = client
+            .post(url.as_str())
+            .json(&request)
+            .send()
+            .await
+            .map_err(|err| SigningError::Fulcio(url.clone(), err))?
+            .error_for_status()
+            .map_err(|err| SigningError::Fulcio(url.clone(), err.into()))?
+            .json::<SigningCertificateResponse>()
+            .await
+            .map_err(|err| SigningError::Fulcio(url.clone(), err.into()))?;
+
+        // The first certificate in the chain is the signing certificate.
+        let certificate_pem = response
+            .signed_certificate_embedded_sct
+            .or(response.signed_certificate_detached_sct)
+            .and_then(|certificate| certificate.chain.certificates.into_iter().next())
+            .ok_or_else(|| SigningError::MalformedCertificateChain(url.clone()))?;
+        let (_, certificate) = parse_x509_pem(certificate_pem.as_bytes())
+            .map_err(|_| SigningError::MalformedCertificateChain(url.clone()))?;
+
+        Ok(Self {
+            client,
+            rekor_url: config.rekor_url.clone(),
+            key,
+            certificate_pem,
+            certificate_der: certificate.contents,
+        })
+    }
+
+    /// Record a signature over an in-toto statement in the transparency log.
+    async fn record(
+        &self,
+        statement: &[u8],
+        signature: &[u8],
+    ) -> Result<TransparencyLogEntry, SigningError> {
+        let url = self.rekor_url.join("api/v1/log/entries")?;
+        debug!("Recording signature in the transparency log at: {url}");
+        let envelope = json!({
+            "payloadType": IN_TOTO_PAYLOAD_TYPE,
+            "payload": BASE64_STANDARD.encode(statement),
+            "signatures": [{"sig": BASE64_STANDARD.encode(signature)}],
+        });
+        let request = json!({
+            "apiVersion": "0.0.1",
+            "kind": "dsse",
+            "spec": {
+                "proposedContent": {
+                    "envelope": envelope.to_string(),
+                    "verifiers": [BASE64_STANDARD.encode(&self.certificate_pem)],
+                },
+            },
+        });
+        let response = self
+            .client
+            .post(url.as_str())
+            .json(&request)
+            .send()
+            .await
+            .map_err(|err| SigningError::Rekor(url.clone(), err))?
+            .error_for_status()
+            .map_err(|err| SigningError::Rekor(url.clone(), err.into()))?
+            .json::<BTreeMap<String, LogEntry>>()
+            .await
+            .map_err(|err| SigningError::Rekor(url.clone(), err.into()))?;
+
+        // The response maps the UUID of the new entry to the entry.
+        let entry = response
+            .into_values()
+            .next()
+            .ok_or_else(|| SigningError::MalformedLogEntry(url.clone()))?;
+        entry
+            .into_transparency_log_entry()
+            .ok_or(SigningError::MalformedLogEntry(url))
+    }
+}
+
+#[async_trait]
+impl Signer for SigstoreSigner {
+    async fn sign(&self, statement: &[u8]) -> Result<Attestation, SigningError> {
+        let signature = self
+            .key
+            .sign(&SystemRandom::new(), &pae(IN_TOTO_PAYLOAD_TYPE, statement))
+            .map_err(|_| SigningError::Signature)?;
+        let entry = self.record(statement, signature.as_ref()).await?;
+        Ok(Attestation {
+            version: 1,
+            verification_material: VerificationMaterial {
+                certificate: BASE64_STANDARD.encode(&self.certificate_der),
+                transparency_entries: vec![entry],
+            },
+            envelope: Envelope {
+                statement: BASE64_STANDARD.encode(statement),
+                signature: BASE64_STANDARD.encode(signature),
+            },
+        })
+    }
+}
+
+/// Extract the `sub` claim from an OIDC identity token, without verifying it.
+fn token_subject(identity_token: &str) -> Result<String, SigningError> {
+    #[derive(Deserialize)]
+    struct Claims {
+        sub: String,
+    }
+
+    let payload = identity_token
+        .split('.')
+        .nth(1)
+        .ok_or(SigningError::MalformedIdentityToken)?;
+    let payload = BASE64_URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|_| SigningError::MalformedIdentityToken)?;
+    let claims: Claims =
+        serde_json::from_slice(&payload).map_err(|_| SigningError::MalformedIdentityToken)?;
+    Ok(claims.sub)
+}
+
+/// Encode DER data as PEM, with the given label.
+fn pem(label: &str, der: &[u8]) -> String {
+    let encoded = BASE64_STANDARD.encode(der);
+    let lines = encoded
+        .as_bytes()
+        .chunks(64)
+        .map(|line| std::str::from_utf8(line).expect("base64 is ASCII"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("-----BEGIN {label}-----\n{lines}\n-----END {label}-----\n")
+}
+
+/// The response from Fulcio's `signingCert` endpoint.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SigningCertificateResponse {
+    signed_certificate_embedded_sct: Option<SignedCertificate>,
+    signed_certificate_detached_sct: Option<SignedCertificate>,
+}
+
+#[derive(Deserialize)]
+struct SignedCertificate {
+    chain: CertificateChain,
+}
+
+#[derive(Deserialize)]
+struct CertificateChain {
+    /// The PEM-encoded certificates, starting with the signing certificate.
+    certificates: Vec<String>,
+}
+
+/// A log entry, as returned by Rekor's `log/entries` endpoint.
+///
+/// Unlike the [`TransparencyLogEntry`] in an attestation, hashes and log IDs are hex-encoded.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogEntry {
+    body: String,
+    integrated_time: i64,
+    #[serde(rename = "logID")]
+    log_id: String,
+    log_index: i64,
+    verification: LogEntryVerification,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogEntryVerification {
+    inclusion_proof: Option<LogEntryInclusionProof>,
+    signed_entry_timestamp: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogEntryInclusionProof {
+    checkpoint: String,
+    hashes: Vec<String>,
+    log_index: i64,
+    root_hash: String,
+    tree_size: i64,
+}
+
+impl LogEntry {
+    /// Convert the entry to the encoding used in attestations.
+    fn into_transparency_log_entry(self) -> Option<TransparencyLogEntry> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Body {
+            api_version: String,
+            kind: String,
+        }
+
+        let body: Body = serde_json::from_slice(&BASE64_STANDARD.decode(&self.body).ok()?).ok()?;
+        let hex_to_base64 = |value: &str| Some(BASE64_STANDARD.encode(hex::decode(value).ok()?));
+        let inclusion_proof = match self.verification.inclusion_proof {
+            Some(proof) => Some(InclusionProof {
+                log_index: proof.log_index,
+                root_hash: hex_to_base64(&proof.root_hash)?,
+                tree_size: proof.tree_size,
+                hashes: proof
+                    .hashes
+                    .iter()
+                    .map(|hash| hex_to_base64(hash))
+                    .collect::<Option<_>>()?,
+                checkpoint: Checkpoint {
+                    envelope: proof.checkpoint,
+                },
+            }),
+            None => None,
+        };
+        Some(TransparencyLogEntry {
+            log_index: self.log_index,
+            log_id: LogId {
+                key_id: hex_to_base64(&self.log_id)?,
+            },
+            kind_version: KindVersion {
+                kind: body.kind,
+                version: body.api_version,
+            },
+            integrated_time: self.integrated_time,
+            inclusion_promise: Some(InclusionPromise {
+                signed_entry_timestamp: self.verification.signed_entry_timestamp,
+            }),
+            inclusion_proof,
+            canonicalized_body: self.body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uv_client::BaseClientBuilder;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    use crate::verify::tests::{FILENAME, Instance, SHA256, github};
+
+    use super::*;
+
+    /// An unsigned OIDC identity token with the given subject.
+    fn identity_token(subject: &str) -> String {
+        format!(
+            "{}.{}.",
+            BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"none"}"#),
+            BASE64_URL_SAFE_NO_PAD.encode(json!({"sub": subject}).to_string())
+        )
+    }
+
+    /// Start a fake Fulcio and Rekor, backed by the given test instance.
+    async fn start(instance: Arc<Instance>) -> MockServer {
+        let server = MockServer::start().await;
+
+        let fulcio = instance.clone();
+        Mock::given(method("POST"))
+            .and(path("/api/v2/signingCert"))
+            .respond_with(move |request: &Request| {
+                let request: serde_json::Value = request.body_json().unwrap();
+                assert_eq!(
+                    request["credentials"]["oidcIdentityToken"],
+                    identity_token("repo:pypa/sampleproject:ref:refs/tags/v4.0.0")
+                );
+                let public_key = rcgen::SubjectPublicKeyInfo::from_pem(
+                    request["publicKeyRequest"]["publicKey"]["content"]
+                        .as_str()
+                        .unwrap(),
+                )
+                .unwrap();
+                let certificate = fulcio.certify(&public_key, "pypa/sampleproject");
+                ResponseTemplate::new(201).set_body_json(json!({
+                    "signedCertificateEmbeddedSct": {
+                        "chain": {"certificates": [certificate.pem()]},
+                    },
+                }))
+            })
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let rekor = instance;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/log/entries"))
+            .respond_with(move |request: &Request| {
+                let request: serde_json::Value = request.body_json().unwrap();
+                let content = &request["spec"]["proposedContent"];
+                let envelope: serde_json::Value =
+                    serde_json::from_str(content["envelope"].as_str().unwrap()).unwrap();
+                let statement = BASE64_STANDARD
+                    .decode(envelope["payload"].as_str().unwrap())
+                    .unwrap();
+                let signature = BASE64_STANDARD
+                    .decode(envelope["signatures"][0]["sig"].as_str().unwrap())
+                    .unwrap();
+                let verifier = BASE64_STANDARD
+                    .decode(content["verifiers"][0].as_str().unwrap())
+                    .unwrap();
+                let entry = rekor.record(
+                    &statement,
+                    &signature,
+                    std::str::from_utf8(&verifier).unwrap(),
+                );
+                ResponseTemplate::new(201).set_body_json(json!({
+                    "108e9186e8c5677a": {
+                        "body": entry.body,
+                        "integratedTime": entry.integrated_time,
+                        "logID": hex::encode(&entry.key_id),
+                        "logIndex": 0,
+                        "verification": {
+                            "inclusionProof": {
+                                "checkpoint": entry.checkpoint,
+                                "hashes": [],
+                                "logIndex": 0,
+                                "rootHash": hex::encode(entry.root_hash),
+                                "treeSize": 1,
+                            },
+                            "signedEntryTimestamp": BASE64_STANDARD.encode(&entry.signed_entry_timestamp),
+                        },
+                    },
+                }))
+            })
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[tokio::test]
+    async fn sign_publish_attestation() {
+        let instance = Arc::new(Instance::new());
+        let server = start(instance.clone()).await;
+        let url = DisplaySafeUrl::parse(&server.uri()).unwrap();
+        let config = SigningConfig {
+            fulcio_url: url.clone(),
+            rekor_url: url.clone(),
+        };
+
+        let client = BaseClientBuilder::default().build().unwrap();
+        let signer = SigstoreSigner::new(
+            client.for_host(&url).raw_client().clone(),
+            &config,
+            &identity_token("repo:pypa/sampleproject:ref:refs/tags/v4.0.0"),
+        )
+        .await
+        .unwrap();
+
+        // A single certificate is used for all attestations.
+        for filename in [FILENAME, "sampleproject-4.0.0.tar.gz"] {
+            let attestation = attest_publish(&signer, filename, SHA256).await.unwrap();
+            attestation
+                .verify(
+                    &instance.trust_root(),
+                    &github("pypa/sampleproject"),
+                    filename,
+                    SHA256,
+                )
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_malformed_token() {
+        let config = SigningConfig::public_good();
+        let client = BaseClientBuilder::default().build().unwrap();
+        let err = SigstoreSigner::new(
+            client.for_host(&config.fulcio_url).raw_client().clone(),
+            &config,
+            "not-a-jwt",
+        )
+        .await
+        .err()
+        .unwrap();
+        assert!(matches!(err, SigningError::MalformedIdentityToken));
+    }
+}
@@ -0,0 +1,290 @@
+//! Verification of Rekor transparency log entries.
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use jiff::Timestamp;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use x509_parser::pem::parse_x509_pem;
+use x509_parser::prelude::FromDer;
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+use crate::provenance::{InclusionProof, TransparencyLogEntry};
+use crate::trust_root::{TransparencyLog, TrustRoot};
+use crate::verify::VerificationError;
+
+/// Verify that a transparency log entry records the given signature, returning the time at which
+/// the entry was integrated into the log.
+///
+/// The entry must be a DSSE entry whose payload hash and signature match the attestation, carry a
+/// valid signed entry timestamp, and be provably included in a log from the trust root.
+pub(crate) fn verify_entry(
+    entry: &TransparencyLogEntry,
+    trust_root: &TrustRoot,
+    certificate: &[u8],
+    statement: &[u8],
+    signature: &[u8],
+) -> Result<Timestamp, VerificationError> {
+    if entry.kind_version.kind != "dsse" {
+        return Err(VerificationError::UnsupportedEntryKind(
+            entry.kind_version.kind.clone(),
+            entry.kind_version.version.clone(),
+        ));
+    }
+
+    // Ensure that the entry records this attestation.
+    let body = BASE64_STANDARD.decode(&entry.canonicalized_body)?;
+    let parsed: DsseEntry =
+        serde_json::from_slice(&body).map_err(VerificationError::MalformedEntry)?;
+    if parsed.spec.payload_hash.algorithm != "sha256"
+        || !parsed
+            .spec
+            .payload_hash
+            .value
+            .eq_ignore_ascii_case(&hex::encode(Sha256::digest(statement)))
+    {
+        return Err(VerificationError::EntryMismatch("payload hash"));
+    }
+    let mut recorded = false;
+    for recorded_signature in &parsed.spec.signatures {
+        if BASE64_STANDARD.decode(&recorded_signature.signature)? != signature {
+            continue;
+        }
+        let verifier = BASE64_STANDARD.decode(&recorded_signature.verifier)?;
+        let (_, pem) =
+            parse_x509_pem(&verifier).map_err(|_| VerificationError::EntryMismatch("verifier"))?;
+        if pem.contents == certificate {
+            recorded = true;
+            break;
+        }
+    }
+    if !recorded {
+        return Err(VerificationError::EntryMismatch("signature"));
+    }
+
+    // Ensure that the entry was recorded by a trusted log.
+    let key_id = BASE64_STANDARD.decode(&entry.log_id.key_id)?;
+    let log = trust_root
+        .transparency_log(&key_id)
+        .ok_or_else(|| VerificationError::UnknownLog(hex::encode(&key_id)))?;
+    let integrated_time = Timestamp::from_second(entry.integrated_time)
+        .map_err(|_| VerificationError::InvalidIntegratedTime(entry.integrated_time))?;
+    if !log.valid_for.contains(integrated_time) {
+        return Err(VerificationError::InvalidIntegratedTime(
+            entry.integrated_time,
+        ));
+    }
+
+    // Verify the signed entry timestamp, which commits the log to the integration time.
+    let promise = entry
+        .inclusion_promise
+        .as_ref()
+        .ok_or(VerificationError::MissingInclusionPromise)?;
+    let payload = format!(
+        r#"{{"body":"{}","integratedTime":{},"logID":"{}","logIndex":{}}}"#,
+        entry.canonicalized_body,
+        entry.integrated_time,
+        hex::encode(&key_id),
+        entry.log_index,
+    );
+    verify_log_signature(
+        log,
+        payload.as_bytes(),
+        &BASE64_STANDARD.decode(&promise.signed_entry_timestamp)?,
+    )
+    .map_err(|()| VerificationError::InvalidInclusionPromise)?;
+
+    // Verify the inclusion proof and the checkpoint that commits the log to its root hash.
+    let proof = entry
+        .inclusion_proof
+        .as_ref()
+        .ok_or(VerificationError::MissingInclusionProof)?;
+    verify_inclusion(proof, &body)?;
+    verify_checkpoint(proof, log)?;
+
+    Ok(integrated_time)
+}
+
+/// Verify a Merkle tree inclusion proof for the given entry body, per RFC 9162, Section 2.1.3.2.
+fn verify_inclusion(proof: &InclusionProof, body: &[u8]) -> Result<(), VerificationError> {
+    let (Ok(index), Ok(size)) = (
+        u64::try_from(proof.log_index),
+        u64::try_from(proof.tree_size),
+    ) else {
+        return Err(VerificationError::InvalidInclusionProof);
+    };
+    if index >= size {
+        return Err(VerificationError::InvalidInclusionProof);
+    }
+
+    let mut hash: [u8; 32] = Sha256::new()
+        .chain_update([0x00])
+        .chain_update(body)
+        .finalize()
+        .into();
+    let mut fn_ = index;
+    let mut sn = size - 1;
+    for sibling in &proof.hashes {
+        let sibling = BASE64_STANDARD.decode(sibling)?;
+        if sn == 0 {
+            return Err(VerificationError::InvalidInclusionProof);
+        }
+        if fn_ & 1 == 1 || fn_ == sn {
+            hash = node_hash(&sibling, &hash);
+            if fn_ & 1 == 0 {
+                while fn_ & 1 == 0 && fn_ != 0 {
+                    fn_ >>= 1;
+                    sn >>= 1;
+                }
+            }
+        } else {
+            hash = node_hash(&hash, &sibling);
+        }
+        fn_ >>= 1;
+        sn >>= 1;
+    }
+
+    if sn != 0 || BASE64_STANDARD.decode(&proof.root_hash)? != hash {
+        return Err(VerificationError::InvalidInclusionProof);
+    }
+    Ok(())
+}
+
+/// Hash an interior node of a Merkle tree.
+fn node_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([0x01])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Verify that the proof's checkpoint is signed by the log and commits to the proof's root hash.
+///
+/// Checkpoints are signed notes: a body of newline-terminated lines (the log origin, the tree
+/// size, and the base64-encoded root hash), a blank line, and one or more signature lines.
+fn verify_checkpoint(
+    proof: &InclusionProof,
+    log: &TransparencyLog,
+) -> Result<(), VerificationError> {
+    let envelope = &proof.checkpoint.envelope;
+    let (text, signatures) = envelope
+        .split_once("\n\n")
+        .ok_or(VerificationError::InvalidCheckpoint)?;
+
+    let mut lines = text.lines().skip(1);
+    let size = lines.next().and_then(|size| size.parse::<i64>().ok());
+    let root_hash = lines.next();
+    if size != Some(proof.tree_size) || root_hash != Some(proof.root_hash.as_str()) {
+        return Err(VerificationError::InvalidCheckpoint);
+    }
+
+    // The signed message includes the trailing newline of the body.
+    let message = &envelope[..=text.len()];
+    for line in signatures.lines() {
+        let Some(signature) = line
+            .strip_prefix("\u{2014} ")
+            .and_then(|line| line.split_once(' '))
+            .map(|(_, signature)| signature)
+        else {
+            continue;
+        };
+        let signature = BASE64_STANDARD.decode(signature)?;
+        let Some((key_hint, signature)) = signature.split_first_chunk::<4>() else {
+            continue;
+        };
+        if log.key_id.starts_with(key_hint)
+            && verify_log_signature(log, message.as_bytes(), signature).is_ok()
+        {
+            return Ok(());
+        }
+    }
+    Err(VerificationError::InvalidCheckpoint)
+}
+
+/// Verify an ECDSA P-256 signature by a transparency log.
+fn verify_log_signature(log: &TransparencyLog, message: &[u8], signature: &[u8]) -> Result<(), ()> {
+    let (_, spki) = SubjectPublicKeyInfo::from_der(&log.public_key).map_err(|_| ())?;
+    webpki::aws_lc_rs::ECDSA_P256_SHA256
+        .verify_signature(&spki.subject_public_key.data, message, signature)
+        .map_err(|_| ())
+}
+
+/// The canonicalized body of a Rekor `dsse` entry.
+#[derive(Deserialize)]
+struct DsseEntry {
+    spec: DsseEntrySpec,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DsseEntrySpec {
+    payload_hash: EntryHash,
+    signatures: Vec<EntrySignature>,
+}
+
+#[derive(Deserialize)]
+struct EntryHash {
+    algorithm: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct EntrySignature {
+    signature: String,
+    verifier: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provenance::Checkpoint;
+
+    fn leaf_hash(body: &[u8]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update([0x00])
+            .chain_update(body)
+            .finalize()
+            .into()
+    }
+
+    fn proof(index: i64, size: i64, root: [u8; 32], hashes: &[[u8; 32]]) -> InclusionProof {
+        InclusionProof {
+            log_index: index,
+            root_hash: BASE64_STANDARD.encode(root),
+            tree_size: size,
+            hashes: hashes
+                .iter()
+                .map(|hash| BASE64_STANDARD.encode(hash))
+                .collect(),
+            checkpoint: Checkpoint {
+                envelope: String::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn inclusion_three_leaves() {
+        // The tree over leaves `a`, `b`, and `c` is `H(H(a, b), c)`.
+        let [a, b, c] = [b"a", b"b", b"c"].map(|leaf| leaf_hash(leaf));
+        let ab = node_hash(&a, &b);
+        let root = node_hash(&ab, &c);
+
+        verify_inclusion(&proof(0, 3, root, &[b, c]), b"a").unwrap();
+        verify_inclusion(&proof(1, 3, root, &[a, c]), b"b").unwrap();
+        verify_inclusion(&proof(2, 3, root, &[ab]), b"c").unwrap();
+
+        // The proof for one leaf doesn't verify another.
+        assert!(verify_inclusion(&proof(0, 3, root, &[b, c]), b"c").is_err());
+        assert!(verify_inclusion(&proof(2, 3, root, &[a, c]), b"c").is_err());
+        assert!(verify_inclusion(&proof(3, 3, root, &[ab]), b"c").is_err());
+    }
+
+    #[test]
+    fn inclusion_single_leaf() {
+        let root = leaf_hash(b"a");
+        verify_inclusion(&proof(0, 1, root, &[]), b"a").unwrap();
+        assert!(verify_inclusion(&proof(0, 1, root, &[]), b"b").is_err());
+    }
+}
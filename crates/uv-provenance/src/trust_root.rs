@@ -0,0 +1,198 @@
+use std::sync::LazyLock;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use jiff::Timestamp;
+use serde::Deserialize;
+
+/// The trust root for Sigstore's public-good instance, as distributed by its TUF repository.
+static PUBLIC_GOOD: LazyLock<TrustRoot> = LazyLock::new(|| {
+    TrustRoot::from_json(include_str!("trusted_root.json"))
+        .expect("bundled Sigstore trust root is valid")
+});
+
+#[derive(Debug, thiserror::Error)]
+pub enum TrustRootError {
+    #[error("Failed to parse Sigstore trust root")]
+    Json(#[from] serde_json::Error),
+    #[error("Sigstore trust root contains invalid base64")]
+    Base64(#[from] base64::DecodeError),
+    #[error("Sigstore trust root contains a certificate authority without certificates")]
+    EmptyChain,
+}
+
+/// The keys and certificates trusted to issue signing certificates and record signatures.
+///
+/// This is the subset of Sigstore's `TrustedRoot` message needed to verify PEP 740 attestations
+/// offline: the Fulcio certificate authorities and the Rekor transparency logs.
+#[derive(Debug, Clone)]
+pub struct TrustRoot {
+    pub(crate) certificate_authorities: Vec<CertificateAuthority>,
+    pub(crate) transparency_logs: Vec<TransparencyLog>,
+}
+
+/// A Fulcio certificate authority.
+#[derive(Debug, Clone)]
+pub(crate) struct CertificateAuthority {
+    /// The DER-encoded self-signed root certificate.
+    pub(crate) root: Vec<u8>,
+    /// The DER-encoded intermediate certificates, ordered from the leaf towards the root.
+    pub(crate) intermediates: Vec<Vec<u8>>,
+    pub(crate) valid_for: ValidityPeriod,
+}
+
+/// A Rekor transparency log.
+#[derive(Debug, Clone)]
+pub(crate) struct TransparencyLog {
+    /// The SHA-256 digest of the log's DER-encoded public key.
+    pub(crate) key_id: Vec<u8>,
+    /// The DER-encoded `SubjectPublicKeyInfo` of the log's signing key.
+    pub(crate) public_key: Vec<u8>,
+    pub(crate) valid_for: ValidityPeriod,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ValidityPeriod {
+    start: Timestamp,
+    end: Option<Timestamp>,
+}
+
+impl ValidityPeriod {
+    /// Returns `true` if the period contains the given time.
+    pub(crate) fn contains(&self, time: Timestamp) -> bool {
+        self.start <= time && self.end.is_none_or(|end| time <= end)
+    }
+}
+
+impl TrustRoot {
+    /// Return the trust root for Sigstore's public-good instance, which PyPI uses.
+    pub fn public_good() -> &'static Self {
+        &PUBLIC_GOOD
+    }
+
+    /// Parse a trust root from the JSON encoding of Sigstore's `TrustedRoot` message.
+    pub fn from_json(json: &str) -> Result<Self, TrustRootError> {
+        let wire: TrustedRootWire = serde_json::from_str(json)?;
+
+        let certificate_authorities = wire
+            .certificate_authorities
+            .into_iter()
+            .map(|authority| {
+                let mut certificates = authority
+                    .cert_chain
+                    .certificates
+                    .into_iter()
+                    .map(|certificate| BASE64_STANDARD.decode(certificate.raw_bytes))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let root = certificates.pop().ok_or(TrustRootError::EmptyChain)?;
+                Ok(CertificateAuthority {
+                    root,
+                    intermediates: certificates,
+                    valid_for: authority.valid_for.into(),
+                })
+            })
+            .collect::<Result<Vec<_>, TrustRootError>>()?;
+
+        let transparency_logs = wire
+            .tlogs
+            .into_iter()
+            .map(|log| {
+                Ok(TransparencyLog {
+                    key_id: BASE64_STANDARD.decode(log.log_id.key_id)?,
+                    public_key: BASE64_STANDARD.decode(log.public_key.raw_bytes)?,
+                    valid_for: log.public_key.valid_for.into(),
+                })
+            })
+            .collect::<Result<Vec<_>, TrustRootError>>()?;
+
+        Ok(Self {
+            certificate_authorities,
+            transparency_logs,
+        })
+    }
+
+    /// Return the transparency log with the given key ID.
+    pub(crate) fn transparency_log(&self, key_id: &[u8]) -> Option<&TransparencyLog> {
+        self.transparency_logs
+            .iter()
+            .find(|log| log.key_id == key_id)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrustedRootWire {
+    tlogs: Vec<TransparencyLogWire>,
+    certificate_authorities: Vec<CertificateAuthorityWire>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransparencyLogWire {
+    public_key: PublicKeyWire,
+    log_id: LogIdWire,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PublicKeyWire {
+    raw_bytes: String,
+    valid_for: ValidityPeriodWire,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LogIdWire {
+    key_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CertificateAuthorityWire {
+    cert_chain: CertChainWire,
+    valid_for: ValidityPeriodWire,
+}
+
+#[derive(Deserialize)]
+struct CertChainWire {
+    certificates: Vec<CertificateWire>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CertificateWire {
+    raw_bytes: String,
+}
+
+#[derive(Deserialize)]
+struct ValidityPeriodWire {
+    start: Timestamp,
+    end: Option<Timestamp>,
+}
+
+impl From<ValidityPeriodWire> for ValidityPeriod {
+    fn from(value: ValidityPeriodWire) -> Self {
+        Self {
+            start: value.start,
+            end: value.end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_good() {
+        let trust_root = TrustRoot::public_good();
+        assert_eq!(trust_root.certificate_authorities.len(), 2);
+        assert!(
+            trust_root
+                .certificate_authorities
+                .iter()
+                .any(|authority| authority.valid_for.end.is_none())
+        );
+        assert!(!trust_root.transparency_logs.is_empty());
+    }
+}
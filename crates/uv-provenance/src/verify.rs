@@ -0,0 +1,825 @@
+use std::time::Duration;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use jiff::Timestamp;
+use rustls_pki_types::{CertificateDer, SignatureVerificationAlgorithm, UnixTime};
+use serde::Deserialize;
+use tracing::debug;
+use webpki::{EndEntityCert, KeyUsage};
+use x509_parser::certificate::X509Certificate;
+use x509_parser::der_parser::der::parse_der_utf8string;
+use x509_parser::der_parser::oid::Oid;
+use x509_parser::extensions::GeneralName;
+use x509_parser::prelude::FromDer;
+
+use crate::provenance::{Attestation, Provenance, Publisher};
+use crate::tlog;
+use crate::trust_root::TrustRoot;
+
+/// The DSSE payload type of an in-toto statement.
+pub(crate) const IN_TOTO_PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// The in-toto statement type used by PEP 740 attestations.
+pub(crate) const IN_TOTO_STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+
+/// The predicate type of attestations produced by a Trusted Publisher when publishing.
+pub(crate) const PUBLISH_PREDICATE_TYPE: &str = "https://docs.pypi.org/attestations/publish/v1";
+
+/// The predicate types that PyPI accepts for attestations.
+const PREDICATE_TYPES: &[&str] = &[PUBLISH_PREDICATE_TYPE, "https://slsa.dev/provenance/v1"];
+
+/// The DER-encoded OID of the code signing extended key usage (`1.3.6.1.5.5.7.3.3`).
+const EKU_CODE_SIGNING: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x03];
+
+/// The signature algorithms used by Fulcio and by the keys it certifies.
+static SIGNATURE_ALGORITHMS: &[&dyn SignatureVerificationAlgorithm] = &[
+    webpki::aws_lc_rs::ECDSA_P256_SHA256,
+    webpki::aws_lc_rs::ECDSA_P256_SHA384,
+    webpki::aws_lc_rs::ECDSA_P384_SHA256,
+    webpki::aws_lc_rs::ECDSA_P384_SHA384,
+    webpki::aws_lc_rs::ED25519,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+    #[error("Unsupported provenance version: {0}")]
+    UnsupportedVersion(u8),
+    #[error("The provenance doesn't contain any attestations")]
+    NoAttestations,
+    #[error("Invalid base64 in attestation")]
+    Base64(#[from] base64::DecodeError),
+    #[error("Malformed in-toto statement")]
+    MalformedStatement(#[source] serde_json::Error),
+    #[error("Unsupported in-toto statement type `{0}`")]
+    UnsupportedStatementType(String),
+    #[error("Unsupported attestation predicate type `{0}`")]
+    UnsupportedPredicateType(String),
+    #[error("The attestation is for `{subject}`, not `{filename}`")]
+    SubjectMismatch { subject: String, filename: String },
+    #[error(
+        "The attested SHA-256 digest `{attested}` doesn't match the expected digest `{expected}`"
+    )]
+    DigestMismatch { attested: String, expected: String },
+    #[error("The attestation doesn't have a transparency log entry")]
+    MissingTransparencyEntry,
+    #[error("Unsupported transparency log entry kind `{0}` (version `{1}`)")]
+    UnsupportedEntryKind(String, String),
+    #[error("Malformed transparency log entry")]
+    MalformedEntry(#[source] serde_json::Error),
+    #[error("The transparency log entry doesn't match the attestation's {0}")]
+    EntryMismatch(&'static str),
+    #[error("The transparency log entry was recorded by an untrusted log (`{0}`)")]
+    UnknownLog(String),
+    #[error("The transparency log entry has an invalid integration time: {0}")]
+    InvalidIntegratedTime(i64),
+    #[error("The transparency log entry doesn't have a signed entry timestamp")]
+    MissingInclusionPromise,
+    #[error("The transparency log entry has an invalid signed entry timestamp")]
+    InvalidInclusionPromise,
+    #[error("The transparency log entry doesn't have an inclusion proof")]
+    MissingInclusionProof,
+    #[error("The transparency log entry has an invalid inclusion proof")]
+    InvalidInclusionProof,
+    #[error("The transparency log entry has an invalid checkpoint")]
+    InvalidCheckpoint,
+    #[error("The signing certificate wasn't issued by a trusted certificate authority: {0}")]
+    UntrustedCertificate(webpki::Error),
+    #[error("Malformed signing certificate")]
+    MalformedCertificate,
+    #[error("Invalid attestation signature")]
+    InvalidSignature,
+    #[error("Unsupported Trusted Publisher kind `{0}`")]
+    UnsupportedPublisher(String),
+    #[error("The {publisher} is missing the `{claim}` claim")]
+    MissingPublisherClaim {
+        publisher: Box<Publisher>,
+        claim: &'static str,
+    },
+    #[error("The signing certificate's {field} (`{actual}`) doesn't match the {publisher}")]
+    IdentityMismatch {
+        publisher: Box<Publisher>,
+        field: &'static str,
+        actual: String,
+    },
+}
+
+impl Provenance {
+    /// Verify that every attestation in the provenance object attests to the given file, and was
+    /// signed by the Trusted Publisher it's attributed to.
+    ///
+    /// The `sha256` digest is the expected hex-encoded SHA-256 digest of the file.
+    pub fn verify(
+        &self,
+        trust_root: &TrustRoot,
+        filename: &str,
+        sha256: &str,
+    ) -> Result<(), VerificationError> {
+        if self.version != 1 {
+            return Err(VerificationError::UnsupportedVersion(self.version));
+        }
+        let mut verified = false;
+        for bundle in &self.attestation_bundles {
+            for attestation in &bundle.attestations {
+                attestation.verify(trust_root, &bundle.publisher, filename, sha256)?;
+                verified = true;
+            }
+        }
+        if !verified {
+            return Err(VerificationError::NoAttestations);
+        }
+        Ok(())
+    }
+}
+
+impl Attestation {
+    /// Verify that the attestation attests to the given file, and was signed by the given
+    /// Trusted Publisher.
+    pub fn verify(
+        &self,
+        trust_root: &TrustRoot,
+        publisher: &Publisher,
+        filename: &str,
+        sha256: &str,
+    ) -> Result<(), VerificationError> {
+        if self.version != 1 {
+            return Err(VerificationError::UnsupportedVersion(self.version));
+        }
+
+        let certificate = BASE64_STANDARD.decode(&self.verification_material.certificate)?;
+        let statement = BASE64_STANDARD.decode(&self.envelope.statement)?;
+        let signature = BASE64_STANDARD.decode(&self.envelope.signature)?;
+
+        // Ensure that the statement is about the expected file.
+        verify_statement(&statement, filename, sha256)?;
+
+        // Ensure that the signature was recorded in the transparency log, which determines the
+        // time at which the signing certificate must have been valid.
+        let entry = self
+            .verification_material
+            .transparency_entries
+            .first()
+            .ok_or(VerificationError::MissingTransparencyEntry)?;
+        let integrated_time =
+            tlog::verify_entry(entry, trust_root, &certificate, &statement, &signature)?;
+
+        // Ensure that the signing certificate was issued by Fulcio, and signed the statement.
+        let certificate_der = CertificateDer::from(certificate.as_slice());
+        let end_entity = EndEntityCert::try_from(&certificate_der)
+            .map_err(|_| VerificationError::MalformedCertificate)?;
+        verify_chain(&end_entity, trust_root, integrated_time)?;
+        let message = pae(IN_TOTO_PAYLOAD_TYPE, &statement);
+        if !SIGNATURE_ALGORITHMS.iter().any(|algorithm| {
+            end_entity
+                .verify_signature(*algorithm, &message, &signature)
+                .is_ok()
+        }) {
+            return Err(VerificationError::InvalidSignature);
+        }
+
+        // Ensure that the signing certificate's identity matches the Trusted Publisher.
+        let (_, certificate) = X509Certificate::from_der(&certificate)
+            .map_err(|_| VerificationError::MalformedCertificate)?;
+        verify_identity(&Identity::from_certificate(&certificate)?, publisher)
+    }
+}
+
+/// Verify that the in-toto statement attests to the given file.
+fn verify_statement(
+    statement: &[u8],
+    filename: &str,
+    sha256: &str,
+) -> Result<(), VerificationError> {
+    let statement: Statement =
+        serde_json::from_slice(statement).map_err(VerificationError::MalformedStatement)?;
+    if statement.type_ != IN_TOTO_STATEMENT_TYPE {
+        return Err(VerificationError::UnsupportedStatementType(statement.type_));
+    }
+    if !PREDICATE_TYPES.contains(&statement.predicate_type.as_str()) {
+        return Err(VerificationError::UnsupportedPredicateType(
+            statement.predicate_type,
+        ));
+    }
+
+    // PEP 740 attestations have exactly one subject: the distribution file.
+    let [subject] = statement.subject.as_slice() else {
+        return Err(VerificationError::SubjectMismatch {
+            subject: statement
+                .subject
+                .iter()
+                .map(|subject| subject.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            filename: filename.to_string(),
+        });
+    };
+    if subject.name != filename {
+        return Err(VerificationError::SubjectMismatch {
+            subject: subject.name.clone(),
+            filename: filename.to_string(),
+        });
+    }
+    let attested = subject.digest.sha256.as_deref().unwrap_or_default();
+    if !attested.eq_ignore_ascii_case(sha256) {
+        return Err(VerificationError::DigestMismatch {
+            attested: attested.to_string(),
+            expected: sha256.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Verify that the certificate chains to a certificate authority in the trust root that was
+/// valid at the given time.
+fn verify_chain(
+    end_entity: &EndEntityCert<'_>,
+    trust_root: &TrustRoot,
+    time: Timestamp,
+) -> Result<(), VerificationError> {
+    let unix_time = UnixTime::since_unix_epoch(Duration::from_secs(
+        u64::try_from(time.as_second()).unwrap_or_default(),
+    ));
+    let mut error = webpki::Error::UnknownIssuer;
+    for authority in &trust_root.certificate_authorities {
+        if !authority.valid_for.contains(time) {
+            continue;
+        }
+        let root = CertificateDer::from(authority.root.as_slice());
+        let anchor = match webpki::anchor_from_trusted_cert(&root) {
+            Ok(anchor) => anchor,
+            Err(err) => {
+                debug!("Skipping invalid certificate authority: {err}");
+                continue;
+            }
+        };
+        let intermediates = authority
+            .intermediates
+            .iter()
+            .map(|intermediate| CertificateDer::from(intermediate.as_slice()))
+            .collect::<Vec<_>>();
+        match end_entity.verify_for_usage(
+            SIGNATURE_ALGORITHMS,
+            &[anchor],
+            &intermediates,
+            unix_time,
+            KeyUsage::required(EKU_CODE_SIGNING),
+            None,
+            None,
+        ) {
+            Ok(_) => return Ok(()),
+            Err(err) => error = err,
+        }
+    }
+    Err(VerificationError::UntrustedCertificate(error))
+}
+
+/// Compute the DSSE pre-authentication encoding of a payload.
+pub(crate) fn pae(payload_type: &str, payload: &[u8]) -> Vec<u8> {
+    let mut message = format!(
+        "DSSEv1 {} {payload_type} {} ",
+        payload_type.len(),
+        payload.len()
+    )
+    .into_bytes();
+    message.extend_from_slice(payload);
+    message
+}
+
+/// The identity claims that Fulcio embeds in a signing certificate.
+#[derive(Debug, Default)]
+struct Identity {
+    /// The subject alternative name, i.e., the workflow URI or the email address.
+    subject: Option<String>,
+    /// The OIDC issuer that authenticated the signer.
+    issuer: Option<String>,
+    /// The URI of the source repository the signer ran in.
+    source_repository: Option<String>,
+}
+
+impl Identity {
+    /// `1.3.6.1.4.1.57264.1.1`: the OIDC issuer, as raw bytes (deprecated).
+    const ISSUER_V1: &[u64] = &[1, 3, 6, 1, 4, 1, 57264, 1, 1];
+    /// `1.3.6.1.4.1.57264.1.8`: the OIDC issuer, as a DER-encoded `UTF8String`.
+    const ISSUER_V2: &[u64] = &[1, 3, 6, 1, 4, 1, 57264, 1, 8];
+    /// `1.3.6.1.4.1.57264.1.12`: the source repository URI, as a DER-encoded `UTF8String`.
+    const SOURCE_REPOSITORY_URI: &[u64] = &[1, 3, 6, 1, 4, 1, 57264, 1, 12];
+
+    fn from_certificate(certificate: &X509Certificate<'_>) -> Result<Self, VerificationError> {
+        let mut identity = Self::default();
+
+        let san = certificate
+            .subject_alternative_name()
+            .map_err(|_| VerificationError::MalformedCertificate)?;
+        identity.subject = san.and_then(|san| {
+            san.value.general_names.iter().find_map(|name| match name {
+                GeneralName::URI(uri) => Some((*uri).to_string()),
+                GeneralName::RFC822Name(email) => Some((*email).to_string()),
+                _ => None,
+            })
+        });
+
+        let issuer_v1 = Oid::from(Self::ISSUER_V1).expect("valid OID");
+        let issuer_v2 = Oid::from(Self::ISSUER_V2).expect("valid OID");
+        let source_repository = Oid::from(Self::SOURCE_REPOSITORY_URI).expect("valid OID");
+        for extension in certificate.extensions() {
+            if extension.oid == issuer_v2 {
+                identity.issuer = Some(der_utf8_string(extension.value)?);
+            } else if extension.oid == issuer_v1 && identity.issuer.is_none() {
+                identity.issuer = Some(
+                    std::str::from_utf8(extension.value)
+                        .map_err(|_| VerificationError::MalformedCertificate)?
+                        .to_string(),
+                );
+            } else if extension.oid == source_repository {
+                identity.source_repository = Some(der_utf8_string(extension.value)?);
+            }
+        }
+
+        Ok(identity)
+    }
+}
+
+/// Decode a DER-encoded `UTF8String`.
+fn der_utf8_string(value: &[u8]) -> Result<String, VerificationError> {
+    let (_, object) =
+        parse_der_utf8string(value).map_err(|_| VerificationError::MalformedCertificate)?;
+    object
+        .as_str()
+        .map(ToString::to_string)
+        .map_err(|_| VerificationError::MalformedCertificate)
+}
+
+/// Verify that the certificate identity matches the Trusted Publisher.
+///
+/// This mirrors the policies that PyPI applies when accepting attestations for each kind of
+/// Trusted Publisher.
+fn verify_identity<'a>(
+    identity: &Identity,
+    publisher: &'a Publisher,
+) -> Result<(), VerificationError> {
+    let claim = |value: Option<&'a str>, claim: &'static str| {
+        value.ok_or_else(|| VerificationError::MissingPublisherClaim {
+            publisher: Box::new(publisher.clone()),
+            claim,
+        })
+    };
+
+    let (issuer, source_repository, subject_prefix) = match publisher.kind.as_str() {
+        "GitHub" => {
+            let repository = claim(publisher.repository.as_deref(), "repository")?;
+            let workflow = claim(publisher.workflow.as_deref(), "workflow")?;
+            (
+                "https://token.actions.githubusercontent.com",
+                Some(format!("https://github.com/{repository}")),
+                format!("https://github.com/{repository}/.github/workflows/{workflow}@"),
+            )
+        }
+        "GitLab" => {
+            let repository = claim(publisher.repository.as_deref(), "repository")?;
+            let workflow_filepath =
+                claim(publisher.workflow_filepath.as_deref(), "workflow_filepath")?;
+            (
+                "https://gitlab.com",
+                Some(format!("https://gitlab.com/{repository}")),
+                format!("https://gitlab.com/{repository}//{workflow_filepath}@"),
+            )
+        }
+        "Google" => {
+            let email = claim(publisher.email.as_deref(), "email")?;
+            ("https://accounts.google.com", None, email.to_string())
+        }
+        kind => return Err(VerificationError::UnsupportedPublisher(kind.to_string())),
+    };
+
+    let mismatch =
+        |field: &'static str, actual: Option<&str>| VerificationError::IdentityMismatch {
+            publisher: Box::new(publisher.clone()),
+            field,
+            actual: actual.unwrap_or_default().to_string(),
+        };
+
+    if identity.issuer.as_deref() != Some(issuer) {
+        return Err(mismatch("issuer", identity.issuer.as_deref()));
+    }
+
+    // Repository names are case-insensitive on both GitHub and GitLab.
+    if let Some(source_repository) = source_repository {
+        if !identity
+            .source_repository
+            .as_deref()
+            .is_some_and(|actual| actual.eq_ignore_ascii_case(&source_repository))
+        {
+            return Err(mismatch(
+                "source repository",
+                identity.source_repository.as_deref(),
+            ));
+        }
+    }
+
+    let subject_matches = identity.subject.as_deref().is_some_and(|subject| {
+        if publisher.kind == "Google" {
+            subject.eq_ignore_ascii_case(&subject_prefix)
+        } else {
+            subject
+                .get(..subject_prefix.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&subject_prefix))
+        }
+    });
+    if !subject_matches {
+        return Err(mismatch("subject", identity.subject.as_deref()));
+    }
+
+    Ok(())
+}
+
+/// An in-toto statement.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Statement {
+    #[serde(rename = "_type")]
+    type_: String,
+    subject: Vec<Subject>,
+    predicate_type: String,
+}
+
+#[derive(Deserialize)]
+struct Subject {
+    name: String,
+    digest: SubjectDigest,
+}
+
+#[derive(Deserialize)]
+struct SubjectDigest {
+    sha256: Option<String>,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use rcgen::{
+        BasicConstraints, CertificateParams, CustomExtension, ExtendedKeyUsagePurpose, IsCa,
+        Issuer, KeyPair, KeyUsagePurpose, PKCS_ECDSA_P256_SHA256, PublicKeyData, SanType,
+        SigningKey,
+    };
+    use serde_json::json;
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+
+    pub(crate) const FILENAME: &str = "sampleproject-4.0.0-py3-none-any.whl";
+    pub(crate) const SHA256: &str =
+        "4ce8d0bbb3bc5b4ac1b4a6a9fbb1fc9a0a0d8d2ab7e01d1d2aaf6a4f3c2d1e0f";
+    const INTEGRATED_TIME: i64 = 1_730_000_000;
+
+    /// A test Sigstore instance: a Fulcio-like CA and a Rekor-like log.
+    pub(crate) struct Instance {
+        root: rcgen::Certificate,
+        issuer: Issuer<'static, KeyPair>,
+        log_key: KeyPair,
+    }
+
+    /// A single-entry log, recording a signature.
+    pub(crate) struct LogEntry {
+        /// The base64-encoded canonicalized entry body.
+        pub(crate) body: String,
+        pub(crate) integrated_time: i64,
+        pub(crate) key_id: Vec<u8>,
+        pub(crate) signed_entry_timestamp: Vec<u8>,
+        pub(crate) root_hash: [u8; 32],
+        pub(crate) checkpoint: String,
+    }
+
+    impl Instance {
+        pub(crate) fn new() -> Self {
+            let key = KeyPair::generate_for(&PKCS_ECDSA_P256_SHA256).unwrap();
+            let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+            params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            params.key_usages = vec![KeyUsagePurpose::KeyCertSign];
+            let root = params.self_signed(&key).unwrap();
+            Self {
+                root,
+                issuer: Issuer::new(params, key),
+                log_key: KeyPair::generate_for(&PKCS_ECDSA_P256_SHA256).unwrap(),
+            }
+        }
+
+        fn key_id(&self) -> Vec<u8> {
+            Sha256::digest(self.log_key.subject_public_key_info()).to_vec()
+        }
+
+        pub(crate) fn trust_root(&self) -> TrustRoot {
+            TrustRoot::from_json(
+                &json!({
+                    "tlogs": [{
+                        "publicKey": {
+                            "rawBytes": BASE64_STANDARD.encode(self.log_key.subject_public_key_info()),
+                            "validFor": {"start": "2021-01-01T00:00:00Z"},
+                        },
+                        "logId": {"keyId": BASE64_STANDARD.encode(self.key_id())},
+                    }],
+                    "certificateAuthorities": [{
+                        "certChain": {"certificates": [
+                            {"rawBytes": BASE64_STANDARD.encode(self.root.der())},
+                        ]},
+                        "validFor": {"start": "2021-01-01T00:00:00Z"},
+                    }],
+                })
+                .to_string(),
+            )
+            .unwrap()
+        }
+
+        /// Issue a signing certificate for a key, to a GitHub Actions workflow in the given
+        /// repository.
+        pub(crate) fn certify(
+            &self,
+            key: &impl PublicKeyData,
+            repository: &str,
+        ) -> rcgen::Certificate {
+            let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+            params.subject_alt_names = vec![SanType::URI(
+                format!(
+                    "https://github.com/{repository}/.github/workflows/release.yml@refs/tags/v4.0.0"
+                )
+                .try_into()
+                .unwrap(),
+            )];
+            params.extended_key_usages = vec![ExtendedKeyUsagePurpose::CodeSigning];
+            params.custom_extensions = vec![
+                utf8_extension(
+                    Identity::ISSUER_V2,
+                    "https://token.actions.githubusercontent.com",
+                ),
+                utf8_extension(
+                    Identity::SOURCE_REPOSITORY_URI,
+                    &format!("https://github.com/{repository}"),
+                ),
+            ];
+            params.signed_by(key, &self.issuer).unwrap()
+        }
+
+        /// Record a signature over a statement in a single-entry log.
+        pub(crate) fn record(
+            &self,
+            statement: &[u8],
+            signature: &[u8],
+            certificate_pem: &str,
+        ) -> LogEntry {
+            let body = BASE64_STANDARD.encode(
+                json!({
+                    "apiVersion": "0.0.1",
+                    "kind": "dsse",
+                    "spec": {
+                        "payloadHash": {
+                            "algorithm": "sha256",
+                            "value": hex::encode(Sha256::digest(statement)),
+                        },
+                        "signatures": [{
+                            "signature": BASE64_STANDARD.encode(signature),
+                            "verifier": BASE64_STANDARD.encode(certificate_pem),
+                        }],
+                    },
+                })
+                .to_string(),
+            );
+            let key_id = self.key_id();
+            let signed_entry_timestamp = self
+                .log_key
+                .sign(
+                    format!(
+                        r#"{{"body":"{body}","integratedTime":{INTEGRATED_TIME},"logID":"{}","logIndex":0}}"#,
+                        hex::encode(&key_id)
+                    )
+                    .as_bytes(),
+                )
+                .unwrap();
+            let root_hash: [u8; 32] = Sha256::new()
+                .chain_update([0x00])
+                .chain_update(BASE64_STANDARD.decode(&body).unwrap())
+                .finalize()
+                .into();
+            let note = format!(
+                "rekor.example.com\n1\n{}\n",
+                BASE64_STANDARD.encode(root_hash)
+            );
+            let note_signature =
+                [&key_id[..4], &self.log_key.sign(note.as_bytes()).unwrap()].concat();
+            let checkpoint = format!(
+                "{note}\n\u{2014} rekor.example.com {}\n",
+                BASE64_STANDARD.encode(note_signature)
+            );
+            LogEntry {
+                body,
+                integrated_time: INTEGRATED_TIME,
+                key_id,
+                signed_entry_timestamp,
+                root_hash,
+                checkpoint,
+            }
+        }
+
+        /// Sign a statement about a file, as a GitHub Actions workflow in the given repository.
+        pub(crate) fn attest(&self, repository: &str, filename: &str, sha256: &str) -> Attestation {
+            let key = KeyPair::generate_for(&PKCS_ECDSA_P256_SHA256).unwrap();
+            let certificate = self.certify(&key, repository);
+
+            let statement = json!({
+                "_type": IN_TOTO_STATEMENT_TYPE,
+                "subject": [{"name": filename, "digest": {"sha256": sha256}}],
+                "predicateType": PREDICATE_TYPES[0],
+                "predicate": {},
+            })
+            .to_string();
+            let signature = key
+                .sign(&pae(IN_TOTO_PAYLOAD_TYPE, statement.as_bytes()))
+                .unwrap();
+            let entry = self.record(statement.as_bytes(), &signature, &certificate.pem());
+
+            serde_json::from_value(json!({
+                "version": 1,
+                "verification_material": {
+                    "certificate": BASE64_STANDARD.encode(certificate.der()),
+                    "transparency_entries": [{
+                        "logIndex": "0",
+                        "logId": {"keyId": BASE64_STANDARD.encode(&entry.key_id)},
+                        "kindVersion": {"kind": "dsse", "version": "0.0.1"},
+                        "integratedTime": entry.integrated_time.to_string(),
+                        "inclusionPromise": {
+                            "signedEntryTimestamp": BASE64_STANDARD.encode(entry.signed_entry_timestamp),
+                        },
+                        "inclusionProof": {
+                            "logIndex": "0",
+                            "rootHash": BASE64_STANDARD.encode(entry.root_hash),
+                            "treeSize": "1",
+                            "hashes": [],
+                            "checkpoint": {"envelope": entry.checkpoint},
+                        },
+                        "canonicalizedBody": entry.body,
+                    }],
+                },
+                "envelope": {
+                    "statement": BASE64_STANDARD.encode(&statement),
+                    "signature": BASE64_STANDARD.encode(&signature),
+                },
+            }))
+            .unwrap()
+        }
+    }
+
+    fn utf8_extension(oid: &[u64], value: &str) -> CustomExtension {
+        let mut content = vec![0x0c, u8::try_from(value.len()).unwrap()];
+        content.extend_from_slice(value.as_bytes());
+        CustomExtension::from_oid_content(oid, content)
+    }
+
+    pub(crate) fn github(repository: &str) -> Publisher {
+        Publisher {
+            kind: "GitHub".to_string(),
+            repository: Some(repository.to_string()),
+            workflow: Some("release.yml".to_string()),
+            workflow_filepath: None,
+            environment: None,
+            email: None,
+        }
+    }
+
+    #[test]
+    fn verify_attestation() {
+        let instance = Instance::new();
+        let attestation = instance.attest("pypa/sampleproject", FILENAME, SHA256);
+        attestation
+            .verify(
+                &instance.trust_root(),
+                &github("pypa/sampleproject"),
+                FILENAME,
+                SHA256,
+            )
+            .unwrap();
+
+        // Repository names are case-insensitive.
+        attestation
+            .verify(
+                &instance.trust_root(),
+                &github("PyPA/SampleProject"),
+                FILENAME,
+                SHA256,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn verify_wrong_file() {
+        let instance = Instance::new();
+        let attestation = instance.attest("pypa/sampleproject", FILENAME, SHA256);
+        let publisher = github("pypa/sampleproject");
+
+        let err = attestation
+            .verify(
+                &instance.trust_root(),
+                &publisher,
+                "sampleproject-4.0.0.tar.gz",
+                SHA256,
+            )
+            .unwrap_err();
+        assert!(matches!(err, VerificationError::SubjectMismatch { .. }));
+
+        let err = attestation
+            .verify(
+                &instance.trust_root(),
+                &publisher,
+                FILENAME,
+                &"0".repeat(64),
+            )
+            .unwrap_err();
+        assert!(matches!(err, VerificationError::DigestMismatch { .. }));
+    }
+
+    #[test]
+    fn verify_wrong_publisher() {
+        let instance = Instance::new();
+        let attestation = instance.attest("attacker/sampleproject", FILENAME, SHA256);
+        let err = attestation
+            .verify(
+                &instance.trust_root(),
+                &github("pypa/sampleproject"),
+                FILENAME,
+                SHA256,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            VerificationError::IdentityMismatch {
+                field: "source repository",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn verify_untrusted() {
+        // An attestation from another instance isn't trusted, even if it's otherwise valid.
+        let instance = Instance::new();
+        let attestation = instance.attest("pypa/sampleproject", FILENAME, SHA256);
+        let err = attestation
+            .verify(
+                &Instance::new().trust_root(),
+                &github("pypa/sampleproject"),
+                FILENAME,
+                SHA256,
+            )
+            .unwrap_err();
+        assert!(matches!(err, VerificationError::UnknownLog(_)));
+
+        // Nor is an attestation against the public-good instance.
+        let err = attestation
+            .verify(
+                TrustRoot::public_good(),
+                &github("pypa/sampleproject"),
+                FILENAME,
+                SHA256,
+            )
+            .unwrap_err();
+        assert!(matches!(err, VerificationError::UnknownLog(_)));
+    }
+
+    #[test]
+    fn verify_tampered_statement() {
+        let instance = Instance::new();
+        let mut attestation = instance.attest("pypa/sampleproject", FILENAME, SHA256);
+        let other = instance.attest("pypa/sampleproject", FILENAME, &"0".repeat(64));
+        attestation.envelope.statement = other.envelope.statement;
+        let err = attestation
+            .verify(
+                &instance.trust_root(),
+                &github("pypa/sampleproject"),
+                FILENAME,
+                &"0".repeat(64),
+            )
+            .unwrap_err();
+        assert!(matches!(err, VerificationError::EntryMismatch(_)));
+    }
+
+    #[test]
+    fn verify_provenance() {
+        let instance = Instance::new();
+        let provenance: Provenance = serde_json::from_value(json!({
+            "version": 1,
+            "attestation_bundles": [],
+        }))
+        .unwrap();
+        let err = provenance
+            .verify(&instance.trust_root(), FILENAME, SHA256)
+            .unwrap_err();
+        assert!(matches!(err, VerificationError::NoAttestations));
+
+        let provenance = Provenance {
+            version: 1,
+            attestation_bundles: vec![crate::AttestationBundle {
+                publisher: github("pypa/sampleproject"),
+                attestations: vec![instance.attest("pypa/sampleproject", FILENAME, SHA256)],
+            }],
+        };
+        provenance
+            .verify(&instance.trust_root(), FILENAME, SHA256)
+            .unwrap();
+    }
+}
@@ -0,0 +1,1013 @@
+//! Local checks for distributions before they're uploaded, in the spirit of `twine check`.
+//!
+//! The registry validates uploads too, but only after the file was sent, and it doesn't verify
+//! everything that installers rely on, such as the hashes in a wheel's `RECORD`.
+
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use fs_err::tokio::File;
+use futures::StreamExt;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tar_codec::{Archive as _, Member, MemberPayload as _, TarArchive};
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, BufReader};
+use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
+use tracing::debug;
+
+use uv_distribution_filename::{DistFilename, SourceDistExtension, WheelFilename};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_install_wheel::{RecordEntry, WheelFile, read_record};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_preview::PreviewFeature;
+use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23};
+
+/// The `Metadata-Version` values defined by the core metadata specification.
+const METADATA_VERSIONS: &[&str] = &["1.0", "1.1", "1.2", "2.1", "2.2", "2.3", "2.4", "2.5"];
+
+/// The content types for which registries can render a long description.
+const DESCRIPTION_CONTENT_TYPES: &[&str] = &["text/plain", "text/x-rst", "text/markdown"];
+
+/// The Markdown variants supported by PyPI's README renderer.
+const MARKDOWN_VARIANTS: &[&str] = &["GFM", "CommonMark"];
+
+/// Failure to read a distribution while checking it.
+///
+/// Problems with the contents of a readable distribution are reported as [`Diagnostic`]s instead.
+#[derive(Error, Debug)]
+pub enum CheckError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to read wheel")]
+    Zip(#[source] async_zip::error::ZipError),
+    #[error("Failed to read: `{0}`")]
+    ZipRead(String, #[source] async_zip::error::ZipError),
+    #[error("Failed to decode source distribution")]
+    Decode(#[source] tar_codec::DecodeError),
+    #[error("Failed to read: `{0}`")]
+    Read(String, #[source] tar_codec::DecodeError),
+    #[error("Failed to read source distribution")]
+    TokioTar(#[source] io::Error),
+    #[error("Failed to read: `{0}`")]
+    TokioTarRead(String, #[source] io::Error),
+}
+
+/// How severe a [`Diagnostic`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The distribution is invalid, and registries or installers may reject it.
+    Error,
+    /// The distribution is valid, but it's likely not what was intended.
+    Warning,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// A problem found in a distribution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// A stable, kebab-case identifier for the kind of problem, e.g., `record-hash`.
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+/// Collects the [`Diagnostic`]s for a single distribution.
+#[derive(Debug, Default)]
+struct Diagnostics(Vec<Diagnostic>);
+
+impl Diagnostics {
+    fn error(&mut self, code: &'static str, message: impl Into<String>) {
+        self.0.push(Diagnostic {
+            severity: Severity::Error,
+            code,
+            message: message.into(),
+        });
+    }
+
+    fn warning(&mut self, code: &'static str, message: impl Into<String>) {
+        self.0.push(Diagnostic {
+            severity: Severity::Warning,
+            code,
+            message: message.into(),
+        });
+    }
+}
+
+/// Check a wheel or source distribution for problems before it's uploaded.
+///
+/// This checks the filename, the core metadata, the content type of the long description, the
+/// `RECORD` and `WHEEL` files of wheels, the presence of license files, and the paths of all
+/// archive members.
+///
+/// Returns the problems found, in the order they were found. Archives that can't be read at all
+/// are an error.
+pub async fn check_distribution(
+    file: &Path,
+    raw_filename: &str,
+    filename: &DistFilename,
+) -> Result<Vec<Diagnostic>, CheckError> {
+    debug!("Checking {}", raw_filename);
+    let mut diagnostics = Diagnostics::default();
+
+    check_filename(raw_filename, filename, &mut diagnostics);
+
+    match filename {
+        DistFilename::WheelFilename(wheel) => {
+            check_wheel(file, wheel, &mut diagnostics).await?;
+        }
+        DistFilename::SourceDistFilename(source_dist) => {
+            if source_dist.extension == SourceDistExtension::TarGz {
+                check_source_dist(file, filename, &mut diagnostics).await?;
+            } else {
+                // See PEP 625.
+                diagnostics.error(
+                    "sdist-format",
+                    format!(
+                        "Source distributions must be `.tar.gz` archives, found `.{}`",
+                        source_dist.extension
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(diagnostics.0)
+}
+
+/// Check that the filename uses the normalized name and version.
+fn check_filename(raw_filename: &str, filename: &DistFilename, diagnostics: &mut Diagnostics) {
+    let normalized = filename.to_string();
+    if raw_filename == normalized {
+        return;
+    }
+    let message = format!("Filename `{raw_filename}` is not normalized (expected `{normalized}`)");
+    match filename {
+        // PyPI rejects source distributions with non-normalized filenames (PEP 625).
+        DistFilename::SourceDistFilename(_) => diagnostics.error("filename", message),
+        DistFilename::WheelFilename(_) => diagnostics.warning("filename", message),
+    }
+}
+
+/// Check for duplicate paths and for paths that would be extracted outside the target directory.
+fn check_paths<'a>(paths: impl IntoIterator<Item = &'a str>, diagnostics: &mut Diagnostics) {
+    let mut seen = FxHashSet::default();
+    let mut duplicates = FxHashSet::default();
+    for path in paths {
+        if path.starts_with(['/', '\\']) || path.as_bytes().get(1) == Some(&b':') {
+            diagnostics.error("unsafe-path", format!("`{path}` is an absolute path"));
+        } else if path.split(['/', '\\']).any(|component| component == "..") {
+            diagnostics.error(
+                "unsafe-path",
+                format!("`{path}` escapes the archive root with `..`"),
+            );
+        }
+
+        // Directories can be listed with or without a trailing slash.
+        let path = path.trim_end_matches('/');
+        if !seen.insert(path) && duplicates.insert(path) {
+            diagnostics.error(
+                "duplicate-path",
+                format!("`{path}` appears more than once in the archive"),
+            );
+        }
+    }
+}
+
+/// Check the core metadata (`METADATA` or `PKG-INFO`) against the filename.
+///
+/// License files are resolved relative to `license_root` in `paths`.
+fn check_metadata(
+    contents: &[u8],
+    metadata_file: &str,
+    filename: &DistFilename,
+    paths: &FxHashSet<&str>,
+    license_root: &str,
+    diagnostics: &mut Diagnostics,
+) {
+    let metadata = match Metadata23::parse(contents) {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            diagnostics.error(
+                "metadata-invalid",
+                format!("Failed to parse `{metadata_file}`: {err}"),
+            );
+            return;
+        }
+    };
+
+    if !METADATA_VERSIONS.contains(&metadata.metadata_version.as_str()) {
+        diagnostics.error(
+            "metadata-version",
+            format!(
+                "Unknown `Metadata-Version` `{}` (expected one of: {})",
+                metadata.metadata_version,
+                METADATA_VERSIONS.join(", ")
+            ),
+        );
+    }
+
+    match PackageName::from_str(&metadata.name) {
+        Ok(name) if &name == filename.name() => {}
+        Ok(name) => diagnostics.error(
+            "name-mismatch",
+            format!(
+                "`{metadata_file}` declares name `{name}`, but the filename declares `{}`",
+                filename.name()
+            ),
+        ),
+        Err(err) => diagnostics.error(
+            "metadata-invalid",
+            format!("Invalid `Name` in `{metadata_file}`: {err}"),
+        ),
+    }
+
+    match Version::from_str(&metadata.version) {
+        Ok(version) if &version == filename.version() => {}
+        Ok(version) => diagnostics.error(
+            "version-mismatch",
+            format!(
+                "`{metadata_file}` declares version `{version}`, but the filename declares `{}`",
+                filename.version()
+            ),
+        ),
+        Err(err) => diagnostics.error(
+            "metadata-invalid",
+            format!("Invalid `Version` in `{metadata_file}`: {err}"),
+        ),
+    }
+
+    check_description(
+        metadata.description.as_deref(),
+        metadata.description_content_type.as_deref(),
+        diagnostics,
+    );
+
+    // Before Metadata 2.4 (PEP 639), `License-File` wasn't standardized, and build backends
+    // recorded the paths of license files that aren't necessarily included in the archive.
+    if !Version::from_str(&metadata.metadata_version)
+        .is_ok_and(|version| version >= Version::new([2, 4]))
+    {
+        return;
+    }
+    for license_file in &metadata.license_files {
+        let path = format!("{license_root}/{license_file}");
+        if !paths.contains(path.as_str()) {
+            diagnostics.error(
+                "license-file",
+                format!("License file `{license_file}` is declared in `{metadata_file}`, but `{path}` is missing"),
+            );
+        }
+    }
+}
+
+/// Check that registries can render the long description.
+fn check_description(
+    description: Option<&str>,
+    content_type: Option<&str>,
+    diagnostics: &mut Diagnostics,
+) {
+    if description.is_none_or(|description| description.trim().is_empty()) {
+        diagnostics.warning(
+            "description-missing",
+            "No long description found, the project page will be empty",
+        );
+        return;
+    }
+
+    let Some(content_type) = content_type else {
+        diagnostics.warning(
+            "description-content-type",
+            "`Description-Content-Type` is missing, the long description will be rendered as \
+            reStructuredText",
+        );
+        return;
+    };
+
+    // The content type is a MIME type with optional `charset` and `variant` parameters, e.g.,
+    // `text/markdown; charset=UTF-8; variant=GFM`.
+    let mut parts = content_type.split(';').map(str::trim);
+    let mime_type = parts.next().unwrap_or_default().to_ascii_lowercase();
+    if !DESCRIPTION_CONTENT_TYPES.contains(&mime_type.as_str()) {
+        diagnostics.error(
+            "description-content-type",
+            format!(
+                "Unsupported `Description-Content-Type` `{content_type}` (expected one of: {})",
+                DESCRIPTION_CONTENT_TYPES.join(", ")
+            ),
+        );
+        return;
+    }
+
+    for parameter in parts.filter(|parameter| !parameter.is_empty()) {
+        let Some((key, value)) = parameter.split_once('=') else {
+            diagnostics.error(
+                "description-content-type",
+                format!("Invalid parameter `{parameter}` in `Description-Content-Type`"),
+            );
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        match key.trim().to_ascii_lowercase().as_str() {
+            "charset" => {
+                if !value.eq_ignore_ascii_case("utf-8") {
+                    diagnostics.warning(
+                        "description-content-type",
+                        format!(
+                            "The long description uses charset `{value}`, but only `UTF-8` is \
+                            supported"
+                        ),
+                    );
+                }
+            }
+            "variant" if mime_type == "text/markdown" => {
+                if !MARKDOWN_VARIANTS.contains(&value) {
+                    diagnostics.error(
+                        "description-content-type",
+                        format!(
+                            "Unsupported Markdown variant `{value}` (expected one of: {})",
+                            MARKDOWN_VARIANTS.join(", ")
+                        ),
+                    );
+                }
+            }
+            _ => {
+                diagnostics.warning(
+                    "description-content-type",
+                    format!("Unknown parameter `{parameter}` in `Description-Content-Type`"),
+                );
+            }
+        }
+    }
+}
+
+/// Check the contents of a wheel.
+async fn check_wheel(
+    file: &Path,
+    filename: &WheelFilename,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), CheckError> {
+    let reader = futures::io::BufReader::new(File::open(file).await?.compat());
+    let mut zip = async_zip::base::read::seek::ZipFileReader::new(reader)
+        .await
+        .map_err(CheckError::Zip)?;
+
+    // The files in the archive, with their index and whether they are directories.
+    let mut entries = Vec::new();
+    for (index, entry) in zip.file().entries().iter().enumerate() {
+        let Ok(path) = entry.filename().as_str() else {
+            diagnostics.error(
+                "unsafe-path",
+                format!(
+                    "`{}` is not valid UTF-8",
+                    String::from_utf8_lossy(entry.filename().as_bytes())
+                ),
+            );
+            continue;
+        };
+        let is_dir = entry.dir().map_err(CheckError::Zip)?;
+        entries.push((index, path.to_string(), is_dir));
+    }
+    check_paths(
+        entries.iter().map(|(_, path, _)| path.as_str()),
+        diagnostics,
+    );
+
+    let (_, dist_info_prefix) = match uv_metadata::find_archive_dist_info(
+        filename,
+        entries
+            .iter()
+            .map(|(index, path, _)| (*index, path.as_str())),
+    ) {
+        Ok(dist_info) => dist_info,
+        Err(err) => {
+            diagnostics.error("metadata-missing", err.to_string());
+            return Ok(());
+        }
+    };
+    let dist_info_dir = format!("{dist_info_prefix}.dist-info");
+    let index_of = |name: &str| {
+        let path = format!("{dist_info_dir}/{name}");
+        entries
+            .iter()
+            .find(|(_, entry, _)| *entry == path)
+            .map(|(index, _, _)| *index)
+    };
+    let paths: FxHashSet<&str> = entries.iter().map(|(_, path, _)| path.as_str()).collect();
+
+    // `find_archive_dist_info` ensures that the `METADATA` file exists.
+    let metadata_file = format!("{dist_info_dir}/METADATA");
+    let metadata = read_zip_entry(&mut zip, index_of("METADATA").unwrap(), &metadata_file).await?;
+    check_metadata(
+        &metadata,
+        &metadata_file,
+        &DistFilename::WheelFilename(filename.clone()),
+        &paths,
+        &format!("{dist_info_dir}/licenses"),
+        diagnostics,
+    );
+
+    let wheel_file = format!("{dist_info_dir}/WHEEL");
+    if let Some(index) = index_of("WHEEL") {
+        let contents = read_zip_entry(&mut zip, index, &wheel_file).await?;
+        check_wheel_tags(&contents, &wheel_file, filename, diagnostics);
+    } else {
+        diagnostics.error("wheel-missing", format!("`{wheel_file}` is missing"));
+    }
+
+    let record_file = format!("{dist_info_dir}/RECORD");
+    let Some(record_index) = index_of("RECORD") else {
+        diagnostics.error("record-missing", format!("`{record_file}` is missing"));
+        return Ok(());
+    };
+    let contents = read_zip_entry(&mut zip, record_index, &record_file).await?;
+    let record = match read_record(contents.as_slice()) {
+        Ok(record) => record,
+        Err(err) => {
+            diagnostics.error(
+                "record-invalid",
+                format!("Failed to parse `{record_file}`: {err}"),
+            );
+            return Ok(());
+        }
+    };
+    let mut record: FxHashMap<String, RecordEntry> = record
+        .into_iter()
+        .map(|entry| (entry.path.clone(), entry))
+        .collect();
+
+    // The `RECORD` can't contain its own hash, and signatures of the `RECORD` aren't listed.
+    let unrecorded = [
+        record_file.clone(),
+        format!("{dist_info_dir}/RECORD.jws"),
+        format!("{dist_info_dir}/RECORD.p7s"),
+    ];
+    for (index, path, is_dir) in &entries {
+        if *is_dir {
+            continue;
+        }
+        let entry = record.remove(path);
+        if unrecorded.contains(path) {
+            continue;
+        }
+        let Some(entry) = entry else {
+            diagnostics.error(
+                "record-unlisted",
+                format!("`{path}` is not listed in `{record_file}`"),
+            );
+            continue;
+        };
+        check_record_entry(&mut zip, *index, &entry, diagnostics).await?;
+    }
+
+    for path in record.into_keys().sorted() {
+        diagnostics.error(
+            "record-missing-file",
+            format!("`{path}` is listed in `{record_file}`, but missing from the archive"),
+        );
+    }
+
+    Ok(())
+}
+
+/// Check that the tags in the `WHEEL` file match the tags in the filename.
+fn check_wheel_tags(
+    contents: &[u8],
+    wheel_file: &str,
+    filename: &WheelFilename,
+    diagnostics: &mut Diagnostics,
+) {
+    let wheel = match std::str::from_utf8(contents)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| WheelFile::parse(contents).map_err(|err| err.to_string()))
+    {
+        Ok(wheel) => wheel,
+        Err(err) => {
+            diagnostics.error(
+                "wheel-invalid",
+                format!("Failed to parse `{wheel_file}`: {err}"),
+            );
+            return;
+        }
+    };
+
+    let Some(tags) = wheel.tags() else {
+        diagnostics.error(
+            "wheel-tags",
+            format!("`{wheel_file}` does not declare any tags"),
+        );
+        return;
+    };
+    let declared: FxHashSet<&str> = tags.iter().map(String::as_str).collect();
+
+    // A compressed tag set in the filename, e.g., `py2.py3-none-any`, expands to the product of
+    // its parts.
+    let expected: FxHashSet<String> = filename
+        .python_tags()
+        .iter()
+        .cartesian_product(filename.abi_tags())
+        .cartesian_product(filename.platform_tags())
+        .map(|((python, abi), platform)| format!("{python}-{abi}-{platform}"))
+        .collect();
+
+    let missing = expected
+        .iter()
+        .filter(|tag| !declared.contains(tag.as_str()))
+        .sorted()
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        diagnostics.error(
+            "wheel-tags",
+            format!(
+                "The filename declares tags that are missing from `{wheel_file}`: `{}`",
+                missing.iter().join("`, `")
+            ),
+        );
+    }
+
+    let extra = declared
+        .iter()
+        .filter(|tag| !expected.contains(**tag))
+        .sorted()
+        .collect::<Vec<_>>();
+    if !extra.is_empty() {
+        diagnostics.error(
+            "wheel-tags",
+            format!(
+                "`{wheel_file}` declares tags that are missing from the filename: `{}`",
+                extra.iter().join("`, `")
+            ),
+        );
+    }
+}
+
+/// Check the hash and size of a file against its `RECORD` entry.
+async fn check_record_entry<R>(
+    zip: &mut async_zip::base::read::seek::ZipFileReader<R>,
+    index: usize,
+    entry: &RecordEntry,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), CheckError>
+where
+    R: futures::AsyncBufRead + futures::AsyncSeek + Unpin,
+{
+    let path = &entry.path;
+    let Some(hash) = entry.hash.as_deref().filter(|hash| !hash.is_empty()) else {
+        diagnostics.error("record-hash", format!("`{path}` has no hash in `RECORD`"));
+        return Ok(());
+    };
+    let Some((algorithm, expected)) = hash.split_once('=') else {
+        diagnostics.error(
+            "record-hash",
+            format!("`{path}` has an invalid hash in `RECORD`: `{hash}`"),
+        );
+        return Ok(());
+    };
+    // Only the SHA-2 family is allowed; `hashlib`'s `blake2b` has a different digest size than
+    // ours, so we don't accept it either.
+    let algorithm = match algorithm {
+        "sha256" => HashAlgorithm::Sha256,
+        "sha384" => HashAlgorithm::Sha384,
+        "sha512" => HashAlgorithm::Sha512,
+        "md5" | "sha1" => {
+            diagnostics.error(
+                "record-hash",
+                format!("`{path}` uses the insecure hash algorithm `{algorithm}` in `RECORD`"),
+            );
+            return Ok(());
+        }
+        _ => {
+            diagnostics.error(
+                "record-hash",
+                format!("`{path}` uses the unsupported hash algorithm `{algorithm}` in `RECORD`"),
+            );
+            return Ok(());
+        }
+    };
+    let Ok(expected) = BASE64_URL_SAFE_NO_PAD.decode(expected.trim_end_matches('=')) else {
+        diagnostics.error(
+            "record-hash",
+            format!("`{path}` has an invalid hash in `RECORD`: `{hash}`"),
+        );
+        return Ok(());
+    };
+
+    let reader = zip
+        .reader_with_entry(index)
+        .await
+        .map_err(|err| CheckError::ZipRead(path.clone(), err))?;
+    let mut hashers = vec![Hasher::from(algorithm)];
+    let mut hash_reader = HashReader::new(reader.compat(), &mut hashers);
+    hash_reader.finish().await?;
+    let size = hash_reader.bytes_read();
+    let digest = HashDigest::from(hashers.remove(0));
+
+    if *digest.digest != hex::encode(expected) {
+        diagnostics.error(
+            "record-hash",
+            format!("The {algorithm} hash of `{path}` does not match `RECORD`"),
+        );
+    }
+    if let Some(expected_size) = entry.size
+        && expected_size != size
+    {
+        diagnostics.error(
+            "record-size",
+            format!("`{path}` is {size} bytes, but `RECORD` declares {expected_size} bytes"),
+        );
+    }
+
+    Ok(())
+}
+
+/// Read a small file, such as `METADATA`, from a wheel.
+async fn read_zip_entry<R>(
+    zip: &mut async_zip::base::read::seek::ZipFileReader<R>,
+    index: usize,
+    path: &str,
+) -> Result<Vec<u8>, CheckError>
+where
+    R: futures::AsyncBufRead + futures::AsyncSeek + Unpin,
+{
+    let mut contents = Vec::new();
+    zip.reader_with_entry(index)
+        .await
+        .map_err(|err| CheckError::ZipRead(path.to_string(), err))?
+        .read_to_end_checked(&mut contents)
+        .await
+        .map_err(|err| CheckError::ZipRead(path.to_string(), err))?;
+    Ok(contents)
+}
+
+/// Check the contents of a `.tar.gz` source distribution.
+async fn check_source_dist(
+    file: &Path,
+    filename: &DistFilename,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), CheckError> {
+    // The archive must contain a single top-level directory named after the distribution.
+    let top_level = format!(
+        "{}-{}",
+        filename.name().as_dist_info_name(),
+        filename.version()
+    );
+    let pkg_info_file = format!("{top_level}/PKG-INFO");
+
+    let (paths, pkg_info) = if uv_preview::is_enabled(PreviewFeature::TarCodec) {
+        source_dist_members_tar_codec(file, &pkg_info_file).await?
+    } else {
+        source_dist_members_tokio_tar(file, &pkg_info_file).await?
+    };
+    check_paths(paths.iter().map(String::as_str), diagnostics);
+
+    let outside = paths
+        .iter()
+        .filter(|path| {
+            path.split('/')
+                .next()
+                .is_none_or(|component| component != top_level)
+        })
+        .collect::<Vec<_>>();
+    if !outside.is_empty() {
+        diagnostics.error(
+            "sdist-layout",
+            format!(
+                "Source distributions must contain a single top-level directory `{top_level}`, \
+                but found: `{}`",
+                outside.iter().join("`, `")
+            ),
+        );
+    }
+
+    let Some(pkg_info) = pkg_info else {
+        diagnostics.error("metadata-missing", format!("`{pkg_info_file}` is missing"));
+        return Ok(());
+    };
+    let paths: FxHashSet<&str> = paths.iter().map(String::as_str).collect();
+    check_metadata(
+        &pkg_info,
+        &pkg_info_file,
+        filename,
+        &paths,
+        &top_level,
+        diagnostics,
+    );
+
+    Ok(())
+}
+
+/// Read the paths of all members of a source distribution, and the contents of its `PKG-INFO`.
+async fn source_dist_members_tokio_tar(
+    file: &Path,
+    pkg_info_file: &str,
+) -> Result<(Vec<String>, Option<Vec<u8>>), CheckError> {
+    let reader = BufReader::new(File::open(&file).await?);
+    let decoded = async_compression::tokio::bufread::GzipDecoder::new(reader);
+    let mut archive = tokio_tar::Archive::new(decoded);
+    let mut entries = archive.entries().map_err(CheckError::TokioTar)?;
+
+    let mut paths = Vec::new();
+    let mut pkg_info = None;
+    while let Some(mut entry) = entries
+        .next()
+        .await
+        .transpose()
+        .map_err(CheckError::TokioTar)?
+    {
+        let path = entry
+            .path()
+            .map_err(CheckError::TokioTar)?
+            .to_string_lossy()
+            .to_string();
+        if path == pkg_info_file {
+            let mut buffer = Vec::new();
+            // We have to read while iterating or the entry is empty as we're beyond it in the file.
+            entry
+                .read_to_end(&mut buffer)
+                .await
+                .map_err(|err| CheckError::TokioTarRead(path.clone(), err))?;
+            pkg_info = Some(buffer);
+        }
+        paths.push(path);
+    }
+
+    Ok((paths, pkg_info))
+}
+
+/// Read the paths of all members of a source distribution, and the contents of its `PKG-INFO`.
+async fn source_dist_members_tar_codec(
+    file: &Path,
+    pkg_info_file: &str,
+) -> Result<(Vec<String>, Option<Vec<u8>>), CheckError> {
+    let reader = BufReader::new(File::open(&file).await?);
+    let decoded = async_compression::tokio::bufread::GzipDecoder::new(reader);
+    let mut members = TarArchive::new(decoded).members();
+
+    let mut paths = Vec::new();
+    let mut pkg_info = None;
+    while let Some(member) = members.next().await.map_err(CheckError::Decode)? {
+        let path = member.metadata().path.clone();
+        if path == pkg_info_file
+            && let Member::File { mut payload, .. } | Member::HardLink { mut payload, .. } = member
+        {
+            let mut buffer = Vec::new();
+            let mut chunk = Vec::new();
+            while payload
+                .next_chunk(&mut chunk, 8 * 1024)
+                .await
+                .map_err(|err| CheckError::Read(path.clone(), err))?
+            {
+                buffer.extend_from_slice(&chunk);
+            }
+            pkg_info = Some(buffer);
+        }
+        paths.push(path);
+    }
+
+    Ok((paths, pkg_info))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_zip::base::write::ZipFileWriter;
+    use async_zip::{Compression, ZipEntryBuilder};
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use insta::{allow_duplicates, assert_snapshot};
+    use itertools::Itertools;
+    use tempfile::NamedTempFile;
+
+    use uv_distribution_filename::DistFilename;
+    use uv_pypi_types::HashDigest;
+
+    use super::check_distribution;
+    use crate::tests::{TAR_BACKENDS, source_dist};
+
+    const METADATA: &str = "Metadata-Version: 2.4\nName: example\nVersion: 1.0\nLicense-File: LICENSE\nDescription-Content-Type: text/markdown\n\n# Example\n";
+    const WHEEL: &str =
+        "Wheel-Version: 1.0\nGenerator: test\nRoot-Is-Purelib: true\nTag: py3-none-any\n";
+
+    async fn wheel(entries: &[(&str, &[u8])]) -> NamedTempFile {
+        let mut archive = ZipFileWriter::new(futures::io::Cursor::new(Vec::new()));
+        for (path, contents) in entries {
+            let entry = ZipEntryBuilder::new((*path).to_string().into(), Compression::Deflate);
+            archive
+                .write_entry_whole(entry, contents)
+                .await
+                .expect("test archive entry should be written");
+        }
+        let bytes = archive
+            .close()
+            .await
+            .expect("test archive should finish")
+            .into_inner();
+
+        let file = NamedTempFile::new().expect("temporary file should be created");
+        fs_err::write(file.path(), bytes).expect("test archive should be written");
+        file
+    }
+
+    /// A `RECORD` line with the SHA-256 hash and size of the contents.
+    async fn record_line(path: &str, contents: &[u8]) -> String {
+        let mut hashers = vec![super::Hasher::from(uv_pypi_types::HashAlgorithm::Sha256)];
+        super::HashReader::new(contents, &mut hashers)
+            .finish()
+            .await
+            .unwrap();
+        let digest = HashDigest::from(hashers.remove(0));
+        let digest = BASE64_URL_SAFE_NO_PAD.encode(hex::decode(&*digest.digest).unwrap());
+        format!("{path},sha256={digest},{}", contents.len())
+    }
+
+    async fn check(file: &NamedTempFile, raw_filename: &str) -> String {
+        let filename = DistFilename::try_from_normalized_filename(raw_filename).unwrap();
+        check_distribution(file.path(), raw_filename, &filename)
+            .await
+            .unwrap()
+            .iter()
+            .join("\n")
+    }
+
+    #[tokio::test]
+    async fn check_valid_wheel() {
+        let module = b"print('hello')\n".as_slice();
+        let record = [
+            record_line("example/__init__.py", module).await,
+            record_line("example-1.0.dist-info/METADATA", METADATA.as_bytes()).await,
+            record_line("example-1.0.dist-info/WHEEL", WHEEL.as_bytes()).await,
+            record_line("example-1.0.dist-info/licenses/LICENSE", b"MIT").await,
+            "example-1.0.dist-info/RECORD,,".to_string(),
+        ]
+        .join("\n");
+        let file = wheel(&[
+            ("example/__init__.py", module),
+            ("example-1.0.dist-info/METADATA", METADATA.as_bytes()),
+            ("example-1.0.dist-info/WHEEL", WHEEL.as_bytes()),
+            ("example-1.0.dist-info/licenses/LICENSE", b"MIT"),
+            ("example-1.0.dist-info/RECORD", record.as_bytes()),
+        ])
+        .await;
+
+        assert_snapshot!(check(&file, "example-1.0-py3-none-any.whl").await, @"");
+    }
+
+    #[tokio::test]
+    async fn check_invalid_wheel() {
+        let metadata = "Metadata-Version: 3.0\nName: other\nVersion: 1.0\nLicense-File: LICENSE\nDescription-Content-Type: text/markdown; variant=Original\n\n# Example\n";
+        let wheel_file =
+            "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py2-none-any\nTag: py3-none-any\n";
+        let record = [
+            record_line("example/__init__.py", b"print('goodbye')\n").await,
+            "example/util.py,md5=1B2M2Y8AsgTpgAmY7PhCfg,0".to_string(),
+            record_line("example/missing.py", b"").await,
+            record_line("example-1.0.dist-info/METADATA", metadata.as_bytes()).await,
+            record_line("example-1.0.dist-info/WHEEL", wheel_file.as_bytes()).await,
+            "example-1.0.dist-info/RECORD,,".to_string(),
+        ]
+        .join("\n");
+        let file = wheel(&[
+            ("example/__init__.py", b"print('hello')\n"),
+            ("example/util.py", b""),
+            ("example/extra.py", b""),
+            ("../escape.py", b""),
+            ("example-1.0.dist-info/METADATA", metadata.as_bytes()),
+            ("example-1.0.dist-info/WHEEL", wheel_file.as_bytes()),
+            ("example-1.0.dist-info/RECORD", record.as_bytes()),
+        ])
+        .await;
+
+        assert_snapshot!(check(&file, "example-1.0-py3-none-any.whl").await, @r"
+        error[unsafe-path]: `../escape.py` escapes the archive root with `..`
+        error[metadata-version]: Unknown `Metadata-Version` `3.0` (expected one of: 1.0, 1.1, 1.2, 2.1, 2.2, 2.3, 2.4, 2.5)
+        error[name-mismatch]: `example-1.0.dist-info/METADATA` declares name `other`, but the filename declares `example`
+        error[description-content-type]: Unsupported Markdown variant `Original` (expected one of: GFM, CommonMark)
+        error[license-file]: License file `LICENSE` is declared in `example-1.0.dist-info/METADATA`, but `example-1.0.dist-info/licenses/LICENSE` is missing
+        error[wheel-tags]: `example-1.0.dist-info/WHEEL` declares tags that are missing from the filename: `py2-none-any`
+        error[record-hash]: The sha256 hash of `example/__init__.py` does not match `RECORD`
+        error[record-size]: `example/__init__.py` is 15 bytes, but `RECORD` declares 17 bytes
+        error[record-hash]: `example/util.py` uses the insecure hash algorithm `md5` in `RECORD`
+        error[record-unlisted]: `example/extra.py` is not listed in `example-1.0.dist-info/RECORD`
+        error[record-unlisted]: `../escape.py` is not listed in `example-1.0.dist-info/RECORD`
+        error[record-missing-file]: `example/missing.py` is listed in `example-1.0.dist-info/RECORD`, but missing from the archive
+        ");
+    }
+
+    /// License files are only checked as of Metadata 2.4, which standardized `License-File`.
+    #[tokio::test]
+    async fn check_wheel_legacy_license_file() {
+        let metadata = "Metadata-Version: 2.1\nName: example\nVersion: 1.0\nLicense-File: LICENSE\nDescription-Content-Type: text/markdown\n\n# Example\n";
+        let record = [
+            record_line("example-1.0.dist-info/METADATA", metadata.as_bytes()).await,
+            record_line("example-1.0.dist-info/WHEEL", WHEEL.as_bytes()).await,
+            record_line("example-1.0.dist-info/LICENSE", b"MIT").await,
+            "example-1.0.dist-info/RECORD,,".to_string(),
+        ]
+        .join("\n");
+        let file = wheel(&[
+            ("example-1.0.dist-info/METADATA", metadata.as_bytes()),
+            ("example-1.0.dist-info/WHEEL", WHEEL.as_bytes()),
+            ("example-1.0.dist-info/LICENSE", b"MIT"),
+            ("example-1.0.dist-info/RECORD", record.as_bytes()),
+        ])
+        .await;
+
+        assert_snapshot!(check(&file, "example-1.0-py3-none-any.whl").await, @"");
+    }
+
+    #[tokio::test]
+    async fn check_wheel_without_record() {
+        let file = wheel(&[
+            ("example/__init__.py", b""),
+            ("example/__init__.py", b""),
+            ("example-1.0.dist-info/METADATA", METADATA.as_bytes()),
+        ])
+        .await;
+
+        assert_snapshot!(check(&file, "Example-1.0-py3-none-any.whl").await, @r"
+        warning[filename]: Filename `Example-1.0-py3-none-any.whl` is not normalized (expected `example-1.0-py3-none-any.whl`)
+        error[duplicate-path]: `example/__init__.py` appears more than once in the archive
+        error[license-file]: License file `LICENSE` is declared in `example-1.0.dist-info/METADATA`, but `example-1.0.dist-info/licenses/LICENSE` is missing
+        error[wheel-missing]: `example-1.0.dist-info/WHEEL` is missing
+        error[record-missing]: `example-1.0.dist-info/RECORD` is missing
+        ");
+    }
+
+    #[tokio::test]
+    async fn check_valid_source_dist() {
+        let file = source_dist(&[
+            ("example-1.0/PKG-INFO", METADATA.as_bytes()),
+            ("example-1.0/LICENSE", b"MIT"),
+            ("example-1.0/pyproject.toml", b""),
+        ])
+        .await;
+
+        for features in TAR_BACKENDS {
+            let _preview = uv_preview::test::with_features(features);
+            let diagnostics = check(&file, "example-1.0.tar.gz").await;
+            allow_duplicates! {
+                assert_snapshot!(diagnostics, @"");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn check_invalid_source_dist() {
+        let metadata = "Metadata-Version: 2.4\nName: example\nVersion: 2.0\nLicense-File: LICENSE\nDescription-Content-Type: text/html\n\n<h1>Example</h1>\n";
+        let file = source_dist(&[
+            ("example-1.0/PKG-INFO", metadata.as_bytes()),
+            ("example-1.0/pyproject.toml", b""),
+            ("other/setup.py", b""),
+        ])
+        .await;
+
+        for features in TAR_BACKENDS {
+            let _preview = uv_preview::test::with_features(features);
+            let diagnostics = check(&file, "Example-1.0.tar.gz").await;
+            allow_duplicates! {
+                assert_snapshot!(diagnostics, @r"
+                error[filename]: Filename `Example-1.0.tar.gz` is not normalized (expected `example-1.0.tar.gz`)
+                error[sdist-layout]: Source distributions must contain a single top-level directory `example-1.0`, but found: `other/setup.py`
+                error[version-mismatch]: `example-1.0/PKG-INFO` declares version `2.0`, but the filename declares `1.0`
+                error[description-content-type]: Unsupported `Description-Content-Type` `text/html` (expected one of: text/plain, text/x-rst, text/markdown)
+                error[license-file]: License file `LICENSE` is declared in `example-1.0/PKG-INFO`, but `example-1.0/LICENSE` is missing
+                ");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn check_source_dist_without_description() {
+        let file = source_dist(&[(
+            "example-1.0/PKG-INFO",
+            b"Metadata-Version: 2.4\nName: example\nVersion: 1.0\n",
+        )])
+        .await;
+
+        for features in TAR_BACKENDS {
+            let _preview = uv_preview::test::with_features(features);
+            let diagnostics = check(&file, "example-1.0.tar.gz").await;
+            allow_duplicates! {
+                assert_snapshot!(diagnostics, @"warning[description-missing]: No long description found, the project page will be empty");
+            }
+        }
+    }
+}
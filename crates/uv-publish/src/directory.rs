@@ -0,0 +1,665 @@
+//! Publishing to a local directory that is served as a static index.
+//!
+//! Each project gets a directory with its distributions, a PEP 658 `.metadata` file for each wheel,
+//! and the project page in both the PEP 503 HTML (`index.html`) and the PEP 691 JSON
+//! (`index.json`) format. The root of the directory lists all projects in the same two formats.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use fs_err::tokio::File;
+use serde::Serialize;
+use thiserror::Error;
+use tokio::io::{AsyncRead, BufReader};
+use tracing::debug;
+
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{LockedFile, LockedFileError, LockedFileMode, Simplified};
+use uv_metadata::read_metadata_async_seek;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23};
+
+use crate::{PublishError, PublishPrepareError, UploadDistribution, metadata};
+
+/// The version of the simple API that the generated pages implement.
+const API_VERSION: &str = "1.1";
+
+/// Failure to update a local index directory.
+#[derive(Error, Debug)]
+pub enum DirectoryError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to lock the index directory")]
+    Lock(#[from] LockedFileError),
+    #[error("Failed to read the metadata of `{}`", _0.user_display())]
+    Metadata(PathBuf, #[source] Box<PublishPrepareError>),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl DirectoryError {
+    fn into_publish_error(self, root: &Path) -> PublishError {
+        PublishError::Directory(root.to_path_buf(), Box::new(self))
+    }
+}
+
+/// Check whether a distribution is already present in a local index directory.
+///
+/// Returns `true` if the identical file exists, and an error if a file with the same name but
+/// different contents exists.
+pub async fn check_directory(
+    root: &Path,
+    group: &UploadDistribution,
+) -> Result<bool, PublishError> {
+    let target = project_dir(root, &group.filename).join(&group.raw_filename);
+    if !target.is_file() {
+        return Ok(false);
+    }
+
+    let local = sha256_file(&group.file)
+        .await
+        .map_err(|err| DirectoryError::from(err).into_publish_error(root))?;
+    let existing = sha256_file(&target)
+        .await
+        .map_err(|err| DirectoryError::from(err).into_publish_error(root))?;
+    if local.sha256 == existing.sha256 {
+        debug!(
+            "Found identical file in the index directory: `{}`",
+            target.user_display()
+        );
+        Ok(true)
+    } else {
+        Err(PublishError::HashMismatch {
+            filename: Box::new(group.filename.clone()),
+            hash_algorithm: HashAlgorithm::Sha256,
+            local: local.sha256,
+            remote: existing.sha256,
+        })
+    }
+}
+
+/// Copy a distribution into a local index directory.
+///
+/// For wheels, the core metadata is written next to the file as specified in PEP 658. Attestations
+/// are copied next to the file, too. The project pages are not updated; call
+/// [`update_directory_index`] once all files are copied.
+///
+/// Returns `false` if the identical file already exists.
+pub async fn publish_to_directory(
+    root: &Path,
+    group: &UploadDistribution,
+) -> Result<bool, PublishError> {
+    let _lock = lock(root)
+        .await
+        .map_err(|err| err.into_publish_error(root))?;
+
+    if check_directory(root, group).await? {
+        return Ok(false);
+    }
+
+    let project_dir = project_dir(root, &group.filename);
+    copy_distribution(&project_dir, group)
+        .await
+        .map_err(|err| err.into_publish_error(root))?;
+    Ok(true)
+}
+
+/// Regenerate the pages of the given projects and the project list in a local index directory.
+pub async fn update_directory_index<'a>(
+    root: &Path,
+    projects: impl IntoIterator<Item = &'a PackageName>,
+) -> Result<(), PublishError> {
+    let _lock = lock(root)
+        .await
+        .map_err(|err| err.into_publish_error(root))?;
+
+    for project in projects {
+        write_project_pages(&root.join(project.as_str()), project)
+            .await
+            .map_err(|err| err.into_publish_error(root))?;
+    }
+    write_root_pages(root)
+        .await
+        .map_err(|err| err.into_publish_error(root))?;
+    Ok(())
+}
+
+/// The directory of a project in the index, named after the normalized project name.
+fn project_dir(root: &Path, filename: &DistFilename) -> PathBuf {
+    root.join(filename.name().as_str())
+}
+
+/// Acquire an exclusive lock on the index directory, so concurrent publishes don't overwrite each
+/// other's pages.
+async fn lock(root: &Path) -> Result<LockedFile, DirectoryError> {
+    fs_err::tokio::create_dir_all(root).await?;
+    Ok(LockedFile::acquire(
+        root.join(".lock"),
+        LockedFileMode::Exclusive,
+        root.user_display(),
+    )
+    .await?)
+}
+
+async fn copy_distribution(
+    project_dir: &Path,
+    group: &UploadDistribution,
+) -> Result<(), DirectoryError> {
+    fs_err::tokio::create_dir_all(project_dir).await?;
+
+    // Write the metadata first, so the index never references a wheel without its metadata.
+    if let DistFilename::WheelFilename(wheel) = &group.filename {
+        let reader = BufReader::new(File::open(&group.file).await?);
+        let contents = read_metadata_async_seek(wheel, reader)
+            .await
+            .map_err(|err| {
+                DirectoryError::Metadata(
+                    group.file.clone(),
+                    Box::new(PublishPrepareError::from(err)),
+                )
+            })?;
+        let path = project_dir.join(format!("{}.metadata", group.raw_filename));
+        uv_fs::write_atomic(&path, contents).await?;
+    }
+
+    for attestation in &group.attestations {
+        let Some(name) = attestation.file_name() else {
+            continue;
+        };
+        let target = project_dir.join(name);
+        debug!(
+            "Copying attestation `{}` to `{}`",
+            attestation.user_display(),
+            target.user_display()
+        );
+        let attestation = attestation.clone();
+        tokio::task::spawn_blocking(move || uv_fs::copy_atomic_sync(attestation, target))
+            .await
+            .map_err(io::Error::other)??;
+    }
+
+    let target = project_dir.join(&group.raw_filename);
+    debug!(
+        "Copying `{}` to `{}`",
+        group.file.user_display(),
+        target.user_display()
+    );
+    let file = group.file.clone();
+    tokio::task::spawn_blocking(move || uv_fs::copy_atomic_sync(file, target))
+        .await
+        .map_err(io::Error::other)??;
+    Ok(())
+}
+
+/// A distribution as listed on a project page.
+#[derive(Debug)]
+pub(crate) struct IndexFile {
+    pub(crate) filename: String,
+    version: Version,
+    sha256: String,
+    size: u64,
+    requires_python: Option<String>,
+    /// The SHA-256 of the PEP 658 metadata file, if any.
+    core_metadata: Option<String>,
+}
+
+/// Hash a distribution and read the fields listed on its project page.
+///
+/// For wheels, `core_metadata` are the contents of the PEP 658 metadata file, while source
+/// distributions are read for their metadata instead.
+pub(crate) async fn index_file(
+    path: &Path,
+    raw_filename: String,
+    filename: &DistFilename,
+    core_metadata: Option<&[u8]>,
+) -> Result<IndexFile, DirectoryError> {
+    let HashedFile { sha256, size } = sha256_file(path).await?;
+
+    let (requires_python, core_metadata) = if let Some(contents) = core_metadata {
+        let metadata = Metadata23::parse(contents).map_err(|err| {
+            DirectoryError::Metadata(path.to_path_buf(), Box::new(PublishPrepareError::from(err)))
+        })?;
+        let HashedFile {
+            sha256: metadata_sha256,
+            ..
+        } = sha256_reader(contents).await?;
+        (metadata.requires_python, Some(metadata_sha256))
+    } else {
+        let metadata = metadata(path, filename)
+            .await
+            .map_err(|err| DirectoryError::Metadata(path.to_path_buf(), Box::new(err)))?;
+        (metadata.requires_python, None)
+    };
+
+    Ok(IndexFile {
+        version: filename.version().clone(),
+        filename: raw_filename,
+        sha256,
+        size,
+        requires_python,
+        core_metadata,
+    })
+}
+
+/// Collect the distributions of a project from its directory.
+async fn read_project_files(
+    project_dir: &Path,
+    project: &PackageName,
+) -> Result<Vec<IndexFile>, DirectoryError> {
+    let mut files = Vec::new();
+    let mut entries = fs_err::tokio::read_dir(project_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let Some(raw_filename) = entry.file_name().to_str().map(ToString::to_string) else {
+            continue;
+        };
+        let Some(filename) = DistFilename::try_from_filename(&raw_filename, project) else {
+            continue;
+        };
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let core_metadata = match &filename {
+            DistFilename::WheelFilename(wheel) => {
+                let metadata_path = project_dir.join(format!("{raw_filename}.metadata"));
+                let contents = match fs_err::tokio::read(&metadata_path).await {
+                    Ok(contents) => contents,
+                    // Add the metadata for wheels that were copied into the directory manually.
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                        let reader = BufReader::new(File::open(&path).await?);
+                        let contents =
+                            read_metadata_async_seek(wheel, reader)
+                                .await
+                                .map_err(|err| {
+                                    DirectoryError::Metadata(
+                                        path.clone(),
+                                        Box::new(PublishPrepareError::from(err)),
+                                    )
+                                })?;
+                        uv_fs::write_atomic(&metadata_path, &contents).await?;
+                        contents
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+                Some(contents)
+            }
+            DistFilename::SourceDistFilename(_) => None,
+        };
+
+        files.push(index_file(&path, raw_filename, &filename, core_metadata.as_deref()).await?);
+    }
+    files.sort_by(|left, right| left.filename.cmp(&right.filename));
+    Ok(files)
+}
+
+async fn write_project_pages(
+    project_dir: &Path,
+    project: &PackageName,
+) -> Result<(), DirectoryError> {
+    let files = read_project_files(project_dir, project).await?;
+    debug!(
+        "Writing the project page for {project} with {} files",
+        files.len()
+    );
+
+    let (html, json) = render_project_pages(project, &files)?;
+    uv_fs::write_atomic(project_dir.join("index.html"), html).await?;
+    uv_fs::write_atomic(project_dir.join("index.json"), json).await?;
+    Ok(())
+}
+
+/// Render the page of a project in the PEP 503 HTML and the PEP 691 JSON format.
+///
+/// The files are linked relative to the page, i.e., they're expected next to it.
+pub(crate) fn render_project_pages(
+    project: &PackageName,
+    files: &[IndexFile],
+) -> Result<(String, String), serde_json::Error> {
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>").unwrap();
+    writeln!(html, "<html>").unwrap();
+    writeln!(html, "  <head>").unwrap();
+    writeln!(
+        html,
+        "    <meta name=\"pypi:repository-version\" content=\"{API_VERSION}\">"
+    )
+    .unwrap();
+    writeln!(html, "    <title>Links for {project}</title>").unwrap();
+    writeln!(html, "  </head>").unwrap();
+    writeln!(html, "  <body>").unwrap();
+    writeln!(html, "    <h1>Links for {project}</h1>").unwrap();
+    for file in files {
+        let filename = html_escape::encode_double_quoted_attribute(&file.filename);
+        write!(html, "    <a href=\"{filename}#sha256={}\"", file.sha256).unwrap();
+        if let Some(requires_python) = &file.requires_python {
+            write!(
+                html,
+                " data-requires-python=\"{}\"",
+                html_escape::encode_double_quoted_attribute(requires_python)
+            )
+            .unwrap();
+        }
+        if let Some(core_metadata) = &file.core_metadata {
+            write!(
+                html,
+                " data-dist-info-metadata=\"sha256={core_metadata}\" \
+                data-core-metadata=\"sha256={core_metadata}\""
+            )
+            .unwrap();
+        }
+        writeln!(
+            html,
+            ">{}</a><br />",
+            html_escape::encode_text(&file.filename)
+        )
+        .unwrap();
+    }
+    writeln!(html, "  </body>").unwrap();
+    writeln!(html, "</html>").unwrap();
+
+    let json = ProjectPage {
+        meta: Meta::default(),
+        name: project,
+        versions: files.iter().map(|file| &file.version).collect(),
+        files: files
+            .iter()
+            .map(|file| ProjectFile {
+                filename: &file.filename,
+                url: &file.filename,
+                hashes: Hashes {
+                    sha256: &file.sha256,
+                },
+                requires_python: file.requires_python.as_deref(),
+                core_metadata: CoreMetadata::from(file.core_metadata.as_deref()),
+                dist_info_metadata: CoreMetadata::from(file.core_metadata.as_deref()),
+                size: file.size,
+            })
+            .collect(),
+    };
+
+    Ok((html, serde_json::to_string_pretty(&json)?))
+}
+
+async fn write_root_pages(root: &Path) -> Result<(), DirectoryError> {
+    // List every project that has a project page.
+    let mut projects = BTreeSet::new();
+    let mut entries = fs_err::tokio::read_dir(root).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let Some(name) = entry.file_name().to_str().map(ToString::to_string) else {
+            continue;
+        };
+        let Ok(project) = PackageName::from_str(&name) else {
+            continue;
+        };
+        // Project directories are named after the normalized name.
+        if project.as_str() == name && entry.path().join("index.html").is_file() {
+            projects.insert(project);
+        }
+    }
+
+    let (html, json) = render_root_pages(&projects)?;
+    uv_fs::write_atomic(root.join("index.html"), html).await?;
+    uv_fs::write_atomic(root.join("index.json"), json).await?;
+    Ok(())
+}
+
+/// Render the list of projects in the PEP 503 HTML and the PEP 691 JSON format.
+pub(crate) fn render_root_pages(
+    projects: &BTreeSet<PackageName>,
+) -> Result<(String, String), serde_json::Error> {
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>").unwrap();
+    writeln!(html, "<html>").unwrap();
+    writeln!(html, "  <head>").unwrap();
+    writeln!(
+        html,
+        "    <meta name=\"pypi:repository-version\" content=\"{API_VERSION}\">"
+    )
+    .unwrap();
+    writeln!(html, "    <title>Simple index</title>").unwrap();
+    writeln!(html, "  </head>").unwrap();
+    writeln!(html, "  <body>").unwrap();
+    for project in projects {
+        writeln!(html, "    <a href=\"{project}/\">{project}</a><br />").unwrap();
+    }
+    writeln!(html, "  </body>").unwrap();
+    writeln!(html, "</html>").unwrap();
+
+    let json = RootPage {
+        meta: Meta::default(),
+        projects: projects
+            .iter()
+            .map(|project| RootProject { name: project })
+            .collect(),
+    };
+
+    Ok((html, serde_json::to_string_pretty(&json)?))
+}
+
+struct HashedFile {
+    /// The hex-encoded SHA-256 digest.
+    sha256: String,
+    size: u64,
+}
+
+async fn sha256_file(path: &Path) -> Result<HashedFile, io::Error> {
+    sha256_reader(BufReader::new(File::open(path).await?)).await
+}
+
+async fn sha256_reader(reader: impl AsyncRead + Unpin) -> Result<HashedFile, io::Error> {
+    let mut hashers = [Hasher::from(HashAlgorithm::Sha256)];
+    let mut reader = HashReader::new(reader, &mut hashers);
+    reader.finish().await?;
+    let size = reader.bytes_read();
+    let [hasher] = hashers;
+    Ok(HashedFile {
+        sha256: HashDigest::from(hasher).digest.to_string(),
+        size,
+    })
+}
+
+/// The `meta` key of a PEP 691 response.
+#[derive(Debug, Serialize)]
+struct Meta {
+    #[serde(rename = "api-version")]
+    api_version: &'static str,
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self {
+            api_version: API_VERSION,
+        }
+    }
+}
+
+/// A PEP 691 project list.
+#[derive(Debug, Serialize)]
+struct RootPage<'a> {
+    meta: Meta,
+    projects: Vec<RootProject<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct RootProject<'a> {
+    name: &'a PackageName,
+}
+
+/// A PEP 691 project page.
+#[derive(Debug, Serialize)]
+struct ProjectPage<'a> {
+    meta: Meta,
+    name: &'a PackageName,
+    versions: BTreeSet<&'a Version>,
+    files: Vec<ProjectFile<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct ProjectFile<'a> {
+    filename: &'a str,
+    url: &'a str,
+    hashes: Hashes<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<&'a str>,
+    core_metadata: CoreMetadata<'a>,
+    dist_info_metadata: CoreMetadata<'a>,
+    size: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct Hashes<'a> {
+    sha256: &'a str,
+}
+
+/// The `core-metadata` key of a file: either the hashes of the metadata file, or `false`.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum CoreMetadata<'a> {
+    Hashes(Hashes<'a>),
+    Missing(bool),
+}
+
+impl<'a> From<Option<&'a str>> for CoreMetadata<'a> {
+    fn from(sha256: Option<&'a str>) -> Self {
+        match sha256 {
+            Some(sha256) => Self::Hashes(Hashes { sha256 }),
+            None => Self::Missing(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+    use uv_distribution_filename::DistFilename;
+
+    use super::{check_directory, publish_to_directory, update_directory_index};
+    use crate::UploadDistribution;
+
+    fn group(path: &Path) -> UploadDistribution {
+        let raw_filename = path.file_name().unwrap().to_str().unwrap().to_string();
+        UploadDistribution {
+            file: path.to_path_buf(),
+            filename: DistFilename::try_from_normalized_filename(&raw_filename).unwrap(),
+            raw_filename,
+            attestations: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_wheel_to_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let wheel = group(
+            &Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../test/links/ok-1.0.0-py3-none-any.whl"),
+        );
+
+        assert!(!check_directory(root.path(), &wheel).await.unwrap());
+        assert!(publish_to_directory(root.path(), &wheel).await.unwrap());
+        assert!(check_directory(root.path(), &wheel).await.unwrap());
+        // Publishing the same file again is a no-op.
+        assert!(!publish_to_directory(root.path(), &wheel).await.unwrap());
+        update_directory_index(root.path(), [wheel.filename.name()])
+            .await
+            .unwrap();
+
+        let project_dir = root.path().join("ok");
+        assert!(
+            project_dir
+                .join("ok-1.0.0-py3-none-any.whl.metadata")
+                .is_file()
+        );
+        assert_snapshot!(
+            fs_err::read_to_string(project_dir.join("index.html")).unwrap(),
+            @r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.1">
+            <title>Links for ok</title>
+          </head>
+          <body>
+            <h1>Links for ok</h1>
+            <a href="ok-1.0.0-py3-none-any.whl#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f" data-requires-python="&gt;=3.8" data-dist-info-metadata="sha256=3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43" data-core-metadata="sha256=3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43">ok-1.0.0-py3-none-any.whl</a><br />
+          </body>
+        </html>
+        "#
+        );
+        assert_snapshot!(
+            fs_err::read_to_string(project_dir.join("index.json")).unwrap(),
+            @r#"
+        {
+          "meta": {
+            "api-version": "1.1"
+          },
+          "name": "ok",
+          "versions": [
+            "1.0.0"
+          ],
+          "files": [
+            {
+              "filename": "ok-1.0.0-py3-none-any.whl",
+              "url": "ok-1.0.0-py3-none-any.whl",
+              "hashes": {
+                "sha256": "79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f"
+              },
+              "requires-python": ">=3.8",
+              "core-metadata": {
+                "sha256": "3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43"
+              },
+              "dist-info-metadata": {
+                "sha256": "3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43"
+              },
+              "size": 875
+            }
+          ]
+        }
+        "#
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_attestations_to_directory() {
+        let dist = tempfile::tempdir().unwrap();
+        let wheel_path = dist.path().join("ok-1.0.0-py3-none-any.whl");
+        fs_err::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../test/links/ok-1.0.0-py3-none-any.whl"),
+            &wheel_path,
+        )
+        .unwrap();
+        let attestation_path = dist
+            .path()
+            .join("ok-1.0.0-py3-none-any.whl.publish.attestation");
+        fs_err::write(&attestation_path, "{}").unwrap();
+        let mut wheel = group(&wheel_path);
+        wheel.attestations.push(attestation_path);
+
+        let root = tempfile::tempdir().unwrap();
+        assert!(publish_to_directory(root.path(), &wheel).await.unwrap());
+        update_directory_index(root.path(), [wheel.filename.name()])
+            .await
+            .unwrap();
+
+        // The attestation is copied next to the distribution, but isn't listed as a file.
+        let project_dir = root.path().join("ok");
+        assert_eq!(
+            fs_err::read_to_string(
+                project_dir.join("ok-1.0.0-py3-none-any.whl.publish.attestation")
+            )
+            .unwrap(),
+            "{}"
+        );
+        let index = fs_err::read_to_string(project_dir.join("index.json")).unwrap();
+        assert!(!index.contains(".attestation"));
+    }
+}
@@ -1,3 +1,6 @@
+mod check;
+mod directory;
+mod server;
 mod trusted_publishing;
 
 use std::collections::BTreeSet;
@@ -37,10 +40,16 @@ use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
 use uv_preview::PreviewFeature;
+use uv_provenance::{Signer, SigningConfig, SigningError, SigstoreSigner};
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_warnings::warn_user;
 
+pub use crate::check::{CheckError, Diagnostic, Severity, check_distribution};
+pub use crate::directory::{
+    DirectoryError, check_directory, publish_to_directory, update_directory_index,
+};
+pub use crate::server::{IndexServer, ServeError, ServedIndex};
 use crate::trusted_publishing::pypi::PyPIPublishingService;
 use crate::trusted_publishing::pyx::PyxPublishingService;
 use crate::trusted_publishing::{
@@ -76,6 +85,10 @@ pub enum PublishError {
     ),
     #[error("Failed to obtain token for trusted publishing")]
     TrustedPublishing(#[from] Box<TrustedPublishingError>),
+    #[error("Failed to obtain an OIDC token for signing attestations")]
+    AttestationToken(#[source] Box<TrustedPublishingError>),
+    #[error("Failed to obtain a certificate for signing attestations")]
+    AttestationSigner(#[source] Box<SigningError>),
     #[error("{0} are not allowed when using trusted publishing")]
     MixedCredentials(String),
     #[error("Failed to query check URL")]
@@ -102,6 +115,8 @@ pub enum PublishError {
     S3Upload(PathBuf, #[source] Box<PublishSendError>),
     #[error("Failed to finalize upload for `{}`", _0.user_display())]
     Finalize(PathBuf, #[source] Box<PublishSendError>),
+    #[error("Failed to publish to the index directory `{}`", _0.user_display())]
+    Directory(PathBuf, #[source] Box<DirectoryError>),
 }
 
 /// Failure to get the metadata for a specific file.
@@ -129,6 +144,8 @@ pub enum PublishPrepareError {
     TokioTarRead(String, #[source] io::Error),
     #[error("Invalid PEP 740 attestation (not JSON): `{0}`")]
     InvalidAttestation(PathBuf, #[source] serde_json::Error),
+    #[error("Failed to sign PEP 740 attestation")]
+    Attest(#[source] SigningError),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -406,6 +423,57 @@ pub fn group_files_for_publishing(
     Ok(group_files(unroll_paths(paths)?, no_attestations))
 }
 
+/// Create a signer for PEP 740 attestations, using an ephemeral key that Sigstore certifies for
+/// the ambient OIDC identity, e.g., of a GitHub Actions workflow.
+pub async fn sigstore_signer(
+    client: &BaseClient,
+    config: &SigningConfig,
+) -> Result<SigstoreSigner, PublishError> {
+    debug!("Attempting to get an OIDC token for signing attestations");
+    let client = client.for_host(&config.fulcio_url).raw_client();
+    let token = trusted_publishing::get_sigstore_token(client)
+        .await
+        .map_err(|err| PublishError::AttestationToken(Box::new(err)))?;
+    SigstoreSigner::new(client.clone(), config, token.reveal())
+        .await
+        .map_err(|err| PublishError::AttestationSigner(Box::new(err)))
+}
+
+/// Sign a PEP 740 publish attestation for a distribution.
+///
+/// The attestation is written next to the distribution, as `<file>.publish.attestation`, and
+/// uploaded alongside it. If the distribution already has a publish attestation, it's used as-is.
+///
+/// Returns `true` if a new attestation was created.
+pub async fn attest(
+    group: &mut UploadDistribution,
+    form_metadata: &FormMetadata,
+    signer: &dyn Signer,
+) -> Result<bool, PublishPrepareError> {
+    let mut path = group.file.clone().into_os_string();
+    path.push(".publish.attestation");
+    let path = PathBuf::from(path);
+    if group.attestations.contains(&path) {
+        debug!(
+            "Using existing publish attestation: `{}`",
+            path.user_display()
+        );
+        return Ok(false);
+    }
+
+    let attestation =
+        uv_provenance::attest_publish(signer, &group.raw_filename, form_metadata.sha256_digest())
+            .await
+            .map_err(PublishPrepareError::Attest)?;
+    let contents = serde_json::to_vec(&attestation).expect("Serialization of attestation failed");
+    fs_err::tokio::write(&path, contents).await?;
+    debug!("Wrote publish attestation: `{}`", path.user_display());
+
+    group.attestations.push(path);
+    group.attestations.sort();
+    Ok(true)
+}
+
 pub enum TrustedPublishResult {
     /// We didn't check for trusted publishing.
     Skipped,
@@ -1354,6 +1422,14 @@ impl FormMetadata {
         Self(form_metadata)
     }
 
+    /// Returns the hex-encoded SHA-256 digest of the distribution file.
+    pub fn sha256_digest(&self) -> &str {
+        self.iter()
+            .find(|(key, _)| *key == "sha256_digest")
+            .map(|(_, value)| value.as_str())
+            .expect("form metadata always contains the SHA-256 digest")
+    }
+
     /// Returns an iterator over the metadata fields.
     fn iter(&self) -> std::slice::Iter<'_, (&'static str, String)> {
         self.0.iter()
@@ -1582,7 +1658,7 @@ async fn handle_response(
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use std::assert_matches;
     use std::path::PathBuf;
     use std::sync::Arc;
@@ -1601,7 +1677,7 @@ mod tests {
     use uv_redacted::DisplaySafeUrl;
 
     use crate::{
-        FormMetadata, PublishError, PublishPrepareError, Reporter, UploadDistribution,
+        FormMetadata, PublishError, PublishPrepareError, Reporter, UploadDistribution, attest,
         build_upload_request, group_files, source_dist_pkg_info, upload,
     };
     use tokio::sync::Semaphore;
@@ -1611,7 +1687,7 @@ mod tests {
 
     struct DummyReporter;
 
-    const TAR_BACKENDS: &[&[PreviewFeature]] = &[&[], &[PreviewFeature::TarCodec]];
+    pub(crate) const TAR_BACKENDS: &[&[PreviewFeature]] = &[&[], &[PreviewFeature::TarCodec]];
 
     impl Reporter for DummyReporter {
         fn on_progress(&self, _name: &str, _id: usize) {}
@@ -1627,7 +1703,7 @@ mod tests {
         fn on_hash_complete(&self, _id: usize) {}
     }
 
-    async fn source_dist(entries: &[(&str, &[u8])]) -> NamedTempFile {
+    pub(crate) async fn source_dist(entries: &[(&str, &[u8])]) -> NamedTempFile {
         let mut bytes = Vec::new();
         {
             let mut gzip = GzipEncoder::new(&mut bytes);
@@ -2520,4 +2596,83 @@ mod tests {
         "
         );
     }
+
+    /// A signer that returns the statement without signing it.
+    struct UnsignedSigner;
+
+    #[async_trait::async_trait]
+    impl uv_provenance::Signer for UnsignedSigner {
+        async fn sign(
+            &self,
+            statement: &[u8],
+        ) -> Result<uv_provenance::Attestation, uv_provenance::SigningError> {
+            Ok(uv_provenance::Attestation {
+                version: 1,
+                verification_material: uv_provenance::VerificationMaterial {
+                    certificate: String::new(),
+                    transparency_entries: vec![],
+                },
+                envelope: uv_provenance::Envelope {
+                    statement: String::from_utf8(statement.to_vec()).unwrap(),
+                    signature: String::new(),
+                },
+            })
+        }
+    }
+
+    /// Sign a publish attestation for a wheel, which is uploaded alongside it.
+    #[tokio::test]
+    async fn attest_wheel() {
+        let raw_filename = "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl";
+        let dist = tempfile::tempdir().unwrap();
+        let file = dist.path().join(raw_filename);
+        fs_err::copy(PathBuf::from("../../test/links/").join(raw_filename), &file).unwrap();
+        let mut group = UploadDistribution {
+            file,
+            raw_filename: raw_filename.to_string(),
+            filename: DistFilename::try_from_normalized_filename(raw_filename).unwrap(),
+            attestations: vec![],
+        };
+        let form_metadata =
+            FormMetadata::read_from_file(&group.file, &group.filename, Arc::new(DummyReporter))
+                .await
+                .unwrap();
+
+        assert!(
+            attest(&mut group, &form_metadata, &UnsignedSigner)
+                .await
+                .unwrap()
+        );
+        let path = dist
+            .path()
+            .join(format!("{raw_filename}.publish.attestation"));
+        assert_eq!(group.attestations, vec![path.clone()]);
+        let attestation: serde_json::Value =
+            serde_json::from_str(&fs_err::read_to_string(&path).unwrap()).unwrap();
+        let statement: serde_json::Value =
+            serde_json::from_str(attestation["envelope"]["statement"].as_str().unwrap()).unwrap();
+        assert_snapshot!(serde_json::to_string_pretty(&statement).unwrap(), @r#"
+        {
+          "_type": "https://in-toto.io/Statement/v1",
+          "predicate": {},
+          "predicateType": "https://docs.pypi.org/attestations/publish/v1",
+          "subject": [
+            {
+              "digest": {
+                "sha256": "0d88ca657bc6b64995ca416e0c59c71af85cc10015d940fa446c42a8b485ee1c"
+              },
+              "name": "tqdm-4.66.1-py3-none-manylinux_2_12_x86_64.manylinux2010_x86_64.musllinux_1_1_x86_64.whl"
+            }
+          ]
+        }
+        "#);
+
+        // An existing publish attestation is used as-is.
+        assert!(
+            !attest(&mut group, &form_metadata, &UnsignedSigner)
+                .await
+                .unwrap()
+        );
+        assert_eq!(group.attestations, vec![path]);
+    }
 }
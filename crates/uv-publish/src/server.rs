@@ -0,0 +1,722 @@
+//! Serving distributions as a simple index over HTTP, e.g., for tests and offline demos.
+//!
+//! The pages are rendered like the ones written when publishing to a directory: the list of
+//! projects and each project page are available in the PEP 503 HTML and the PEP 691 JSON format,
+//! selected through the `Accept` header, and wheels are served with their PEP 658 metadata.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::ffi::OsStr;
+use std::io;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use fs_err::tokio::File;
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{self, HeaderValue};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use percent_encoding::percent_decode_str;
+use rustc_hash::FxHashMap;
+use thiserror::Error;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_auth::Credentials;
+use uv_cache::{Cache, CacheBucket};
+use uv_client::MediaType;
+use uv_distribution_filename::DistFilename;
+use uv_fs::Simplified;
+use uv_metadata::read_metadata_async_seek;
+use uv_normalize::PackageName;
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user;
+
+use crate::PublishPrepareError;
+use crate::directory::{
+    DirectoryError, IndexFile, index_file, render_project_pages, render_root_pages,
+};
+
+/// Failure to collect or serve the distributions of an index.
+#[derive(Error, Debug)]
+pub enum ServeError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("Failed to listen on `{0}`")]
+    Bind(SocketAddr, #[source] io::Error),
+}
+
+/// A page in both the PEP 503 HTML and the PEP 691 JSON format.
+#[derive(Debug)]
+struct Page {
+    html: Bytes,
+    json: Bytes,
+}
+
+impl Page {
+    fn new((html, json): (String, String)) -> Self {
+        Self {
+            html: Bytes::from(html),
+            json: Bytes::from(json),
+        }
+    }
+}
+
+/// A distribution served by the index.
+#[derive(Debug)]
+struct ServedFile {
+    path: PathBuf,
+    /// The PEP 658 metadata of a wheel.
+    core_metadata: Option<Bytes>,
+}
+
+#[derive(Debug)]
+struct ServedProject {
+    page: Page,
+    files: FxHashMap<String, ServedFile>,
+}
+
+/// The distributions served by an [`IndexServer`], grouped by project.
+#[derive(Debug)]
+pub struct ServedIndex {
+    root: Page,
+    projects: BTreeMap<PackageName, ServedProject>,
+}
+
+impl ServedIndex {
+    /// Collect the wheels and source distributions in a flat directory, as used with
+    /// `--find-links`.
+    pub async fn from_directory(directory: &Path) -> Result<Self, ServeError> {
+        let mut paths = Vec::new();
+        let mut entries = fs_err::tokio::read_dir(directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_file() {
+                paths.push(entry.path());
+            }
+        }
+        Self::from_paths(paths).await
+    }
+
+    /// Collect the wheels that were built from source distributions and stored in the cache.
+    ///
+    /// If the same wheel was built more than once, the most recent build is served.
+    pub async fn from_cache(cache: &Cache) -> Result<Self, ServeError> {
+        let root = cache.bucket(CacheBucket::SourceDistributions);
+        let paths = tokio::task::spawn_blocking(move || cached_wheels(&root))
+            .await
+            .map_err(io::Error::other)??;
+        Self::from_paths(paths).await
+    }
+
+    async fn from_paths(paths: Vec<PathBuf>) -> Result<Self, ServeError> {
+        let mut collected: BTreeMap<PackageName, (Vec<IndexFile>, FxHashMap<String, ServedFile>)> =
+            BTreeMap::new();
+        for path in paths {
+            let Some(raw_filename) = path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .map(ToString::to_string)
+            else {
+                continue;
+            };
+            let Some(filename) = DistFilename::try_from_normalized_filename(&raw_filename) else {
+                debug!(
+                    "Ignoring file that is not a distribution: `{}`",
+                    path.user_display()
+                );
+                continue;
+            };
+
+            let (file, core_metadata) =
+                match read_distribution(&path, raw_filename.clone(), &filename).await {
+                    Ok(distribution) => distribution,
+                    Err(err) => {
+                        warn_user!("{err}, skipping");
+                        debug!("Failed to read `{}`: {err:?}", path.user_display());
+                        continue;
+                    }
+                };
+
+            let (files, served) = collected.entry(filename.name().clone()).or_default();
+            files.push(file);
+            served.insert(
+                raw_filename,
+                ServedFile {
+                    path,
+                    core_metadata: core_metadata.map(Bytes::from),
+                },
+            );
+        }
+
+        let names = collected.keys().cloned().collect::<BTreeSet<_>>();
+        let root = Page::new(render_root_pages(&names)?);
+        let mut projects = BTreeMap::new();
+        for (name, (mut files, served)) in collected {
+            files.sort_by(|left, right| left.filename.cmp(&right.filename));
+            let page = Page::new(render_project_pages(&name, &files)?);
+            projects.insert(
+                name,
+                ServedProject {
+                    page,
+                    files: served,
+                },
+            );
+        }
+
+        Ok(Self { root, projects })
+    }
+
+    /// The number of projects in the index.
+    pub fn num_projects(&self) -> usize {
+        self.projects.len()
+    }
+
+    /// The number of distributions in the index.
+    pub fn num_files(&self) -> usize {
+        self.projects
+            .values()
+            .map(|project| project.files.len())
+            .sum()
+    }
+}
+
+/// Read a distribution and, for a wheel, its core metadata.
+async fn read_distribution(
+    path: &Path,
+    raw_filename: String,
+    filename: &DistFilename,
+) -> Result<(IndexFile, Option<Vec<u8>>), DirectoryError> {
+    let core_metadata = match filename {
+        DistFilename::WheelFilename(wheel) => {
+            let reader = BufReader::new(File::open(path).await?);
+            let contents = read_metadata_async_seek(wheel, reader)
+                .await
+                .map_err(|err| {
+                    DirectoryError::Metadata(
+                        path.to_path_buf(),
+                        Box::new(PublishPrepareError::from(err)),
+                    )
+                })?;
+            Some(contents)
+        }
+        DistFilename::SourceDistFilename(_) => None,
+    };
+    let file = index_file(path, raw_filename, filename, core_metadata.as_deref()).await?;
+    Ok((file, core_metadata))
+}
+
+/// Find the wheels in the source distribution bucket of the cache, keeping the most recently
+/// built wheel for each filename.
+fn cached_wheels(root: &Path) -> Result<Vec<PathBuf>, io::Error> {
+    if !root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut wheels: BTreeMap<String, (SystemTime, PathBuf)> = BTreeMap::new();
+    for entry in WalkDir::new(root) {
+        let entry = entry?;
+        if !entry.file_type().is_file() || entry.path().extension().is_none_or(|ext| ext != "whl") {
+            continue;
+        }
+        let Some(filename) = entry.file_name().to_str().map(ToString::to_string) else {
+            continue;
+        };
+        let modified = entry.metadata()?.modified()?;
+        match wheels.get(&filename) {
+            Some((existing, _)) if *existing >= modified => {}
+            _ => {
+                wheels.insert(filename, (modified, entry.into_path()));
+            }
+        }
+    }
+    Ok(wheels.into_values().map(|(_, path)| path).collect())
+}
+
+/// An HTTP server that serves a [`ServedIndex`] under `/simple/`.
+pub struct IndexServer {
+    listener: TcpListener,
+    address: SocketAddr,
+    state: Arc<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    index: ServedIndex,
+    /// The `Authorization` header that requests must carry, if any.
+    authorization: Option<HeaderValue>,
+}
+
+impl IndexServer {
+    /// Listen on the given address, where port `0` selects an unused port.
+    ///
+    /// If credentials are given, requests must use them for HTTP basic authentication.
+    pub async fn bind(
+        address: SocketAddr,
+        index: ServedIndex,
+        credentials: Option<&Credentials>,
+    ) -> Result<Self, ServeError> {
+        let listener = TcpListener::bind(address)
+            .await
+            .map_err(|err| ServeError::Bind(address, err))?;
+        let address = listener.local_addr()?;
+        Ok(Self {
+            listener,
+            address,
+            state: Arc::new(State {
+                index,
+                authorization: credentials.map(Credentials::to_header_value),
+            }),
+        })
+    }
+
+    /// The URL of the index, e.g., `http://127.0.0.1:8000/simple/`.
+    pub fn url(&self) -> DisplaySafeUrl {
+        DisplaySafeUrl::parse(&format!("http://{}/simple/", self.address))
+            .expect("a socket address is a valid host")
+    }
+
+    /// Serve requests until the future is dropped.
+    pub async fn serve(self) -> Result<(), ServeError> {
+        loop {
+            let (stream, _) = self.listener.accept().await?;
+            let state = self.state.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| handle(state.clone(), request));
+                if let Err(err) = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    debug!("Failed to serve connection: {err}");
+                }
+            });
+        }
+    }
+}
+
+async fn handle(
+    state: Arc<State>,
+    request: Request<Incoming>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let response = respond(&state, &request).await;
+    debug!(
+        "{} {} {}",
+        request.method(),
+        request.uri().path(),
+        response.status()
+    );
+    Ok(response)
+}
+
+async fn respond(state: &State, request: &Request<Incoming>) -> Response<Full<Bytes>> {
+    if let Some(authorization) = &state.authorization {
+        if request.headers().get(header::AUTHORIZATION) != Some(authorization) {
+            let mut response = status(StatusCode::UNAUTHORIZED);
+            response.headers_mut().insert(
+                header::WWW_AUTHENTICATE,
+                HeaderValue::from_static("Basic realm=\"uv\""),
+            );
+            return response;
+        }
+    }
+
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    let path = percent_decode_str(request.uri().path()).decode_utf8_lossy();
+    let rest = match path.as_ref() {
+        "/" | "/simple" => return redirect("/simple/"),
+        path => match path.strip_prefix("/simple/") {
+            Some(rest) => rest,
+            None => return status(StatusCode::NOT_FOUND),
+        },
+    };
+
+    // The list of projects.
+    if rest.is_empty() {
+        return page(&state.index.root, request.headers().get(header::ACCEPT));
+    }
+
+    let (project, filename) = match rest.split_once('/') {
+        Some((project, filename)) => (project, Some(filename)),
+        None => (rest, None),
+    };
+    let Ok(name) = PackageName::from_str(project) else {
+        return status(StatusCode::NOT_FOUND);
+    };
+
+    // Redirect to the normalized project name and the trailing slash, as described in PEP 503.
+    if filename.is_none() || name.as_str() != project {
+        return redirect(&format!("/simple/{name}/{}", filename.unwrap_or_default()));
+    }
+
+    let Some(project) = state.index.projects.get(&name) else {
+        return status(StatusCode::NOT_FOUND);
+    };
+    match filename {
+        Some("") | None => page(&project.page, request.headers().get(header::ACCEPT)),
+        Some(filename) => file(project, filename).await,
+    }
+}
+
+/// Serve a distribution, or the PEP 658 metadata of a wheel.
+async fn file(project: &ServedProject, filename: &str) -> Response<Full<Bytes>> {
+    let body = if let Some(file) = filename
+        .strip_suffix(".metadata")
+        .and_then(|wheel| project.files.get(wheel))
+    {
+        let Some(core_metadata) = &file.core_metadata else {
+            return status(StatusCode::NOT_FOUND);
+        };
+        core_metadata.clone()
+    } else {
+        let Some(file) = project.files.get(filename) else {
+            return status(StatusCode::NOT_FOUND);
+        };
+        match fs_err::tokio::read(&file.path).await {
+            Ok(contents) => Bytes::from(contents),
+            Err(err) => {
+                debug!("Failed to read `{}`: {err}", file.path.user_display());
+                return status(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        }
+    };
+
+    let mut response = Response::new(Full::new(body));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/octet-stream"),
+    );
+    response
+}
+
+/// Serve a page in the format requested by the `Accept` header.
+fn page(page: &Page, accept: Option<&HeaderValue>) -> Response<Full<Bytes>> {
+    let Some(media_type) = negotiate(accept.and_then(|accept| accept.to_str().ok())) else {
+        return status(StatusCode::NOT_ACCEPTABLE);
+    };
+    let body = match media_type {
+        MediaType::PypiV1Json => page.json.clone(),
+        _ => page.html.clone(),
+    };
+
+    let mut response = Response::new(Full::new(body));
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_str(&media_type.to_string()).expect("media types are valid headers"),
+    );
+    headers.insert(header::VARY, HeaderValue::from_static("Accept"));
+    // The pages are generated once, but the index is typically restarted with other files.
+    headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    response
+}
+
+/// Select the format of a page from the `Accept` header, as described in PEP 691.
+///
+/// The media type with the highest quality wins, where the first one listed wins ties. Without an
+/// `Accept` header, the page is served as HTML.
+fn negotiate(accept: Option<&str>) -> Option<MediaType> {
+    const SUPPORTED: [MediaType; 3] = [
+        MediaType::PypiV1Json,
+        MediaType::PypiV1Html,
+        MediaType::TextHtml,
+    ];
+
+    let Some(accept) = accept else {
+        return Some(MediaType::TextHtml);
+    };
+
+    let mut best: Option<(MediaType, f32)> = None;
+    for media_range in accept.split(',') {
+        let mut parameters = media_range.split(';');
+        let media_range = parameters.next().unwrap_or_default().trim();
+        let quality = parameters
+            .filter_map(|parameter| parameter.trim().strip_prefix("q="))
+            .find_map(|quality| quality.parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if quality <= 0.0 {
+            continue;
+        }
+
+        let media_type = if matches!(media_range, "*/*" | "text/*") {
+            MediaType::TextHtml
+        } else if let Some(media_type) = SUPPORTED
+            .into_iter()
+            .find(|media_type| media_type.to_string() == media_range)
+        {
+            media_type
+        } else {
+            continue;
+        };
+
+        if best.is_none_or(|(_, best)| quality > best) {
+            best = Some((media_type, quality));
+        }
+    }
+    best.map(|(media_type, _)| media_type)
+}
+
+fn status(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = status;
+    response
+}
+
+fn redirect(location: &str) -> Response<Full<Bytes>> {
+    let Ok(location) = HeaderValue::from_str(location) else {
+        return status(StatusCode::BAD_REQUEST);
+    };
+    let mut response = status(StatusCode::MOVED_PERMANENTLY);
+    response.headers_mut().insert(header::LOCATION, location);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use insta::assert_snapshot;
+    use tokio::sync::Semaphore;
+    use uv_auth::Credentials;
+    use uv_cache::Cache;
+    use uv_client::{
+        BaseClientBuilder, MediaType, MetadataFormat, OwnedArchive, RegistryClientBuilder,
+    };
+    use uv_distribution_types::{IndexCapabilities, IndexFormat, IndexMetadataRef, IndexUrl};
+    use uv_normalize::PackageName;
+
+    use super::{IndexServer, ServedIndex, negotiate};
+
+    /// Serve the `ok` wheels from a directory, along with a file that isn't a distribution.
+    async fn serve(credentials: Option<&Credentials>) -> (tempfile::TempDir, String) {
+        let directory = tempfile::tempdir().unwrap();
+        let links = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test/links");
+        for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+            fs_err::copy(links.join(filename), directory.path().join(filename)).unwrap();
+        }
+        fs_err::write(directory.path().join("README.md"), "").unwrap();
+
+        let index = ServedIndex::from_directory(directory.path()).await.unwrap();
+        assert_eq!(index.num_projects(), 1);
+        assert_eq!(index.num_files(), 2);
+        let server = IndexServer::bind("127.0.0.1:0".parse().unwrap(), index, credentials)
+            .await
+            .unwrap();
+        let url = server.url().to_string();
+        tokio::spawn(server.serve());
+        (directory, url)
+    }
+
+    #[test]
+    fn negotiate_media_type() {
+        // The `Accept` header sent by uv.
+        assert_eq!(
+            negotiate(Some(
+                "application/vnd.pypi.simple.v1+json, application/vnd.pypi.simple.v1+html;q=0.2, text/html;q=0.01"
+            )),
+            Some(MediaType::PypiV1Json)
+        );
+        assert_eq!(
+            negotiate(Some(
+                "application/vnd.pypi.simple.v1+json;q=0.1, application/vnd.pypi.simple.v1+html"
+            )),
+            Some(MediaType::PypiV1Html)
+        );
+        assert_eq!(negotiate(Some("text/html")), Some(MediaType::TextHtml));
+        assert_eq!(negotiate(Some("*/*")), Some(MediaType::TextHtml));
+        assert_eq!(negotiate(None), Some(MediaType::TextHtml));
+        assert_eq!(
+            negotiate(Some("application/vnd.pypi.simple.v1+json;q=0")),
+            None
+        );
+        assert_eq!(negotiate(Some("application/xml")), None);
+    }
+
+    #[tokio::test]
+    async fn serve_directory() {
+        let (_directory, url) = serve(None).await;
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+
+        let response = client
+            .get(format!("{url}ok/"))
+            .header("Accept", "application/vnd.pypi.simple.v1+json")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/vnd.pypi.simple.v1+json"
+        );
+        assert_snapshot!(response.text().await.unwrap(), @r#"
+        {
+          "meta": {
+            "api-version": "1.1"
+          },
+          "name": "ok",
+          "versions": [
+            "1.0.0",
+            "2.0.0"
+          ],
+          "files": [
+            {
+              "filename": "ok-1.0.0-py3-none-any.whl",
+              "url": "ok-1.0.0-py3-none-any.whl",
+              "hashes": {
+                "sha256": "79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f"
+              },
+              "requires-python": ">=3.8",
+              "core-metadata": {
+                "sha256": "3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43"
+              },
+              "dist-info-metadata": {
+                "sha256": "3f7be56d53a014d12541da4819ad95f4e327a35c34bcbc556938dfc1c44a3f43"
+              },
+              "size": 875
+            },
+            {
+              "filename": "ok-2.0.0-py3-none-any.whl",
+              "url": "ok-2.0.0-py3-none-any.whl",
+              "hashes": {
+                "sha256": "8163cd4f0477f8e93b856ac6a517fe5fa0f29339291fe2807d5376df685f6697"
+              },
+              "requires-python": ">=3.8",
+              "core-metadata": {
+                "sha256": "cfb7ce491fdc263d74417a9e7542334d6e34291e9ac3e203e1e834c50df831cd"
+              },
+              "dist-info-metadata": {
+                "sha256": "cfb7ce491fdc263d74417a9e7542334d6e34291e9ac3e203e1e834c50df831cd"
+              },
+              "size": 871
+            }
+          ]
+        }
+        "#);
+
+        let response = client.get(&url).send().await.unwrap();
+        assert_eq!(response.headers()["content-type"], "text/html");
+        assert_snapshot!(response.text().await.unwrap(), @r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <meta name="pypi:repository-version" content="1.1">
+            <title>Simple index</title>
+          </head>
+          <body>
+            <a href="ok/">ok</a><br />
+          </body>
+        </html>
+        "#);
+
+        // Project names are normalized.
+        let response = client.get(format!("{url}OK")).send().await.unwrap();
+        assert_eq!(response.status(), 301);
+        assert_eq!(response.headers()["location"], "/simple/ok/");
+
+        let metadata = client
+            .get(format!("{url}ok/ok-1.0.0-py3-none-any.whl.metadata"))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert!(metadata.starts_with("Metadata-Version: "), "{metadata}");
+
+        let wheel = client
+            .get(format!("{url}ok/ok-1.0.0-py3-none-any.whl"))
+            .send()
+            .await
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap();
+        assert_eq!(wheel.len(), 875);
+
+        let response = client.get(format!("{url}missing/")).send().await.unwrap();
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn serve_directory_with_authentication() {
+        let credentials = Credentials::basic(Some("user".to_string()), Some("secret".to_string()));
+        let (_directory, url) = serve(Some(&credentials)).await;
+        let client = reqwest::Client::new();
+
+        let response = client.get(&url).send().await.unwrap();
+        assert_eq!(response.status(), 401);
+        assert_eq!(response.headers()["www-authenticate"], "Basic realm=\"uv\"");
+
+        let response = client
+            .get(&url)
+            .basic_auth("user", Some("wrong"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 401);
+
+        let response = client
+            .get(&url)
+            .basic_auth("user", Some("secret"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    /// The index can be used by uv's registry client.
+    #[tokio::test]
+    async fn registry_client() {
+        let (_directory, url) = serve(None).await;
+        let index = IndexUrl::from_str(&url).unwrap();
+        let client =
+            RegistryClientBuilder::new(BaseClientBuilder::default(), Cache::temp().unwrap())
+                .build()
+                .unwrap();
+
+        let package_name = PackageName::from_str("ok").unwrap();
+        let results = client
+            .simple_detail(
+                &package_name,
+                Some(IndexMetadataRef {
+                    url: &index,
+                    format: IndexFormat::Simple,
+                }),
+                &IndexCapabilities::default(),
+                &Semaphore::new(1),
+            )
+            .await
+            .unwrap();
+        let [(_, MetadataFormat::Simple(metadata))] = results.as_slice() else {
+            panic!("expected a single Simple API response");
+        };
+        let versions = OwnedArchive::deserialize(metadata)
+            .iter()
+            .map(|datum| datum.version.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(versions, ["1.0.0", "2.0.0"]);
+
+        let projects = client
+            .fetch_simple_index(&index)
+            .await
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(projects, ["ok"]);
+    }
+}
@@ -165,6 +165,21 @@ pub(crate) trait TrustedPublishingService {
     }
 }
 
+/// The OIDC audience expected by Sigstore's Fulcio CA.
+const SIGSTORE_AUDIENCE: &str = "sigstore";
+
+/// Perform ambient OIDC token discovery for signing attestations with Sigstore.
+///
+/// This uses the same discovery as trusted publishing, but requests a token for Sigstore rather
+/// than for the registry.
+pub(crate) async fn get_sigstore_token(
+    client: &ClientWithMiddleware,
+) -> Result<ambient_id::IdToken, TrustedPublishingError> {
+    get_oidc_token(SIGSTORE_AUDIENCE, client)
+        .await?
+        .ok_or(TrustedPublishingError::NoToken)
+}
+
 /// Perform ambient OIDC token discovery.
 async fn get_oidc_token(
     audience: &str,
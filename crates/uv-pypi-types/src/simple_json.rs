@@ -27,6 +27,11 @@ pub struct PypiSimpleDetail {
     /// The list of [`PypiFile`]s available for download.
     #[serde(deserialize_with = "deserialize_files")]
     pub files: Vec<PypiFile>,
+    /// The versions of the project, including those without files.
+    ///
+    /// <https://peps.python.org/pep-0700/#specification>
+    #[serde(default)]
+    pub versions: Vec<SmallString>,
 }
 
 /// A single (remote) file belonging to a package, either a wheel or a source distribution, as
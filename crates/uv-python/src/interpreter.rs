@@ -658,6 +658,32 @@ impl Interpreter {
             .chain(interpreter.into_iter().flatten().map(Cow::Borrowed))
     }
 
+    /// Returns the `site-packages` directories of the read-only parent environments layered
+    /// beneath this virtual environment, in order of precedence.
+    ///
+    /// Returns an empty list if the interpreter is not a virtual environment, or if a `--target`
+    /// or `--prefix` directory is in use.
+    pub fn parent_site_packages(&self) -> Vec<PathBuf> {
+        if self.target().is_some() || self.prefix().is_some() || !self.is_virtualenv() {
+            return Vec::new();
+        }
+        let Ok(cfg) = PyVenvConfiguration::parse(self.sys_prefix().join("pyvenv.cfg")) else {
+            return Vec::new();
+        };
+        cfg.parent_environments()
+            .iter()
+            .flat_map(|parent| {
+                let purelib = parent.join(&self.virtualenv().purelib);
+                let platlib = parent.join(&self.virtualenv().platlib);
+                if purelib == platlib {
+                    vec![purelib]
+                } else {
+                    vec![purelib, platlib]
+                }
+            })
+            .collect()
+    }
+
     /// Whether or not this Python interpreter is from a default Python executable name, like
     /// `python`, `python3`, or `python.exe`.
     pub(crate) fn has_default_executable_name(&self) -> bool {
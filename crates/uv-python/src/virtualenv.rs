@@ -47,6 +47,10 @@ pub struct PyVenvConfiguration {
     pub(super) include_system_site_packages: bool,
     /// The Python version the virtual environment was created with
     pub(super) version: Option<PythonVersion>,
+    /// The read-only parent environments layered beneath the virtual environment.
+    pub(super) parent_environments: Vec<PathBuf>,
+    /// The prompt to display when the virtual environment is activated.
+    pub(super) prompt: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -231,6 +235,8 @@ impl PyVenvConfiguration {
         let mut seed = false;
         let mut include_system_site_packages = true;
         let mut version = None;
+        let mut parent_environments = Vec::new();
+        let mut prompt = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -266,6 +272,14 @@ impl PyVenvConfiguration {
                             .map_err(|e| io::Error::new(std::io::ErrorKind::InvalidData, e))?,
                     );
                 }
+                "parent-environments" => {
+                    parent_environments = env::split_paths(value.trim())
+                        .filter(|path| !path.as_os_str().is_empty())
+                        .collect();
+                }
+                "prompt" => {
+                    prompt = Some(value.trim().to_string());
+                }
                 _ => {}
             }
         }
@@ -278,6 +292,8 @@ impl PyVenvConfiguration {
             seed,
             include_system_site_packages,
             version,
+            parent_environments,
+            prompt,
         })
     }
 
@@ -292,7 +308,7 @@ impl PyVenvConfiguration {
     }
 
     /// Returns true if the virtual environment is relocatable.
-    pub(crate) fn is_relocatable(&self) -> bool {
+    pub fn is_relocatable(&self) -> bool {
         self.relocatable
     }
 
@@ -306,6 +322,22 @@ impl PyVenvConfiguration {
         self.include_system_site_packages
     }
 
+    /// Returns the parent environments whose `site-packages` are layered beneath the virtual
+    /// environment, in order of precedence.
+    pub fn parent_environments(&self) -> &[PathBuf] {
+        &self.parent_environments
+    }
+
+    /// Returns the prompt to display when the virtual environment is activated, if set.
+    pub fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
+    /// Returns the Python version the virtual environment was created with, if known.
+    pub fn version(&self) -> Option<&PythonVersion> {
+        self.version.as_ref()
+    }
+
     /// Set the key-value pair in the `pyvenv.cfg` file.
     pub fn set(content: &str, key: &str, value: &str) -> String {
         let mut lines = content.lines().map(Cow::Borrowed).collect::<Vec<_>>();
@@ -442,4 +474,25 @@ mod tests {
             "}
         );
     }
+
+    #[test]
+    fn test_parse_parent_environments() {
+        let tempdir = tempdir().unwrap();
+        let cfg = tempdir.path().join("pyvenv.cfg");
+        let parents = env::join_paths(["/opt/base", "/opt/cuda"]).unwrap();
+        fs::write(
+            &cfg,
+            format!(
+                "home = /path/to/python\nparent-environments = {}\n",
+                parents.to_str().unwrap()
+            ),
+        )
+        .unwrap();
+
+        let cfg = PyVenvConfiguration::parse(&cfg).unwrap();
+        assert_eq!(
+            cfg.parent_environments(),
+            [PathBuf::from("/opt/base"), PathBuf::from("/opt/cuda")]
+        );
+    }
 }
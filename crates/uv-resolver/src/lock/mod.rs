@@ -3947,7 +3947,14 @@ impl Package {
                             GitReference::from(git.kind.clone()),
                             git.precise,
                             git.lfs,
-                        )?;
+                        )?
+                        .with_verify_signature(git.verify_signature)
+                        .with_allowed_signers(
+                            git.allowed_signers
+                                .as_ref()
+                                .map(|path| absolute_path(workspace_root, path))
+                                .transpose()?,
+                        );
 
                         // Reconstruct the PEP 508-compatible URL from the `GitSource`.
                         let url = DisplaySafeUrl::from(ParsedGitPathUrl {
@@ -4150,7 +4157,14 @@ impl Package {
                     GitReference::from(git.kind.clone()),
                     git.precise,
                     git.lfs,
-                )?;
+                )?
+                .with_verify_signature(git.verify_signature)
+                .with_allowed_signers(
+                    git.allowed_signers
+                        .as_ref()
+                        .map(|path| absolute_path(workspace_root, path))
+                        .transpose()?,
+                );
 
                 if let Some(install_path) = git.path.as_ref() {
                     // A direct path source can also be a wheel, so validate the extension.
@@ -4844,7 +4858,7 @@ impl Source {
             BuiltDist::Registry(ref reg_dist) => Self::from_registry_built_dist(reg_dist, root),
             BuiltDist::DirectUrl(ref direct_dist) => Ok(Self::from_direct_built_dist(direct_dist)),
             BuiltDist::Path(ref path_dist) => Self::from_path_built_dist(path_dist, root),
-            BuiltDist::GitPath(ref git_dist) => Ok(Self::from_git_path_built_dist(git_dist)),
+            BuiltDist::GitPath(ref git_dist) => Self::from_git_path_built_dist(git_dist, root),
         }
     }
 
@@ -4860,10 +4874,10 @@ impl Source {
                 Ok(Self::from_direct_source_dist(direct_dist))
             }
             uv_distribution_types::SourceDist::GitDirectory(ref git_dist) => {
-                Ok(Self::from_git_directory_source_dist(git_dist))
+                Self::from_git_directory_source_dist(git_dist, root)
             }
             uv_distribution_types::SourceDist::GitPath(ref git_dist) => {
-                Ok(Self::from_git_path_source_dist(git_dist))
+                Self::from_git_path_source_dist(git_dist, root)
             }
             uv_distribution_types::SourceDist::Path(ref path_dist) => {
                 Self::from_path_source_dist(path_dist, root)
@@ -4963,10 +4977,13 @@ impl Source {
         }
     }
 
-    fn from_git_path_built_dist(git_dist: &GitPathBuiltDist) -> Self {
+    fn from_git_path_built_dist(
+        git_dist: &GitPathBuiltDist,
+        root: &Path,
+    ) -> Result<Self, LockError> {
         let path = git_dist.install_path.clone();
-        Self::Git(
-            UrlString::from(locked_git_url(&git_dist.git, None, Some(&path))),
+        Ok(Self::Git(
+            UrlString::from(locked_git_url(&git_dist.git, None, Some(&path), root)?),
             GitSource {
                 kind: GitSourceKind::from(git_dist.git.reference().clone()),
                 precise: git_dist.git.precise().unwrap_or_else(|| {
@@ -4975,14 +4992,24 @@ impl Source {
                 subdirectory: None,
                 path: Some(path),
                 lfs: git_dist.git.lfs(),
+                verify_signature: git_dist.git.verify_signature(),
+                allowed_signers: git_dist
+                    .git
+                    .allowed_signers()
+                    .map(|path| try_relative_to_if(path, root, true))
+                    .transpose()
+                    .map_err(LockErrorKind::DistributionRelativePath)?,
             },
-        )
+        ))
     }
 
-    fn from_git_path_source_dist(git_dist: &GitPathSourceDist) -> Self {
+    fn from_git_path_source_dist(
+        git_dist: &GitPathSourceDist,
+        root: &Path,
+    ) -> Result<Self, LockError> {
         let path = git_dist.install_path.clone();
-        Self::Git(
-            UrlString::from(locked_git_url(&git_dist.git, None, Some(&path))),
+        Ok(Self::Git(
+            UrlString::from(locked_git_url(&git_dist.git, None, Some(&path), root)?),
             GitSource {
                 kind: GitSourceKind::from(git_dist.git.reference().clone()),
                 precise: git_dist.git.precise().unwrap_or_else(|| {
@@ -4991,17 +5018,28 @@ impl Source {
                 subdirectory: None,
                 path: Some(path),
                 lfs: git_dist.git.lfs(),
+                verify_signature: git_dist.git.verify_signature(),
+                allowed_signers: git_dist
+                    .git
+                    .allowed_signers()
+                    .map(|path| try_relative_to_if(path, root, true))
+                    .transpose()
+                    .map_err(LockErrorKind::DistributionRelativePath)?,
             },
-        )
+        ))
     }
 
-    fn from_git_directory_source_dist(git_dist: &GitDirectorySourceDist) -> Self {
-        Self::Git(
+    fn from_git_directory_source_dist(
+        git_dist: &GitDirectorySourceDist,
+        root: &Path,
+    ) -> Result<Self, LockError> {
+        Ok(Self::Git(
             UrlString::from(locked_git_url(
                 &git_dist.git,
                 git_dist.subdirectory.as_deref(),
                 None,
-            )),
+                root,
+            )?),
             GitSource {
                 kind: GitSourceKind::from(git_dist.git.reference().clone()),
                 precise: git_dist.git.precise().unwrap_or_else(|| {
@@ -5010,8 +5048,15 @@ impl Source {
                 subdirectory: git_dist.subdirectory.clone(),
                 path: None,
                 lfs: git_dist.git.lfs(),
+                verify_signature: git_dist.git.verify_signature(),
+                allowed_signers: git_dist
+                    .git
+                    .allowed_signers()
+                    .map(|path| try_relative_to_if(path, root, true))
+                    .transpose()
+                    .map_err(LockErrorKind::DistributionRelativePath)?,
             },
-        )
+        ))
     }
 
     /// Returns `true` if the source is a registry entry pointing at PyPI (`https://pypi.org/simple`).
@@ -5092,7 +5137,7 @@ impl Source {
                     git, subdirectory, ..
                 },
             ) => {
-                let mut expected = locked_git_url(git, subdirectory.as_deref(), None);
+                let mut expected = locked_git_url(git, subdirectory.as_deref(), None, root)?;
                 expected.set_fragment(None);
                 let mut actual = url.to_url().map_err(LockErrorKind::InvalidUrl)?;
                 actual.set_fragment(None);
@@ -5109,7 +5154,7 @@ impl Source {
                     git, install_path, ..
                 },
             ) => {
-                let mut expected = locked_git_url(git, None, Some(install_path));
+                let mut expected = locked_git_url(git, None, Some(install_path), root)?;
                 expected.set_fragment(None);
                 let mut actual = url.to_url().map_err(LockErrorKind::InvalidUrl)?;
                 actual.set_fragment(None);
@@ -5404,6 +5449,10 @@ struct GitSource {
     path: Option<PathBuf>,
     kind: GitSourceKind,
     lfs: GitLfs,
+    verify_signature: bool,
+    /// The file of SSH keys that are trusted to sign the commit (or tag), relative to the
+    /// workspace root.
+    allowed_signers: Option<PathBuf>,
 }
 
 /// An error that occurs when a source string could not be parsed.
@@ -5421,6 +5470,8 @@ impl GitSource {
         let mut kind = GitSourceKind::DefaultBranch;
         let mut subdirectory = None;
         let mut lfs = GitLfs::Disabled;
+        let mut verify_signature = false;
+        let mut allowed_signers = None;
         let mut path = None;
         for (key, val) in url.query_pairs() {
             match &*key {
@@ -5429,6 +5480,12 @@ impl GitSource {
                 "rev" => kind = GitSourceKind::Rev(val.into_owned()),
                 "subdirectory" => subdirectory = Some(PortablePathBuf::from(val.as_ref()).into()),
                 "lfs" => lfs = GitLfs::from(val.eq_ignore_ascii_case("true")),
+                "verify-signature" => verify_signature = val.eq_ignore_ascii_case("true"),
+                "allowed-signers" => {
+                    allowed_signers = Some(PathBuf::from(Box::<Path>::from(
+                        PortablePathBuf::from(val.as_ref()),
+                    )));
+                }
                 "path" => {
                     path = Some(PathBuf::from(Box::<Path>::from(PortablePathBuf::from(
                         val.as_ref(),
@@ -5459,6 +5516,8 @@ impl GitSource {
             path,
             kind,
             lfs,
+            verify_signature,
+            allowed_signers,
         })
     }
 }
@@ -5832,7 +5891,8 @@ fn locked_git_url(
     git: &GitUrl,
     subdirectory: Option<&Path>,
     path: Option<&Path>,
-) -> DisplaySafeUrl {
+    root: &Path,
+) -> Result<DisplaySafeUrl, LockError> {
     let mut url = git.url().clone();
 
     // Remove the credentials.
@@ -5866,6 +5926,22 @@ fn locked_git_url(
         url.query_pairs_mut().append_pair("lfs", "true");
     }
 
+    // Put verify-signature=true in the package source git url only when explicitly enabled.
+    if git.verify_signature() {
+        url.query_pairs_mut()
+            .append_pair("verify-signature", "true");
+    }
+
+    // Put the allowed signers in the query, relative to the workspace root.
+    if let Some(allowed_signers) = git.allowed_signers() {
+        let allowed_signers = try_relative_to_if(allowed_signers, root, true)
+            .map_err(LockErrorKind::DistributionRelativePath)?;
+        url.query_pairs_mut().append_pair(
+            "allowed-signers",
+            &PortablePath::from(&allowed_signers).to_string(),
+        );
+    }
+
     // Put the requested reference in the query.
     match git.reference() {
         GitReference::Branch(branch) => {
@@ -5885,7 +5961,7 @@ fn locked_git_url(
     // Put the precise commit in the fragment.
     url.set_fragment(git.precise().as_ref().map(GitOid::to_string).as_deref());
 
-    url
+    Ok(url)
 }
 
 #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
@@ -6639,6 +6715,11 @@ fn normalize_requirement(
                     git.precise(),
                     git.lfs(),
                 )?
+                .with_verify_signature(git.verify_signature())
+                .with_allowed_signers(
+                    git.allowed_signers()
+                        .map(|path| normalize_path(root.join(path)).into_owned()),
+                )
             };
 
             // Reconstruct the PEP 508 URL from the underlying data.
@@ -6683,6 +6764,11 @@ fn normalize_requirement(
                     git.precise(),
                     git.lfs(),
                 )?
+                .with_verify_signature(git.verify_signature())
+                .with_allowed_signers(
+                    git.allowed_signers()
+                        .map(|path| normalize_path(root.join(path)).into_owned()),
+                )
             };
 
             // Reconstruct the PEP 508 URL from the underlying data.
@@ -3,9 +3,10 @@ use std::{collections::BTreeMap, num::NonZeroUsize};
 
 use url::Url;
 
+use uv_cache::SharedCache;
 use uv_configuration::{
-    BuildIsolation, ExportFormat, IndexStrategy, KeyringProviderType, NoSources, ProxyUrl,
-    Reinstall, RequiredVersion, TargetTriple, TrustedPublishing, Upgrade,
+    BuildIsolation, CacheAge, CacheSize, ExportFormat, IndexStrategy, KeyringProviderType,
+    NoSources, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, PackageConfigSettings, PipExtraIndex,
@@ -93,6 +94,8 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(CacheAge);
+impl_combine_or!(CacheSize);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExcludeNewerOverride);
 impl_combine_or!(ExcludeNewerValue);
@@ -116,6 +119,7 @@ impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
 impl_combine_or!(RequiredVersion);
+impl_combine_or!(SharedCache);
 impl_combine_or!(ResolutionMode);
 impl_combine_or!(SchemaConflicts);
 impl_combine_or!(String);
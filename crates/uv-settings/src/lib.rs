@@ -315,6 +315,8 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         managed,
         package,
         build_backend,
+        environment,
+        scripts,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
     // permitted by the schema since they _can_ be included in `pyproject.toml` files
@@ -358,6 +360,12 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "build-backend",
         ));
     }
+    if environment.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "environment"));
+    }
+    if scripts.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "scripts"));
+    }
     if environments.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -387,6 +395,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_size,
+                cache_max_age,
+                shared_cache,
                 preview,
                 python_preference,
                 python_downloads,
@@ -394,6 +405,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 concurrent_builds,
                 concurrent_installs,
                 allow_insecure_host,
+                git_mirror,
+                git_allowed_signers,
                 http_proxy,
                 https_proxy,
                 no_proxy,
@@ -424,6 +437,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 compile_bytecode,
                 no_sources,
                 no_sources_package: _,
+                require_provenance,
+                require_provenance_package,
                 upgrade,
                 upgrade_package,
                 reinstall,
@@ -465,6 +480,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         managed: _,
         package: _,
         build_backend: _,
+        environment: _,
+        scripts: _,
     } = options;
 
     let mut masked_fields = vec![];
@@ -487,6 +504,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if cache_max_size.is_some() {
+        masked_fields.push("cache-max-size");
+    }
+    if cache_max_age.is_some() {
+        masked_fields.push("cache-max-age");
+    }
+    if shared_cache.is_some() {
+        masked_fields.push("shared-cache");
+    }
     match preview {
         Some(PreviewOption::Preview(_)) => masked_fields.push("preview"),
         Some(PreviewOption::PreviewFeatures(_)) => masked_fields.push("preview-features"),
@@ -510,6 +536,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if allow_insecure_host.is_some() {
         masked_fields.push("allow-insecure-host");
     }
+    if git_mirror.is_some() {
+        masked_fields.push("git-mirror");
+    }
+    if git_allowed_signers.is_some() {
+        masked_fields.push("git-allowed-signers");
+    }
     if http_proxy.is_some() {
         masked_fields.push("http-proxy");
     }
@@ -588,6 +620,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if no_sources.is_some() {
         masked_fields.push("no-sources");
     }
+    if require_provenance.is_some() {
+        masked_fields.push("require-provenance");
+    }
+    if require_provenance_package.is_some() {
+        masked_fields.push("require-provenance-package");
+    }
     if upgrade.is_some() {
         masked_fields.push("upgrade");
     }
@@ -4,10 +4,12 @@ use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use uv_cache::SharedCache;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    BuildIsolation, ExcludeDependency, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    BuildIsolation, CacheAge, CacheSize, ExcludeDependency, GitMirror, IndexStrategy,
+    KeyringProviderType, PackageNameSpecifier, ProxyUrl, Reinstall, RequiredVersion, TargetTriple,
+    TrustedHost, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexLocations, IndexUrl, IndexUrlError, Origin,
@@ -194,6 +196,12 @@ pub struct Options {
 
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) build_backend: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) environment: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) scripts: Option<serde::de::IgnoredAny>,
 }
 
 impl Options {
@@ -243,6 +251,13 @@ impl Options {
     /// Resolve the [`Options`] relative to the given root directory.
     pub(crate) fn relative_to(self, root_dir: &Path) -> Result<Self, IndexUrlError> {
         Ok(Self {
+            globals: GlobalOptions {
+                git_allowed_signers: self
+                    .globals
+                    .git_allowed_signers
+                    .map(|path| root_dir.join(path)),
+                ..self.globals
+            },
             top_level: self.top_level.relative_to(root_dir)?,
             pip: self.pip.map(|pip| pip.relative_to(root_dir)).transpose()?,
             ..self
@@ -331,6 +346,53 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// The maximum size of the cache, enforced by `uv cache prune`.
+    ///
+    /// When set, `uv cache prune` evicts the least-recently-used unzipped wheels until the cache
+    /// fits within the given size. Unzipped wheels that are linked into an existing environment
+    /// are retained.
+    ///
+    /// Accepts a size with an optional binary unit, e.g., `512M` or `20G`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            cache-max-size = "20G"
+        "#
+    )]
+    pub cache_max_size: Option<CacheSize>,
+    /// The maximum age of unused cache entries, enforced by `uv cache prune`.
+    ///
+    /// When set, `uv cache prune` evicts any unzipped wheels that have not been used within the
+    /// given duration. Unzipped wheels that are linked into an existing environment are retained.
+    ///
+    /// Accepts a duration, e.g., `30d`, `2w`, or `12h`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            cache-max-age = "30d"
+        "#
+    )]
+    pub cache_max_age: Option<CacheAge>,
+    /// A shared cache for wheels built from source, consulted before building a source
+    /// distribution and populated after a successful build.
+    ///
+    /// Accepts either a directory (e.g., on a network file system) or an HTTP(S) URL that supports
+    /// `GET` and `PUT` requests. Wheels built from registry and direct URL source distributions,
+    /// local source distribution archives, and Git repositories are shared, keyed by the source
+    /// distribution's hash or Git commit, the build settings, and the target platform.
+    #[option(
+        default = "None",
+        value_type = "str",
+        uv_toml_only = true,
+        example = r#"
+            shared-cache = "/mnt/shared/uv-cache"
+        "#
+    )]
+    pub shared_cache: Option<SharedCache>,
 
     /// The user's preview configuration.
     #[serde(flatten)]
@@ -436,6 +498,40 @@ pub struct GlobalOptions {
         "#
     )]
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
+    /// Rules for fetching Git dependencies from a mirror.
+    ///
+    /// Each rule replaces a prefix of a Git repository URL with the URL of a mirror, like Git's
+    /// `url.<base>.insteadOf` configuration. uv fetches the repository from the mirror, but
+    /// retains the original URL in the lockfile, such that the lockfile remains usable in
+    /// environments without the mirror. If multiple rules match a repository, the rule with the
+    /// longest prefix is used.
+    ///
+    /// The URLs of Git submodules are not rewritten.
+    #[option(
+        default = "\"[]\"",
+        value_type = "dict",
+        example = r#"
+            [[tool.uv.git-mirror]]
+            url = "https://github.com/"
+            mirror = "https://git.example.com/github/"
+        "#
+    )]
+    pub git_mirror: Option<Vec<GitMirror>>,
+    /// The file of trusted SSH keys against which to verify the signatures of Git dependencies
+    /// with `verify-signature = true`, in the format of Git's `gpg.ssh.allowedSignersFile`.
+    ///
+    /// Relative paths are resolved relative to the directory of the configuration file. Overridden
+    /// by the `allowed-signers` of a source, if set. If unset, Git's `gpg.ssh.allowedSignersFile`
+    /// configuration is used. GPG signatures are always verified
+    /// against the user's keyring.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            git-allowed-signers = ".github/allowed_signers"
+        "#
+    )]
+    pub git_allowed_signers: Option<PathBuf>,
 }
 
 /// Like [`GlobalOptions`], but with any `#[serde(flatten)]` fields inlined.
@@ -449,6 +545,9 @@ struct GlobalOptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_max_size: Option<CacheSize>,
+    cache_max_age: Option<CacheAge>,
+    shared_cache: Option<SharedCache>,
 
     preview: Option<bool>,
     preview_features: Option<PreviewFeaturesOption>,
@@ -462,6 +561,8 @@ struct GlobalOptionsWire {
     https_proxy: Option<ProxyUrl>,
     no_proxy: Option<Vec<String>>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
+    git_mirror: Option<Vec<GitMirror>>,
+    git_allowed_signers: Option<PathBuf>,
 }
 
 impl TryFrom<GlobalOptionsWire> for GlobalOptions {
@@ -476,6 +577,9 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             offline,
             no_cache,
             cache_dir,
+            cache_max_size,
+            cache_max_age,
+            shared_cache,
             preview,
             preview_features,
             python_preference,
@@ -487,6 +591,8 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            git_mirror,
+            git_allowed_signers,
         } = value;
 
         Ok(Self {
@@ -496,6 +602,9 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             offline,
             no_cache,
             cache_dir,
+            cache_max_size,
+            cache_max_age,
+            shared_cache,
             preview: PreviewOption::try_from(preview, preview_features)?,
             python_preference,
             python_downloads,
@@ -506,6 +615,8 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            git_mirror,
+            git_allowed_signers,
         })
     }
 }
@@ -707,6 +818,8 @@ pub struct ResolverInstallerOptions {
     pub compile_bytecode: Option<bool>,
     pub no_sources: Option<bool>,
     pub no_sources_package: Option<Vec<PackageName>>,
+    pub require_provenance: Option<bool>,
+    pub require_provenance_package: Option<Vec<PackageName>>,
     pub upgrade: Option<Upgrade>,
     pub reinstall: Option<Reinstall>,
     pub no_build: Option<bool>,
@@ -751,6 +864,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             compile_bytecode,
             no_sources,
             no_sources_package,
+            require_provenance,
+            require_provenance_package,
             upgrade,
             upgrade_package,
             reinstall,
@@ -790,6 +905,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             compile_bytecode,
             no_sources,
             no_sources_package,
+            require_provenance,
+            require_provenance_package,
             upgrade: Upgrade::from_args(
                 upgrade,
                 upgrade_package
@@ -1207,6 +1324,38 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub no_sources_package: Option<Vec<PackageName>>,
+    /// Require verified [PEP 740](https://peps.python.org/pep-0740/) provenance for all
+    /// distributions installed from a package index.
+    ///
+    /// When enabled, uv fetches the provenance for each distribution from the index's integrity
+    /// API, and verifies that every attestation was signed by the Trusted Publisher it's attributed
+    /// to and recorded in Sigstore's transparency log, using a trust root bundled with uv.
+    /// Distributions without valid provenance are not installed.
+    ///
+    /// Distributions from other sources, such as Git repositories, direct URLs, and local paths,
+    /// are not affected.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            require-provenance = true
+        "#
+    )]
+    pub require_provenance: Option<bool>,
+    /// Require verified [PEP 740](https://peps.python.org/pep-0740/) provenance for the
+    /// distributions of the specified packages.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            require-provenance-package = ["requests"]
+        "#
+    )]
+    pub require_provenance_package: Option<Vec<PackageName>>,
     /// Allow package upgrades, ignoring pinned versions in any existing output file.
     #[option(
         default = "false",
@@ -2322,6 +2471,8 @@ pub struct ToolOptions {
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
     no_sources_package: Option<Vec<PackageName>>,
+    require_provenance: Option<bool>,
+    require_provenance_package: Option<Vec<PackageName>>,
     no_build: Option<bool>,
     no_build_package: Option<Vec<PackageName>>,
     no_binary: Option<bool>,
@@ -2358,6 +2509,8 @@ pub struct ToolOptionsWire {
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
     no_sources_package: Option<Vec<PackageName>>,
+    require_provenance: Option<bool>,
+    require_provenance_package: Option<Vec<PackageName>>,
     no_build: Option<bool>,
     no_build_package: Option<Vec<PackageName>>,
     no_binary: Option<bool>,
@@ -2396,6 +2549,8 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
             no_sources_package: value.no_sources_package,
+            require_provenance: value.require_provenance,
+            require_provenance_package: value.require_provenance_package,
             no_build: value.no_build,
             no_build_package: value.no_build_package,
             no_binary: value.no_binary,
@@ -2447,6 +2602,8 @@ impl From<ToolOptionsWire> for ToolOptions {
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
             no_sources_package: value.no_sources_package,
+            require_provenance: value.require_provenance,
+            require_provenance_package: value.require_provenance_package,
             no_build: value.no_build,
             no_build_package: value.no_build_package,
             no_binary: value.no_binary,
@@ -2497,6 +2654,8 @@ impl From<ToolOptions> for ToolOptionsWire {
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
             no_sources_package: value.no_sources_package,
+            require_provenance: value.require_provenance,
+            require_provenance_package: value.require_provenance_package,
             no_build: value.no_build,
             no_build_package: value.no_build_package,
             no_binary: value.no_binary,
@@ -2534,6 +2693,8 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
             no_sources_package: value.no_sources_package,
+            require_provenance: value.require_provenance,
+            require_provenance_package: value.require_provenance_package,
             upgrade: None,
             reinstall: None,
             no_build: value.no_build,
@@ -2558,6 +2719,9 @@ struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_max_size: Option<CacheSize>,
+    cache_max_age: Option<CacheAge>,
+    shared_cache: Option<SharedCache>,
     preview: Option<bool>,
     preview_features: Option<PreviewFeaturesOption>,
     python_preference: Option<PythonPreference>,
@@ -2579,6 +2743,8 @@ struct OptionsWire {
     https_proxy: Option<ProxyUrl>,
     no_proxy: Option<Vec<String>>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
+    git_mirror: Option<Vec<GitMirror>>,
+    git_allowed_signers: Option<PathBuf>,
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     prerelease_package: Option<PrereleasePackage>,
@@ -2596,6 +2762,8 @@ struct OptionsWire {
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
     no_sources_package: Option<Vec<PackageName>>,
+    require_provenance: Option<bool>,
+    require_provenance_package: Option<Vec<PackageName>>,
     upgrade: Option<bool>,
     upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     reinstall: Option<bool>,
@@ -2647,6 +2815,8 @@ struct OptionsWire {
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
+    environment: Option<serde::de::IgnoredAny>,
+    scripts: Option<serde::de::IgnoredAny>,
 
     // Build backend
     build_backend: Option<serde::de::IgnoredAny>,
@@ -2664,6 +2834,9 @@ impl TryFrom<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            cache_max_size,
+            cache_max_age,
+            shared_cache,
             preview,
             preview_features,
             python_preference,
@@ -2685,6 +2858,8 @@ impl TryFrom<OptionsWire> for Options {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            git_mirror,
+            git_allowed_signers,
             resolution,
             prerelease,
             prerelease_package,
@@ -2700,6 +2875,8 @@ impl TryFrom<OptionsWire> for Options {
             compile_bytecode,
             no_sources,
             no_sources_package,
+            require_provenance,
+            require_provenance_package,
             upgrade,
             upgrade_package,
             reinstall,
@@ -2731,6 +2908,8 @@ impl TryFrom<OptionsWire> for Options {
             dev_dependencies,
             managed,
             package,
+            environment,
+            scripts,
             add_bounds: bounds,
             // Used by the build backend
             build_backend,
@@ -2744,6 +2923,9 @@ impl TryFrom<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_size,
+                cache_max_age,
+                shared_cache,
                 preview: PreviewOption::try_from(preview, preview_features)?,
                 python_preference,
                 python_downloads,
@@ -2755,6 +2937,8 @@ impl TryFrom<OptionsWire> for Options {
                 no_proxy,
                 // Used twice for backwards compatibility
                 allow_insecure_host: allow_insecure_host.clone(),
+                git_mirror,
+                git_allowed_signers,
             },
             top_level: ResolverInstallerSchema {
                 index,
@@ -2781,6 +2965,8 @@ impl TryFrom<OptionsWire> for Options {
                 compile_bytecode,
                 no_sources,
                 no_sources_package,
+                require_provenance,
+                require_provenance_package,
                 upgrade,
                 upgrade_package,
                 reinstall,
@@ -2820,6 +3006,8 @@ impl TryFrom<OptionsWire> for Options {
             dependency_groups,
             managed,
             package,
+            environment,
+            scripts,
         })
     }
 }
@@ -68,6 +68,22 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--max-size` argument to `uv cache prune`. If set, uv will evict
+    /// least-recently-used cache entries until the cache fits within the given size (e.g., `20G`).
+    #[attr_added_in("next release")]
+    pub const UV_CACHE_MAX_SIZE: &'static str = "UV_CACHE_MAX_SIZE";
+
+    /// Equivalent to the `--max-age` argument to `uv cache prune`. If set, uv will evict
+    /// cache entries that have not been used within the given duration (e.g., `30d`).
+    #[attr_added_in("next release")]
+    pub const UV_CACHE_MAX_AGE: &'static str = "UV_CACHE_MAX_AGE";
+
+    /// Equivalent to the `--shared-cache` command-line argument. If set, uv will consult the
+    /// given directory or HTTP(S) URL for wheels built from source before building them, and
+    /// populate it after successful builds.
+    #[attr_added_in("next release")]
+    pub const UV_SHARED_CACHE: &'static str = "UV_SHARED_CACHE";
+
     /// The directory for storage of credentials when using a plain text backend.
     #[attr_added_in("0.8.15")]
     pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
@@ -338,7 +354,8 @@ impl EnvVars {
     pub const UV_PUBLISH_TOKEN: &'static str = "UV_PUBLISH_TOKEN";
 
     /// Equivalent to the `--index` command-line argument in `uv publish`. If
-    /// set, uv will use the index with this name in the configuration for publishing.
+    /// set, uv will use the index with this name in the configuration for publishing. Multiple
+    /// indexes may be provided as a comma-separated list.
     #[attr_added_in("0.5.8")]
     pub const UV_PUBLISH_INDEX: &'static str = "UV_PUBLISH_INDEX";
 
@@ -352,11 +369,31 @@ impl EnvVars {
     #[attr_added_in("0.4.16")]
     pub const UV_PUBLISH_PASSWORD: &'static str = "UV_PUBLISH_PASSWORD";
 
+    /// Equivalent to the `--username` command-line argument in `uv index serve`. If set, uv
+    /// will require HTTP basic authentication with this username.
+    #[attr_added_in("next release")]
+    pub const UV_SERVE_USERNAME: &'static str = "UV_SERVE_USERNAME";
+
+    /// Equivalent to the `--password` command-line argument in `uv index serve`. If set, uv
+    /// will require HTTP basic authentication with this password.
+    #[attr_added_in("next release")]
+    pub const UV_SERVE_PASSWORD: &'static str = "UV_SERVE_PASSWORD";
+
     /// Equivalent to the `--check-url` command-line argument in `uv publish`.
     /// Don't upload a file if it already exists on the index. The value is the URL of the index.
     #[attr_added_in("0.4.30")]
     pub const UV_PUBLISH_CHECK_URL: &'static str = "UV_PUBLISH_CHECK_URL";
 
+    /// Equivalent to the `--fulcio-url` command-line argument in `uv publish`. The URL of the
+    /// Sigstore certificate authority used to sign attestations.
+    #[attr_added_in("next release")]
+    pub const UV_PUBLISH_FULCIO_URL: &'static str = "UV_PUBLISH_FULCIO_URL";
+
+    /// Equivalent to the `--rekor-url` command-line argument in `uv publish`. The URL of the
+    /// Sigstore transparency log in which attestation signatures are recorded.
+    #[attr_added_in("next release")]
+    pub const UV_PUBLISH_REKOR_URL: &'static str = "UV_PUBLISH_REKOR_URL";
+
     /// Equivalent to the `--no-attestations` command-line argument in `uv publish`. If set,
     /// uv will skip uploading any collected attestations for the published distributions.
     #[attr_added_in("0.9.12")]
@@ -668,6 +705,13 @@ impl EnvVars {
     #[attr_added_in("0.11.22")]
     pub const UV_INTERNAL__BUILD_DIR: &'static str = "UV_INTERNAL__BUILD_DIR";
 
+    /// Used by the `uv_build` Python package to describe the interpreter a wheel is built for, as
+    /// `<implementation> <major>.<minor>[t] <platform>`.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const UV_INTERNAL__BUILD_BACKEND_INTERPRETER: &'static str =
+        "UV_INTERNAL__BUILD_BACKEND_INTERPRETER";
+
     /// Used to force showing the derivation tree during resolver error reporting.
     #[attr_hidden]
     #[attr_added_in("0.3.0")]
@@ -917,6 +961,13 @@ impl EnvVars {
     #[attr_added_in("0.8.16")]
     pub const ANDROID_API_LEVEL: &'static str = "ANDROID_API_LEVEL";
 
+    /// The timestamp to use for the files in distributions built with `uv_build`, in seconds
+    /// since the Unix epoch.
+    ///
+    /// See [reproducible-builds.org](https://reproducible-builds.org/specs/source-date-epoch/).
+    #[attr_added_in("next release")]
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// Disables colored output (takes precedence over `FORCE_COLOR`).
     ///
     /// See [no-color.org](https://no-color.org).
@@ -937,6 +988,11 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const PATH: &'static str = "PATH";
 
+    /// The standard `LD_LIBRARY_PATH` env var, used to find the shared libraries to vendor with
+    /// `uv build --repair`.
+    #[attr_added_in("next release")]
+    pub const LD_LIBRARY_PATH: &'static str = "LD_LIBRARY_PATH";
+
     /// The standard `HOME` env var.
     #[attr_added_in("0.0.5")]
     pub const HOME: &'static str = "HOME";
@@ -1053,6 +1109,11 @@ impl EnvVars {
     #[attr_added_in("0.8.18")]
     pub const TESTPYPI_ID_TOKEN: &'static str = "TESTPYPI_ID_TOKEN";
 
+    /// Used for testing GitLab CI attestation signing.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const SIGSTORE_ID_TOKEN: &'static str = "SIGSTORE_ID_TOKEN";
+
     /// Sets the encoding for standard I/O streams (e.g., PYTHONIOENCODING=utf-8).
     #[attr_hidden]
     #[attr_added_in("0.4.18")]
@@ -1413,6 +1474,11 @@ impl EnvVars {
     #[attr_added_in("0.7.13")]
     pub const UV_NO_GITHUB_FAST_PATH: &'static str = "UV_NO_GITHUB_FAST_PATH";
 
+    /// Disable partial (blob-less) fetches and sparse checkouts of Git dependencies, such that
+    /// uv always fetches and checks out the full repository.
+    #[attr_added_in("next release")]
+    pub const UV_NO_GIT_PARTIAL_CLONE: &'static str = "UV_NO_GIT_PARTIAL_CLONE";
+
     /// Authentication token for Hugging Face requests. When set, uv will use this token
     /// when making requests to `https://huggingface.co/` and any subdomains.
     #[attr_added_in("0.8.1")]
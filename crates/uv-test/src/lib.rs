@@ -6,6 +6,7 @@ pub mod find_links;
 mod http_server;
 pub mod packse;
 pub mod pypi_proxy;
+pub mod simple_index;
 mod vendor;
 
 use std::borrow::BorrowMut;
@@ -1485,6 +1486,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv workspace exec` command with options shared across scenarios.
+    pub fn workspace_exec(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace").arg("exec");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv export` command with options shared across scenarios.
     pub fn export(&self) -> Command {
         let mut command = self.new_command();
@@ -1728,6 +1737,38 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache export` command.
+    pub fn cache_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("export");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache import` command.
+    pub fn cache_import(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("import");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache stats` command.
+    pub fn cache_stats(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("stats");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -0,0 +1,93 @@
+//! A local HTTP server that serves a directory of distributions as a simple index.
+//!
+//! Uses the same implementation as `uv index serve`, with generated HTML and JSON pages, hashes
+//! and PEP 658 metadata, and optionally HTTP basic authentication.
+
+use std::net::SocketAddr;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use uv_auth::Credentials;
+use uv_publish::{IndexServer, ServedIndex};
+
+/// A running HTTP server that serves the distributions in a directory as a simple index.
+pub struct SimpleIndexServer {
+    url: String,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl SimpleIndexServer {
+    /// Start a server that serves the wheels and source distributions in the given directory.
+    pub fn new(directory: &Path) -> Self {
+        Self::start(directory, None)
+    }
+
+    /// Start a server that requires HTTP basic authentication with the given credentials.
+    pub fn with_credentials(directory: &Path, username: &str, password: &str) -> Self {
+        Self::start(
+            directory,
+            Some(Credentials::basic(
+                Some(username.to_string()),
+                Some(password.to_string()),
+            )),
+        )
+    }
+
+    fn start(directory: &Path, credentials: Option<Credentials>) -> Self {
+        let directory = directory.to_path_buf();
+        let (url_tx, url_rx) = std::sync::mpsc::channel::<String>();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+        let thread = thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to create tokio runtime for local index server");
+
+            runtime.block_on(async move {
+                let index = ServedIndex::from_directory(&directory)
+                    .await
+                    .expect("failed to read the index directory");
+                let server = IndexServer::bind(
+                    SocketAddr::from(([127, 0, 0, 1], 0)),
+                    index,
+                    credentials.as_ref(),
+                )
+                .await
+                .expect("failed to start local index server");
+
+                url_tx.send(server.url().to_string()).ok();
+                tokio::select! {
+                    result = server.serve() => result.expect("local index server failed"),
+                    _ = shutdown_rx => {}
+                }
+            });
+        });
+
+        let url = url_rx
+            .recv_timeout(Duration::from_secs(30))
+            .expect("timed out waiting for local index server to start");
+
+        Self {
+            url,
+            shutdown: Some(shutdown_tx),
+            thread: Some(thread),
+        }
+    }
+
+    /// The Simple API index URL (e.g., `http://127.0.0.1:PORT/simple/`).
+    pub fn index_url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for SimpleIndexServer {
+    fn drop(&mut self) {
+        drop(self.shutdown.take());
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
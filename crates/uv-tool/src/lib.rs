@@ -162,6 +162,10 @@ impl InstalledTools {
             else {
                 continue;
             };
+            // Skip the shared environments directory.
+            if name.starts_with('.') {
+                continue;
+            }
             let name = PackageName::from_str(name)?;
             let path = directory.join("uv-receipt.toml");
             let contents = match fs_err::read_to_string(&path) {
@@ -235,6 +239,9 @@ impl InstalledTools {
 
     /// Remove the environment for a tool.
     ///
+    /// If the tool is installed into a shared environment, the shared environment is only removed
+    /// once no other tool is installed into it.
+    ///
     /// Does not remove the tool's entrypoints.
     ///
     /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
@@ -252,6 +259,138 @@ impl InstalledTools {
 
         uv_fs::remove_virtualenv(environment_path.as_path()).map_err(uv_virtualenv::Error::from)?;
 
+        self.remove_unused_shared_environments()?;
+
+        Ok(())
+    }
+
+    /// Return the directory for the shared environment with the given ID.
+    pub fn shared_environment_dir(&self, id: &str) -> PathBuf {
+        self.root.join(".shared").join(id)
+    }
+
+    /// Return the ID of the shared environment at the given path, if the path refers to a shared
+    /// environment.
+    pub fn shared_environment_id(&self, path: &Path) -> Option<String> {
+        let parent = path.parent()?;
+        let shared = self.root.join(".shared");
+        if parent != shared && fs::canonicalize(parent).ok()? != fs::canonicalize(&shared).ok()? {
+            return None;
+        }
+        path.file_name()?.to_str().map(ToString::to_string)
+    }
+
+    /// Return the shared environments, along with their IDs.
+    ///
+    /// Environments that can't be read (e.g., because they're linked to a non-existent
+    /// interpreter) are skipped.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn shared_environments(
+        &self,
+        cache: &Cache,
+    ) -> Result<Vec<(String, PythonEnvironment)>, Error> {
+        let mut environments = Vec::new();
+        for directory in uv_fs::directories(self.root.join(".shared"))? {
+            let Some(id) = self.shared_environment_id(&directory) else {
+                continue;
+            };
+            match PythonEnvironment::from_root(&directory, cache) {
+                Ok(environment) => environments.push((id, environment)),
+                Err(err) => {
+                    warn!(
+                        "Ignoring unreadable shared tool environment at {}: {err}",
+                        directory.user_display()
+                    );
+                }
+            }
+        }
+        environments.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(environments)
+    }
+
+    /// Create a new shared environment, returning its ID.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn create_shared_environment(
+        &self,
+        interpreter: Interpreter,
+    ) -> Result<(String, PythonEnvironment), Error> {
+        let mut index = 1usize;
+        while self.shared_environment_dir(&index.to_string()).exists() {
+            index += 1;
+        }
+        let id = index.to_string();
+        let environment_path = self.shared_environment_dir(&id);
+
+        debug!(
+            "Creating shared tool environment: {}",
+            environment_path.user_display()
+        );
+
+        let venv = uv_virtualenv::create_venv(
+            &environment_path,
+            interpreter,
+            uv_virtualenv::Prompt::None,
+            false,
+            uv_virtualenv::OnExisting::Remove(uv_virtualenv::RemovalReason::ManagedEnvironment),
+            false,
+            uv_virtualenv::Seed::Disabled,
+            false,
+        )?;
+
+        Ok((id, venv))
+    }
+
+    /// Prepare the directory for a tool that will be installed into a shared environment.
+    ///
+    /// Removes any existing environment for the tool, leaving an empty directory to hold the
+    /// receipt.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn create_shared_tool_dir(&self, name: &PackageName) -> Result<(), Error> {
+        let tool_dir = self.tool_dir(name);
+        match uv_fs::remove_virtualenv(&tool_dir) {
+            Ok(()) => {
+                debug!(
+                    "Removed existing environment for tool `{name}`: {}",
+                    tool_dir.user_display()
+                );
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(uv_virtualenv::Error::from(err).into()),
+        }
+        fs::create_dir_all(&tool_dir)?;
+        Ok(())
+    }
+
+    /// Remove any shared environments that no tool is installed into.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn remove_unused_shared_environments(&self) -> Result<(), Error> {
+        let shared = self.root.join(".shared");
+        if !shared.is_dir() {
+            return Ok(());
+        }
+
+        let used = self
+            .tools()?
+            .into_iter()
+            .filter_map(|(_, tool)| tool.ok()?.shared_environment().map(ToString::to_string))
+            .collect::<Vec<_>>();
+        for directory in uv_fs::directories(&shared)? {
+            let Some(id) = self.shared_environment_id(&directory) else {
+                continue;
+            };
+            if used.contains(&id) {
+                continue;
+            }
+            debug!(
+                "Removing unused shared tool environment: {}",
+                directory.user_display()
+            );
+            uv_fs::remove_virtualenv(&directory).map_err(uv_virtualenv::Error::from)?;
+        }
         Ok(())
     }
 
@@ -266,7 +405,7 @@ impl InstalledTools {
         name: &PackageName,
         cache: &Cache,
     ) -> Result<Option<ToolEnvironment>, Error> {
-        let environment_path = self.tool_dir(name);
+        let environment_path = self.environment_dir(name);
 
         match PythonEnvironment::from_root(&environment_path, cache) {
             Ok(venv) => {
@@ -312,6 +451,20 @@ impl InstalledTools {
         }
     }
 
+    /// Return the directory of the environment the given tool is installed into.
+    ///
+    /// For tools installed into a shared environment, this is the shared environment; otherwise,
+    /// it's the tool directory itself.
+    pub fn environment_dir(&self, name: &PackageName) -> PathBuf {
+        match self.get_tool_receipt(name) {
+            Ok(Some(tool)) => match tool.shared_environment() {
+                Some(id) => self.shared_environment_dir(id),
+                None => self.tool_dir(name),
+            },
+            _ => self.tool_dir(name),
+        }
+    }
+
     /// Create the [`PythonEnvironment`] for a given tool, removing any existing environments.
     ///
     /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
@@ -334,6 +487,9 @@ impl InstalledTools {
             Err(err) => return Err(uv_virtualenv::Error::from(err).into()),
         }
 
+        // The tool may have been installed into a shared environment that's no longer in use.
+        self.remove_unused_shared_environments()?;
+
         debug!(
             "Creating environment for tool `{name}`: {}",
             environment_path.user_display()
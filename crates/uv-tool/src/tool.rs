@@ -30,6 +30,8 @@ pub struct Tool {
     build_constraints: Vec<Requirement>,
     /// The Python requested by the user during installation.
     python: Option<PythonRequest>,
+    /// The shared environment the tool is installed into, if any.
+    shared_environment: Option<String>,
     /// A mapping of entry point names to their metadata.
     entrypoints: Vec<ToolEntrypoint>,
     /// The [`ToolOptions`] used to install this tool.
@@ -50,6 +52,7 @@ struct ToolWire {
     #[serde(default)]
     build_constraint_dependencies: Vec<Requirement>,
     python: Option<PythonRequest>,
+    shared_environment: Option<String>,
     entrypoints: Vec<ToolEntrypoint>,
     #[serde(default)]
     options: ToolOptionsWire,
@@ -78,6 +81,7 @@ impl From<Tool> for ToolWire {
             excludes: tool.excludes,
             build_constraint_dependencies: tool.build_constraints,
             python: tool.python,
+            shared_environment: tool.shared_environment,
             entrypoints: tool.entrypoints,
             options: tool.options.into(),
         }
@@ -102,6 +106,7 @@ impl TryFrom<ToolWire> for Tool {
             excludes: tool.excludes,
             build_constraints: tool.build_constraint_dependencies,
             python: tool.python,
+            shared_environment: tool.shared_environment,
             entrypoints: tool.entrypoints,
             options: tool.options.into(),
         })
@@ -190,6 +195,7 @@ impl Tool {
             excludes,
             build_constraints,
             python,
+            shared_environment: None,
             entrypoints,
             options,
         }
@@ -201,6 +207,15 @@ impl Tool {
         Self { options, ..self }
     }
 
+    /// Create a new [`Tool`] installed into the given shared environment.
+    #[must_use]
+    pub fn with_shared_environment(self, shared_environment: Option<String>) -> Self {
+        Self {
+            shared_environment,
+            ..self
+        }
+    }
+
     /// Returns the TOML table for this tool.
     pub(crate) fn to_toml(&self) -> Result<Table, toml_edit::ser::Error> {
         let mut table = Table::new();
@@ -325,6 +340,10 @@ impl Tool {
             );
         }
 
+        if let Some(ref shared_environment) = self.shared_environment {
+            table.insert("shared-environment", value(shared_environment));
+        }
+
         table.insert("entrypoints", {
             let entrypoints = each_element_on_its_line_array(
                 self.entrypoints
@@ -379,6 +398,10 @@ impl Tool {
         &self.python
     }
 
+    pub fn shared_environment(&self) -> Option<&str> {
+        self.shared_environment.as_deref()
+    }
+
     pub fn options(&self) -> &ToolOptions {
         &self.options
     }
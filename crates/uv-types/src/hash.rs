@@ -329,6 +329,45 @@ impl HashStrategy {
         }
     }
 
+    /// Return a [`HashStrategy`] that requires each of the given distributions to match exactly
+    /// the given digest.
+    ///
+    /// Distributions that already have hashes must include the pinned digest; distributions
+    /// without hashes are added, except in [`HashStrategy::Require`] mode, in which the set of
+    /// allowed distributions is left unchanged.
+    pub fn pin_digests<'a>(
+        self,
+        digests: impl IntoIterator<Item = (VersionId, &'a HashDigest)>,
+    ) -> Result<Self, HashStrategyError> {
+        let (mut hashes, require) = match self {
+            Self::None | Self::Generate(_) => (FxHashMap::default(), false),
+            Self::Verify(hashes) => (Arc::unwrap_or_clone(hashes), false),
+            Self::Require(hashes) => (Arc::unwrap_or_clone(hashes), true),
+        };
+
+        for (id, digest) in digests {
+            match hashes.get_mut(&id) {
+                Some(existing) if !existing.is_empty() && !existing.contains(digest) => {
+                    return Err(HashStrategyError::PinnedHashMismatch(
+                        id.to_string(),
+                        digest.clone(),
+                    ));
+                }
+                Some(existing) => *existing = vec![digest.clone()],
+                None if require => {}
+                None => {
+                    hashes.insert(id, vec![digest.clone()]);
+                }
+            }
+        }
+
+        if require {
+            Ok(Self::Require(Arc::new(hashes)))
+        } else {
+            Ok(Self::Verify(Arc::new(hashes)))
+        }
+    }
+
     /// Augment an existing set of hashes with archive URL hashes discovered in additional
     /// requirements.
     ///
@@ -506,6 +545,8 @@ pub enum HashStrategyError {
         "In `{1}` mode, all requirements must have a hash, but there were no overlapping hashes between the requirements and constraints for: {0}"
     )]
     NoIntersection(String, HashCheckingMode),
+    #[error("The hash `{1}` reported by the index is not among the provided hashes for: {0}")]
+    PinnedHashMismatch(String, HashDigest),
 }
 
 #[cfg(test)]
@@ -577,4 +618,57 @@ mod tests {
             assert_eq!(hasher.get_url(url), HashPolicy::All(expected.as_slice()));
         }
     }
+
+    #[test]
+    fn pin_registry_hashes() {
+        let name = "anyio".parse().unwrap();
+        let version = "4.0.0".parse().unwrap();
+        let id = uv_distribution_types::VersionId::from_registry(
+            "anyio".parse().unwrap(),
+            "4.0.0".parse().unwrap(),
+        );
+        let wheel = HashDigest::from_str(
+            "sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+        )
+        .unwrap();
+        let sdist = HashDigest::from_str(
+            "sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a",
+        )
+        .unwrap();
+
+        // Without a hash policy, the pinned digest is verified.
+        let hasher = HashStrategy::None
+            .pin_digests([(id.clone(), &wheel)])
+            .unwrap();
+        assert_eq!(
+            hasher.get_package(&name, &version),
+            HashPolicy::Any(std::slice::from_ref(&wheel))
+        );
+
+        // Existing hashes are narrowed to the pinned digest.
+        let mut hashes = rustc_hash::FxHashMap::default();
+        hashes.insert(id.clone(), vec![sdist.clone(), wheel.clone()]);
+        let hasher = HashStrategy::Require(std::sync::Arc::new(hashes))
+            .pin_digests([(id.clone(), &wheel)])
+            .unwrap();
+        assert_eq!(
+            hasher.get_package(&name, &version),
+            HashPolicy::Any(std::slice::from_ref(&wheel))
+        );
+
+        // But must include it.
+        let mut hashes = rustc_hash::FxHashMap::default();
+        hashes.insert(id.clone(), vec![sdist]);
+        assert!(
+            HashStrategy::Verify(std::sync::Arc::new(hashes))
+                .pin_digests([(id.clone(), &wheel)])
+                .is_err()
+        );
+
+        // Pinning doesn't allow additional packages in `--require-hashes` mode.
+        let hasher = HashStrategy::Require(std::sync::Arc::default())
+            .pin_digests([(id, &wheel)])
+            .unwrap();
+        assert!(!hasher.allows_package(&name, &version));
+    }
 }
@@ -0,0 +1,253 @@
+//! Render project-declared environment variables into the activation scripts.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use uv_fs::Simplified;
+use uv_shell::escape_posix_for_single_quotes;
+
+use crate::Error;
+
+/// The environment to apply when a virtual environment is activated, e.g., as declared in
+/// `[tool.uv.environment]`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ActivationEnvironment {
+    /// The variables to set, unless already set in the calling environment.
+    pub variables: Vec<(String, String)>,
+    /// The directories to prepend to `PATH`, after the environment's own executables.
+    pub path_prepend: Vec<PathBuf>,
+}
+
+impl ActivationEnvironment {
+    /// Returns `true` if the environment does not set any variables or `PATH` entries.
+    pub fn is_empty(&self) -> bool {
+        self.variables.is_empty() && self.path_prepend.is_empty()
+    }
+
+    /// Render the `{{ ACTIVATE_PATH }}`, `{{ ACTIVATE_VARIABLES }}`, and
+    /// `{{ DEACTIVATE_VARIABLES }}` placeholders for the given activator.
+    ///
+    /// Variables that were set on activation are tracked via an `_UV_ENVIRONMENT_{name}` marker,
+    /// such that deactivation only unsets the variables it set.
+    pub(crate) fn render(
+        &self,
+        activator: &str,
+        path_sep: &str,
+    ) -> Result<[(&'static str, String); 3], Error> {
+        let paths = self
+            .path_prepend
+            .iter()
+            .map(|path| {
+                path.simplified()
+                    .to_str()
+                    .ok_or_else(|| Error::NonUtf8Path { path: path.clone() })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut activate_path = String::new();
+        let mut activate_variables = String::new();
+        let mut deactivate_variables = String::new();
+
+        match activator {
+            "activate" => {
+                if !paths.is_empty() {
+                    activate_path.push_str("PATH=");
+                    for path in &paths {
+                        let _ =
+                            write!(activate_path, "'{}':", escape_posix_for_single_quotes(path));
+                    }
+                    let _ = writeln!(activate_path, "\"$PATH\"");
+                }
+                for (name, value) in &self.variables {
+                    let value = escape_posix_for_single_quotes(value);
+                    let _ = writeln!(activate_variables, "if [ -z \"${{{name}+_}}\" ] ; then");
+                    let _ = writeln!(activate_variables, "    {name}='{value}'");
+                    let _ = writeln!(activate_variables, "    export {name}");
+                    let _ = writeln!(activate_variables, "    _UV_ENVIRONMENT_{name}=1");
+                    let _ = writeln!(activate_variables, "fi");
+                    let _ = writeln!(
+                        deactivate_variables,
+                        "    if ! [ -z \"${{_UV_ENVIRONMENT_{name}+_}}\" ] ; then"
+                    );
+                    let _ = writeln!(deactivate_variables, "        unset {name}");
+                    let _ = writeln!(deactivate_variables, "        unset _UV_ENVIRONMENT_{name}");
+                    let _ = writeln!(deactivate_variables, "    fi");
+                }
+            }
+            "activate.fish" => {
+                if !paths.is_empty() {
+                    activate_path.push_str("set -gx PATH ");
+                    for path in &paths {
+                        let _ = write!(activate_path, "'{}' ", escape_fish(path));
+                    }
+                    let _ = writeln!(activate_path, "$PATH");
+                }
+                for (name, value) in &self.variables {
+                    let _ = writeln!(activate_variables, "if not set -q {name}");
+                    let _ = writeln!(
+                        activate_variables,
+                        "    set -gx {name} '{}'",
+                        escape_fish(value)
+                    );
+                    let _ = writeln!(activate_variables, "    set -g _UV_ENVIRONMENT_{name} 1");
+                    let _ = writeln!(activate_variables, "end");
+                    let _ = writeln!(deactivate_variables, "    if set -q _UV_ENVIRONMENT_{name}");
+                    let _ = writeln!(deactivate_variables, "        set -e {name}");
+                    let _ = writeln!(
+                        deactivate_variables,
+                        "        set -e _UV_ENVIRONMENT_{name}"
+                    );
+                    let _ = writeln!(deactivate_variables, "    end");
+                }
+            }
+            "activate.csh" => {
+                if !paths.is_empty() {
+                    activate_path.push_str("setenv PATH ");
+                    for path in &paths {
+                        let _ = write!(activate_path, "'{}':", escape_csh(path));
+                    }
+                    let _ = writeln!(activate_path, "\"$PATH:q\"");
+                }
+                for (name, value) in &self.variables {
+                    let _ = writeln!(activate_variables, "if ( ! $?{name} ) then");
+                    let _ = writeln!(
+                        activate_variables,
+                        "    setenv {name} '{}'",
+                        escape_csh(value)
+                    );
+                    let _ = writeln!(activate_variables, "    set _UV_ENVIRONMENT_{name}=1");
+                    let _ = writeln!(activate_variables, "endif");
+                    // The `deactivate` alias is defined on a single line.
+                    let _ = write!(
+                        deactivate_variables,
+                        "test $?_UV_ENVIRONMENT_{name} != 0 && unsetenv {name} && unset _UV_ENVIRONMENT_{name}; "
+                    );
+                }
+            }
+            "activate.nu" => {
+                // Nushell restores the environment when the overlay is hidden, so there's no need
+                // to track the variables that were set.
+                if !paths.is_empty() {
+                    activate_path.push_str("    let venv_path = [$venv_path");
+                    for path in &paths {
+                        let _ = write!(activate_path, " {}", escape_nu(path));
+                    }
+                    let _ = writeln!(activate_path, "]");
+                }
+                for (name, value) in &self.variables {
+                    let _ = writeln!(
+                        activate_variables,
+                        "    let new_env = if ('{name}' in $env) {{ $new_env }} else {{ $new_env | insert {name} {} }}",
+                        escape_nu(value)
+                    );
+                }
+            }
+            "activate.ps1" => {
+                if !paths.is_empty() {
+                    activate_path.push_str("$env:PATH = ");
+                    for path in &paths {
+                        let _ = write!(activate_path, "'{}{path_sep}' + ", escape_powershell(path));
+                    }
+                    let _ = writeln!(activate_path, "$env:PATH");
+                }
+                for (name, value) in &self.variables {
+                    let _ = writeln!(activate_variables, "if (-not (Test-Path env:{name})) {{");
+                    let _ = writeln!(
+                        activate_variables,
+                        "    $env:{name} = '{}'",
+                        escape_powershell(value)
+                    );
+                    let _ = writeln!(
+                        activate_variables,
+                        "    Set-Variable -Scope global -Name _UV_ENVIRONMENT_{name} -Value $true"
+                    );
+                    let _ = writeln!(activate_variables, "}}");
+                    let _ = writeln!(
+                        deactivate_variables,
+                        "    if (Test-Path variable:_UV_ENVIRONMENT_{name}) {{"
+                    );
+                    let _ = writeln!(
+                        deactivate_variables,
+                        "        Remove-Item env:{name} -ErrorAction SilentlyContinue"
+                    );
+                    let _ = writeln!(
+                        deactivate_variables,
+                        "        Remove-Variable \"_UV_ENVIRONMENT_{name}\" -Scope global"
+                    );
+                    let _ = writeln!(deactivate_variables, "    }}");
+                }
+            }
+            "activate.xsh" => {
+                // The xonsh activator tracks the variables it overrides itself, so the
+                // placeholders are rendered as Python literals.
+                activate_path = format!(
+                    "[{}]",
+                    paths
+                        .iter()
+                        .map(|path| escape_python(path))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                activate_variables = format!(
+                    "{{{}}}",
+                    self.variables
+                        .iter()
+                        .map(|(name, value)| format!("\"{name}\": {}", escape_python(value)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+            _ => {}
+        }
+
+        Ok([
+            ("{{ ACTIVATE_PATH }}", activate_path),
+            ("{{ ACTIVATE_VARIABLES }}", activate_variables),
+            ("{{ DEACTIVATE_VARIABLES }}", deactivate_variables),
+        ])
+    }
+}
+
+/// Escape a string for use within single quotes in fish.
+fn escape_fish(value: &str) -> Cow<'_, str> {
+    if value.contains(['\\', '\'']) {
+        Cow::Owned(value.replace('\\', r"\\").replace('\'', r"\'"))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Escape a string for use within single quotes in csh.
+fn escape_csh(value: &str) -> Cow<'_, str> {
+    if value.contains(['\'', '!']) {
+        Cow::Owned(value.replace('\'', r#"'"'"'"#).replace('!', r"\!"))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Escape a string for use within single quotes in PowerShell.
+fn escape_powershell(value: &str) -> Cow<'_, str> {
+    if value.contains('\'') {
+        Cow::Owned(value.replace('\'', "''"))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Serialize a string as a Nushell raw string literal.
+fn escape_nu(value: &str) -> String {
+    // A raw string is terminated by a single quote followed by as many `#` as it was opened with.
+    let mut hashes = 1;
+    while value.contains(&format!("'{}", "#".repeat(hashes))) {
+        hashes += 1;
+    }
+    let hashes = "#".repeat(hashes);
+    format!("r{hashes}'{value}'{hashes}")
+}
+
+/// Serialize a string as a Python expression that evaluates to a `str`.
+fn escape_python(value: &str) -> String {
+    format!(r#"b"{}".decode("utf-8")"#, value.as_bytes().escape_ascii())
+}
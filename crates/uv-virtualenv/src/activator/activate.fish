@@ -51,6 +51,7 @@ function deactivate -d 'Exit virtualenv mode and return to the normal environmen
         set -gx PYTHONHOME "$_OLD_VIRTUAL_PYTHONHOME"
         set -e _OLD_VIRTUAL_PYTHONHOME
     end
+{{ DEACTIVATE_VARIABLES }}
 
     if test -n "$_OLD_FISH_PROMPT_OVERRIDE"
        and functions -q _old_fish_prompt
@@ -90,6 +91,7 @@ if test (string sub -s 1 -l 1 $FISH_VERSION) -lt 3
 else
     set -gx _OLD_VIRTUAL_PATH $PATH
 end
+{{ ACTIVATE_PATH }}
 set -gx PATH "$VIRTUAL_ENV"'/{{ BIN_NAME }}' $PATH
 
 # Prompt override provided?
@@ -99,6 +101,7 @@ if test -n '{{ VIRTUAL_PROMPT }}'
 else
     set -gx VIRTUAL_ENV_PROMPT (basename "$VIRTUAL_ENV")
 end
+{{ ACTIVATE_VARIABLES }}
 
 # Unset `$PYTHONHOME` if set.
 if set -q PYTHONHOME
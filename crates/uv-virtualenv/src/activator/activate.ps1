@@ -33,6 +33,7 @@ function global:deactivate([switch] $NonDestructive) {
         Remove-Item function:\_old_virtual_prompt
     }
 
+{{ DEACTIVATE_VARIABLES }}
     if ($env:VIRTUAL_ENV) {
         Remove-Item env:VIRTUAL_ENV -ErrorAction SilentlyContinue
     }
@@ -64,8 +65,10 @@ if ("{{ VIRTUAL_PROMPT }}" -ne "") {
 else {
     $env:VIRTUAL_ENV_PROMPT = $( Split-Path $env:VIRTUAL_ENV -Leaf )
 }
+{{ ACTIVATE_VARIABLES }}
 
 New-Variable -Scope global -Name _OLD_VIRTUAL_PATH -Value $env:PATH
+{{ ACTIVATE_PATH }}
 
 $env:PATH = "$env:VIRTUAL_ENV/{{ BIN_NAME }}{{ PATH_SEP }}" + $env:PATH
 if (!$env:VIRTUAL_ENV_DISABLE_PROMPT) {
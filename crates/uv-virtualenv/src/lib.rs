@@ -6,8 +6,12 @@ use thiserror::Error;
 use uv_fs::Simplified;
 use uv_python::{Interpreter, PythonEnvironment};
 
+pub use activation::ActivationEnvironment;
+pub use parents::add_parent_environments;
 pub use virtualenv::{ClearNonVirtualenv, OnExisting, RemovalReason, Seed};
 
+mod activation;
+mod parents;
 mod virtualenv;
 
 #[derive(Debug, Error)]
@@ -37,6 +41,28 @@ pub enum Error {
         /// The non-UTF-8 virtual environment path.
         path: PathBuf,
     },
+    #[error("Parent environment is not a virtual environment: {}", path.user_display())]
+    ParentNotVirtualenv {
+        /// The path to the parent environment.
+        path: PathBuf,
+    },
+    #[error("A virtual environment cannot be its own parent: {}", path.user_display())]
+    RecursiveParent {
+        /// The path to the virtual environment.
+        path: PathBuf,
+    },
+    #[error(
+        "Parent environment at {} uses Python {found}, but the virtual environment uses Python {expected}",
+        path.user_display()
+    )]
+    IncompatibleParent {
+        /// The path to the parent environment.
+        path: PathBuf,
+        /// The Python version of the parent environment.
+        found: String,
+        /// The Python version of the virtual environment.
+        expected: String,
+    },
 }
 
 impl uv_errors::Hint for Error {
@@ -103,3 +129,26 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Rewrite the activation scripts of a virtual environment to apply the given environment.
+///
+/// Virtual environments that were not created by uv are left untouched, as are scripts whose
+/// contents are unchanged.
+pub fn update_activators(
+    environment: &PythonEnvironment,
+    activation: &ActivationEnvironment,
+) -> Result<(), Error> {
+    let Ok(cfg) = environment.cfg() else {
+        return Ok(());
+    };
+    if !cfg.is_uv() {
+        return Ok(());
+    }
+    virtualenv::write_activators(
+        environment.root(),
+        environment.interpreter(),
+        cfg.prompt(),
+        cfg.is_relocatable(),
+        activation,
+    )
+}
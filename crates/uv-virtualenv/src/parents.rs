@@ -0,0 +1,124 @@
+//! Layer read-only parent environments beneath a virtual environment.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_fs::{PythonExt, Simplified};
+use uv_python::{PyVenvConfiguration, PythonEnvironment};
+
+use crate::Error;
+
+/// The `.pth` file used to append the `site-packages` of parent environments to `sys.path`.
+const PARENTS_PTH: &str = "_uv_parents.pth";
+
+/// The `pyvenv.cfg` key used to record the parent environments of a virtual environment.
+const PARENTS_KEY: &str = "parent-environments";
+
+/// Layer the given parent environments beneath a virtual environment.
+///
+/// The `site-packages` directories of each parent (and, transitively, of the parents declared by
+/// each parent) are appended to `sys.path` via a `.pth` file, such that packages installed in the
+/// virtual environment itself take precedence. The parents are recorded in `pyvenv.cfg`, so that
+/// uv can treat packages provided by a parent as installed.
+///
+/// Parent environments are never modified.
+pub fn add_parent_environments(
+    environment: &PythonEnvironment,
+    parents: &[PathBuf],
+) -> Result<(), Error> {
+    let interpreter = environment.interpreter();
+    let layers = flatten_parents(environment.root(), parents)?;
+
+    for layer in &layers {
+        let cfg = PyVenvConfiguration::parse(layer.join("pyvenv.cfg")).map_err(|_| {
+            Error::ParentNotVirtualenv {
+                path: layer.clone(),
+            }
+        })?;
+        if let Some(version) = cfg.version()
+            && (version.major(), version.minor())
+                != (interpreter.python_major(), interpreter.python_minor())
+        {
+            return Err(Error::IncompatibleParent {
+                path: layer.clone(),
+                found: format!("{}.{}", version.major(), version.minor()),
+                expected: format!(
+                    "{}.{}",
+                    interpreter.python_major(),
+                    interpreter.python_minor()
+                ),
+            });
+        }
+    }
+
+    // Write a `.pth` file that appends each layer's `site-packages` to `sys.path`. We use
+    // `site.addsitedir` (rather than a bare path) so that `.pth` files in the parent environments,
+    // e.g., for editable installs, are processed too.
+    let site_packages = environment.root().join(&interpreter.virtualenv().purelib);
+    let mut contents = String::new();
+    for layer in &layers {
+        for directory in [
+            interpreter.virtualenv().purelib.as_path(),
+            interpreter.virtualenv().platlib.as_path(),
+        ]
+        .into_iter()
+        .dedup()
+        {
+            let directory = layer.join(directory);
+            debug!(
+                "Layering parent `site-packages`: {}",
+                directory.user_display()
+            );
+            writeln!(
+                contents,
+                "import site; site.addsitedir({})",
+                directory.escape_for_python()
+            )
+            .expect("writing to a `String` is infallible");
+        }
+    }
+    fs_err::create_dir_all(&site_packages)?;
+    fs_err::write(site_packages.join(PARENTS_PTH), contents)?;
+
+    let value = std::env::join_paths(&layers).map_err(|_| Error::NonUtf8Path {
+        path: environment.root().to_path_buf(),
+    })?;
+    let value = value.to_str().ok_or_else(|| Error::NonUtf8Path {
+        path: environment.root().to_path_buf(),
+    })?;
+    let pyvenv_cfg = environment.root().join("pyvenv.cfg");
+    let content = fs_err::read_to_string(&pyvenv_cfg)?;
+    fs_err::write(
+        &pyvenv_cfg,
+        PyVenvConfiguration::set(&content, PARENTS_KEY, value),
+    )?;
+
+    Ok(())
+}
+
+/// Resolve the given parents to absolute paths, expanding the parents declared by each parent
+/// environment in depth-first order.
+fn flatten_parents(root: &Path, parents: &[PathBuf]) -> Result<Vec<PathBuf>, Error> {
+    let root = std::path::absolute(root)?;
+    let mut seen = FxHashSet::default();
+    let mut layers = Vec::new();
+    let mut stack = parents.iter().rev().cloned().collect::<Vec<_>>();
+    while let Some(parent) = stack.pop() {
+        let parent = std::path::absolute(&parent)?;
+        if parent == root {
+            return Err(Error::RecursiveParent { path: parent });
+        }
+        if !seen.insert(parent.clone()) {
+            continue;
+        }
+        if let Ok(cfg) = PyVenvConfiguration::parse(parent.join("pyvenv.cfg")) {
+            stack.extend(cfg.parent_environments().iter().rev().cloned());
+        }
+        layers.push(parent);
+    }
+    Ok(layers)
+}
@@ -14,7 +14,7 @@ use owo_colors::OwoColorize;
 
 use tracing::{debug, trace};
 
-use crate::{Error, Prompt};
+use crate::{ActivationEnvironment, Error, Prompt};
 use uv_fs::{CWD, PythonExt, Simplified, cachedir};
 use uv_platform_tags::Os;
 use uv_preview::PreviewFeature;
@@ -196,13 +196,6 @@ pub(crate) fn create(
     // Use the absolute path for all further operations.
     let location = absolute;
 
-    let bin_name = if cfg!(unix) {
-        "bin"
-    } else if cfg!(windows) {
-        "Scripts"
-    } else {
-        unimplemented!("Only Windows and Unix are supported")
-    };
     let scripts = location.join(&interpreter.virtualenv().scripts);
 
     // Add the CACHEDIR.TAG.
@@ -470,74 +463,13 @@ pub(crate) fn create(
     }
 
     // Add all the activate scripts for different shells
-    for (name, template) in ACTIVATE_TEMPLATES {
-        // csh has no way to determine its own script location, so a relocatable
-        // activate.csh is not possible. Skip it entirely instead of generating a
-        // non-functional script.
-        if relocatable && *name == "activate.csh" {
-            continue;
-        }
-
-        let path_sep = if cfg!(windows) { ";" } else { ":" };
-
-        let relative_site_packages = [
-            interpreter.virtualenv().purelib.as_path(),
-            interpreter.virtualenv().platlib.as_path(),
-        ]
-        .iter()
-        .dedup()
-        .map(|path| {
-            pathdiff::diff_paths(path, &interpreter.virtualenv().scripts)
-                .expect("Failed to calculate relative path to site-packages")
-        })
-        .map(|path| path.simplified().to_str().unwrap().replace('\\', "\\\\"))
-        .join(path_sep);
-
-        let location_string = location
-            .simplified()
-            .to_str()
-            .ok_or_else(|| Error::NonUtf8Path {
-                path: location.clone(),
-            })?;
-        let virtual_env_dir = match (relocatable, name.to_owned()) {
-            (true, "activate") => Cow::Borrowed(
-                r#"'"$(dirname -- "$(dirname -- "$(realpath -- "$SCRIPT_PATH")")")"'"#,
-            ),
-            (true, "activate.bat") => Cow::Borrowed(r"%~dp0.."),
-            (true, "activate.fish") => {
-                Cow::Borrowed(r"'(dirname -- (dirname -- (realpath -- (status -f))))'")
-            }
-            (true, "activate.nu") => Cow::Borrowed(r"(path self | path dirname | path dirname)"),
-            (false, "activate.nu") => Cow::Owned(format!(
-                "'{}'",
-                escape_posix_for_single_quotes(location_string)
-            )),
-            // Note: `activate.ps1` is already relocatable by default.
-            _ => escape_posix_for_single_quotes(location_string),
-        };
-
-        let virtual_prompt = prompt.as_deref().unwrap_or_default();
-        let virtual_prompt = match *name {
-            "activate.xsh" => Cow::Owned(format!(
-                r#"b"{}".decode("utf-8")"#,
-                virtual_prompt.as_bytes().escape_ascii(),
-            )),
-            _ => Cow::Borrowed(virtual_prompt),
-        };
-
-        let bin_name = match *name {
-            "activate.xsh" => Cow::Owned(bin_name.escape_for_python()),
-            _ => Cow::Borrowed(bin_name),
-        };
-
-        let activator = template
-            .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
-            .replace("{{ BIN_NAME }}", &bin_name)
-            .replace("{{ VIRTUAL_PROMPT }}", &virtual_prompt)
-            .replace("{{ PATH_SEP }}", path_sep)
-            .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
-        fs_err::write(scripts.join(name), activator)?;
-    }
+    write_activators(
+        &location,
+        interpreter,
+        prompt.as_deref(),
+        relocatable,
+        &ActivationEnvironment::default(),
+    )?;
 
     let mut pyvenv_cfg_data: Vec<(String, String)> = vec![
         (
@@ -636,6 +568,107 @@ pub(crate) fn create(
     })
 }
 
+/// Write the activation scripts for the virtual environment at the given location.
+pub(crate) fn write_activators(
+    location: &Path,
+    interpreter: &Interpreter,
+    prompt: Option<&str>,
+    relocatable: bool,
+    environment: &ActivationEnvironment,
+) -> Result<(), Error> {
+    let bin_name = if cfg!(unix) {
+        "bin"
+    } else if cfg!(windows) {
+        "Scripts"
+    } else {
+        unimplemented!("Only Windows and Unix are supported")
+    };
+    let scripts = location.join(&interpreter.virtualenv().scripts);
+
+    for (name, template) in ACTIVATE_TEMPLATES {
+        // csh has no way to determine its own script location, so a relocatable
+        // activate.csh is not possible. Skip it entirely instead of generating a
+        // non-functional script.
+        if relocatable && *name == "activate.csh" {
+            continue;
+        }
+
+        let path_sep = if cfg!(windows) { ";" } else { ":" };
+
+        let relative_site_packages = [
+            interpreter.virtualenv().purelib.as_path(),
+            interpreter.virtualenv().platlib.as_path(),
+        ]
+        .iter()
+        .dedup()
+        .map(|path| {
+            pathdiff::diff_paths(path, &interpreter.virtualenv().scripts)
+                .expect("Failed to calculate relative path to site-packages")
+        })
+        .map(|path| path.simplified().to_str().unwrap().replace('\\', "\\\\"))
+        .join(path_sep);
+
+        let location_string = location
+            .simplified()
+            .to_str()
+            .ok_or_else(|| Error::NonUtf8Path {
+                path: location.to_path_buf(),
+            })?;
+        let virtual_env_dir = match (relocatable, name.to_owned()) {
+            (true, "activate") => Cow::Borrowed(
+                r#"'"$(dirname -- "$(dirname -- "$(realpath -- "$SCRIPT_PATH")")")"'"#,
+            ),
+            (true, "activate.bat") => Cow::Borrowed(r"%~dp0.."),
+            (true, "activate.fish") => {
+                Cow::Borrowed(r"'(dirname -- (dirname -- (realpath -- (status -f))))'")
+            }
+            (true, "activate.nu") => Cow::Borrowed(r"(path self | path dirname | path dirname)"),
+            (false, "activate.nu") => Cow::Owned(format!(
+                "'{}'",
+                escape_posix_for_single_quotes(location_string)
+            )),
+            // Note: `activate.ps1` is already relocatable by default.
+            _ => escape_posix_for_single_quotes(location_string),
+        };
+
+        let virtual_prompt = prompt.unwrap_or_default();
+        let virtual_prompt = match *name {
+            "activate.xsh" => Cow::Owned(format!(
+                r#"b"{}".decode("utf-8")"#,
+                virtual_prompt.as_bytes().escape_ascii(),
+            )),
+            _ => Cow::Borrowed(virtual_prompt),
+        };
+
+        let bin_name = match *name {
+            "activate.xsh" => Cow::Owned(bin_name.escape_for_python()),
+            _ => Cow::Borrowed(bin_name),
+        };
+
+        let mut activator = template
+            .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
+            .replace("{{ BIN_NAME }}", &bin_name)
+            .replace("{{ VIRTUAL_PROMPT }}", &virtual_prompt)
+            .replace("{{ PATH_SEP }}", path_sep)
+            .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
+        for (placeholder, value) in environment.render(name, path_sep)? {
+            // Drop placeholders that occupy an entire line, along with the line itself.
+            activator = activator
+                .replace(&format!("\n{placeholder}\n"), &format!("\n{value}"))
+                .replace(placeholder, &value);
+        }
+
+        // Avoid rewriting unchanged scripts, e.g., when refreshing the project environment.
+        let path = scripts.join(name);
+        if fs_err::read_to_string(&path).is_ok_and(|existing| existing == activator) {
+            continue;
+        }
+        fs_err::write(path, activator)?;
+    }
+
+    Ok(())
+}
+
 /// Prompt a confirmation that the virtual environment should be cleared.
 ///
 /// If not a TTY, returns `None`.
@@ -132,6 +132,22 @@ impl PyProjectToml {
             .and_then(|uv| uv.package)
     }
 
+    /// Returns the `tool.uv.environment` table, if set.
+    pub fn tool_uv_environment(&self) -> Option<&ToolUvEnvironment> {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.environment.as_ref())
+    }
+
+    /// Returns the `tool.uv.scripts` table, if set.
+    pub fn tool_uv_scripts(&self) -> Option<&BTreeMap<String, ToolUvScript>> {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.scripts.as_ref())
+    }
+
     /// Returns whether the project manifest contains any script table.
     pub fn has_scripts(&self) -> bool {
         if let Some(ref project) = self.project {
@@ -692,6 +708,33 @@ pub struct ToolUv {
     )]
     pub(crate) conflicts: Option<SchemaConflicts>,
 
+    /// Environment variables to set when running commands in the project environment.
+    ///
+    /// The variables are applied by `uv run` and written into the activation scripts of the
+    /// project environment. Variables that are already set in the calling environment take
+    /// precedence.
+    #[option_group]
+    pub environment: Option<ToolUvEnvironment>,
+
+    /// Named tasks to run in the project environment with `uv run <task>`.
+    ///
+    /// Each task is either a command string, which is split into arguments using shell-like
+    /// syntax, a list of arguments, or a table with a `cmd`, along with any `env` variables to
+    /// set, dependency `groups` to sync, and other tasks to run first (`depends-on`).
+    ///
+    /// Tasks take precedence over executables of the same name in the project environment.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str | list[str] | dict]",
+        example = r#"
+            [tool.uv.scripts]
+            lint = "ruff check ."
+            test = { cmd = ["pytest", "-x"], groups = ["test"], depends-on = ["lint"] }
+            serve = { cmd = "python -m http.server", env = { PYTHONUNBUFFERED = "1" }, help = "Serve the docs" }
+        "#
+    )]
+    pub scripts: Option<BTreeMap<String, ToolUvScript>>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -952,6 +995,163 @@ pub(crate) struct ToolUvWorkspace {
     pub(crate) exclude: Option<Vec<SerdePattern>>,
 }
 
+/// The environment to apply when running commands in the project environment.
+#[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ToolUvEnvironment {
+    /// Static environment variables to set.
+    ///
+    /// Values take precedence over those read from `env-files`.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            variables = { DJANGO_SETTINGS_MODULE = "app.settings", LOG_LEVEL = "debug" }
+        "#
+    )]
+    pub variables: Option<BTreeMap<String, String>>,
+    /// Directories to prepend to `PATH`, after the environment's own executables.
+    ///
+    /// Relative paths are resolved against the workspace root.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            path-prepend = ["tools/bin"]
+        "#
+    )]
+    pub path_prepend: Option<Vec<PathBuf>>,
+    /// Dotenv files from which to read environment variables.
+    ///
+    /// Relative paths are resolved against the workspace root. Files are read in order, such
+    /// that values in later files take precedence over those in earlier files.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            env-files = [".env", ".env.local"]
+        "#
+    )]
+    pub env_files: Option<Vec<PathBuf>>,
+}
+
+/// A named task, as declared in `tool.uv.scripts`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ToolUvScript {
+    /// A command, e.g., `lint = "ruff check ."`.
+    Command(ScriptCommand),
+    /// A command with additional settings, e.g., `test = { cmd = "pytest", groups = ["test"] }`.
+    Table(ToolUvScriptTable),
+}
+
+impl ToolUvScript {
+    /// The command to run, if any.
+    ///
+    /// A task without a command only runs the tasks it depends on.
+    pub fn command(&self) -> Option<&ScriptCommand> {
+        match self {
+            Self::Command(command) => Some(command),
+            Self::Table(table) => table.cmd.as_ref(),
+        }
+    }
+
+    /// The description of the task, if any.
+    pub fn help(&self) -> Option<&str> {
+        match self {
+            Self::Command(_) => None,
+            Self::Table(table) => table.help.as_deref(),
+        }
+    }
+
+    /// The environment variables to set when running the task.
+    pub fn env(&self) -> impl Iterator<Item = (&String, &String)> {
+        match self {
+            Self::Command(_) => None,
+            Self::Table(table) => table.env.as_ref(),
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// The dependency groups to sync before running the task.
+    pub fn groups(&self) -> &[GroupName] {
+        match self {
+            Self::Command(_) => &[],
+            Self::Table(table) => table.groups.as_deref().unwrap_or_default(),
+        }
+    }
+
+    /// The tasks to run before this task.
+    pub fn depends_on(&self) -> &[String] {
+        match self {
+            Self::Command(_) => &[],
+            Self::Table(table) => table.depends_on.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+/// The command for a task in `tool.uv.scripts`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ScriptCommand {
+    /// A command string, split into arguments using shell-like syntax.
+    ///
+    /// The command is not run in a shell, so pipes, redirects, and variable expansion are not
+    /// supported.
+    Shell(String),
+    /// A list of arguments.
+    Args(Vec<String>),
+}
+
+impl ScriptCommand {
+    /// Split the command into its arguments.
+    ///
+    /// Returns `None` if a command string contains unbalanced quotes.
+    pub fn to_args(&self) -> Option<Vec<String>> {
+        match self {
+            Self::Shell(command) => shlex::split(command),
+            Self::Args(args) => Some(args.clone()),
+        }
+    }
+}
+
+impl std::fmt::Display for ScriptCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shell(command) => f.write_str(command),
+            Self::Args(args) => f.write_str(
+                &shlex::try_join(args.iter().map(String::as_str))
+                    .unwrap_or_else(|_| args.join(" ")),
+            ),
+        }
+    }
+}
+
+/// A task in `tool.uv.scripts`, declared as a table.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ToolUvScriptTable {
+    /// The command to run, as a command string or a list of arguments.
+    pub cmd: Option<ScriptCommand>,
+    /// A description of the task, shown by `uv run --list`.
+    pub help: Option<String>,
+    /// Environment variables to set when running the task.
+    pub env: Option<BTreeMap<String, String>>,
+    /// Dependency groups to sync before running the task.
+    pub groups: Option<Vec<GroupName>>,
+    /// Tasks to run, in order, before running this task.
+    pub depends_on: Option<Vec<String>>,
+}
+
 /// (De)serialize globs as strings.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SerdePattern(Pattern);
@@ -1039,6 +1239,7 @@ impl IntoIterator for Sources {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(untagged))]
+#[expect(clippy::large_enum_variant)]
 enum SourcesWire {
     One(Source),
     Many(Vec<Source>),
@@ -1155,6 +1356,19 @@ pub enum Source {
         branch: Option<String>,
         /// Whether to use Git LFS when cloning the repository.
         lfs: Option<bool>,
+        /// Whether to require that the commit (or, with `tag`, the tag) is signed by a trusted
+        /// key. `false` by default.
+        ///
+        /// SSH signatures are verified against the file configured with `allowed-signers` (or
+        /// `git-allowed-signers`, or Git's `gpg.ssh.allowedSignersFile`), and GPG signatures
+        /// against the user's keyring.
+        #[serde(rename = "verify-signature")]
+        verify_signature: Option<bool>,
+        /// The path to the file of SSH keys that are trusted to sign the commit (or tag), relative
+        /// to the project, in lieu of the `git-allowed-signers` setting. Requires
+        /// `verify-signature`.
+        #[serde(rename = "allowed-signers")]
+        allowed_signers: Option<PortablePathBuf>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1266,6 +1480,8 @@ impl<'de> Deserialize<'de> for Source {
             tag: Option<String>,
             branch: Option<String>,
             lfs: Option<bool>,
+            verify_signature: Option<bool>,
+            allowed_signers: Option<PortablePathBuf>,
             url: Option<DisplaySafeUrl>,
             path: Option<PortablePathBuf>,
             editable: Option<bool>,
@@ -1290,6 +1506,8 @@ impl<'de> Deserialize<'de> for Source {
             tag,
             branch,
             lfs,
+            verify_signature,
+            allowed_signers,
             url,
             path,
             editable,
@@ -1340,6 +1558,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `subdirectory` and `path`",
                 ));
             }
+            if allowed_signers.is_some() && verify_signature != Some(true) {
+                return Err(serde::de::Error::custom(
+                    "cannot specify `allowed-signers` without `verify-signature = true`",
+                ));
+            }
 
             // At most one of `rev`, `tag`, or `branch` may be set.
             match (rev.as_ref(), tag.as_ref(), branch.as_ref()) {
@@ -1369,6 +1592,8 @@ impl<'de> Deserialize<'de> for Source {
                 tag,
                 branch,
                 lfs,
+                verify_signature,
+                allowed_signers,
                 marker,
                 extra,
                 group,
@@ -1412,6 +1637,16 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `url` and `branch`",
                 ));
             }
+            if verify_signature.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `verify-signature`",
+                ));
+            }
+            if allowed_signers.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `allowed-signers`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `url` and `editable`",
@@ -1469,6 +1704,16 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `path` and `branch`",
                 ));
             }
+            if verify_signature.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `verify-signature`",
+                ));
+            }
+            if allowed_signers.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `allowed-signers`",
+                ));
+            }
 
             // A project must be packaged in order to be installed as editable.
             if editable == Some(true) && package == Some(false) {
@@ -1524,6 +1769,16 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `branch`",
                 ));
             }
+            if verify_signature.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `verify-signature`",
+                ));
+            }
+            if allowed_signers.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `allowed-signers`",
+                ));
+            }
             if editable.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `editable`",
@@ -1580,6 +1835,16 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `workspace` and `branch`",
                 ));
             }
+            if verify_signature.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `workspace` and `verify-signature`",
+                ));
+            }
+            if allowed_signers.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `workspace` and `allowed-signers`",
+                ));
+            }
             if package.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `workspace` and `package`",
@@ -1671,6 +1936,22 @@ impl Source {
         root: &Path,
         existing_sources: Option<&BTreeMap<PackageName, Sources>>,
     ) -> Result<Option<Self>, SourceError> {
+        // Retain any signature requirement from an existing Git source, such that updating the
+        // reference doesn't silently drop it.
+        let (verify_signature, allowed_signers) = existing_sources
+            .and_then(|sources| sources.get(name))
+            .and_then(|sources| {
+                sources.iter().find_map(|source| match source {
+                    Self::Git {
+                        verify_signature,
+                        allowed_signers,
+                        ..
+                    } => Some((*verify_signature, allowed_signers.clone())),
+                    _ => None,
+                })
+            })
+            .unwrap_or_default();
+
         // If the user specified a Git reference for a non-Git source, try existing Git sources before erroring.
         if !matches!(
             source,
@@ -1701,6 +1982,8 @@ impl Source {
                             tag,
                             branch,
                             lfs: lfs.into(),
+                            verify_signature,
+                            allowed_signers,
                             marker: *marker,
                             path: path.clone(),
                             extra: extra.clone(),
@@ -1831,6 +2114,8 @@ impl Source {
                         tag,
                         branch,
                         lfs: lfs.into(),
+                        verify_signature,
+                        allowed_signers: allowed_signers.clone(),
                         git: git.url().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
                         path: None,
@@ -1844,6 +2129,8 @@ impl Source {
                         tag,
                         branch,
                         lfs: lfs.into(),
+                        verify_signature,
+                        allowed_signers: allowed_signers.clone(),
                         git: git.url().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
                         path: None,
@@ -1870,6 +2157,8 @@ impl Source {
                         tag,
                         branch,
                         lfs: lfs.into(),
+                        verify_signature,
+                        allowed_signers: allowed_signers.clone(),
                         git: git.url().clone(),
                         subdirectory: None,
                         path: Some(PortablePathBuf::from(install_path.as_path())),
@@ -1883,6 +2172,8 @@ impl Source {
                         tag,
                         branch,
                         lfs: lfs.into(),
+                        verify_signature,
+                        allowed_signers: allowed_signers.clone(),
                         git: git.url().clone(),
                         subdirectory: None,
                         path: Some(PortablePathBuf::from(install_path.as_path())),
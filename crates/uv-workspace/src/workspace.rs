@@ -2591,6 +2591,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "environment": null,
+                      "scripts": null,
                       "build-backend": null
                     }
                   },
@@ -2692,6 +2694,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "environment": null,
+                      "scripts": null,
                       "build-backend": null
                     }
                   },
@@ -3027,6 +3031,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "environment": null,
+                      "scripts": null,
                       "build-backend": null
                     }
                   },
@@ -3137,6 +3143,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "environment": null,
+                      "scripts": null,
                       "build-backend": null
                     }
                   },
@@ -3260,6 +3268,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "environment": null,
+                      "scripts": null,
                       "build-backend": null
                     }
                   },
@@ -3357,6 +3367,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "environment": null,
+                      "scripts": null,
                       "build-backend": null
                     }
                   },
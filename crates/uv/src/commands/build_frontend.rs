@@ -6,11 +6,14 @@ use std::str::FromStr;
 use std::{fmt, io, iter};
 
 use anyhow::{Context, Result};
+use futures::StreamExt;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use thiserror::Error;
 use tracing::{debug, instrument};
 
 use uv_build_backend::check_direct_build;
+use uv_build_frontend::Difference;
 use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
@@ -26,12 +29,12 @@ use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
     PackageConfigSettings, Requirement, SourceDist,
 };
-use uv_errors::{ErrorOptions, Hint, Hints, write_error_chain_with_options};
+use uv_errors::{ErrorOptions, Hint, HintPrefix, Hints, write_error_chain_with_options};
 use uv_fs::{Simplified, normalize_path, relative_to};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersionFile, VersionFileDiscoveryOptions,
@@ -39,14 +42,16 @@ use uv_python::{
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildStack, HashStrategy, SourceTreeEditablePolicy};
-use uv_warnings::warn_user;
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError};
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::operations;
 use crate::commands::project::{ProjectError, find_requires_python};
+use crate::commands::publish::check_distributions;
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
 use crate::settings::ResolverSettings;
@@ -100,6 +105,20 @@ pub(crate) enum Error {
     NameMismatch(PackageName, PackageName),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error("Failed to build a wheel with Python `{0}`")]
+    BuildMatrix(String, #[source] Box<Self>),
+    #[error(
+        "The wheels built with Python `{first}` and `{second}` are both named `{filename}`, but their contents differ"
+    )]
+    BuildMatrixMismatch {
+        filename: String,
+        first: String,
+        second: String,
+    },
+    #[error("Failed to compare the builds of `{0}`")]
+    CompareBuilds(String, #[source] uv_build_frontend::CompareError),
+    #[error("Failed to repair `{0}`")]
+    Repair(String, #[source] Box<uv_build_frontend::RepairError>),
 }
 
 impl From<ProjectError> for Error {
@@ -201,10 +220,13 @@ pub(crate) async fn build_frontend(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    check: bool,
+    verify_reproducible: bool,
+    repair: bool,
     build_constraints: Vec<RequirementsSource>,
     build_constraints_from_workspace: Vec<Requirement>,
     hash_checking: Option<HashCheckingMode>,
-    python: Option<String>,
+    python: Vec<String>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
@@ -217,6 +239,38 @@ pub(crate) async fn build_frontend(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if check && !preview.is_enabled(PreviewFeature::DistributionChecks) {
+        warn_user_once!(
+            "The `--check` option is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::DistributionChecks
+        );
+    }
+
+    if verify_reproducible && !preview.is_enabled(PreviewFeature::ReproducibleBuilds) {
+        warn_user_once!(
+            "The `--verify-reproducible` option is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::ReproducibleBuilds
+        );
+    }
+
+    if repair && !preview.is_enabled(PreviewFeature::WheelRepair) {
+        warn_user_once!(
+            "The `--repair` option is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::WheelRepair
+        );
+    }
+
+    if python.len() > 1 && !preview.is_enabled(PreviewFeature::BuildMatrix) {
+        warn_user_once!(
+            "Building with multiple Python interpreters is experimental and may change without \
+            warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::BuildMatrix
+        );
+    }
+
     let build_result = build_impl(
         project_dir,
         src.as_deref(),
@@ -230,10 +284,13 @@ pub(crate) async fn build_frontend(
         gitignore,
         force_pep517,
         clear,
+        check,
+        verify_reproducible,
+        repair,
         &build_constraints,
         &build_constraints_from_workspace,
         hash_checking,
-        python.as_deref(),
+        &python,
         install_mirrors,
         settings,
         client_builder,
@@ -279,10 +336,13 @@ async fn build_impl(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    check: bool,
+    verify_reproducible: bool,
+    repair: bool,
     build_constraints: &[RequirementsSource],
     build_constraints_from_workspace: &[Requirement],
     hash_checking: Option<HashCheckingMode>,
-    python_request: Option<&str>,
+    python_requests: &[String],
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
@@ -461,60 +521,146 @@ async fn build_impl(
         }
     }
 
-    let results: Vec<_> = futures::future::join_all(packages.into_iter().map(|source| {
-        let future = build_package(
-            source.clone(),
-            output_dir,
-            python_request,
-            install_mirrors.clone(),
-            config_discovery,
-            workspace.as_deref(),
-            python_preference,
-            python_downloads,
-            cache,
-            workspace_cache,
-            printer,
-            index_locations,
-            client_builder.clone(),
-            hash_checking,
-            build_logs,
-            gitignore,
-            force_pep517,
-            clear,
-            build_constraints,
-            build_constraints_from_workspace,
-            build_isolation,
-            extra_build_dependencies,
-            extra_build_variables,
-            *index_strategy,
-            *keyring_provider,
-            exclude_newer.clone(),
-            sources.clone(),
-            concurrency,
-            build_options,
-            sdist,
-            wheel,
-            list,
-            dependency_metadata,
-            *link_mode,
-            config_setting,
-            config_settings_package,
-            preview,
-        );
-        async {
-            let result = Box::pin(future).await;
-            (source, result)
+    let build_args = BuildArgs {
+        install_mirrors,
+        config_discovery,
+        workspace: workspace.as_deref(),
+        python_preference,
+        python_downloads,
+        cache,
+        workspace_cache,
+        printer,
+        index_locations,
+        client_builder,
+        hash_checking,
+        build_logs,
+        force_pep517,
+        build_constraints,
+        build_constraints_from_workspace,
+        build_isolation,
+        extra_build_dependencies,
+        extra_build_variables,
+        index_strategy: *index_strategy,
+        keyring_provider: *keyring_provider,
+        exclude_newer,
+        sources,
+        concurrency,
+        build_options,
+        dependency_metadata,
+        link_mode: *link_mode,
+        config_setting,
+        config_settings_package,
+        preview,
+    };
+
+    // Each package may build with multiple interpreters, so bound the number of packages that are
+    // built at once.
+    let results: Vec<_> = futures::stream::iter(packages.into_iter().map(|source| async {
+        let result = async {
+            // To verify that the build is reproducible, build twice into separate directories.
+            let verify_dirs = if verify_reproducible && !list {
+                let output_dir =
+                    resolve_output_dir(output_dir, workspace.as_deref(), &source)?.into_owned();
+                if clear && output_dir.exists() {
+                    fs_err::remove_dir_all(&output_dir)?;
+                }
+                prepare_output_directory(&output_dir, gitignore).await?;
+                let build_dirs = [
+                    tempfile::tempdir_in(&output_dir)?,
+                    tempfile::tempdir_in(&output_dir)?,
+                ];
+                Some((output_dir, build_dirs))
+            } else {
+                None
+            };
+            let build_output_dirs = match &verify_dirs {
+                Some((_, build_dirs)) => build_dirs
+                    .iter()
+                    .map(|build_dir| Some(build_dir.path()))
+                    .collect(),
+                None => vec![output_dir],
+            };
+
+            let mut builds = Vec::with_capacity(build_output_dirs.len());
+            for build_output_dir in build_output_dirs {
+                let build = Box::pin(build_matrix(
+                    source.clone(),
+                    build_output_dir,
+                    python_requests,
+                    gitignore && verify_dirs.is_none(),
+                    clear && verify_dirs.is_none(),
+                    sdist,
+                    wheel,
+                    list,
+                    &build_args,
+                ))
+                .await?;
+                builds.push(build);
+            }
+
+            let (mut messages, mut summary, reproducibility) =
+                if let Some((output_dir, build_dirs)) = verify_dirs {
+                    let [first, second] = <[_; 2]>::try_from(builds).expect("Two builds");
+                    let result = compare_builds(first, second, &output_dir).await;
+                    // Remove the build directories only after the distributions were moved.
+                    drop(build_dirs);
+                    result?
+                } else {
+                    let (messages, summary) = builds.pop().expect("One build");
+                    (messages, summary, None)
+                };
+
+            if repair && !list {
+                repair_wheels(&mut messages, summary.as_mut(), printer).await?;
+            }
+
+            Ok::<_, Error>((messages, summary, reproducibility))
         }
+        .await;
+        (source, result)
     }))
+    .buffered(concurrency.builds)
+    .collect()
     .await;
 
     let mut success = true;
     for (source, result) in results {
         match result {
-            Ok(messages) => {
-                for message in messages {
+            Ok((messages, summary, reproducibility)) => {
+                for message in &messages {
                     message.print(printer)?;
                 }
+                if let Some(summary) = summary {
+                    summary.print(&source, python_requests, printer)?;
+                }
+                if let Some(reproducibility) = reproducibility {
+                    if !reproducibility.print(&source, printer)? {
+                        success = false;
+                    }
+                }
+                if check {
+                    let built = messages
+                        .iter()
+                        .filter_map(|message| match message {
+                            BuildMessage::Build { output_dir, .. } => Some((
+                                output_dir.join(message.raw_filename()),
+                                message.raw_filename(),
+                                message.normalized_filename(),
+                            )),
+                            BuildMessage::List { .. } => None,
+                        })
+                        .collect::<Vec<_>>();
+                    let failed = check_distributions(
+                        built.iter().map(|(file, raw_filename, filename)| {
+                            (file.as_path(), *raw_filename, *filename)
+                        }),
+                        printer,
+                    )
+                    .await?;
+                    if failed > 0 {
+                        success = false;
+                    }
+                }
             }
             Err(err) => {
                 let err = anyhow::Error::from(err).context(format!("Failed to build `{source}`"));
@@ -537,6 +683,419 @@ async fn build_impl(
     }
 }
 
+/// The arguments shared by all builds of a package.
+struct BuildArgs<'a> {
+    install_mirrors: PythonInstallMirrors,
+    config_discovery: ConfigDiscovery,
+    workspace: Result<&'a Workspace, &'a WorkspaceError>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    cache: &'a Cache,
+    workspace_cache: &'a WorkspaceCache,
+    printer: Printer,
+    index_locations: &'a IndexLocations,
+    client_builder: &'a BaseClientBuilder<'a>,
+    hash_checking: Option<HashCheckingMode>,
+    build_logs: bool,
+    force_pep517: bool,
+    build_constraints: &'a [RequirementsSource],
+    build_constraints_from_workspace: &'a [Requirement],
+    build_isolation: &'a BuildIsolation,
+    extra_build_dependencies: &'a ExtraBuildDependencies,
+    extra_build_variables: &'a ExtraBuildVariables,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    exclude_newer: &'a ExcludeNewer,
+    sources: &'a NoSources,
+    concurrency: &'a Concurrency,
+    build_options: &'a BuildOptions,
+    dependency_metadata: &'a DependencyMetadata,
+    link_mode: LinkMode,
+    config_setting: &'a ConfigSettings,
+    config_settings_package: &'a PackageConfigSettings,
+    preview: Preview,
+}
+
+impl BuildArgs<'_> {
+    /// Build a package with a single Python interpreter.
+    #[expect(clippy::fn_params_excessive_bools)]
+    async fn build_package(
+        &self,
+        source: AnnotatedSource<'_>,
+        output_dir: Option<&Path>,
+        python_request: Option<&str>,
+        gitignore: bool,
+        clear: bool,
+        sdist: bool,
+        wheel: bool,
+        list: bool,
+    ) -> Result<Vec<BuildMessage>, Error> {
+        Box::pin(build_package(
+            source,
+            output_dir,
+            python_request,
+            self.install_mirrors.clone(),
+            self.config_discovery,
+            self.workspace,
+            self.python_preference,
+            self.python_downloads,
+            self.cache,
+            self.workspace_cache,
+            self.printer,
+            self.index_locations,
+            self.client_builder.clone(),
+            self.hash_checking,
+            self.build_logs,
+            gitignore,
+            self.force_pep517,
+            clear,
+            self.build_constraints,
+            self.build_constraints_from_workspace,
+            self.build_isolation,
+            self.extra_build_dependencies,
+            self.extra_build_variables,
+            self.index_strategy,
+            self.keyring_provider,
+            self.exclude_newer.clone(),
+            self.sources.clone(),
+            self.concurrency,
+            self.build_options,
+            sdist,
+            wheel,
+            list,
+            self.dependency_metadata,
+            self.link_mode,
+            self.config_setting,
+            self.config_settings_package,
+            self.preview,
+        ))
+        .await
+    }
+}
+
+/// Build a package with each of the requested Python interpreters.
+///
+/// The source distribution doesn't depend on the interpreter and is built once, with the first
+/// interpreter. The wheels are then built concurrently with each interpreter, each into its own
+/// temporary directory, and moved into the output directory. Wheels that are identical across
+/// interpreters, like pure-Python wheels, are only kept once; if two interpreters build a wheel
+/// with the same filename but different contents, the build fails.
+#[expect(clippy::fn_params_excessive_bools)]
+async fn build_matrix(
+    source: AnnotatedSource<'_>,
+    output_dir: Option<&Path>,
+    python_requests: &[String],
+    gitignore: bool,
+    clear: bool,
+    sdist: bool,
+    wheel: bool,
+    list: bool,
+    args: &BuildArgs<'_>,
+) -> Result<(Vec<BuildMessage>, Option<BuildMatrixSummary>), Error> {
+    // Listing the files doesn't depend on the interpreter.
+    let (first_request, matrix) = match python_requests {
+        [] => (None, false),
+        [python_request] => (Some(python_request.as_str()), false),
+        [python_request, ..] => (Some(python_request.as_str()), !list),
+    };
+
+    // Determine the build plan, and build the source distribution, if any.
+    let plan = if matrix {
+        Some(BuildPlan::determine(&source, sdist, wheel).map_err(Error::BuildPlan)?)
+    } else {
+        None
+    };
+    let build_sdist = match plan {
+        None | Some(BuildPlan::Sdist) => {
+            let messages = args
+                .build_package(
+                    source,
+                    output_dir,
+                    first_request,
+                    gitignore,
+                    clear,
+                    sdist,
+                    wheel,
+                    list,
+                )
+                .await?;
+            return Ok((messages, None));
+        }
+        Some(BuildPlan::SdistToWheel | BuildPlan::SdistAndWheel) => true,
+        Some(BuildPlan::Wheel | BuildPlan::WheelFromSdist) => false,
+    };
+
+    let output_dir = resolve_output_dir(output_dir, args.workspace, &source)?.into_owned();
+
+    // Clear the output directory once, before any of the builds.
+    if clear && output_dir.exists() {
+        fs_err::remove_dir_all(&output_dir)?;
+    }
+    prepare_output_directory(&output_dir, gitignore).await?;
+
+    let mut messages = Vec::new();
+    let wheel_source = if build_sdist {
+        let sdist_messages = args
+            .build_package(
+                source.clone(),
+                Some(&output_dir),
+                first_request,
+                gitignore,
+                false,
+                true,
+                false,
+                false,
+            )
+            .await?;
+
+        // By default, the wheels are built from the source distribution.
+        let wheel_source = if plan == Some(BuildPlan::SdistToWheel) {
+            let sdist_build = sdist_messages
+                .iter()
+                .find(|message| matches!(message, BuildMessage::Build { .. }))
+                .expect("A source distribution was built");
+            AnnotatedSource {
+                source: Source::File(Cow::Owned(output_dir.join(sdist_build.raw_filename()))),
+                package: source.package.clone(),
+            }
+        } else {
+            source
+        };
+        messages.extend(sdist_messages);
+        wheel_source
+    } else {
+        source
+    };
+
+    let temp_dirs = python_requests
+        .iter()
+        .map(|_| tempfile::tempdir_in(&output_dir))
+        .collect::<Result<Vec<_>, _>>()?;
+    let results: Vec<_> = futures::stream::iter(python_requests.iter().zip(&temp_dirs).map(
+        |(python_request, temp_dir)| {
+            args.build_package(
+                wheel_source.clone(),
+                Some(temp_dir.path()),
+                Some(python_request),
+                false,
+                false,
+                false,
+                true,
+                false,
+            )
+        },
+    ))
+    .buffered(args.concurrency.builds)
+    .collect()
+    .await;
+
+    // Move the wheels into the output directory, in the order of the requests, keeping only the
+    // first of any identical wheels.
+    let mut summary = BuildMatrixSummary::default();
+    for ((python_request, temp_dir), result) in python_requests.iter().zip(&temp_dirs).zip(results)
+    {
+        let wheel_messages =
+            result.map_err(|err| Error::BuildMatrix(python_request.clone(), Box::new(err)))?;
+        for message in wheel_messages {
+            let BuildMessage::Build {
+                normalized_filename,
+                raw_filename,
+                ..
+            } = message
+            else {
+                continue;
+            };
+            if let Some((_, requests)) = summary
+                .wheels
+                .iter_mut()
+                .find(|(filename, _)| *filename == raw_filename)
+            {
+                // A wheel with the same filename must be identical, since only one of them can be
+                // kept.
+                let kept = fs_err::tokio::read(output_dir.join(&raw_filename)).await?;
+                let duplicate = fs_err::tokio::read(temp_dir.path().join(&raw_filename)).await?;
+                if kept != duplicate {
+                    return Err(Error::BuildMatrixMismatch {
+                        filename: raw_filename,
+                        first: requests[0].clone(),
+                        second: python_request.clone(),
+                    });
+                }
+                debug!("Discarding duplicate wheel `{raw_filename}` built with `{python_request}`");
+                requests.push(python_request.clone());
+                continue;
+            }
+            fs_err::tokio::rename(
+                temp_dir.path().join(&raw_filename),
+                output_dir.join(&raw_filename),
+            )
+            .await?;
+            summary
+                .wheels
+                .push((raw_filename.clone(), vec![python_request.clone()]));
+            messages.push(BuildMessage::Build {
+                normalized_filename,
+                raw_filename,
+                output_dir: output_dir.clone(),
+            });
+        }
+    }
+
+    Ok((messages, Some(summary)))
+}
+
+/// Repair the built Linux wheels, replacing them with the repaired wheels.
+async fn repair_wheels(
+    messages: &mut [BuildMessage],
+    mut summary: Option<&mut BuildMatrixSummary>,
+    printer: Printer,
+) -> Result<(), Error> {
+    for message in messages {
+        let BuildMessage::Build {
+            normalized_filename: normalized_filename @ DistFilename::WheelFilename(_),
+            raw_filename,
+            output_dir,
+        } = message
+        else {
+            continue;
+        };
+        let wheel = output_dir.join(&*raw_filename);
+        let repaired = tokio::task::spawn_blocking(move || uv_build_frontend::repair_wheel(&wheel))
+            .await?
+            .map_err(|err| Error::Repair(raw_filename.clone(), Box::new(err)))?;
+        let Some(repaired) = repaired else {
+            continue;
+        };
+
+        let filename = repaired.filename.to_string();
+        if repaired.vendored.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "Repaired {} as {}",
+                raw_filename.cyan(),
+                filename.cyan()
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Repaired {} as {}, vendoring {}",
+                raw_filename.cyan(),
+                filename.cyan(),
+                repaired
+                    .vendored
+                    .iter()
+                    .map(|library| format!("`{library}`"))
+                    .join(", ")
+            )?;
+        }
+        if let Some(summary) = summary.as_deref_mut() {
+            for (wheel, _) in &mut summary.wheels {
+                if wheel == raw_filename {
+                    wheel.clone_from(&filename);
+                }
+            }
+        }
+        *raw_filename = filename;
+        *normalized_filename = DistFilename::WheelFilename(repaired.filename);
+    }
+    Ok(())
+}
+
+/// Compare the distributions of two builds of a package, and move the distributions of the first
+/// build into the output directory.
+async fn compare_builds(
+    first: (Vec<BuildMessage>, Option<BuildMatrixSummary>),
+    second: (Vec<BuildMessage>, Option<BuildMatrixSummary>),
+    output_dir: &Path,
+) -> Result<
+    (
+        Vec<BuildMessage>,
+        Option<BuildMatrixSummary>,
+        Option<Reproducibility>,
+    ),
+    Error,
+> {
+    let (mut messages, summary) = first;
+    let (second_messages, _) = second;
+
+    let mut reproducibility = Reproducibility::default();
+    for message in &mut messages {
+        let BuildMessage::Build {
+            normalized_filename,
+            raw_filename,
+            output_dir: build_output_dir,
+        } = message
+        else {
+            continue;
+        };
+        let other = second_messages.iter().find_map(|other| match other {
+            BuildMessage::Build {
+                raw_filename: other_filename,
+                output_dir: other_output_dir,
+                ..
+            } if other_filename == raw_filename => Some(other_output_dir.join(other_filename)),
+            _ => None,
+        });
+        let first_path = build_output_dir.join(&*raw_filename);
+        let differences = if let Some(other) = other {
+            uv_build_frontend::compare_distributions(&first_path, &other, normalized_filename)
+                .await
+                .map_err(|err| Error::CompareBuilds(raw_filename.clone(), err))?
+        } else {
+            vec![Difference {
+                path: None,
+                message: "Only built by the first build".to_string(),
+            }]
+        };
+        reproducibility
+            .distributions
+            .push((raw_filename.clone(), differences));
+
+        fs_err::tokio::rename(&first_path, output_dir.join(&*raw_filename)).await?;
+        *build_output_dir = output_dir.to_path_buf();
+    }
+
+    // Distributions that only the second build produced, e.g., from a non-deterministic filename.
+    for other in &second_messages {
+        if let BuildMessage::Build { raw_filename, .. } = other
+            && !messages
+                .iter()
+                .any(|message| message.raw_filename() == raw_filename)
+        {
+            reproducibility.distributions.push((
+                raw_filename.clone(),
+                vec![Difference {
+                    path: None,
+                    message: "Only built by the second build".to_string(),
+                }],
+            ));
+        }
+    }
+
+    Ok((messages, summary, Some(reproducibility)))
+}
+
+/// The output directory for a build, defaulting to the `dist` directory of the workspace or the
+/// source directory.
+fn resolve_output_dir<'a>(
+    output_dir: Option<&Path>,
+    workspace: Result<&Workspace, &WorkspaceError>,
+    source: &'a AnnotatedSource<'_>,
+) -> Result<Cow<'a, Path>, Error> {
+    Ok(if let Some(output_dir) = output_dir {
+        Cow::Owned(std::path::absolute(output_dir)?)
+    } else {
+        if let Ok(workspace) = workspace {
+            Cow::Owned(workspace.install_path().join("dist"))
+        } else {
+            match &source.source {
+                Source::Directory(src) => Cow::Owned(src.join("dist")),
+                Source::File(src) => Cow::Borrowed(src.parent().unwrap()),
+            }
+        }
+    })
+}
+
 #[expect(clippy::fn_params_excessive_bools)]
 async fn build_package(
     source: AnnotatedSource<'_>,
@@ -577,18 +1136,7 @@ async fn build_package(
     config_settings_package: &PackageConfigSettings,
     preview: Preview,
 ) -> Result<Vec<BuildMessage>, Error> {
-    let output_dir = if let Some(output_dir) = output_dir {
-        Cow::Owned(std::path::absolute(output_dir)?)
-    } else {
-        if let Ok(workspace) = workspace {
-            Cow::Owned(workspace.install_path().join("dist"))
-        } else {
-            match &source.source {
-                Source::Directory(src) => Cow::Owned(src.join("dist")),
-                Source::File(src) => Cow::Borrowed(src.parent().unwrap()),
-            }
-        }
-    };
+    let output_dir = resolve_output_dir(output_dir, workspace, &source)?;
 
     // Clear the output directory if requested
     if clear && output_dir.exists() {
@@ -1429,6 +1977,90 @@ impl BuildMessage {
     }
 }
 
+/// The wheels built for a package with multiple Python interpreters.
+#[derive(Debug, Default)]
+struct BuildMatrixSummary {
+    /// The filename of each wheel, with the Python requests that built it.
+    wheels: Vec<(String, Vec<String>)>,
+}
+
+impl BuildMatrixSummary {
+    fn print(
+        &self,
+        source: &AnnotatedSource,
+        python_requests: &[String],
+        printer: Printer,
+    ) -> Result<()> {
+        writeln!(
+            printer.stderr(),
+            "{}Built {} {} for Python {}:",
+            source.message_prefix(),
+            self.wheels.len(),
+            if self.wheels.len() == 1 {
+                "wheel"
+            } else {
+                "wheels"
+            },
+            python_requests.join(", "),
+        )?;
+        for (filename, requests) in &self.wheels {
+            writeln!(
+                printer.stderr(),
+                "  {} {}",
+                filename.cyan(),
+                format!("({})", requests.join(", ")).dimmed()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The differences between two builds of the distributions of a package.
+#[derive(Debug, Default)]
+struct Reproducibility {
+    /// The filename of each distribution, with the differences between the builds.
+    distributions: Vec<(String, Vec<Difference>)>,
+}
+
+impl Reproducibility {
+    /// Print the differences, returning `false` if any distribution is not reproducible.
+    fn print(&self, source: &AnnotatedSource, printer: Printer) -> Result<bool> {
+        for (filename, differences) in &self.distributions {
+            if differences.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "{}Verified that {} is reproducible",
+                    source.message_prefix(),
+                    filename.bold()
+                )?;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} is not reproducible:",
+                    source.message_prefix(),
+                    filename.bold()
+                )?;
+                for difference in differences {
+                    writeln!(printer.stderr(), "  {difference}")?;
+                }
+            }
+        }
+
+        let reproducible = self
+            .distributions
+            .iter()
+            .all(|(_, differences)| differences.is_empty());
+        if !reproducible && std::env::var_os(EnvVars::SOURCE_DATE_EPOCH).is_none() {
+            writeln!(
+                printer.stderr(),
+                "{HintPrefix} Set `SOURCE_DATE_EPOCH` to use a fixed timestamp in build backends \
+                that embed the current time"
+            )?;
+        }
+        Ok(reproducible)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum BuildPlan {
     /// Build a source distribution from source, then build the wheel from the source distribution.
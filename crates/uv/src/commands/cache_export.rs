@@ -0,0 +1,127 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheManifest, CacheSelection, ManifestEntry};
+use uv_distribution::{HttpArchivePointer, PathArchivePointer};
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_resolver::Lock;
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Export the cache entries required to sync a lockfile into a `.tar.zst` archive.
+pub(crate) async fn cache_export(
+    lock_path: &Path,
+    output_file: &Path,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheExport) {
+        warn_user!(
+            "`uv cache export` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheExport
+        );
+    }
+
+    let lock_path = std::path::absolute(lock_path)?;
+    let contents = fs_err::tokio::read_to_string(&lock_path).await?;
+    let lock = Lock::from_toml(&contents)
+        .with_context(|| format!("Failed to parse: `{}`", lock_path.user_display()))?;
+    let root = lock_path.parent().unwrap_or(&lock_path);
+
+    // Select the cache entries for each package in the lockfile.
+    let mut selection = CacheSelection::default();
+    for package in lock.packages() {
+        if let (Some(index), Some(version)) = (package.index(root)?, package.version()) {
+            selection.registry(cache, &index, package.name(), version)?;
+        } else if let Some(git) = package.as_git_ref()? {
+            selection.git(cache, &git.reference.url, git.sha.as_str())?;
+        } else {
+            selection.direct(cache, package.name())?;
+        }
+    }
+    selection.flat_index(cache);
+    selection.python(cache);
+
+    // Downloaded wheels are stored as pointers into the archive bucket; include their targets.
+    let wheels = cache.bucket(CacheBucket::Wheels);
+    for path in selection.paths(cache).collect::<Vec<_>>() {
+        if !path.starts_with(&wheels) {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(&path) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let archive = match entry.path().extension().and_then(|ext| ext.to_str()) {
+                Some("http") => HttpArchivePointer::read_from(entry.path())
+                    .map(|pointer| pointer.map(HttpArchivePointer::into_archive)),
+                Some("rev") => PathArchivePointer::read_from(entry.path())
+                    .map(|pointer| pointer.map(PathArchivePointer::into_archive)),
+                _ => continue,
+            };
+            match archive {
+                Ok(Some(archive)) => selection.archive(cache, &archive.id),
+                Ok(None) => {}
+                Err(err) => debug!(
+                    "Skipping unreadable archive pointer `{}`: {err}",
+                    entry.path().user_display()
+                ),
+            }
+        }
+    }
+
+    let manifest = cache.export_manifest(&selection)?;
+
+    // Write the manifest first, followed by the entries it describes.
+    let file = fs_err::tokio::File::create(output_file).await?;
+    let encoder = async_compression::tokio::write::ZstdEncoder::new(file);
+    let mut tar = tokio_tar::Builder::new(encoder);
+
+    let contents = serde_json::to_vec_pretty(&manifest)?;
+    let mut header = tokio_tar::Header::new_gnu();
+    header.set_size(contents.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    tar.append_data(&mut header, CacheManifest::FILENAME, contents.as_slice())
+        .await?;
+
+    let mut num_files = 0;
+    let mut num_bytes = 0;
+    for entry in &manifest.entries {
+        match entry {
+            ManifestEntry::Directory { path } => {
+                tar.append_dir(path, cache.root().join(path)).await?;
+            }
+            ManifestEntry::File { path, size, .. } => {
+                tar.append_path_with_name(cache.root().join(path), path)
+                    .await?;
+                num_files += 1;
+                num_bytes += size;
+            }
+            ManifestEntry::Link { .. } => {}
+        }
+    }
+
+    let mut encoder = tar.into_inner().await?;
+    encoder.shutdown().await?;
+
+    writeln!(
+        printer.stderr(),
+        "Exported {} ({:.1}) to: {}",
+        format!("{num_files} file{}", if num_files == 1 { "" } else { "s" }).bold(),
+        human_readable_bytes(num_bytes).green(),
+        output_file.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,75 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::{Cache, CacheManifest};
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Import the cache entries in a `.tar.zst` archive created by `uv cache export`.
+pub(crate) async fn cache_import(
+    archive: &Path,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheExport) {
+        warn_user!(
+            "`uv cache import` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheExport
+        );
+    }
+
+    let cache = cache.init().await?;
+
+    // Unpack the archive into the cache directory, such that entries can be moved into place.
+    let staging = tempfile::tempdir_in(cache.root())?;
+    let file = fs_err::tokio::File::open(archive).await?;
+    uv_extract::stream::untar_zst(file, staging.path())
+        .await
+        .with_context(|| format!("Failed to unpack: `{}`", archive.user_display()))?;
+
+    let manifest = fs_err::tokio::read(staging.path().join(CacheManifest::FILENAME))
+        .await
+        .with_context(|| {
+            format!(
+                "Archive is missing a cache manifest: `{}`",
+                archive.user_display()
+            )
+        })?;
+    let manifest: CacheManifest = serde_json::from_slice(&manifest)
+        .with_context(|| format!("Invalid cache manifest in: `{}`", archive.user_display()))?;
+
+    let summary = cache
+        .import(staging.path(), &manifest)
+        .with_context(|| format!("Failed to import: `{}`", archive.user_display()))?;
+
+    write!(
+        printer.stderr(),
+        "Imported {} ({:.1}) into: {}",
+        format!(
+            "{} file{}",
+            summary.num_files,
+            if summary.num_files == 1 { "" } else { "s" }
+        )
+        .bold(),
+        human_readable_bytes(summary.num_bytes).green(),
+        cache.root().user_display().cyan()
+    )?;
+    if summary.num_skipped > 0 {
+        write!(
+            printer.stderr(),
+            " ({} already present)",
+            summary.num_skipped
+        )?;
+    }
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -4,17 +4,22 @@ use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use uv_cache::{Cache, RemovalMode};
+use uv_cache::{Cache, EvictionPolicy, RemovalMode};
+use uv_configuration::{CacheAge, CacheSize};
 use uv_fs::Simplified;
 use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user_once;
 
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
-/// Prune dangling cache entries and cached environments.
+/// Prune dangling cache entries and cached environments, and evict cache entries that exceed the
+/// given size or age limits.
 pub(crate) async fn cache_prune(
     ci: bool,
     force: bool,
+    max_size: Option<CacheSize>,
+    max_age: Option<CacheAge>,
     cache: Cache,
     printer: Printer,
     preview: Preview,
@@ -43,11 +48,25 @@ pub(crate) async fn cache_prune(
         }
     };
 
-    let removal_mode = if preview.is_enabled(PreviewFeature::CachePhysicalSpace) {
-        RemovalMode::Physical
-    } else {
-        RemovalMode::Logical
+    let policy = EvictionPolicy {
+        max_size: max_size.map(CacheSize::bytes),
+        max_age: max_age.as_ref().map(CacheAge::duration),
     };
+    if !policy.is_empty() && !preview.is_enabled(PreviewFeature::CacheEviction) {
+        warn_user_once!(
+            "Size- and age-bounded cache eviction is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheEviction
+        );
+    }
+
+    // Eviction reports the physical space it reclaims, since archives that are shared with other
+    // entries (e.g., via hardlinks) don't free any space when removed.
+    let removal_mode =
+        if preview.is_enabled(PreviewFeature::CachePhysicalSpace) || !policy.is_empty() {
+            RemovalMode::Physical
+        } else {
+            RemovalMode::Logical
+        };
     let cache = cache.with_removal_mode(removal_mode);
 
     writeln!(
@@ -67,6 +86,16 @@ pub(crate) async fn cache_prune(
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    // Evict any entries that exceed the configured size or age limits.
+    if !policy.is_empty() {
+        summary += cache.evict(policy).with_context(|| {
+            format!(
+                "Failed to evict from cache at: {}",
+                cache.root().user_display()
+            )
+        })?;
+    }
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
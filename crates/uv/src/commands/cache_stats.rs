@@ -0,0 +1,396 @@
+use std::fmt::Write;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::{Cache, CacheStats, CacheUsage, PhysicalSpace};
+use uv_cli::CacheStatsFormat;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// The upper bounds of the age ranges used to summarize when archives were last used.
+const AGE_RANGES: [(Option<u64>, &str); 5] = [
+    (Some(1), "< 1 day"),
+    (Some(7), "< 7 days"),
+    (Some(30), "< 30 days"),
+    (Some(90), "< 90 days"),
+    (None, ">= 90 days"),
+];
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Display the disk usage of the cache, by bucket and by package.
+pub(crate) fn cache_stats(
+    cache: &Cache,
+    top: usize,
+    output_format: CacheStatsFormat,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheStats) {
+        warn_user!(
+            "`uv cache stats` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheStats
+        );
+    }
+
+    let stats = cache.stats().with_context(|| {
+        format!(
+            "Failed to compute statistics for cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+    let report = StatsReport::new(cache, &stats, top, SystemTime::now());
+
+    match output_format {
+        CacheStatsFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+        }
+        CacheStatsFormat::Text => report.write(printer)?,
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    /// An unstable, experimental schema.
+    #[default]
+    Preview,
+}
+
+#[derive(Serialize, Debug, Default)]
+struct SchemaReport {
+    /// The version of the schema.
+    version: SchemaVersion,
+}
+
+#[derive(Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum PhysicalSpaceReport {
+    /// Physical storage was measured for every file.
+    Exact,
+    /// Some files could not be measured; physical sizes are a lower bound.
+    Incomplete,
+    /// The filesystem does not support physical space accounting.
+    Unsupported,
+}
+
+/// Disk usage statistics for the cache.
+#[derive(Debug, Serialize)]
+struct StatsReport {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The cache directory.
+    cache_dir: String,
+    /// The accuracy of the reported physical sizes.
+    physical_space: PhysicalSpaceReport,
+    /// The total usage of the cache.
+    total: UsageReport,
+    /// The usage of each cache bucket.
+    buckets: Vec<BucketReport>,
+    /// The usage of any entries outside the current cache buckets.
+    other: UsageReport,
+    /// The usage of the largest packages, in descending order of size.
+    packages: Vec<PackageReport>,
+    /// The number and size of unzipped wheels, by when they were last used.
+    archive_ages: Vec<AgeReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct UsageReport {
+    /// The number of files.
+    num_files: u64,
+    /// The logical size of the files, in bytes.
+    logical_bytes: u64,
+    /// The exclusively owned physical storage of the files, in bytes, if supported.
+    physical_bytes: Option<u64>,
+    /// When the least-recently-used entry was last used.
+    oldest: Option<jiff::Timestamp>,
+    /// When the most-recently-used entry was last used.
+    newest: Option<jiff::Timestamp>,
+}
+
+#[derive(Debug, Serialize)]
+struct BucketReport {
+    /// The name of the bucket directory.
+    bucket: String,
+    #[serde(flatten)]
+    usage: UsageReport,
+}
+
+#[derive(Debug, Serialize)]
+struct PackageReport {
+    /// The name of the package.
+    name: String,
+    #[serde(flatten)]
+    usage: UsageReport,
+}
+
+#[derive(Debug, Serialize)]
+struct AgeReport {
+    /// The exclusive upper bound of the time since last use, in days, if any.
+    max_age_days: Option<u64>,
+    /// The number of archives in the range.
+    num_archives: u64,
+    /// The logical size of the archives, in bytes.
+    logical_bytes: u64,
+    /// The exclusively owned physical storage of the archives, in bytes, if supported.
+    physical_bytes: Option<u64>,
+}
+
+impl UsageReport {
+    fn new(usage: &CacheUsage, physical: bool) -> Self {
+        Self {
+            num_files: usage.num_files,
+            logical_bytes: usage.logical_bytes,
+            physical_bytes: physical.then_some(usage.physical_bytes),
+            oldest: usage
+                .oldest
+                .and_then(|time| jiff::Timestamp::try_from(time).ok()),
+            newest: usage
+                .newest
+                .and_then(|time| jiff::Timestamp::try_from(time).ok()),
+        }
+    }
+
+    /// The size used to rank entries: physical storage if supported, and logical size otherwise.
+    fn size(&self) -> u64 {
+        self.physical_bytes.unwrap_or(self.logical_bytes)
+    }
+}
+
+impl StatsReport {
+    fn new(cache: &Cache, stats: &CacheStats, top: usize, now: SystemTime) -> Self {
+        let physical_space = match stats.physical_space {
+            PhysicalSpace::Exact => PhysicalSpaceReport::Exact,
+            PhysicalSpace::Incomplete => PhysicalSpaceReport::Incomplete,
+            PhysicalSpace::Unsupported => PhysicalSpaceReport::Unsupported,
+        };
+        let physical = !matches!(physical_space, PhysicalSpaceReport::Unsupported);
+
+        let mut packages = stats
+            .packages
+            .iter()
+            .map(|package| PackageReport {
+                name: package.name.to_string(),
+                usage: UsageReport::new(&package.usage, physical),
+            })
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| {
+            b.usage
+                .size()
+                .cmp(&a.usage.size())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        packages.truncate(top);
+
+        let mut archive_ages = AGE_RANGES
+            .iter()
+            .map(|(max_age_days, _)| AgeReport {
+                max_age_days: *max_age_days,
+                num_archives: 0,
+                logical_bytes: 0,
+                physical_bytes: physical.then_some(0),
+            })
+            .collect::<Vec<_>>();
+        for archive in &stats.archives {
+            let age = now
+                .duration_since(archive.last_used)
+                .unwrap_or(Duration::ZERO);
+            let range = archive_ages
+                .iter_mut()
+                .find(|range| {
+                    range
+                        .max_age_days
+                        .is_none_or(|days| age < Duration::from_secs(days * SECONDS_PER_DAY))
+                })
+                .expect("the last age range is unbounded");
+            range.num_archives += 1;
+            range.logical_bytes += archive.usage.logical_bytes;
+            if let Some(physical_bytes) = range.physical_bytes.as_mut() {
+                *physical_bytes += archive.usage.physical_bytes;
+            }
+        }
+
+        Self {
+            schema: SchemaReport::default(),
+            cache_dir: cache.root().simplified_display().to_string(),
+            physical_space,
+            total: UsageReport::new(&stats.total(), physical),
+            buckets: stats
+                .buckets
+                .iter()
+                .map(|bucket| BucketReport {
+                    bucket: bucket.bucket.to_string(),
+                    usage: UsageReport::new(&bucket.usage, physical),
+                })
+                .collect(),
+            other: UsageReport::new(&stats.other, physical),
+            packages,
+            archive_ages,
+        }
+    }
+
+    /// Write the report in a human-readable format.
+    fn write(&self, printer: Printer) -> Result<()> {
+        let physical = !matches!(self.physical_space, PhysicalSpaceReport::Unsupported);
+
+        write!(
+            printer.stdout(),
+            "Cache at {}: {} in {}",
+            self.cache_dir.cyan(),
+            format!("{:.1}", human_readable_bytes(self.total.logical_bytes)).green(),
+            format!(
+                "{} file{}",
+                self.total.num_files,
+                if self.total.num_files == 1 { "" } else { "s" }
+            )
+            .bold()
+        )?;
+        if let Some(physical_bytes) = self.total.physical_bytes {
+            write!(
+                printer.stdout(),
+                " ({} reclaimable)",
+                format!("{:.1}", human_readable_bytes(physical_bytes)).green()
+            )?;
+        }
+        writeln!(printer.stdout())?;
+        if matches!(self.physical_space, PhysicalSpaceReport::Incomplete) {
+            writeln!(
+                printer.stderr(),
+                "Some files could not be measured; reclaimable sizes are a lower bound"
+            )?;
+        }
+
+        if self.total.num_files == 0 {
+            return Ok(());
+        }
+
+        // Display the usage of each bucket.
+        let mut rows = self
+            .buckets
+            .iter()
+            .map(|bucket| (bucket.bucket.clone(), &bucket.usage, None))
+            .collect::<Vec<_>>();
+        if self.other.num_files > 0 {
+            rows.push(("(other)".to_string(), &self.other, None));
+        }
+        writeln!(printer.stdout())?;
+        write_table(printer, "Bucket", &rows, physical)?;
+
+        // Display the largest packages.
+        if !self.packages.is_empty() {
+            let now = SystemTime::now();
+            let rows = self
+                .packages
+                .iter()
+                .map(|package| {
+                    let last_used = package
+                        .usage
+                        .newest
+                        .map(SystemTime::from)
+                        .map(|newest| format_age(now, newest));
+                    (package.name.clone(), &package.usage, last_used)
+                })
+                .collect::<Vec<_>>();
+            writeln!(printer.stdout())?;
+            write_table(printer, "Package", &rows, physical)?;
+        }
+
+        // Display when the unzipped wheels were last used.
+        if self.archive_ages.iter().any(|range| range.num_archives > 0) {
+            writeln!(printer.stdout())?;
+            let mut line = format!("{:<10}  {:>8}  {:>10}", "Last used", "Archives", "Logical");
+            if physical {
+                write!(line, "  {:>11}", "Reclaimable")?;
+            }
+            writeln!(printer.stdout(), "{}", line.bold())?;
+            for (range, (_, label)) in self.archive_ages.iter().zip(AGE_RANGES) {
+                let mut line = format!(
+                    "{label:<10}  {:>8}  {:>10}",
+                    range.num_archives,
+                    format!("{:.1}", human_readable_bytes(range.logical_bytes)),
+                );
+                if let Some(physical_bytes) = range.physical_bytes {
+                    write!(
+                        line,
+                        "  {:>11}",
+                        format!("{:.1}", human_readable_bytes(physical_bytes))
+                    )?;
+                }
+                writeln!(printer.stdout(), "{line}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Write a table of usage, with an optional "last used" column.
+fn write_table(
+    printer: Printer,
+    header: &str,
+    rows: &[(String, &UsageReport, Option<String>)],
+    physical: bool,
+) -> Result<()> {
+    let width = rows
+        .iter()
+        .map(|(name, ..)| name.len())
+        .chain([header.len()])
+        .max()
+        .unwrap_or_default();
+    let last_used = rows.iter().any(|(.., last_used)| last_used.is_some());
+
+    let mut line = format!("{header:<width$}  {:>8}  {:>10}", "Files", "Logical");
+    if physical {
+        write!(line, "  {:>11}", "Reclaimable")?;
+    }
+    if last_used {
+        line.push_str("  Last used");
+    }
+    writeln!(printer.stdout(), "{}", line.trim_end().bold())?;
+
+    for (name, usage, age) in rows {
+        let mut line = format!(
+            "{name:<width$}  {:>8}  {:>10}",
+            usage.num_files,
+            format!("{:.1}", human_readable_bytes(usage.logical_bytes)),
+        );
+        if let Some(physical_bytes) = usage.physical_bytes {
+            write!(
+                line,
+                "  {:>11}",
+                format!("{:.1}", human_readable_bytes(physical_bytes))
+            )?;
+        }
+        if let Some(age) = age {
+            write!(line, "  {age}")?;
+        }
+        writeln!(printer.stdout(), "{line}")?;
+    }
+    Ok(())
+}
+
+/// Format the time since the given instant, e.g., `3d ago`.
+fn format_age(now: SystemTime, time: SystemTime) -> String {
+    let secs = now.duration_since(time).unwrap_or(Duration::ZERO).as_secs();
+    if secs >= SECONDS_PER_DAY {
+        format!("{}d ago", secs / SECONDS_PER_DAY)
+    } else if secs >= 60 * 60 {
+        format!("{}h ago", secs / (60 * 60))
+    } else {
+        "< 1h ago".to_string()
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Verify the integrity of the cache, removing any corrupt entries.
+pub(crate) async fn cache_verify(
+    quick: bool,
+    force: bool,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::CacheVerify) {
+        warn_user!(
+            "`uv cache verify` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::CacheVerify
+        );
+    }
+
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let cache = match cache.with_exclusive_lock_no_wait() {
+        Ok(cache) => cache,
+        Err(cache) if force => {
+            debug!("Cache is currently in use, proceeding due to `--force`");
+            cache
+        }
+        Err(cache) => {
+            writeln!(
+                printer.stderr(),
+                "Cache is currently in-use, waiting for other uv processes to finish (use `--force` to override)"
+            )?;
+            cache.with_exclusive_lock().await?
+        }
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let verification = uv_distribution::verify(&cache, quick)
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?;
+
+    let s = if verification.num_archives == 1 {
+        ""
+    } else {
+        "s"
+    };
+    let ies = if verification.num_metadata == 1 {
+        "y"
+    } else {
+        "ies"
+    };
+    writeln!(
+        printer.stderr(),
+        "Checked {} and {}",
+        format!("{} archive{s}", verification.num_archives).bold(),
+        format!("{} metadata entr{ies}", verification.num_metadata).bold()
+    )?;
+    if !quick && verification.num_unhashed > 0 {
+        let s = if verification.num_unhashed == 1 {
+            ""
+        } else {
+            "s"
+        };
+        writeln!(
+            printer.stderr(),
+            "{} without a recorded hash; only their structure was checked",
+            format!("{} archive{s}", verification.num_unhashed).bold()
+        )?;
+    }
+
+    if verification.corrupt.is_empty() {
+        writeln!(printer.stderr(), "No corrupt entries found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for entry in &verification.corrupt {
+        let path = entry.path.strip_prefix(cache.root()).unwrap_or(&entry.path);
+        writeln!(
+            printer.stderr(),
+            "Removing corrupt cache entry: {} ({})",
+            path.user_display().cyan(),
+            entry.reason
+        )?;
+    }
+
+    let summary = uv_distribution::repair(&cache, &verification).with_context(|| {
+        format!(
+            "Failed to remove corrupt entries from cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    let num_corrupt = verification.corrupt.len();
+    let ies = if num_corrupt == 1 { "y" } else { "ies" };
+    write!(printer.stderr(), "Removed {num_corrupt} corrupt entr{ies}")?;
+    let reported_bytes = summary.physical_bytes.unwrap_or(summary.logical_bytes);
+    if reported_bytes > 0 {
+        write!(
+            printer.stderr(),
+            " ({:.1})",
+            human_readable_bytes(reported_bytes).green()
+        )?;
+    }
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
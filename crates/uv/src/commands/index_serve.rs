@@ -0,0 +1,85 @@
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::Credentials;
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeature};
+use uv_publish::{IndexServer, ServedIndex};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Serve a directory of distributions, or the wheels built into the cache, as a package index.
+pub(crate) async fn index_serve(
+    directory: Option<&Path>,
+    address: SocketAddr,
+    username: Option<String>,
+    password: Option<String>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::IndexServe) {
+        warn_user!(
+            "`uv index serve` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::IndexServe
+        );
+    }
+
+    let (index, source) = if let Some(directory) = directory {
+        let index = ServedIndex::from_directory(directory)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to read distributions from: {}",
+                    directory.user_display()
+                )
+            })?;
+        (index, directory)
+    } else {
+        let index = ServedIndex::from_cache(cache).await.with_context(|| {
+            format!(
+                "Failed to read wheels from cache at: {}",
+                cache.root().user_display()
+            )
+        })?;
+        (index, cache.root())
+    };
+
+    let num_files = index.num_files();
+    let num_projects = index.num_projects();
+    if num_files == 0 {
+        warn_user!(
+            "No wheels or source distributions found in: {}",
+            source.user_display()
+        );
+    }
+
+    let credentials = username.map(|username| Credentials::basic(Some(username), password));
+    let server = IndexServer::bind(address, index, credentials.as_ref()).await?;
+    writeln!(
+        printer.stderr(),
+        "Serving {} of {} from `{}` at: {}",
+        format!("{num_files} file{}", if num_files == 1 { "" } else { "s" }).bold(),
+        format!(
+            "{num_projects} project{}",
+            if num_projects == 1 { "" } else { "s" }
+        )
+        .bold(),
+        source.user_display(),
+        server.url().cyan()
+    )?;
+
+    tokio::select! {
+        result = server.serve() => result?,
+        result = tokio::signal::ctrl_c() => result?,
+    }
+
+    Ok(ExitStatus::Success)
+}
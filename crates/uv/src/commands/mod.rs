@@ -18,9 +18,14 @@ pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_export::cache_export;
+pub(crate) use cache_import::cache_import;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
+pub(crate) use cache_stats::cache_stats;
+pub(crate) use cache_verify::cache_verify;
 pub(crate) use help::help;
+pub(crate) use index_serve::index_serve;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
@@ -74,6 +79,7 @@ use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
 pub(crate) use workspace::dir::dir;
+pub(crate) use workspace::exec::exec;
 pub(crate) use workspace::list::list;
 pub(crate) use workspace::metadata::metadata;
 
@@ -85,11 +91,16 @@ pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_export;
+mod cache_import;
 mod cache_prune;
 mod cache_size;
+mod cache_stats;
+mod cache_verify;
 pub(crate) mod diagnostics;
 mod editable;
 mod help;
+mod index_serve;
 pub(crate) mod pip;
 mod project;
 mod publish;
@@ -13,7 +13,7 @@ use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, EditableMode,
     ExcludeDependency, ExtrasSpecification, HashCheckingMode, IndexStrategy, NoSources, Override,
-    Reinstall, Upgrade,
+    Reinstall, RequireProvenance, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
@@ -102,6 +102,7 @@ pub(crate) async fn pip_install(
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     reinstall: Reinstall,
+    require_provenance: &RequireProvenance,
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
@@ -668,6 +669,7 @@ pub(crate) async fn pip_install(
         InstallationStrategy::Permissive,
         modifications,
         &reinstall,
+        require_provenance,
         &build_options,
         link_mode,
         compile.then_some(operations::BytecodeCompilation::Installed),
@@ -9,13 +9,14 @@ use std::time::{Duration, Instant};
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, ExcludeDependency, Excludes,
-    ExtrasSpecification, Override, Overrides, Reinstall, Upgrade,
+    ExtrasSpecification, Override, Overrides, Reinstall, RequireProvenance, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
@@ -50,6 +51,7 @@ use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
+use crate::commands::pip::provenance::{self, ProvenanceError};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
 use crate::commands::{compile_bytecode, compile_bytecode_files};
 use crate::printer::Printer;
@@ -674,6 +676,7 @@ pub(crate) async fn install(
     installation: InstallationStrategy,
     modifications: Modifications,
     reinstall: &Reinstall,
+    require_provenance: &RequireProvenance,
     build_options: &BuildOptions,
     link_mode: LinkMode,
     compile: Option<BytecodeCompilation>,
@@ -691,6 +694,8 @@ pub(crate) async fn install(
     printer: Printer,
     preview: Preview,
 ) -> Result<Changelog, Error> {
+    let hasher = &provenance::pin_hashes(resolution, require_provenance, hasher.clone(), preview)?;
+
     let plan = InstallationPlan::build(
         resolution,
         site_packages,
@@ -711,6 +716,7 @@ pub(crate) async fn install(
     plan.execute(
         resolution,
         modifications,
+        require_provenance,
         build_options,
         link_mode,
         compile,
@@ -737,6 +743,7 @@ impl InstallationPlan {
         self,
         resolution: &Resolution,
         modifications: Modifications,
+        require_provenance: &RequireProvenance,
         build_options: &BuildOptions,
         link_mode: LinkMode,
         compile: Option<BytecodeCompilation>,
@@ -781,6 +788,23 @@ impl InstallationPlan {
             Modifications::Exact => extraneous,
         };
 
+        // Verify the provenance of any distributions to be installed, before modifying the
+        // environment.
+        let packages = cached
+            .iter()
+            .map(Name::name)
+            .chain(remote.iter().map(|dist| dist.name()))
+            .collect::<FxHashSet<_>>();
+        provenance::verify(
+            resolution,
+            require_provenance,
+            &packages,
+            client,
+            cache,
+            concurrency.downloads,
+        )
+        .await?;
+
         // Nothing to do.
         if remote.is_empty()
             && cached.is_empty()
@@ -1381,6 +1405,9 @@ pub(crate) enum Error {
     #[error(transparent)]
     Hash(#[from] uv_types::HashStrategyError),
 
+    #[error(transparent)]
+    Provenance(#[from] ProvenanceError),
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
@@ -0,0 +1,153 @@
+//! Enforcement of the `require-provenance` policy.
+
+use futures::{StreamExt, TryStreamExt};
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::RegistryClient;
+use uv_configuration::RequireProvenance;
+use uv_distribution_types::{
+    BuiltDist, Dist, DistributionMetadata, File, IndexUrl, Name, Resolution, ResolvedDist,
+    SourceDist,
+};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_preview::{Preview, PreviewFeature};
+use uv_provenance::{ProvenanceClient, TrustRoot, VerificationError};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_types::HashStrategy;
+use uv_warnings::warn_user_once;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ProvenanceError {
+    #[error(
+        "The index didn't provide a SHA-256 digest for `{0}`, which is required to verify its provenance"
+    )]
+    MissingDigest(String),
+    #[error("Failed to fetch the provenance of `{0}`")]
+    Fetch(String, #[source] Box<uv_provenance::Error>),
+    #[error("Failed to verify the provenance of `{0}`")]
+    Verify(String, #[source] Box<VerificationError>),
+}
+
+/// A registry distribution file that is subject to the provenance policy.
+struct Covered<'a> {
+    dist: &'a Dist,
+    version: &'a Version,
+    file: &'a File,
+    index: &'a IndexUrl,
+}
+
+impl<'a> Covered<'a> {
+    /// Return the SHA-256 digest of the file, as reported by the index.
+    fn sha256(&self) -> Option<&'a HashDigest> {
+        self.file
+            .hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+    }
+}
+
+/// Return the registry distributions in the resolution that require verified provenance.
+fn covered<'a>(
+    resolution: &'a Resolution,
+    require_provenance: &'a RequireProvenance,
+) -> impl Iterator<Item = Covered<'a>> {
+    resolution.distributions().filter_map(|dist| {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            return None;
+        };
+        if !require_provenance.for_package(dist.name()) {
+            return None;
+        }
+        let (version, file, index) = match dist.as_ref() {
+            Dist::Built(BuiltDist::Registry(built)) => {
+                let wheel = built.best_wheel();
+                (&wheel.filename.version, wheel.file.as_ref(), &wheel.index)
+            }
+            Dist::Source(SourceDist::Registry(source)) => {
+                (&source.version, source.file.as_ref(), &source.index)
+            }
+            _ => return None,
+        };
+        Some(Covered {
+            dist,
+            version,
+            file,
+            index,
+        })
+    })
+}
+
+/// Pin the hashes of the distributions that require verified provenance to the digests reported
+/// by the index, such that the installed artifacts are those covered by the provenance.
+pub(crate) fn pin_hashes(
+    resolution: &Resolution,
+    require_provenance: &RequireProvenance,
+    hasher: HashStrategy,
+    preview: Preview,
+) -> Result<HashStrategy, uv_types::HashStrategyError> {
+    if require_provenance.is_none() {
+        return Ok(hasher);
+    }
+    if !preview.is_enabled(PreviewFeature::ProvenanceVerification) {
+        warn_user_once!(
+            "The `require-provenance` setting is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::ProvenanceVerification
+        );
+    }
+    hasher.pin_digests(
+        covered(resolution, require_provenance)
+            .filter_map(|covered| Some((covered.dist.version_id(), covered.sha256()?))),
+    )
+}
+
+/// Verify the provenance of the given packages' distributions, if required by the policy.
+pub(crate) async fn verify(
+    resolution: &Resolution,
+    require_provenance: &RequireProvenance,
+    packages: &FxHashSet<&PackageName>,
+    client: &RegistryClient,
+    cache: &Cache,
+    concurrency: usize,
+) -> Result<(), ProvenanceError> {
+    if require_provenance.is_none() {
+        return Ok(());
+    }
+
+    let provenance_client = ProvenanceClient::new(client.cached_client(), cache);
+    futures::stream::iter(
+        covered(resolution, require_provenance)
+            .filter(|covered| packages.contains(covered.dist.name())),
+    )
+    .map(|covered| verify_one(covered, &provenance_client))
+    .buffer_unordered(concurrency)
+    .try_collect::<()>()
+    .await
+}
+
+/// Verify the provenance of a single distribution file.
+async fn verify_one(
+    covered: Covered<'_>,
+    client: &ProvenanceClient<'_>,
+) -> Result<(), ProvenanceError> {
+    let filename = covered.file.filename.as_ref();
+    let sha256 = covered
+        .sha256()
+        .ok_or_else(|| ProvenanceError::MissingDigest(filename.to_string()))?;
+    let provenance = client
+        .fetch(
+            covered.index.url(),
+            covered.dist.name(),
+            covered.version,
+            filename,
+        )
+        .await
+        .map_err(|err| ProvenanceError::Fetch(filename.to_string(), Box::new(err)))?;
+    provenance
+        .verify(TrustRoot::public_good(), filename, &sha256.digest)
+        .map_err(|err| ProvenanceError::Verify(filename.to_string(), Box::new(err)))?;
+    debug!("Verified provenance for: {filename}");
+    Ok(())
+}
@@ -9,7 +9,7 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, NoSources, Reinstall, Upgrade,
+    HashCheckingMode, IndexStrategy, NoSources, Reinstall, RequireProvenance, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
@@ -59,6 +59,7 @@ pub(crate) async fn pip_sync(
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
     reinstall: Reinstall,
+    require_provenance: &RequireProvenance,
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
@@ -535,6 +536,7 @@ pub(crate) async fn pip_sync(
         InstallationStrategy::Permissive,
         Modifications::Exact,
         &reinstall,
+        require_provenance,
         &build_options,
         link_mode,
         compile.then_some(operations::BytecodeCompilation::All),
@@ -930,6 +930,8 @@ fn edits(
                 tag,
                 branch,
                 lfs,
+                verify_signature,
+                allowed_signers,
                 marker,
                 extra,
                 group,
@@ -950,6 +952,8 @@ fn edits(
                     tag,
                     branch,
                     lfs,
+                    verify_signature,
+                    allowed_signers,
                     marker,
                     extra,
                     group,
@@ -35,9 +35,9 @@ use uv_pypi_types::{ConflictItem, ConflictKind, ConflictSet, Conflicts};
 use uv_python::managed::{ManagedPythonInstallation, PythonMinorVersionLink};
 use uv_python::{
     BrokenLink, ConfigDiscovery, EnvironmentPreference, Interpreter, InvalidEnvironmentKind,
-    LenientImplementationName, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonSource, PythonVariant, PythonVersionFile,
-    VersionFileDiscoveryOptions, VersionRequest,
+    LenientImplementationName, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonSource, PythonVariant,
+    PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
 };
 use uv_requirements::{
     LockedRequirements, NamedRequirementsResolver, RequirementsSpecification,
@@ -81,6 +81,7 @@ pub(crate) mod lock_target;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
+pub(crate) mod task;
 mod toolchain;
 pub(crate) mod tree;
 pub(crate) mod upgrade;
@@ -294,6 +295,12 @@ pub(crate) enum ProjectError {
     #[error("Cannot write parent environment path to `pyvenv.cfg` because it is not valid UTF-8")]
     InvalidParentEnvironmentPath,
 
+    #[error("Invalid environment variable name in `tool.uv.environment`: `{0}`")]
+    InvalidEnvironmentVariableName(String),
+
+    #[error("Failed to read environment file `{}`", _0.user_display())]
+    EnvironmentFile(PathBuf, #[source] dotenvy::Error),
+
     #[error("Attempted to drop a temporary virtual environment while still in-use")]
     DroppedEnvironment,
 
@@ -1914,8 +1921,15 @@ impl ProjectEnvironment {
         {
             // If we found an existing, compatible environment, use it.
             ProjectInterpreter::Environment(environment) => {
-                if centralized && !dry_run.enabled() {
-                    update_project_environment_link(&environment, workspace, link_error_reporting);
+                if !dry_run.enabled() {
+                    if centralized {
+                        update_project_environment_link(
+                            &environment,
+                            workspace,
+                            link_error_reporting,
+                        );
+                    }
+                    update_activation_scripts(&environment, workspace);
                 }
                 Ok(Self::Existing(environment))
             }
@@ -2010,6 +2024,15 @@ impl ProjectEnvironment {
                     });
                 }
 
+                // Preserve any parent environments layered beneath the existing environment.
+                let parents = if replace_environment {
+                    PyVenvConfiguration::parse(root.join("pyvenv.cfg"))
+                        .map(|cfg| cfg.parent_environments().to_vec())
+                        .unwrap_or_default()
+                } else {
+                    Vec::new()
+                };
+
                 if replace_environment {
                     // Remove centralized references directly to preserve their cached targets.
                     let removed = if centralized_environment_reference {
@@ -2065,10 +2088,19 @@ impl ProjectEnvironment {
                     upgradeable,
                 )?;
 
+                if !parents.is_empty() {
+                    if let Err(err) = uv_virtualenv::add_parent_environments(&environment, &parents)
+                    {
+                        warn_user!("Failed to preserve parent environments: {err}");
+                    }
+                }
+
                 if centralized {
                     update_project_environment_link(&environment, workspace, link_error_reporting);
                 }
 
+                update_activation_scripts(&environment, workspace);
+
                 if replace_environment {
                     Ok(Self::Replaced(environment))
                 } else {
@@ -2360,6 +2392,7 @@ pub(crate) async fn resolve_names(
             },
         compile_bytecode: _,
         reinstall: _,
+        require_provenance: _,
     } = settings;
 
     let client_builder = client_builder.clone().keyring(*keyring_provider);
@@ -2795,6 +2828,7 @@ pub(crate) async fn sync_environment(
         link_mode,
         compile_bytecode,
         reinstall,
+        require_provenance,
         build_options,
         sources,
     } = settings;
@@ -2878,6 +2912,7 @@ pub(crate) async fn sync_environment(
         InstallationStrategy::Permissive,
         modifications,
         reinstall,
+        require_provenance,
         build_options,
         link_mode,
         compile_bytecode.then_some(pip::operations::BytecodeCompilation::All),
@@ -2969,6 +3004,7 @@ pub(crate) async fn update_environment(
             },
         compile_bytecode,
         reinstall,
+        require_provenance,
     } = settings;
 
     let client_builder = client_builder.clone().keyring(*keyring_provider);
@@ -3179,6 +3215,7 @@ pub(crate) async fn update_environment(
         InstallationStrategy::Permissive,
         modifications,
         reinstall,
+        require_provenance,
         build_options,
         *link_mode,
         (*compile_bytecode).then_some(pip::operations::BytecodeCompilation::All),
@@ -3285,6 +3322,93 @@ pub(crate) fn default_dependency_groups(
     }
 }
 
+/// Resolve the environment declared in `[tool.uv.environment]` for the workspace.
+///
+/// Relative paths are resolved against the workspace root. Missing environment files are skipped,
+/// and static variables take precedence over those read from environment files.
+pub(crate) fn project_environment_variables(
+    workspace: &Workspace,
+) -> Result<uv_virtualenv::ActivationEnvironment, ProjectError> {
+    let Some(config) = workspace.pyproject_toml().tool_uv_environment() else {
+        return Ok(uv_virtualenv::ActivationEnvironment::default());
+    };
+
+    if !uv_preview::is_enabled(PreviewFeature::ProjectEnvironmentVariables) {
+        warn_user_once!(
+            "The `tool.uv.environment` setting is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::ProjectEnvironmentVariables
+        );
+    }
+
+    let mut variables = BTreeMap::new();
+    for env_file in config.env_files.iter().flatten() {
+        let path = workspace.install_path().join(env_file);
+        let iter = match dotenvy::from_path_iter(&path) {
+            Ok(iter) => iter,
+            Err(dotenvy::Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => {
+                debug!(
+                    "Skipping missing environment file: `{}`",
+                    path.user_display()
+                );
+                continue;
+            }
+            Err(err) => return Err(ProjectError::EnvironmentFile(path, err)),
+        };
+        for item in iter {
+            let (key, value) =
+                item.map_err(|err| ProjectError::EnvironmentFile(path.clone(), err))?;
+            variables.insert(key, value);
+        }
+    }
+    for (key, value) in config.variables.iter().flatten() {
+        variables.insert(key.clone(), value.clone());
+    }
+
+    // Reject names that can't be safely written into the activation scripts.
+    for key in variables.keys() {
+        let mut chars = key.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|char| char.is_ascii_alphabetic() || char == '_')
+            && chars.all(|char| char.is_ascii_alphanumeric() || char == '_');
+        if !valid {
+            return Err(ProjectError::InvalidEnvironmentVariableName(key.clone()));
+        }
+    }
+
+    let path_prepend = config
+        .path_prepend
+        .iter()
+        .flatten()
+        .map(|path| workspace.install_path().join(path))
+        .collect();
+
+    Ok(uv_virtualenv::ActivationEnvironment {
+        variables: variables.into_iter().collect(),
+        path_prepend,
+    })
+}
+
+/// Write the environment declared in `[tool.uv.environment]` into the activation scripts of the
+/// project environment.
+///
+/// Projects that don't declare `[tool.uv.environment]` are left untouched.
+fn update_activation_scripts(environment: &PythonEnvironment, workspace: &Workspace) {
+    if workspace.pyproject_toml().tool_uv_environment().is_none() {
+        return;
+    }
+    let activation = match project_environment_variables(workspace) {
+        Ok(activation) => activation,
+        Err(err) => {
+            warn_user_once!("Failed to resolve `tool.uv.environment`: {err}");
+            return;
+        }
+    };
+    if let Err(err) = uv_virtualenv::update_activators(environment, &activation) {
+        warn_user!("Failed to update activation scripts: {err}");
+    }
+}
+
 /// Validate that we aren't trying to install extras or groups that
 /// are declared as conflicting.
 pub(crate) fn detect_conflicts(
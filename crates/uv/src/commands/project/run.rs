@@ -30,7 +30,7 @@ use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads,
     PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
@@ -46,7 +46,8 @@ use uv_settings::{
 use uv_shell::WindowsRunnable;
 use uv_static::EnvVars;
 use uv_types::SourceTreeEditablePolicy;
-use uv_warnings::warn_user;
+use uv_virtualenv::ActivationEnvironment;
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceErrorKind};
 
 use crate::base_client_builder;
@@ -70,11 +71,12 @@ use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironm
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::task::TaskPlan;
 use crate::commands::project::{
     EnvironmentSpecification, LinkErrorReporting, PreferenceLocation, ProjectEnvironment,
     ProjectError, ScriptEnvironment, ScriptInterpreter, UniversalState, WorkspacePython,
-    default_dependency_groups, script_extra_build_requires, script_specification,
-    update_environment, validate_project_requires_python,
+    default_dependency_groups, project_environment_variables, script_extra_build_requires,
+    script_specification, update_environment, validate_project_requires_python,
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, diagnostics, project, read_env_files};
@@ -89,7 +91,7 @@ use crate::settings::{
 pub(crate) async fn run(
     project_dir: &Path,
     script: Option<Pep723Item>,
-    command: Option<RunCommand>,
+    mut command: Option<RunCommand>,
     requirements: Vec<RequirementsSource>,
     show_resolution: bool,
     lock_check: LockCheck,
@@ -118,6 +120,8 @@ pub(crate) async fn run(
     workspace_cache: &WorkspaceCache,
     printer: Printer,
     env_file: EnvFile,
+    print_env: bool,
+    list: bool,
     preview: Preview,
     max_recursion_depth: u32,
     malware_settings: MalwareCheckSettings,
@@ -501,6 +505,13 @@ pub(crate) async fn run(
         None
     };
 
+    // The environment declared by the project in `[tool.uv.environment]`, if any.
+    let mut project_environment = ActivationEnvironment::default();
+
+    // The tasks declared by the project in `[tool.uv.scripts]`, and the requested task, if any.
+    let mut project_tasks = Vec::new();
+    let mut task_plan: Option<(TaskPlan, PathBuf)> = None;
+
     // Discover and sync the base environment.
     let temp_dir;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
@@ -537,6 +548,10 @@ pub(crate) async fn run(
             );
         }
 
+        if list {
+            bail!("`--list` is not supported for Python scripts with inline metadata");
+        }
+
         script_interpreter
     } else {
         let project = if let Some(package) = package.as_ref() {
@@ -624,6 +639,10 @@ pub(crate) async fn run(
             }
         }
 
+        if list && project.is_none() {
+            bail!("No project found; `--list` lists the tasks declared in `tool.uv.scripts`");
+        }
+
         if let Some(project) = project {
             if let Some(project_name) = project.project_name() {
                 debug!(
@@ -636,10 +655,72 @@ pub(crate) async fn run(
                     project.workspace().install_path().display()
                 );
             }
+            project_environment = project_environment_variables(project.workspace())?;
+
+            if let Some(scripts) = project.pyproject_toml().tool_uv_scripts() {
+                if !preview.is_enabled(PreviewFeature::ProjectTasks) {
+                    warn_user_once!(
+                        "The `tool.uv.scripts` setting is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                        PreviewFeature::ProjectTasks
+                    );
+                }
+                project_tasks = scripts
+                    .iter()
+                    .map(|(name, script)| {
+                        let description = script
+                            .help()
+                            .map(ToString::to_string)
+                            .or_else(|| script.command().map(ToString::to_string))
+                            .unwrap_or_else(|| {
+                                format!("Run {}", script.depends_on().iter().join(", "))
+                            });
+                        (name.clone(), description)
+                    })
+                    .collect();
+            }
+
+            // If requested, list the tasks instead of running a command.
+            if list {
+                let width = project_tasks
+                    .iter()
+                    .map(|(name, _)| name.len())
+                    .max()
+                    .unwrap_or_default();
+                for (name, description) in &project_tasks {
+                    writeln!(
+                        printer.stdout(),
+                        "{}  {description}",
+                        format!("{name:width$}").cyan()
+                    )?;
+                }
+                return Ok(ExitStatus::Success);
+            }
+
+            // Tasks take precedence over executables of the same name.
+            if let Some(RunCommand::External(name, args)) = &command {
+                if let Some(plan) = name
+                    .to_str()
+                    .map(|name| TaskPlan::resolve(project.pyproject_toml(), name, args))
+                    .transpose()?
+                    .flatten()
+                {
+                    debug!(
+                        "Resolved task `{}` to: {}",
+                        name.to_string_lossy(),
+                        plan.tasks.iter().map(|task| &task.name).join(", ")
+                    );
+                    task_plan = Some((plan, project.root().to_path_buf()));
+                }
+            }
+
             // Determine the groups and extras to include.
             let default_groups = default_dependency_groups(project.pyproject_toml())?;
             let default_extras = DefaultExtras::default();
-            let groups = groups.with_defaults(default_groups);
+            let groups = match &task_plan {
+                Some((plan, _)) => groups.with_groups(plan.groups.iter().cloned()),
+                None => groups.clone(),
+            }
+            .with_defaults(default_groups);
             let extras = extras.with_defaults(default_extras);
 
             let venv = if isolated {
@@ -1203,6 +1284,144 @@ pub(crate) async fn run(
         .or(requirements_env.as_ref())
         .map_or_else(|| &base_interpreter, |env| env.interpreter());
 
+    // Variables declared by the project don't override the calling environment, nor the variables
+    // read from `--env-file`.
+    let project_variables = project_environment
+        .variables
+        .into_iter()
+        .filter(|(key, _)| {
+            std::env::var_os(key).is_none()
+                && !env_file_environment
+                    .iter()
+                    .any(|(existing, _)| existing == key)
+        })
+        .collect::<Vec<_>>();
+
+    // Construct the `PATH` environment variable.
+    let new_path = std::env::join_paths(
+        ephemeral_env
+            .as_ref()
+            .map(PythonEnvironment::scripts)
+            .into_iter()
+            .chain(requirements_env.as_ref().map(PythonEnvironment::scripts))
+            .chain(std::iter::once(base_interpreter.scripts()))
+            .chain(
+                // On Windows, non-virtual Python distributions put `python.exe` in the top-level
+                // directory, rather than in the `Scripts` subdirectory.
+                cfg!(windows)
+                    .then(|| base_interpreter.sys_executable().parent())
+                    .flatten(),
+            )
+            .dedup()
+            .map(PathBuf::from)
+            .chain(project_environment.path_prepend)
+            .chain(
+                std::env::var_os(EnvVars::PATH)
+                    .as_ref()
+                    .iter()
+                    .flat_map(std::env::split_paths),
+            ),
+    )?;
+
+    // If a task was requested, determine its environment and the tasks to run before it.
+    let mut task_dir = None;
+    let mut task_variables = Vec::new();
+    let mut task_dependencies = Vec::new();
+    if let Some((plan, root)) = task_plan {
+        let mut tasks = plan.tasks;
+        let task = tasks.pop().expect("the requested task is always resolved");
+        command = task
+            .command
+            .as_deref()
+            .map(|args| RunCommand::from_task(args, &root));
+        task_variables = task.env;
+        task_dependencies = tasks;
+        task_dir = Some(root);
+    }
+
+    // If requested, print the environment instead of running the command.
+    if print_env {
+        for (key, value) in env_file_environment
+            .iter()
+            .chain(&project_variables)
+            .chain(&task_variables)
+        {
+            writeln!(printer.stdout(), "{key}={value}")?;
+        }
+        writeln!(
+            printer.stdout(),
+            "{}={}",
+            EnvVars::PATH,
+            new_path.to_string_lossy()
+        )?;
+        if interpreter.is_virtualenv() {
+            writeln!(
+                printer.stdout(),
+                "{}={}",
+                EnvVars::VIRTUAL_ENV,
+                interpreter.sys_prefix().simplified_display()
+            )?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    #[cfg(unix)]
+    if let Some(limit) = run_rlimit_nofile {
+        uv_unix::set_open_file_limit(limit).with_context(|| {
+            format!(
+                "Failed to apply `{}` value `{limit}`",
+                EnvVars::UV_RUN_RLIMIT_NOFILE
+            )
+        })?;
+    }
+
+    // Configure the environment of a spawned command.
+    let configure = |process: &mut Command, variables: &[(String, String)]| {
+        process.envs(env_file_environment.iter().map(|(key, value)| (key, value)));
+        process.envs(project_variables.iter().map(|(key, value)| (key, value)));
+        process.envs(variables.iter().map(|(key, value)| (key, value)));
+        process.env(EnvVars::PATH, &new_path);
+
+        // Increment recursion depth counter.
+        process.env(
+            EnvVars::UV_RUN_RECURSION_DEPTH,
+            (recursion_depth + 1).to_string(),
+        );
+
+        // Ensure `VIRTUAL_ENV` is set.
+        if interpreter.is_virtualenv() {
+            process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+        }
+
+        // Run tasks from the project root.
+        if let Some(task_dir) = &task_dir {
+            process.current_dir(task_dir);
+        }
+    };
+
+    // Run the tasks that the requested task depends on, stopping at the first failure.
+    for task in task_dependencies {
+        let Some(args) = task.command else {
+            continue;
+        };
+        let command = RunCommand::from_task(&args, task_dir.as_deref().unwrap_or(project_dir));
+        debug!("Running task `{}`: `{command}`", task.name);
+        let mut process = command.as_command(interpreter);
+        configure(&mut process, &task.env);
+        let handle = process
+            .spawn()
+            .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))?;
+        let status = run_to_completion(handle).await?;
+        if !matches!(status, ExitStatus::Success | ExitStatus::External(0)) {
+            return Ok(status);
+        }
+    }
+
+    // A task without a command only runs the tasks it depends on.
+    if task_dir.is_some() && command.is_none() {
+        return Ok(ExitStatus::Success);
+    }
+
     // Check if any run command is given.
     // If not, print the available scripts for the current interpreter.
     let Some(command) = command else {
@@ -1249,6 +1468,19 @@ pub(crate) async fn run(
             .sorted()
             .collect_vec();
 
+        if !project_tasks.is_empty() {
+            writeln!(
+                printer.stdout(),
+                "The following tasks are defined by the project:\n"
+            )?;
+            for (name, _) in &project_tasks {
+                writeln!(printer.stdout(), "- {name}")?;
+            }
+            if !commands.is_empty() {
+                writeln!(printer.stdout())?;
+            }
+        }
+
         if !commands.is_empty() {
             writeln!(
                 printer.stdout(),
@@ -1265,54 +1497,7 @@ pub(crate) async fn run(
 
     debug!("Running `{command}`");
     let mut process = command.as_command(interpreter);
-    process.envs(env_file_environment);
-
-    // Construct the `PATH` environment variable.
-    let new_path = std::env::join_paths(
-        ephemeral_env
-            .as_ref()
-            .map(PythonEnvironment::scripts)
-            .into_iter()
-            .chain(requirements_env.as_ref().map(PythonEnvironment::scripts))
-            .chain(std::iter::once(base_interpreter.scripts()))
-            .chain(
-                // On Windows, non-virtual Python distributions put `python.exe` in the top-level
-                // directory, rather than in the `Scripts` subdirectory.
-                cfg!(windows)
-                    .then(|| base_interpreter.sys_executable().parent())
-                    .flatten(),
-            )
-            .dedup()
-            .map(PathBuf::from)
-            .chain(
-                std::env::var_os(EnvVars::PATH)
-                    .as_ref()
-                    .iter()
-                    .flat_map(std::env::split_paths),
-            ),
-    )?;
-    process.env(EnvVars::PATH, new_path);
-
-    // Increment recursion depth counter.
-    process.env(
-        EnvVars::UV_RUN_RECURSION_DEPTH,
-        (recursion_depth + 1).to_string(),
-    );
-
-    // Ensure `VIRTUAL_ENV` is set.
-    if interpreter.is_virtualenv() {
-        process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
-    }
-
-    #[cfg(unix)]
-    if let Some(limit) = run_rlimit_nofile {
-        uv_unix::set_open_file_limit(limit).with_context(|| {
-            format!(
-                "Failed to apply `{}` value `{limit}`",
-                EnvVars::UV_RUN_RLIMIT_NOFILE
-            )
-        })?;
-    }
+    configure(&mut process, &task_variables);
 
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
@@ -1652,6 +1837,32 @@ impl ParsedRunCommand {
 }
 
 impl RunCommand {
+    /// Determine the [`RunCommand`] for a task declared in `tool.uv.scripts`, which runs from the
+    /// given project root.
+    fn from_task(args: &[OsString], root: &Path) -> Self {
+        let (target, args) = args.split_first().expect("task commands are non-empty");
+        let target_path = PathBuf::from(target);
+        let is_file = root.join(&target_path).is_file();
+
+        if target.eq_ignore_ascii_case("python") {
+            Self::Python(args.to_vec())
+        } else if target_path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("py") || ext.eq_ignore_ascii_case("pyc"))
+            && is_file
+        {
+            Self::PythonScript(target_path, args.to_vec())
+        } else if target_path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("pyw"))
+            && is_file
+        {
+            Self::PythonGuiScript(target_path, args.to_vec())
+        } else {
+            Self::External(target.clone(), args.to_vec())
+        }
+    }
+
     /// Read any inline PEP 723 metadata associated with this command target.
     async fn read_pep723_item(&self) -> Result<Option<Pep723Item>, Pep723Error> {
         match self {
@@ -47,7 +47,7 @@ use crate::commands::editable::apply_editable_mode;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::{ChangedDist, Changelog, Modifications};
 use crate::commands::pip::resolution_markers;
-use crate::commands::pip::{operations, resolution_tags};
+use crate::commands::pip::{operations, provenance, resolution_tags};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
@@ -675,6 +675,7 @@ pub(crate) async fn do_sync<'a>(
         link_mode,
         compile_bytecode,
         reinstall,
+        require_provenance,
         build_options,
         sources,
     } = settings;
@@ -807,6 +808,7 @@ pub(crate) async fn do_sync<'a>(
 
     // Extract the hashes from the lockfile.
     let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+    let hasher = provenance::pin_hashes(&resolution, require_provenance, hasher, preview)?;
 
     // Populate credentials from the target.
     store_credentials_from_target(target, &client_builder)?;
@@ -931,6 +933,7 @@ pub(crate) async fn do_sync<'a>(
         .execute(
             &resolution,
             modifications,
+            require_provenance,
             build_options,
             link_mode,
             bytecode_compilation,
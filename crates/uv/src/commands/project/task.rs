@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+use rustc_hash::FxHashSet;
+use thiserror::Error;
+
+use uv_normalize::GroupName;
+use uv_workspace::pyproject::{PyProjectToml, ToolUvScript};
+
+#[derive(Debug, Error)]
+pub(crate) enum TaskError {
+    #[error("Task `{task}` depends on `{dependency}`, which is not defined in `tool.uv.scripts`")]
+    UnknownDependency { task: String, dependency: String },
+
+    #[error("Task `{0}` depends on itself: {1}")]
+    Cycle(String, String),
+
+    #[error("Failed to parse the command for task `{0}` (unbalanced quotes)")]
+    InvalidCommand(String),
+
+    #[error("Task `{0}` has an empty command")]
+    EmptyCommand(String),
+
+    #[error("Task `{0}` doesn't define a command, so it can't be run with additional arguments")]
+    UnexpectedArguments(String),
+}
+
+/// A task from `tool.uv.scripts`, ready to run.
+#[derive(Debug)]
+pub(crate) struct ResolvedTask {
+    /// The name of the task.
+    pub(crate) name: String,
+    /// The command to run, if any.
+    pub(crate) command: Option<Vec<OsString>>,
+    /// The environment variables to set for the command.
+    pub(crate) env: Vec<(String, String)>,
+}
+
+/// The tasks to run for a `uv run <task>` invocation.
+#[derive(Debug)]
+pub(crate) struct TaskPlan {
+    /// The tasks to run, in order, ending with the requested task.
+    pub(crate) tasks: Vec<ResolvedTask>,
+    /// The dependency groups required by any of the tasks.
+    pub(crate) groups: Vec<GroupName>,
+}
+
+impl TaskPlan {
+    /// Resolve the task with the given name in the `pyproject.toml`, along with the tasks it
+    /// depends on.
+    ///
+    /// The given arguments are appended to the command of the requested task. Returns `None` if
+    /// the project doesn't declare a task with the given name.
+    pub(crate) fn resolve(
+        pyproject_toml: &PyProjectToml,
+        name: &str,
+        args: &[OsString],
+    ) -> Result<Option<Self>, TaskError> {
+        let Some(scripts) = pyproject_toml.tool_uv_scripts() else {
+            return Ok(None);
+        };
+        if !scripts.contains_key(name) {
+            return Ok(None);
+        }
+
+        let mut plan = Self {
+            tasks: Vec::new(),
+            groups: Vec::new(),
+        };
+        let mut seen = FxHashSet::default();
+        let mut stack = Vec::new();
+        plan.visit(scripts, name, &mut seen, &mut stack)?;
+
+        // Forward any additional arguments to the requested task.
+        if !args.is_empty() {
+            let task = plan
+                .tasks
+                .last_mut()
+                .expect("the requested task is always resolved last");
+            let Some(command) = task.command.as_mut() else {
+                return Err(TaskError::UnexpectedArguments(task.name.clone()));
+            };
+            command.extend(args.iter().cloned());
+        }
+
+        Ok(Some(plan))
+    }
+
+    /// Add the given task to the plan, after the tasks it depends on.
+    fn visit<'a>(
+        &mut self,
+        scripts: &'a BTreeMap<String, ToolUvScript>,
+        name: &'a str,
+        seen: &mut FxHashSet<&'a str>,
+        stack: &mut Vec<&'a str>,
+    ) -> Result<(), TaskError> {
+        if stack.contains(&name) {
+            stack.push(name);
+            return Err(TaskError::Cycle(
+                stack[0].to_string(),
+                stack
+                    .iter()
+                    .map(|task| format!("`{task}`"))
+                    .collect::<Vec<_>>()
+                    .join(" -> "),
+            ));
+        }
+        if !seen.insert(name) {
+            return Ok(());
+        }
+
+        let script = &scripts[name];
+
+        stack.push(name);
+        for dependency in script.depends_on() {
+            if !scripts.contains_key(dependency) {
+                return Err(TaskError::UnknownDependency {
+                    task: name.to_string(),
+                    dependency: dependency.clone(),
+                });
+            }
+            self.visit(scripts, dependency, seen, stack)?;
+        }
+        stack.pop();
+
+        let command = script
+            .command()
+            .map(|command| {
+                let args = command
+                    .to_args()
+                    .ok_or_else(|| TaskError::InvalidCommand(name.to_string()))?;
+                if args.is_empty() {
+                    return Err(TaskError::EmptyCommand(name.to_string()));
+                }
+                Ok(args.into_iter().map(OsString::from).collect())
+            })
+            .transpose()?;
+
+        for group in script.groups() {
+            if !self.groups.contains(group) {
+                self.groups.push(group.clone());
+            }
+        }
+
+        self.tasks.push(ResolvedTask {
+            name: name.to_string(),
+            command,
+            env: script
+                .env()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+    use std::path::Path;
+
+    use uv_workspace::pyproject::PyProjectToml;
+
+    use super::TaskPlan;
+
+    fn pyproject(contents: &str) -> PyProjectToml {
+        PyProjectToml::from_string(contents.to_string(), Path::new("pyproject.toml")).unwrap()
+    }
+
+    #[test]
+    fn resolve_dependencies() {
+        let pyproject = pyproject(
+            r#"
+            [tool.uv.scripts]
+            lint = "ruff check 'src dir'"
+            typecheck = { cmd = ["mypy", "."], groups = ["typing"] }
+            check = { depends-on = ["lint", "typecheck"] }
+            test = { cmd = "pytest", env = { CI = "1" }, groups = ["test", "typing"], depends-on = ["check", "lint"] }
+            "#,
+        );
+
+        let plan = TaskPlan::resolve(&pyproject, "test", &[OsString::from("-x")])
+            .unwrap()
+            .unwrap();
+        let tasks = plan
+            .tasks
+            .iter()
+            .map(|task| (task.name.as_str(), task.command.clone(), task.env.clone()))
+            .collect::<Vec<_>>();
+        insta::assert_debug_snapshot!(tasks, @r#"
+        [
+            (
+                "lint",
+                Some(
+                    [
+                        "ruff",
+                        "check",
+                        "src dir",
+                    ],
+                ),
+                [],
+            ),
+            (
+                "typecheck",
+                Some(
+                    [
+                        "mypy",
+                        ".",
+                    ],
+                ),
+                [],
+            ),
+            (
+                "check",
+                None,
+                [],
+            ),
+            (
+                "test",
+                Some(
+                    [
+                        "pytest",
+                        "-x",
+                    ],
+                ),
+                [
+                    (
+                        "CI",
+                        "1",
+                    ),
+                ],
+            ),
+        ]
+        "#);
+        insta::assert_debug_snapshot!(plan.groups, @r#"
+        [
+            GroupName(
+                "typing",
+            ),
+            GroupName(
+                "test",
+            ),
+        ]
+        "#);
+
+        assert!(
+            TaskPlan::resolve(&pyproject, "pytest", &[])
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn resolve_errors() {
+        let pyproject = pyproject(
+            r#"
+            [tool.uv.scripts]
+            a = { cmd = "echo a", depends-on = ["b"] }
+            b = { cmd = "echo b", depends-on = ["a"] }
+            c = { cmd = "echo c", depends-on = ["d"] }
+            e = "echo 'e"
+            f = { depends-on = [] }
+            "#,
+        );
+
+        let err = TaskPlan::resolve(&pyproject, "a", &[]).unwrap_err();
+        insta::assert_snapshot!(err, @"Task `a` depends on itself: `a` -> `b` -> `a`");
+        let err = TaskPlan::resolve(&pyproject, "c", &[]).unwrap_err();
+        insta::assert_snapshot!(err, @"Task `c` depends on `d`, which is not defined in `tool.uv.scripts`");
+        let err = TaskPlan::resolve(&pyproject, "e", &[]).unwrap_err();
+        insta::assert_snapshot!(err, @"Failed to parse the command for task `e` (unbalanced quotes)");
+        let err = TaskPlan::resolve(&pyproject, "f", &[OsString::from("-x")]).unwrap_err();
+        insta::assert_snapshot!(err, @"Task `f` doesn't define a command, so it can't be run with additional arguments");
+    }
+}
@@ -142,6 +142,27 @@ pub(crate) async fn project_version(
         DependencyTarget::PyProjectToml,
     )?;
 
+    // The uv build backend can derive a dynamic version from Git tags, which we can read but not
+    // set.
+    if toml.has_dynamic_version() {
+        if let Some(version) = uv_build_backend::dynamic_version(project.root())? {
+            if !is_read_only {
+                return Err(anyhow!(
+                    "The version of `{name}` is derived from Git tags (`tool.uv.build-backend.version-source`), create a Git tag to set it instead: {}",
+                    pyproject_path.user_display()
+                ));
+            }
+            print_version(
+                ProjectVersionInfo::new(Some(&name), &version),
+                None,
+                short,
+                output_format,
+                printer,
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+    }
+
     let old_version = toml.version().map_err(|err| match err {
         Error::MalformedWorkspace => {
             if toml.has_dynamic_version() {
@@ -1,13 +1,17 @@
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, anyhow, bail};
 use console::Term;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 use tokio::sync::Semaphore;
 use tracing::{debug, info, trace};
 use uv_auth::{Credentials, PyxTokenStore};
 use uv_cache::Cache;
+use uv_cli::PublishFormat;
 use uv_client::{
     AuthIntegration, BaseClient, BaseClientBuilder, RedirectPolicy, RegistryClientBuilder,
 };
@@ -15,10 +19,12 @@ use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{IndexCapabilities, IndexLocations, IndexUrl};
 use uv_errors::{ErrorOptions, Hints, write_error_chain_with_options};
+use uv_fs::Simplified;
 use uv_preview::{Preview, PreviewFeature};
+use uv_provenance::{SigningConfig, SigstoreSigner};
 use uv_publish::{
-    CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult, check_trusted_publishing,
-    group_files_for_publishing, upload, upload_two_phase,
+    CheckUrlClient, FormMetadata, PublishError, Severity, TrustedPublishResult, UploadDistribution,
+    check_trusted_publishing, group_files_for_publishing, upload, upload_two_phase,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_settings::EnvironmentOptions;
@@ -28,6 +34,7 @@ use crate::commands::reporters::PublishReporter;
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn publish(
     paths: Vec<String>,
     publish_url: DisplaySafeUrl,
@@ -38,11 +45,17 @@ pub(crate) async fn publish(
     username: Option<String>,
     password: Option<String>,
     check_url: Option<IndexUrl>,
-    index: Option<String>,
+    index: Vec<String>,
+    to_directory: Option<PathBuf>,
     index_locations: IndexLocations,
     dry_run: bool,
     no_attestations: bool,
+    attest: bool,
+    fulcio_url: Option<DisplaySafeUrl>,
+    rekor_url: Option<DisplaySafeUrl>,
+    check: bool,
     direct: bool,
+    output_format: PublishFormat,
     preview: Preview,
     cache: &Cache,
     printer: Printer,
@@ -59,49 +72,99 @@ pub(crate) async fn publish(
         );
     }
 
-    let token_store = PyxTokenStore::from_settings()?;
+    if attest && !preview.is_enabled(PreviewFeature::PublishAttestations) {
+        warn_user_once!(
+            "The `--attest` option is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::PublishAttestations
+        );
+    }
 
-    let (publish_url, check_url) = if let Some(index_name) = index {
-        // If the user provided an index by name, look it up.
-        debug!("Publishing with index {index_name}");
-        let index = index_locations
-            .simple_indexes()
-            .find(|index| {
-                index
-                    .name
-                    .as_ref()
-                    .is_some_and(|name| name.as_ref() == index_name)
-            })
-            .with_context(|| {
-                let mut index_names: Vec<String> = index_locations
-                    .simple_indexes()
-                    .filter_map(|index| index.name.as_ref())
-                    .map(ToString::to_string)
-                    .collect();
-                index_names.sort();
-                if index_names.is_empty() {
-                    format!("No indexes were found, can't use index: `{index_name}`")
-                } else {
-                    let index_names = index_names.join("`, `");
-                    format!("Index not found: `{index_name}`. Found indexes: `{index_names}`")
-                }
-            })?;
-        let publish_url = index
-            .publish_url
-            .clone()
-            .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
+    if check && !preview.is_enabled(PreviewFeature::DistributionChecks) {
+        warn_user_once!(
+            "The `--check` option is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::DistributionChecks
+        );
+    }
 
-        // pyx has the same behavior as PyPI where uploads of identical
-        // files + contents are idempotent, so we don't need to pre-check.
-        if token_store.is_known_url(&publish_url) {
-            (publish_url, None)
-        } else {
-            let check_url = index.url.clone();
-            (publish_url, Some(check_url))
-        }
+    if index.len() > 1 && !preview.is_enabled(PreviewFeature::MultiIndexPublish) {
+        warn_user_once!(
+            "Publishing to multiple indexes is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::MultiIndexPublish
+        );
+    }
+
+    if matches!(output_format, PublishFormat::Json)
+        && !preview.is_enabled(PreviewFeature::JsonOutput)
+    {
+        warn_user_once!(
+            "The `--output-format json` option is experimental and the schema may change without \
+            warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
+    let token_store = PyxTokenStore::from_settings()?;
+
+    let targets = if let Some(directory) = to_directory {
+        let directory = std::path::absolute(directory)?;
+        let publish_url = DisplaySafeUrl::from_file_path(&directory)
+            .map_err(|()| anyhow!("Invalid directory: `{}`", directory.user_display()))?;
+        vec![PublishTarget {
+            index: None,
+            publish_url,
+            check_url: None,
+        }]
+    } else if index.is_empty() {
+        vec![PublishTarget {
+            index: None,
+            publish_url,
+            check_url,
+        }]
     } else {
-        (publish_url, check_url)
+        index
+            .into_iter()
+            .unique()
+            .map(|index_name| PublishTarget::from_index(index_name, &index_locations, &token_store))
+            .collect::<Result<Vec<_>>>()?
     };
+    if targets.iter().any(|target| target.directory().is_some())
+        && !preview.is_enabled(PreviewFeature::DirectoryPublish)
+    {
+        warn_user_once!(
+            "Publishing to a directory is experimental and may change without warning. \
+            Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::DirectoryPublish
+        );
+    }
+
+    // Attestations are signed for an upload to an index; a directory can't serve them.
+    if attest && targets.iter().any(|target| target.directory().is_some()) {
+        bail!("`--attest` can't be used when publishing to a directory");
+    }
+
+    // Credentials from the command line aren't scoped to an index, so they'd be sent to each of
+    // them.
+    if (username.is_some() || password.is_some())
+        && targets
+            .iter()
+            .filter(|target| target.directory().is_none())
+            .count()
+            > 1
+    {
+        bail!(
+            "Credentials can't be provided with `--username`, `--password`, or `--token` when \
+            publishing to multiple indexes, use per-index credentials instead (e.g., \
+            `UV_INDEX_{{name}}_USERNAME` and `UV_INDEX_{{name}}_PASSWORD`)"
+        );
+    }
+
+    let destination = targets
+        .iter()
+        .map(|target| target.publish_url.to_string())
+        .join(", ");
 
     let mut groups = group_files_for_publishing(paths, no_attestations)?;
     // Sort by filename first so the stable type sort preserves filename order within each type.
@@ -112,20 +175,43 @@ pub(crate) async fn publish(
         0 => bail!("No files found to publish"),
         1 => {
             if dry_run {
-                writeln!(printer.stderr(), "Checking 1 file against {publish_url}")?;
+                writeln!(printer.stderr(), "Checking 1 file against {destination}")?;
             } else {
-                writeln!(printer.stderr(), "Publishing 1 file to {publish_url}")?;
+                writeln!(printer.stderr(), "Publishing 1 file to {destination}")?;
             }
         }
         n => {
             if dry_run {
-                writeln!(printer.stderr(), "Checking {n} files against {publish_url}")?;
+                writeln!(printer.stderr(), "Checking {n} files against {destination}")?;
             } else {
-                writeln!(printer.stderr(), "Publishing {n} files to {publish_url}")?;
+                writeln!(printer.stderr(), "Publishing {n} files to {destination}")?;
             }
         }
     }
 
+    // Check all files before uploading any of them, so we don't publish a partial release.
+    if check {
+        let failed = check_distributions(
+            groups.iter().map(|group| {
+                (
+                    group.file.as_path(),
+                    group.raw_filename.as_str(),
+                    &group.filename,
+                )
+            }),
+            printer,
+        )
+        .await?;
+        if failed > 0 {
+            let files = if failed == 1 { "file" } else { "files" };
+            writeln!(
+                printer.stderr(),
+                "Found errors in {failed} {files}, not publishing"
+            )?;
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
     // * For the uploads themselves, we roll our own retries due to
     //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
     //   the default retries. We set the retries to 0 here and manually construct the retry policy
@@ -166,75 +252,102 @@ pub(crate) async fn publish(
         .client_name("s3")
         .build()?;
 
-    let retry_policy = client_builder.retry_policy();
     // We're only checking a single URL and one at a time, so 1 permit is sufficient
     let download_concurrency = Arc::new(Semaphore::new(1));
 
-    // Load credentials.
-    let (publish_url, credentials) = gather_credentials(
-        publish_url,
-        username,
-        password,
-        trusted_publishing,
-        keyring_provider,
-        &token_store,
-        &oidc_client,
-        check_url.as_ref(),
-        Prompt::Enabled,
-        printer,
-    )
-    .await?;
+    // Load the credentials for all registries before uploading anything.
+    let mut credentials = Vec::with_capacity(targets.len());
+    let mut targets = targets;
+    for target in &mut targets {
+        if target.directory().is_some() {
+            credentials.push(None);
+            continue;
+        }
+        let (publish_url, target_credentials) = gather_credentials(
+            target.publish_url.clone(),
+            username.clone(),
+            password.clone(),
+            trusted_publishing,
+            keyring_provider,
+            &token_store,
+            &oidc_client,
+            target.check_url.as_ref(),
+            Prompt::Enabled,
+            printer,
+        )
+        .await?;
+        target.publish_url = publish_url;
+        credentials.push(Some(target_credentials));
+    }
 
-    // Initialize the registry client.
-    let check_url_client = if let Some(index_url) = &check_url {
-        let registry_client_builder =
-            RegistryClientBuilder::new(client_builder.clone(), cache.clone())
-                .index_locations(index_locations)
-                .keyring(keyring_provider);
-        Some(CheckUrlClient {
-            index_url: index_url.clone(),
-            registry_client_builder,
-            client: &upload_client,
-            index_capabilities: IndexCapabilities::default(),
-            cache,
-        })
+    // Obtain a certificate for signing attestations. Signatures are recorded in a public
+    // transparency log, so we don't sign anything during a dry run.
+    let signer = if attest && !dry_run {
+        let mut signing_config = SigningConfig::public_good();
+        if let Some(fulcio_url) = fulcio_url {
+            signing_config.fulcio_url = fulcio_url;
+        }
+        if let Some(rekor_url) = rekor_url {
+            signing_config.rekor_url = rekor_url;
+        }
+        Some(uv_publish::sigstore_signer(&oidc_client, &signing_config).await?)
     } else {
         None
     };
 
+    // Initialize the registry clients.
+    let check_url_clients: Vec<_> = targets
+        .iter()
+        .map(|target| {
+            let index_url = target.check_url.as_ref()?;
+            let registry_client_builder =
+                RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+                    .index_locations(index_locations.clone())
+                    .keyring(keyring_provider);
+            Some(CheckUrlClient {
+                index_url: index_url.clone(),
+                registry_client_builder,
+                client: &upload_client,
+                index_capabilities: IndexCapabilities::default(),
+                cache,
+            })
+        })
+        .collect();
+
+    let mut report = PublishReport::new(&targets, &groups, dry_run);
     let mut error_count: usize = 0;
 
-    for group in groups {
-        // Check if the filename is normalized (e.g., version `2025.09.4` should be `2025.9.4`).
-        let normalized_filename = group.filename.to_string();
-        if group.raw_filename != normalized_filename {
-            warn_user_once!(
-                "`{}` has a non-normalized filename (expected `{normalized_filename}`), skipping",
-                group.raw_filename
-            );
+    // Check which files already exist on each target before uploading anything, so a conflict
+    // with one target doesn't leave the other targets with a partial release.
+    for (target_index, target) in targets.iter().enumerate() {
+        let directory = target.directory();
+        let check_url_client = check_url_clients[target_index].as_ref();
+        if directory.is_none() && check_url_client.is_none() {
             continue;
         }
+        for (group_index, group) in groups.iter().enumerate() {
+            // Files with non-normalized filenames are skipped below.
+            if group.raw_filename != group.filename.to_string() {
+                continue;
+            }
 
-        let reporter = Arc::new(PublishReporter::single(printer));
-
-        if let Some(check_url_client) = &check_url_client {
-            match uv_publish::check_url(
-                check_url_client,
-                &group.file,
-                &group.filename,
-                &download_concurrency,
-                reporter.clone(),
-            )
-            .await
-            {
-                Ok(true) => {
-                    writeln!(
-                        printer.stderr(),
-                        "File {} already exists, skipping",
-                        group.filename
-                    )?;
-                    continue;
-                }
+            let exists = if let Some(directory) = &directory {
+                uv_publish::check_directory(directory, group).await
+            } else if let Some(check_url_client) = check_url_client {
+                let reporter = Arc::new(PublishReporter::single(printer));
+                uv_publish::check_url(
+                    check_url_client,
+                    &group.file,
+                    &group.filename,
+                    &download_concurrency,
+                    reporter,
+                )
+                .await
+            } else {
+                continue;
+            };
+            match exists {
+                Ok(true) => report.set(target_index, group_index, FileStatus::Exists),
                 Ok(false) => {}
                 Err(err) => {
                     if dry_run {
@@ -243,179 +356,587 @@ pub(crate) async fn publish(
                             Hints::none(),
                             ErrorOptions::default().with_stream(printer.stderr()),
                         )?;
+                        report.set(target_index, group_index, FileStatus::Failed);
                         error_count += 1;
                         continue;
                     }
+                    report.set(target_index, group_index, FileStatus::Failed);
+                    report.write(output_format, printer)?;
                     return Err(err.into());
                 }
             }
         }
+    }
 
-        let bytes = human_readable_bytes(fs_err::metadata(&group.file)?.len());
-        if dry_run {
-            writeln!(
-                printer.stderr(),
-                "{} {} {}",
-                "Checking".bold().cyan(),
-                group.filename,
-                format!("({bytes:.1})").dimmed()
-            )?;
-        } else {
+    for (target_index, target) in targets.iter().enumerate() {
+        if targets.len() > 1 {
             writeln!(
                 printer.stderr(),
-                "{} {} {}",
-                "Hashing".bold().green(),
-                group.filename,
-                format!("({bytes:.1})").dimmed()
+                "{}",
+                format!("Publishing to {}", target.publish_url).bold()
             )?;
         }
 
-        // Collect the metadata for the file.
-        let form_metadata =
-            match FormMetadata::read_from_file(&group.file, &group.filename, reporter.clone())
+        for (group_index, group) in groups.iter_mut().enumerate() {
+            // Check if the filename is normalized (e.g., version `2025.09.4` should be `2025.9.4`).
+            let normalized_filename = group.filename.to_string();
+            if group.raw_filename != normalized_filename {
+                warn_user_once!(
+                    "`{}` has a non-normalized filename (expected `{normalized_filename}`), skipping",
+                    group.raw_filename
+                );
+                report.set(target_index, group_index, FileStatus::Skipped);
+                continue;
+            }
+
+            match report.status(target_index, group_index) {
+                FileStatus::Exists => {
+                    writeln!(
+                        printer.stderr(),
+                        "File {} already exists, skipping",
+                        group.filename
+                    )?;
+                    continue;
+                }
+                FileStatus::Failed => continue,
+                _ => {}
+            }
+
+            let status = if let Some(directory) = target.directory() {
+                publish_file_to_directory(group, &directory, dry_run, printer).await
+            } else {
+                let credentials = credentials[target_index]
+                    .as_ref()
+                    .expect("Credentials are loaded for all registries");
+                publish_file(
+                    group,
+                    &target.publish_url,
+                    credentials,
+                    check_url_clients[target_index].as_ref(),
+                    &upload_client,
+                    &s3_client,
+                    client_builder,
+                    &token_store,
+                    signer.as_ref(),
+                    &download_concurrency,
+                    dry_run,
+                    direct,
+                    printer,
+                )
                 .await
-                .map_err(|err| PublishError::PublishPrepare(group.file.clone(), Box::new(err)))
-            {
-                Ok(metadata) => metadata,
-                Err(err) => {
-                    if dry_run {
-                        write_error_chain_with_options(
-                            &err,
-                            Hints::none(),
-                            ErrorOptions::default().with_stream(printer.stderr()),
-                        )?;
+            };
+            match status {
+                Ok(status) => {
+                    if status == FileStatus::Failed {
                         error_count += 1;
-                        continue;
                     }
+                    report.set(target_index, group_index, status);
+                }
+                Err(err) => {
+                    report.set(target_index, group_index, FileStatus::Failed);
+                    report.write(output_format, printer)?;
+                    return Err(err);
+                }
+            }
+        }
+
+        // Regenerate the pages of the published projects once all files are in place.
+        if let Some(directory) = target.directory() {
+            if !dry_run {
+                let projects = groups.iter().map(|group| group.filename.name()).unique();
+                if let Err(err) = uv_publish::update_directory_index(&directory, projects).await {
+                    report.write(output_format, printer)?;
                     return Err(err.into());
                 }
-            };
+            }
+        }
+    }
 
+    report.write(output_format, printer)?;
+
+    if error_count > 0 {
+        let failed = if error_count == 1 { "file" } else { "files" };
+        writeln!(printer.stderr(), "Found issues with {error_count} {failed}")?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Publish a single file to a target.
+///
+/// During a dry run, errors are shown and reported as [`FileStatus::Failed`], so that all files
+/// are checked. Otherwise, errors are returned.
+async fn publish_file(
+    group: &mut UploadDistribution,
+    publish_url: &DisplaySafeUrl,
+    credentials: &Credentials,
+    check_url_client: Option<&CheckUrlClient<'_>>,
+    upload_client: &BaseClient,
+    s3_client: &BaseClient,
+    client_builder: &BaseClientBuilder<'_>,
+    token_store: &PyxTokenStore,
+    signer: Option<&SigstoreSigner>,
+    download_concurrency: &Semaphore,
+    dry_run: bool,
+    direct: bool,
+    printer: Printer,
+) -> Result<FileStatus> {
+    let retry_policy = client_builder.retry_policy();
+    let reporter = Arc::new(PublishReporter::single(printer));
+
+    let bytes = human_readable_bytes(fs_err::metadata(&group.file)?.len());
+    if dry_run {
+        writeln!(
+            printer.stderr(),
+            "{} {} {}",
+            "Checking".bold().cyan(),
+            group.filename,
+            format!("({bytes:.1})").dimmed()
+        )?;
+    } else {
         writeln!(
             printer.stderr(),
             "{} {} {}",
-            "Uploading".bold().green(),
+            "Hashing".bold().green(),
             group.filename,
             format!("({bytes:.1})").dimmed()
         )?;
+    }
 
-        let uploaded = if direct {
-            if dry_run {
-                // For dry run, call validate since we won't call reserve.
-                match uv_publish::validate(
-                    &group.file,
-                    &form_metadata,
-                    &group.raw_filename,
-                    &publish_url,
-                    &token_store,
-                    &upload_client,
-                    &credentials,
-                )
-                .await
-                {
-                    Ok(should_upload) => {
-                        if !should_upload {
-                            writeln!(
-                                printer.stderr(),
-                                "{}",
-                                "File already exists, skipping".dimmed()
-                            )?;
-                        }
-                    }
-                    Err(err) => {
-                        let err: anyhow::Error = err.into();
-                        write_error_chain_with_options(
-                            err.as_ref(),
-                            Hints::none(),
-                            ErrorOptions::default().with_stream(printer.stderr()),
-                        )?;
-                        error_count += 1;
-                    }
+    // Collect the metadata for the file.
+    let form_metadata =
+        match FormMetadata::read_from_file(&group.file, &group.filename, reporter.clone())
+            .await
+            .map_err(|err| PublishError::PublishPrepare(group.file.clone(), Box::new(err)))
+        {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                if dry_run {
+                    write_error_chain_with_options(
+                        &err,
+                        Hints::none(),
+                        ErrorOptions::default().with_stream(printer.stderr()),
+                    )?;
+                    return Ok(FileStatus::Failed);
                 }
-                continue;
+                return Err(err.into());
             }
+        };
 
-            debug!("Using two-phase upload (direct mode)");
-            upload_two_phase(
-                &group,
-                &form_metadata,
-                &publish_url,
-                &upload_client,
-                &s3_client,
-                retry_policy,
-                &credentials,
-                reporter.clone(),
-            )
-            .await?
-        } else {
-            // Run validation checks on the file, but don't upload it (if possible).
-            match uv_publish::validate(
+    if let Some(signer) = signer {
+        uv_publish::attest(group, &form_metadata, signer)
+            .await
+            .map_err(|err| PublishError::PublishPrepare(group.file.clone(), Box::new(err)))?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{} {} {}",
+        "Uploading".bold().green(),
+        group.filename,
+        format!("({bytes:.1})").dimmed()
+    )?;
+
+    let uploaded = if direct {
+        if dry_run {
+            // For dry run, call validate since we won't call reserve.
+            return match uv_publish::validate(
                 &group.file,
                 &form_metadata,
                 &group.raw_filename,
-                &publish_url,
-                &token_store,
-                &upload_client,
-                &credentials,
+                publish_url,
+                token_store,
+                upload_client,
+                credentials,
             )
             .await
             {
                 Ok(should_upload) => {
-                    if dry_run {
-                        continue;
-                    }
-
-                    // If validation indicates the file already exists, skip the upload.
-                    if !should_upload {
-                        false
+                    if should_upload {
+                        Ok(FileStatus::Checked)
                     } else {
-                        upload(
-                            &group,
-                            &form_metadata,
-                            &publish_url,
-                            &upload_client,
-                            retry_policy,
-                            &credentials,
-                            check_url_client.as_ref(),
-                            &download_concurrency,
-                            reporter.clone(),
-                        )
-                        .await? // Filename and/or URL are already attached, if applicable.
+                        writeln!(
+                            printer.stderr(),
+                            "{}",
+                            "File already exists, skipping".dimmed()
+                        )?;
+                        Ok(FileStatus::Exists)
                     }
                 }
                 Err(err) => {
-                    if dry_run {
-                        let err: anyhow::Error = err.into();
-                        write_error_chain_with_options(
-                            err.as_ref(),
-                            Hints::none(),
-                            ErrorOptions::default().with_stream(printer.stderr()),
-                        )?;
-                        error_count += 1;
-                        continue;
-                    }
-                    return Err(err.into());
+                    let err: anyhow::Error = err.into();
+                    write_error_chain_with_options(
+                        err.as_ref(),
+                        Hints::none(),
+                        ErrorOptions::default().with_stream(printer.stderr()),
+                    )?;
+                    Ok(FileStatus::Failed)
                 }
+            };
+        }
+
+        debug!("Using two-phase upload (direct mode)");
+        upload_two_phase(
+            group,
+            &form_metadata,
+            publish_url,
+            upload_client,
+            s3_client,
+            retry_policy,
+            credentials,
+            reporter.clone(),
+        )
+        .await?
+    } else {
+        // Run validation checks on the file, but don't upload it (if possible).
+        match uv_publish::validate(
+            &group.file,
+            &form_metadata,
+            &group.raw_filename,
+            publish_url,
+            token_store,
+            upload_client,
+            credentials,
+        )
+        .await
+        {
+            Ok(should_upload) => {
+                if dry_run {
+                    return Ok(if should_upload {
+                        FileStatus::Checked
+                    } else {
+                        FileStatus::Exists
+                    });
+                }
+
+                // If validation indicates the file already exists, skip the upload.
+                if !should_upload {
+                    false
+                } else {
+                    upload(
+                        group,
+                        &form_metadata,
+                        publish_url,
+                        upload_client,
+                        retry_policy,
+                        credentials,
+                        check_url_client,
+                        download_concurrency,
+                        reporter.clone(),
+                    )
+                    .await? // Filename and/or URL are already attached, if applicable.
+                }
+            }
+            Err(err) => {
+                if dry_run {
+                    let err: anyhow::Error = err.into();
+                    write_error_chain_with_options(
+                        err.as_ref(),
+                        Hints::none(),
+                        ErrorOptions::default().with_stream(printer.stderr()),
+                    )?;
+                    return Ok(FileStatus::Failed);
+                }
+                return Err(err.into());
             }
+        }
+    };
+    info!("Upload succeeded");
+
+    if uploaded {
+        Ok(FileStatus::Uploaded)
+    } else {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "File already exists, skipping".dimmed()
+        )?;
+        Ok(FileStatus::Exists)
+    }
+}
+
+/// Publish a single file to a local index directory.
+async fn publish_file_to_directory(
+    group: &UploadDistribution,
+    directory: &Path,
+    dry_run: bool,
+    printer: Printer,
+) -> Result<FileStatus> {
+    let bytes = human_readable_bytes(fs_err::metadata(&group.file)?.len());
+    if dry_run {
+        // Conflicting files were already detected when checking the directory.
+        writeln!(
+            printer.stderr(),
+            "{} {} {}",
+            "Checking".bold().cyan(),
+            group.filename,
+            format!("({bytes:.1})").dimmed()
+        )?;
+        return Ok(FileStatus::Checked);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{} {} {}",
+        "Copying".bold().green(),
+        group.filename,
+        format!("({bytes:.1})").dimmed()
+    )?;
+    if uv_publish::publish_to_directory(directory, group).await? {
+        Ok(FileStatus::Uploaded)
+    } else {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "File already exists, skipping".dimmed()
+        )?;
+        Ok(FileStatus::Exists)
+    }
+}
+
+/// A registry or local index directory to publish to.
+#[derive(Debug)]
+struct PublishTarget {
+    /// The name of the index in the configuration, if the target was selected with `--index`.
+    index: Option<String>,
+    /// The URL to upload to.
+    publish_url: DisplaySafeUrl,
+    /// The simple index URL to check for existing files.
+    check_url: Option<IndexUrl>,
+}
+
+impl PublishTarget {
+    /// Resolve a target from the name of an index in the configuration.
+    fn from_index(
+        index_name: String,
+        index_locations: &IndexLocations,
+        token_store: &PyxTokenStore,
+    ) -> Result<Self> {
+        debug!("Publishing with index {index_name}");
+        let index = index_locations
+            .simple_indexes()
+            .find(|index| {
+                index
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| name.as_ref() == index_name)
+            })
+            .with_context(|| {
+                let mut index_names: Vec<String> = index_locations
+                    .simple_indexes()
+                    .filter_map(|index| index.name.as_ref())
+                    .map(ToString::to_string)
+                    .collect();
+                index_names.sort();
+                if index_names.is_empty() {
+                    format!("No indexes were found, can't use index: `{index_name}`")
+                } else {
+                    let index_names = index_names.join("`, `");
+                    format!("Index not found: `{index_name}`. Found indexes: `{index_names}`")
+                }
+            })?;
+        let publish_url = index
+            .publish_url
+            .clone()
+            .with_context(|| format!("Index is missing a publish URL: `{index_name}`"))?;
+
+        // pyx has the same behavior as PyPI where uploads of identical
+        // files + contents are idempotent, so we don't need to pre-check. Local index directories
+        // are checked for existing files directly.
+        let check_url = if token_store.is_known_url(&publish_url) || publish_url.scheme() == "file"
+        {
+            None
+        } else {
+            Some(index.url.clone())
         };
-        info!("Upload succeeded");
 
-        if !uploaded {
-            writeln!(
-                printer.stderr(),
-                "{}",
-                "File already exists, skipping".dimmed()
-            )?;
+        Ok(Self {
+            index: Some(index_name),
+            publish_url,
+            check_url,
+        })
+    }
+
+    /// The local index directory, if publishing to a `file://` URL.
+    fn directory(&self) -> Option<PathBuf> {
+        if self.publish_url.scheme() == "file" {
+            self.publish_url.to_file_path().ok()
+        } else {
+            None
         }
     }
+}
 
-    if error_count > 0 {
-        let failed = if error_count == 1 { "file" } else { "files" };
-        writeln!(printer.stderr(), "Found issues with {error_count} {failed}")?;
-        return Ok(ExitStatus::Failure);
+/// What happened to a file on a target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum FileStatus {
+    /// The file was uploaded.
+    Uploaded,
+    /// The file already exists on the target.
+    Exists,
+    /// The file passed the checks of a dry run.
+    Checked,
+    /// The file was skipped, because its filename isn't normalized.
+    Skipped,
+    /// The file couldn't be checked or uploaded.
+    Failed,
+    /// The file wasn't uploaded, because publishing stopped at an earlier error.
+    Pending,
+}
+
+impl FileStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Uploaded => "uploaded",
+            Self::Exists => "already existed",
+            Self::Checked => "checked",
+            Self::Skipped => "skipped",
+            Self::Failed => "failed",
+            Self::Pending => "pending",
+        }
     }
+}
 
-    Ok(ExitStatus::Success)
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+enum SchemaVersion {
+    /// An unstable, experimental schema.
+    #[default]
+    Preview,
+}
+
+#[derive(Serialize, Debug, Default)]
+struct SchemaReport {
+    /// The version of the schema.
+    version: SchemaVersion,
+}
+
+/// A report of which files landed on which target, so a partial failure can be retried.
+#[derive(Debug, Serialize)]
+struct PublishReport {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// Whether this is a dry run.
+    dry_run: bool,
+    /// The targets, in the order they were published to.
+    targets: Vec<TargetReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct TargetReport {
+    /// The name of the index, if the target was selected with `--index`.
+    index: Option<String>,
+    /// The URL the files are uploaded to.
+    publish_url: String,
+    /// The URL used to check for existing files.
+    check_url: Option<String>,
+    /// The files, in the order they were published.
+    files: Vec<FileReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct FileReport {
+    /// The filename of the distribution.
+    filename: String,
+    /// What happened to the file on the target.
+    status: FileStatus,
+}
+
+impl PublishReport {
+    fn new(targets: &[PublishTarget], groups: &[UploadDistribution], dry_run: bool) -> Self {
+        Self {
+            schema: SchemaReport::default(),
+            dry_run,
+            targets: targets
+                .iter()
+                .map(|target| TargetReport {
+                    index: target.index.clone(),
+                    publish_url: target.publish_url.to_string(),
+                    check_url: target.check_url.as_ref().map(ToString::to_string),
+                    files: groups
+                        .iter()
+                        .map(|group| FileReport {
+                            filename: group.raw_filename.clone(),
+                            status: FileStatus::Pending,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn status(&self, target: usize, group: usize) -> FileStatus {
+        self.targets[target].files[group].status
+    }
+
+    fn set(&mut self, target: usize, group: usize, status: FileStatus) {
+        self.targets[target].files[group].status = status;
+    }
+
+    /// Write the report as JSON, or, when publishing to multiple targets, a summary per target.
+    fn write(&self, output_format: PublishFormat, printer: Printer) -> Result<()> {
+        match output_format {
+            PublishFormat::Json => {
+                writeln!(printer.stdout(), "{}", serde_json::to_string_pretty(self)?)?;
+            }
+            PublishFormat::Text => {
+                if self.targets.len() < 2 {
+                    return Ok(());
+                }
+                writeln!(printer.stderr(), "{}", "Summary:".bold())?;
+                for target in &self.targets {
+                    let counts = target
+                        .files
+                        .iter()
+                        .counts_by(|file| file.status)
+                        .into_iter()
+                        .sorted_by_key(|(status, _)| *status as u8)
+                        .map(|(status, count)| format!("{count} {}", status.as_str()))
+                        .join(", ");
+                    let name = target.index.as_deref().unwrap_or(&target.publish_url);
+                    writeln!(printer.stderr(), "  {}: {counts}", name.cyan())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check distributions for problems before they're published, showing the diagnostics for each.
+///
+/// Returns the number of distributions with errors.
+pub(crate) async fn check_distributions<'a>(
+    distributions: impl IntoIterator<Item = (&'a Path, &'a str, &'a DistFilename)>,
+    printer: Printer,
+) -> Result<usize> {
+    let mut failed = 0;
+    for (file, raw_filename, filename) in distributions {
+        let diagnostics = uv_publish::check_distribution(file, raw_filename, filename)
+            .await
+            .with_context(|| format!("Failed to check `{}`", file.user_display()))?;
+        for diagnostic in &diagnostics {
+            let severity = match diagnostic.severity {
+                Severity::Error => diagnostic.severity.to_string().red().bold().to_string(),
+                Severity::Warning => diagnostic.severity.to_string().yellow().bold().to_string(),
+            };
+            writeln!(
+                printer.stderr(),
+                "{}: {severity}{} {}",
+                raw_filename.bold(),
+                format!("[{}]:", diagnostic.code).bold(),
+                diagnostic.message
+            )?;
+        }
+        if diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error)
+        {
+            failed += 1;
+        }
+    }
+    Ok(failed)
 }
 
 /// Whether to allow prompting for username and password.
@@ -938,10 +938,14 @@ pub(crate) fn finalize_tool_install(
         python,
         installed_entrypoints,
         options.clone(),
-    );
+    )
+    .with_shared_environment(installed_tools.shared_environment_id(environment.root()));
     ToolLock::write(&installed_tools.tool_dir(name), lock)?;
     installed_tools.add_tool_receipt(name, tool)?;
 
+    // The tool may have moved out of a shared environment that's no longer in use.
+    installed_tools.remove_unused_shared_environments()?;
+
     warn_out_of_path(&executable_directory);
 
     Ok(())
@@ -14,8 +14,8 @@ use uv_configuration::{
 };
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ExtraBuildRequires, IndexCapabilities, NameRequirementSpecification, Requirement,
-    RequirementSource, UnresolvedRequirementSpecification,
+    ExtraBuildRequires, IndexCapabilities, Name, NameRequirementSpecification, Requirement,
+    RequirementSource, ResolvedDist, UnresolvedRequirementSpecification,
 };
 use uv_installer::{InstallationStrategy, Planner, SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
@@ -48,12 +48,14 @@ use crate::commands::tool::common::{
     ToolLock, ToolPython, finalize_tool_install, refine_interpreter, remove_entrypoints,
     tool_environment_spec,
 };
+use crate::commands::tool::shared::{prune_shared_environment, select_shared_environment};
 use crate::commands::tool::{Target, ToolRequest};
 use crate::commands::{diagnostics, reporters::PythonDownloadReporter};
 use crate::printer::Printer;
 use crate::settings::{ResolverInstallerSettings, ResolverSettings};
 
 /// Install a tool.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn install(
     package: String,
     editable: bool,
@@ -69,6 +71,7 @@ pub(crate) async fn install(
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    shared: bool,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
@@ -84,6 +87,12 @@ pub(crate) async fn install(
     preview: Preview,
 ) -> Result<ExitStatus> {
     let tool_locks = preview.is_enabled(PreviewFeature::ToolInstallLocks);
+    if shared && !preview.is_enabled(PreviewFeature::ToolSharedEnvironments) {
+        warn_user_once!(
+            "The `--shared` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::ToolSharedEnvironments
+        );
+    }
     if settings.resolver.torch_backend.is_some() {
         warn_user_once!(
             "The `--torch-backend` option is experimental and may change without warning."
@@ -507,6 +516,12 @@ pub(crate) async fn install(
     } else {
         installed_tools
             .get_environment(package_name, &cache)?
+            // Move the tool into or out of a shared environment, if requested.
+            .filter(|_| {
+                existing_tool_receipt
+                    .as_ref()
+                    .is_none_or(|receipt| receipt.shared_environment().is_some() == shared)
+            })
             .filter(|environment| {
                 existing_environment_usable(
                     environment.environment(),
@@ -680,9 +695,171 @@ pub(crate) async fn install(
     // This lets us confirm the environment is valid before removing an existing install. However,
     // entrypoints always contain an absolute path to the relevant Python interpreter, which would
     // be invalidated by moving the environment.
+    let previous_shared_environment = existing_tool_receipt
+        .as_ref()
+        .and_then(Tool::shared_environment)
+        .map(ToString::to_string);
+
     let (environment, tool_lock) = if let Some(environment) = existing_environment {
         let environment = environment.into_environment();
-        let (environment, tool_lock) = if tool_locks {
+        let (environment, tool_lock) = if let Some(current) = existing_tool_receipt
+            .as_ref()
+            .and_then(Tool::shared_environment)
+        {
+            let site_packages = SitePackages::from_environment(&environment)?;
+            let satisfied_tool_lock = match existing_tool_lock.take() {
+                Some(lock) if lock.is_satisfied() => Some(lock.into_lock()),
+                lock => {
+                    existing_tool_lock = lock;
+                    None
+                }
+            };
+            let (resolution, tool_lock) = if let Some(tool_lock) = satisfied_tool_lock {
+                let resolution = tool_lock.to_resolution(
+                    Some(package_name),
+                    environment.interpreter(),
+                    python_platform.as_ref(),
+                    &settings.resolver.build_options,
+                )?;
+                (resolution, Some(tool_lock))
+            } else {
+                let spec = if tool_locks {
+                    tool_environment_spec(
+                        spec.clone(),
+                        existing_tool_lock
+                            .as_ref()
+                            .and_then(|lock| lock.preference()),
+                        Some(&site_packages),
+                    )
+                } else {
+                    EnvironmentSpecification::from(spec.clone())
+                };
+                let resolution = match resolve_environment(
+                    spec,
+                    resolution_scope,
+                    environment.interpreter(),
+                    python_platform.as_ref(),
+                    SourceTreeEditablePolicy::Tool,
+                    Constraints::from_requirements(receipt_build_constraints.iter().cloned()),
+                    &settings.resolver,
+                    &client_builder,
+                    &state,
+                    Box::new(DefaultResolveLogger),
+                    &concurrency,
+                    &cache,
+                    workspace_cache,
+                    printer,
+                    preview,
+                )
+                .await
+                {
+                    Ok(resolution) => resolution,
+                    Err(ProjectError::Operation(err)) => {
+                        return diagnostics::OperationDiagnostic::default()
+                            .report(err)
+                            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+                if tool_locks {
+                    let tool_lock = ToolLock::from_resolution(
+                        &tool_dir,
+                        &resolution,
+                        &lock_manifest,
+                        &settings.resolver.index_locations,
+                    )?;
+                    let resolution = tool_lock.to_resolution(
+                        Some(package_name),
+                        environment.interpreter(),
+                        python_platform.as_ref(),
+                        &settings.resolver.build_options,
+                    )?;
+                    (resolution, Some(tool_lock))
+                } else {
+                    (resolution.into(), None)
+                }
+            };
+
+            // Update the tool in its shared environment, unless the update would change packages
+            // that other tools in the environment depend on; in that case, move the tool into
+            // another shared environment.
+            let shared_environment = select_shared_environment(
+                &installed_tools,
+                package_name,
+                Some(current),
+                environment.interpreter(),
+                &resolution,
+                &cache,
+            )?;
+            let is_installed = |dist: &ResolvedDist| {
+                site_packages
+                    .get_packages(dist.name())
+                    .iter()
+                    .any(|installed| Some(installed.version()) == dist.version())
+            };
+            if shared_environment.id == current
+                && resolution.distributions().all(is_installed)
+                && !settings.compile_bytecode
+                && !request.is_latest()
+                && settings.reinstall.is_none()
+                && settings.resolver.upgrade.is_none()
+            {
+                let Some(existing_tool_receipt) = existing_tool_receipt.as_ref() else {
+                    bail!("Expected an existing tool receipt");
+                };
+                let python = if explicit_python_request {
+                    python_request.clone()
+                } else {
+                    existing_tool_receipt.python().clone()
+                };
+                ToolLock::write(&tool_dir, tool_lock.as_ref())?;
+                installed_tools.add_tool_receipt(
+                    package_name,
+                    Tool::new(
+                        requirements.clone(),
+                        receipt_constraints.clone(),
+                        receipt_overrides.clone(),
+                        receipt_excludes.clone(),
+                        receipt_build_constraints.clone(),
+                        python,
+                        existing_tool_receipt.entrypoints().iter().cloned(),
+                        options.clone(),
+                    )
+                    .with_shared_environment(Some(current.to_string())),
+                )?;
+                writeln!(
+                    printer.stderr(),
+                    "`{}` is already installed",
+                    requirement.cyan()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+
+            let hash_strategy = if tool_lock.is_some() {
+                HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?
+            } else {
+                HashStrategy::default()
+            };
+            let modifications = shared_environment.modifications();
+            let environment = sync_environment(
+                shared_environment.environment,
+                &resolution,
+                hash_strategy,
+                modifications,
+                Constraints::from_requirements(receipt_build_constraints.iter().cloned()),
+                (&settings).into(),
+                &client_builder,
+                &state,
+                Box::new(DefaultInstallLogger),
+                installer_metadata,
+                &concurrency,
+                &cache,
+                printer,
+                preview,
+            )
+            .await?;
+            (environment, tool_lock)
+        } else if tool_locks {
             let site_packages = SitePackages::from_environment(&environment)?;
             let satisfied_tool_lock = match existing_tool_lock.take() {
                 Some(lock) if lock.is_satisfied() => Some(lock.into_lock()),
@@ -1022,7 +1199,26 @@ pub(crate) async fn install(
         } else {
             HashStrategy::default()
         };
-        let environment = installed_tools.create_environment(package_name, interpreter)?;
+        let (environment, modifications) = if shared {
+            let shared_environment = select_shared_environment(
+                &installed_tools,
+                package_name,
+                existing_tool_receipt
+                    .as_ref()
+                    .and_then(Tool::shared_environment),
+                &interpreter,
+                &resolution,
+                &cache,
+            )?;
+            installed_tools.create_shared_tool_dir(package_name)?;
+            let modifications = shared_environment.modifications();
+            (shared_environment.environment, modifications)
+        } else {
+            (
+                installed_tools.create_environment(package_name, interpreter)?,
+                Modifications::Exact,
+            )
+        };
 
         // At this point, we removed any existing environment, so we should remove any of its
         // executables.
@@ -1035,7 +1231,7 @@ pub(crate) async fn install(
             environment,
             &resolution,
             hash_strategy,
-            Modifications::Exact,
+            modifications,
             Constraints::from_requirements(receipt_build_constraints.iter().cloned()),
             (&settings).into(),
             &client_builder,
@@ -1085,6 +1281,17 @@ pub(crate) async fn install(
         printer,
     )?;
 
+    // Remove the packages that are no longer required from the shared environment the tool was
+    // moved out of or updated in, if any.
+    let current_shared_environment = installed_tools.shared_environment_id(environment.root());
+    for id in previous_shared_environment
+        .into_iter()
+        .filter(|id| current_shared_environment.as_ref() != Some(id))
+        .chain(current_shared_environment.clone())
+    {
+        prune_shared_environment(&installed_tools, &id, &cache).await?;
+    }
+
     Ok(ExitStatus::Success)
 }
 
@@ -33,6 +33,7 @@ pub(crate) async fn list(
     show_with: bool,
     show_extras: bool,
     show_python: bool,
+    show_environments: bool,
     outdated: bool,
     args: ResolverInstallerOptions,
     filesystem: ResolverInstallerOptions,
@@ -254,6 +255,15 @@ pub(crate) async fn list(
             })
             .unwrap_or_default();
 
+        let environment = if show_environments {
+            format!(
+                " [environment: {}]",
+                tool_env.environment().root().simplified_display()
+            )
+        } else {
+            String::new()
+        };
+
         let latest_version = if outdated {
             latest
                 .get(&name)
@@ -269,7 +279,7 @@ pub(crate) async fn list(
                 printer.stdout(),
                 "{} ({})",
                 format!(
-                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{latest_version}"
+                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{environment}{latest_version}"
                 )
                 .bold(),
                 installed_tools.tool_dir(&name).simplified_display().cyan(),
@@ -279,7 +289,7 @@ pub(crate) async fn list(
                 printer.stdout(),
                 "{}",
                 format!(
-                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{latest_version}"
+                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{environment}{latest_version}"
                 )
                 .bold()
             )?;
@@ -12,6 +12,7 @@ pub(crate) mod dir;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod run;
+pub(crate) mod shared;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 pub(crate) mod upgrade;
@@ -0,0 +1,186 @@
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_distribution_types::{Name, Resolution};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::{Interpreter, PythonEnvironment};
+use uv_tool::{InstalledTools, Tool};
+
+use crate::commands::pip::operations::Modifications;
+
+/// An environment shared by multiple tools.
+#[derive(Debug)]
+pub(crate) struct SharedEnvironment {
+    /// The ID of the shared environment.
+    pub(crate) id: String,
+    /// The shared environment.
+    pub(crate) environment: PythonEnvironment,
+    /// Whether the environment was newly created.
+    pub(crate) created: bool,
+}
+
+impl SharedEnvironment {
+    /// The modifications to apply when installing a tool into the environment.
+    ///
+    /// Packages installed for other tools are retained in existing environments.
+    pub(crate) fn modifications(&self) -> Modifications {
+        if self.created {
+            Modifications::Exact
+        } else {
+            Modifications::Sufficient
+        }
+    }
+}
+
+/// Select the shared environment to install a tool into, creating a new shared environment if no
+/// existing one is compatible.
+///
+/// A shared environment is compatible if it uses the given interpreter, and installing the
+/// resolution into it wouldn't change any package required by the other tools installed into it.
+/// The environment the tool is currently installed into, if any, is preferred.
+///
+/// Note it is generally incorrect to use this without [`InstalledTools::lock`].
+pub(crate) fn select_shared_environment(
+    installed_tools: &InstalledTools,
+    name: &PackageName,
+    current: Option<&str>,
+    interpreter: &Interpreter,
+    resolution: &Resolution,
+    cache: &Cache,
+) -> anyhow::Result<SharedEnvironment> {
+    let tools = installed_tools
+        .tools()?
+        .into_iter()
+        .filter(|(tool_name, _)| tool_name != name)
+        .filter_map(|(_, tool)| tool.ok())
+        .collect::<Vec<_>>();
+
+    let mut environments = installed_tools.shared_environments(cache)?;
+    environments.sort_by_key(|(id, _)| Some(id.as_str()) != current);
+
+    for (id, environment) in environments {
+        if !environment.uses(interpreter) {
+            debug!("Skipping shared tool environment `{id}` with a different interpreter");
+            continue;
+        }
+        let others = tools
+            .iter()
+            .filter(|tool| tool.shared_environment() == Some(id.as_str()))
+            .collect::<Vec<_>>();
+        if is_compatible(&environment, &others, resolution)? {
+            debug!("Using shared tool environment `{id}` for `{name}`");
+            return Ok(SharedEnvironment {
+                id,
+                environment,
+                created: false,
+            });
+        }
+        debug!("Shared tool environment `{id}` is incompatible with `{name}`");
+    }
+
+    let (id, environment) = installed_tools.create_shared_environment(interpreter.clone())?;
+    Ok(SharedEnvironment {
+        id,
+        environment,
+        created: true,
+    })
+}
+
+/// Remove the packages from a shared environment that none of the tools installed into it require,
+/// e.g., after a tool was uninstalled or moved into another shared environment.
+///
+/// Note it is generally incorrect to use this without [`InstalledTools::lock`].
+pub(crate) async fn prune_shared_environment(
+    installed_tools: &InstalledTools,
+    id: &str,
+    cache: &Cache,
+) -> anyhow::Result<()> {
+    let root = installed_tools.shared_environment_dir(id);
+    if !root.is_dir() {
+        return Ok(());
+    }
+    let environment = PythonEnvironment::from_root(&root, cache)?;
+    let tools = installed_tools
+        .tools()?
+        .into_iter()
+        .filter_map(|(_, tool)| tool.ok())
+        .filter(|tool| tool.shared_environment() == Some(id))
+        .collect::<Vec<_>>();
+    let site_packages = SitePackages::from_environment(&environment)?;
+    let required = required_packages(&site_packages, &tools.iter().collect::<Vec<_>>());
+    let layout = environment.interpreter().layout();
+    for dist in site_packages.iter() {
+        if required.contains(dist.name()) {
+            continue;
+        }
+        debug!(
+            "Removing `{}` from shared tool environment: {}",
+            dist.name(),
+            root.user_display()
+        );
+        uv_installer::uninstall(dist, &layout).await?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the resolution can be installed into the environment without changing any
+/// package required by the given tools.
+fn is_compatible(
+    environment: &PythonEnvironment,
+    tools: &[&Tool],
+    resolution: &Resolution,
+) -> anyhow::Result<bool> {
+    let site_packages = SitePackages::from_environment(environment)?;
+    let required = required_packages(&site_packages, tools);
+    for dist in resolution.distributions() {
+        if !required.contains(dist.name()) {
+            continue;
+        }
+        let installed = site_packages.get_packages(dist.name());
+        if !installed
+            .iter()
+            .any(|installed| Some(installed.version()) == dist.version())
+        {
+            debug!(
+                "Package `{}` is required by another tool at a different version",
+                dist.name()
+            );
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Returns the installed packages required by the given tools, transitively.
+///
+/// Markers and extras on dependencies are ignored, so this is a superset of the packages that are
+/// strictly required.
+fn required_packages(site_packages: &SitePackages, tools: &[&Tool]) -> FxHashSet<PackageName> {
+    let mut required = FxHashSet::default();
+    let mut queue = tools
+        .iter()
+        .flat_map(|tool| tool.requirements())
+        .map(|requirement| requirement.name.clone())
+        .collect::<Vec<_>>();
+    while let Some(name) = queue.pop() {
+        if required.contains(&name) {
+            continue;
+        }
+        for dist in site_packages.get_packages(&name) {
+            match dist.read_metadata() {
+                Ok(metadata) => queue.extend(
+                    metadata
+                        .requires_dist
+                        .iter()
+                        .map(|requirement| requirement.name.clone()),
+                ),
+                Err(err) => debug!("Failed to read metadata for `{name}`: {err}"),
+            }
+        }
+        required.insert(name);
+    }
+    required
+}
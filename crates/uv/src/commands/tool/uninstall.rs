@@ -5,15 +5,21 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::debug;
 
+use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_tool::{InstalledTools, Tool, ToolEntrypoint};
 
 use crate::commands::ExitStatus;
+use crate::commands::tool::shared::prune_shared_environment;
 use crate::printer::Printer;
 
 /// Uninstall a tool.
-pub(crate) async fn uninstall(name: Vec<PackageName>, printer: Printer) -> Result<ExitStatus> {
+pub(crate) async fn uninstall(
+    name: Vec<PackageName>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = match installed_tools.lock().await {
         Ok(lock) => lock,
@@ -35,7 +41,7 @@ pub(crate) async fn uninstall(name: Vec<PackageName>, printer: Printer) -> Resul
     };
 
     // Perform the uninstallation.
-    do_uninstall(&installed_tools, name, printer).await?;
+    do_uninstall(&installed_tools, name, cache, printer).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installed_tools.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -97,6 +103,7 @@ impl IgnoreCurrentlyBeingDeleted for Result<(), std::io::Error> {
 async fn do_uninstall(
     installed_tools: &InstalledTools,
     names: Vec<PackageName>,
+    cache: &Cache,
     printer: Printer,
 ) -> Result<()> {
     let mut dangling = false;
@@ -127,7 +134,7 @@ async fn do_uninstall(
                 }
             };
 
-            entrypoints.extend(uninstall_tool(&name, &receipt, installed_tools).await?);
+            entrypoints.extend(uninstall_tool(&name, &receipt, installed_tools, cache).await?);
         }
         entrypoints
     } else {
@@ -155,7 +162,7 @@ async fn do_uninstall(
                 }
             };
 
-            entrypoints.extend(uninstall_tool(&name, &receipt, installed_tools).await?);
+            entrypoints.extend(uninstall_tool(&name, &receipt, installed_tools, cache).await?);
         }
         entrypoints
     };
@@ -188,10 +195,17 @@ async fn uninstall_tool(
     name: &PackageName,
     receipt: &Tool,
     tools: &InstalledTools,
+    cache: &Cache,
 ) -> Result<Vec<ToolEntrypoint>> {
     // Remove the tool itself.
     tools.remove_environment(name)?;
 
+    // Remove the tool's packages from its shared environment, if the environment is still used by
+    // other tools.
+    if let Some(id) = receipt.shared_environment() {
+        prune_shared_environment(tools, id, cache).await?;
+    }
+
     #[cfg(windows)]
     let itself = std::env::current_exe().ok();
 
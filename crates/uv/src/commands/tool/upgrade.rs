@@ -10,7 +10,9 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, DryRun, HashCheckingMode, TargetTriple};
 use uv_distribution::LoweredExtraBuildDependencies;
-use uv_distribution_types::{ExtraBuildRequires, Name, Requirement, RequirementSource};
+use uv_distribution_types::{
+    ExtraBuildRequires, Name, Requirement, RequirementSource, ResolvedDist,
+};
 use uv_errors::{ErrorOptions, Hints, write_error_chain_with_options};
 use uv_fs::CWD;
 use uv_installer::{InstallationStrategy, Planner, SitePackages};
@@ -28,7 +30,7 @@ use uv_types::{HashStrategy, SourceTreeEditablePolicy};
 use uv_workspace::WorkspaceCache;
 
 use crate::commands::pip::loggers::{
-    DefaultInstallLogger, SummaryResolveLogger, UpgradeInstallLogger,
+    DefaultInstallLogger, InstallLogger, SummaryResolveLogger, UpgradeInstallLogger,
 };
 use crate::commands::pip::{operations::Modifications, resolution_tags};
 use crate::commands::project::{
@@ -37,6 +39,7 @@ use crate::commands::project::{
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::{ToolLock, remove_entrypoints, tool_environment_spec};
+use crate::commands::tool::shared::{prune_shared_environment, select_shared_environment};
 use crate::commands::{ExitStatus, conjunction, tool::common::finalize_tool_install};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
@@ -361,7 +364,135 @@ async fn upgrade_tool(
     let tool_dir = installed_tools.tool_dir(name);
     // TODO(zanieb): When updating an existing environment, build it in the cache directory then
     // copy it into the tool directory.
-    let (environment, outcome, tool_lock) = if tool_locks {
+    let (environment, outcome, tool_lock) = if let Some(current) =
+        existing_tool_receipt.shared_environment()
+    {
+        let target_interpreter =
+            requested_interpreter.unwrap_or_else(|| environment.environment().interpreter());
+        let site_packages = SitePackages::from_environment(environment.environment())?;
+        let (resolution, tool_lock) = if tool_locks {
+            let universal_resolution = resolve_environment(
+                tool_environment_spec(spec, None, Some(&site_packages)),
+                EnvironmentResolution::Universal,
+                target_interpreter,
+                python_platform,
+                SourceTreeEditablePolicy::Tool,
+                build_constraints.clone(),
+                &settings.resolver,
+                client_builder,
+                &state,
+                Box::new(SummaryResolveLogger),
+                concurrency,
+                cache,
+                workspace_cache,
+                printer,
+                preview,
+            )
+            .await?;
+            let tool_lock = ToolLock::from_resolution(
+                &tool_dir,
+                &universal_resolution,
+                &lock_manifest,
+                &settings.resolver.index_locations,
+            )?;
+            let resolution = tool_lock.to_resolution(
+                Some(name),
+                target_interpreter,
+                python_platform,
+                &settings.resolver.build_options,
+            )?;
+            (resolution, Some(tool_lock))
+        } else {
+            let resolution = resolve_environment(
+                spec.into(),
+                EnvironmentResolution::Specific,
+                target_interpreter,
+                python_platform,
+                SourceTreeEditablePolicy::Tool,
+                build_constraints.clone(),
+                &settings.resolver,
+                client_builder,
+                &state,
+                Box::new(SummaryResolveLogger),
+                concurrency,
+                cache,
+                workspace_cache,
+                printer,
+                preview,
+            )
+            .await?;
+            (resolution.into(), None)
+        };
+        let hash_strategy = if tool_lock.is_some() {
+            HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?
+        } else {
+            HashStrategy::default()
+        };
+
+        // Upgrade the tool in its shared environment, unless the upgrade would change packages
+        // that other tools in the environment depend on; in that case, move the tool into
+        // another shared environment.
+        let shared = select_shared_environment(
+            installed_tools,
+            name,
+            Some(current),
+            target_interpreter,
+            &resolution,
+            cache,
+        )?;
+        let outcome = if shared.id != current {
+            debug!(
+                "Moving `{name}` from shared environment `{current}` to `{}`",
+                shared.id
+            );
+            UpgradeOutcome::UpgradeEnvironment
+        } else {
+            let is_installed = |dist: &ResolvedDist| {
+                site_packages
+                    .get_packages(dist.name())
+                    .iter()
+                    .any(|installed| Some(installed.version()) == dist.version())
+            };
+            if resolution
+                .distributions()
+                .any(|dist| dist.name() == name && !is_installed(dist))
+            {
+                UpgradeOutcome::UpgradeTool
+            } else if resolution.distributions().all(is_installed) {
+                UpgradeOutcome::NoOp
+            } else {
+                UpgradeOutcome::UpgradeDependencies
+            }
+        };
+        let modifications = shared.modifications();
+        let logger: Box<dyn InstallLogger> = if shared.created {
+            Box::new(DefaultInstallLogger)
+        } else {
+            Box::new(UpgradeInstallLogger::new(name.clone()))
+        };
+        let environment = if matches!(outcome, UpgradeOutcome::NoOp) && !settings.compile_bytecode {
+            shared.environment
+        } else {
+            sync_environment(
+                shared.environment,
+                &resolution,
+                hash_strategy,
+                modifications,
+                build_constraints,
+                (&settings).into(),
+                client_builder,
+                &state,
+                logger,
+                installer_metadata,
+                concurrency,
+                cache,
+                printer,
+                preview,
+            )
+            .await?
+        };
+        (environment, outcome, tool_lock)
+    } else if tool_locks {
         let target_interpreter =
             requested_interpreter.unwrap_or_else(|| environment.environment().interpreter());
         let site_packages = SitePackages::from_environment(environment.environment())?;
@@ -611,6 +742,19 @@ async fn upgrade_tool(
         )?;
     }
 
+    // Remove the packages that are no longer required from the shared environment the tool was
+    // moved out of or upgraded in, if any.
+    let current_shared_environment = installed_tools.shared_environment_id(environment.root());
+    for id in existing_tool_receipt
+        .shared_environment()
+        .map(ToString::to_string)
+        .into_iter()
+        .filter(|id| current_shared_environment.as_ref() != Some(id))
+        .chain(current_shared_environment.clone())
+    {
+        prune_shared_environment(installed_tools, &id, cache).await?;
+    }
+
     let constraint = match &outcome {
         UpgradeOutcome::UpgradeDependencies | UpgradeOutcome::NoOp => {
             pinned_requirement_version(&existing_tool_receipt, name)
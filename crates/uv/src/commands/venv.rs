@@ -22,7 +22,7 @@ use uv_distribution_types::{
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_normalize::DefaultGroups;
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeature};
 use uv_python::{
     ConfigDiscovery, EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference,
     PythonRequest,
@@ -34,7 +34,7 @@ use uv_types::{
     AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy, SourceTreeEditablePolicy,
 };
 use uv_virtualenv::{OnExisting, RemovalReason, Seed};
-use uv_warnings::warn_user;
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceErrorKind};
 
 use crate::commands::ExitStatus;
@@ -91,6 +91,7 @@ pub(crate) async fn venv(
     workspace_cache: &WorkspaceCache,
     printer: Printer,
     relocatable: bool,
+    parents: &[PathBuf],
     preview: Preview,
 ) -> Result<ExitStatus> {
     let project = if no_project {
@@ -283,6 +284,18 @@ pub(crate) async fn venv(
     )
     .map_err(VenvError::Creation)?;
 
+    // Layer the parent environments beneath the virtual environment.
+    if !parents.is_empty() {
+        if !preview.is_enabled(PreviewFeature::LayeredEnvironments) {
+            warn_user_once!(
+                "The `--parent` option is experimental and may change without warning. \
+                Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::LayeredEnvironments
+            );
+        }
+        uv_virtualenv::add_parent_environments(&venv, parents).map_err(VenvError::Creation)?;
+    }
+
     // Install seed packages.
     if let Seed::Enabled = seed {
         // Extract the interpreter.
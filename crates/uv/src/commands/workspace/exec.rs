@@ -0,0 +1,404 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::{ExternalCommand, SyncFormat};
+use uv_client::BaseClientBuilder;
+use uv_configuration::{
+    Concurrency, DependencyGroups, DryRun, ExtrasSpecification, InstallOptions,
+};
+use uv_fs::Simplified;
+use uv_git::GIT;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeature};
+use uv_python::{ConfigDiscovery, PythonDownloads, PythonPreference, PythonRequest};
+use uv_settings::{MalwareCheckSettings, PythonInstallMirrors};
+use uv_shell::WindowsRunnable;
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::child::run_to_completion;
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::{
+    ProjectEnvironment, default_dependency_groups, project_environment_variables,
+};
+use crate::commands::{ExitStatus, project};
+use crate::printer::Printer;
+use crate::settings::{FrozenSource, LockCheck, ResolverInstallerSettings};
+
+/// Run a command in each member of a workspace.
+pub(crate) async fn exec(
+    project_dir: &Path,
+    command: ExternalCommand,
+    packages: Vec<PackageName>,
+    changed_since: Option<String>,
+    parallel: NonZeroUsize,
+    lock_check: LockCheck,
+    frozen: Option<FrozenSource>,
+    active: Option<bool>,
+    no_sync: bool,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    config_discovery: ConfigDiscovery,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+    malware_settings: MalwareCheckSettings,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeature::WorkspaceExec) {
+        warn_user!(
+            "`uv workspace exec` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::WorkspaceExec
+        );
+    }
+
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        workspace_cache,
+    )
+    .await?;
+
+    // Select the members to run the command in.
+    for package in &packages {
+        if !workspace.packages().contains_key(package) {
+            bail!(
+                "Package `{}` is not a member of the workspace",
+                package.cyan()
+            );
+        }
+    }
+    let mut members = workspace
+        .packages()
+        .iter()
+        .filter(|(name, _)| packages.is_empty() || packages.contains(name))
+        .map(|(name, member)| (name.clone(), member.root().clone()))
+        .collect::<Vec<_>>();
+
+    if let Some(reference) = changed_since.as_deref() {
+        let changed = changed_members(&workspace, reference)?;
+        members.retain(|(name, _)| changed.contains(name));
+        if members.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No workspace members have changed since `{}`",
+                reference.cyan()
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+    }
+
+    // Sync the workspace environment to include the selected members.
+    if no_sync {
+        debug!("Skipping environment synchronization due to `--no-sync`");
+    } else {
+        let all_packages = members.len() == workspace.packages().len();
+        let status = Box::pin(project::sync::sync(
+            workspace.install_path(),
+            lock_check,
+            frozen,
+            DryRun::Disabled,
+            active,
+            all_packages,
+            if all_packages {
+                Vec::new()
+            } else {
+                members.iter().map(|(name, _)| name.clone()).collect()
+            },
+            ExtrasSpecification::default(),
+            DependencyGroups::default(),
+            None,
+            InstallOptions::default(),
+            Modifications::Sufficient,
+            python.clone(),
+            None,
+            install_mirrors.clone(),
+            python_preference,
+            python_downloads,
+            settings,
+            client_builder.clone(),
+            None,
+            installer_metadata,
+            concurrency,
+            config_discovery,
+            cache,
+            workspace_cache,
+            printer,
+            preview,
+            SyncFormat::default(),
+            malware_settings,
+        ))
+        .await?;
+        if !matches!(status, ExitStatus::Success) {
+            return Ok(status);
+        }
+    }
+
+    // Discover the workspace environment.
+    let groups = DependencyGroups::default()
+        .with_defaults(default_dependency_groups(workspace.pyproject_toml())?);
+    let environment = ProjectEnvironment::get_or_init(
+        &workspace,
+        &groups,
+        python.as_deref().map(PythonRequest::parse),
+        &install_mirrors,
+        &client_builder,
+        python_preference,
+        python_downloads,
+        true,
+        config_discovery,
+        active,
+        cache,
+        DryRun::Disabled,
+        project::LinkErrorReporting::Log,
+        printer,
+    )
+    .await?
+    .into_environment()?;
+
+    // Variables declared by the workspace don't override the calling environment.
+    let project_environment = project_environment_variables(&workspace)?;
+    let variables = project_environment
+        .variables
+        .into_iter()
+        .filter(|(key, _)| std::env::var_os(key).is_none())
+        .collect::<Vec<_>>();
+
+    // Construct the `PATH` environment variable.
+    let new_path = std::env::join_paths(
+        std::iter::once(environment.scripts().to_path_buf())
+            .chain(project_environment.path_prepend)
+            .chain(
+                std::env::var_os(EnvVars::PATH)
+                    .as_ref()
+                    .iter()
+                    .flat_map(std::env::split_paths),
+            ),
+    )?;
+
+    let (executable, args) = command.split();
+    let Some(executable) = executable else {
+        bail!("Provide a command to run in each workspace member");
+    };
+    let display = command.iter().map(|arg| arg.to_string_lossy()).join(" ");
+
+    let build_command = |root: &Path| {
+        let mut process = if cfg!(windows) {
+            WindowsRunnable::from_script_path(environment.scripts(), executable).into()
+        } else {
+            Command::new(executable)
+        };
+        process.args(args);
+        process.envs(variables.iter().map(|(key, value)| (key, value)));
+        process.env(EnvVars::PATH, &new_path);
+        process.env(EnvVars::VIRTUAL_ENV, environment.root().as_os_str());
+        process.current_dir(root);
+        process
+    };
+
+    let mut outcomes = Vec::with_capacity(members.len());
+    if parallel.get() == 1 {
+        // Run the command in each member in turn, streaming its output.
+        for (name, root) in &members {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!("Running `{display}` in `{name}`").bold()
+            )?;
+            let handle = build_command(root)
+                .spawn()
+                .with_context(|| format!("Failed to spawn: `{}`", executable.to_string_lossy()))?;
+            let status = run_to_completion(handle).await?;
+            outcomes.push((name.clone(), Outcome::from(status)));
+        }
+    } else {
+        // Run the command in several members at once, capturing each run's output.
+        let mut runs = futures::stream::iter(members.iter())
+            .map(|(name, root)| {
+                let mut process = build_command(root);
+                process.stdin(std::process::Stdio::null());
+                async move {
+                    let output = process.output().await.with_context(|| {
+                        format!("Failed to spawn: `{}`", executable.to_string_lossy())
+                    })?;
+                    Ok::<_, anyhow::Error>((name, output))
+                }
+            })
+            .buffer_unordered(parallel.get());
+        while let Some(result) = runs.next().await {
+            let (name, output) = result?;
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!("Ran `{display}` in `{name}`").bold()
+            )?;
+            // The captured output belongs to the command rather than uv, so it's only suppressed
+            // in silent mode.
+            write!(
+                printer.stdout_important(),
+                "{}",
+                String::from_utf8_lossy(&output.stdout)
+            )?;
+            write!(
+                printer.stderr_important(),
+                "{}",
+                String::from_utf8_lossy(&output.stderr)
+            )?;
+            outcomes.push((name.clone(), Outcome::from(output.status)));
+        }
+        outcomes.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+
+    // Summarize the results.
+    let failed = outcomes
+        .iter()
+        .filter(|(_, outcome)| !matches!(outcome, Outcome::Success))
+        .count();
+    writeln!(
+        printer.stderr(),
+        "\nRan `{display}` in {} {}: {} succeeded, {} failed",
+        outcomes.len(),
+        if outcomes.len() == 1 {
+            "member"
+        } else {
+            "members"
+        },
+        outcomes.len() - failed,
+        failed
+    )?;
+    let width = outcomes
+        .iter()
+        .map(|(name, _)| name.as_str().len())
+        .max()
+        .unwrap_or_default();
+    for (name, outcome) in &outcomes {
+        let name = format!("{:width$}", name.as_str());
+        match outcome {
+            Outcome::Success => {
+                writeln!(printer.stderr(), "  {}  {}", name.cyan(), "ok".green())?;
+            }
+            Outcome::Code(code) => {
+                writeln!(
+                    printer.stderr(),
+                    "  {}  {} (exit code {code})",
+                    name.cyan(),
+                    "failed".red()
+                )?;
+            }
+            Outcome::Terminated => {
+                writeln!(
+                    printer.stderr(),
+                    "  {}  {} (terminated by signal)",
+                    name.cyan(),
+                    "failed".red()
+                )?;
+            }
+        }
+    }
+
+    if failed > 0 {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// The outcome of running the command in a workspace member.
+#[derive(Debug)]
+enum Outcome {
+    Success,
+    Code(i32),
+    Terminated,
+}
+
+impl From<ExitStatus> for Outcome {
+    fn from(status: ExitStatus) -> Self {
+        match status {
+            ExitStatus::Success | ExitStatus::External(0) => Self::Success,
+            ExitStatus::External(code) => Self::Code(i32::from(code)),
+            ExitStatus::Failure | ExitStatus::Error => Self::Terminated,
+        }
+    }
+}
+
+impl From<std::process::ExitStatus> for Outcome {
+    fn from(status: std::process::ExitStatus) -> Self {
+        match status.code() {
+            Some(0) => Self::Success,
+            Some(code) => Self::Code(code),
+            None => Self::Terminated,
+        }
+    }
+}
+
+/// Determine the workspace members with changes since the given Git reference.
+///
+/// Changed files are attributed to the innermost member that contains them.
+fn changed_members(workspace: &Workspace, reference: &str) -> Result<BTreeSet<PackageName>> {
+    let Ok(git) = GIT.as_ref() else {
+        bail!("`--changed-since` requires Git, but `git` could not be found");
+    };
+
+    let mut paths = Vec::new();
+    for args in [
+        &["diff", "--name-only", "--relative", reference, "--"][..],
+        &["ls-files", "--others", "--exclude-standard"][..],
+    ] {
+        let output = git
+            .build_command()
+            .args(args)
+            .current_dir(workspace.install_path())
+            .output()
+            .with_context(|| format!("Failed to run `git {}`", args.join(" ")))?;
+        if !output.status.success() {
+            bail!(
+                "`git {}` failed:\n{}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        paths.extend(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| workspace.install_path().join(line)),
+        );
+    }
+
+    let mut changed = BTreeSet::new();
+    for path in paths {
+        if let Some((name, root)) = workspace
+            .packages()
+            .iter()
+            .map(|(name, member)| (name, member.root()))
+            .filter(|(_, root)| path.starts_with(root))
+            .max_by_key(|(_, root)| root.components().count())
+        {
+            debug!(
+                "`{}` changed in `{}`",
+                path.user_display(),
+                root.user_display()
+            );
+            changed.insert(name.clone());
+        }
+    }
+    Ok(changed)
+}
@@ -58,6 +58,16 @@ pub(crate) async fn list(
                 .context("PEP 723 script was discovered outside the workspace root")?;
             writeln!(printer.stdout(), "{}", script.simplified_display().cyan())?;
         }
+
+        // Report the tasks declared in `tool.uv.scripts`, as `<member>:<task>`.
+        for (name, member) in workspace.packages() {
+            let Some(tasks) = member.pyproject_toml().tool_uv_scripts() else {
+                continue;
+            };
+            for task in tasks.keys() {
+                writeln!(printer.stdout(), "{}", format!("{name}:{task}").cyan())?;
+            }
+        }
         return Ok(ExitStatus::Success);
     }
 
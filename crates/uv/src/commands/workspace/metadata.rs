@@ -4,7 +4,7 @@ use std::path::Path;
 use anyhow::{Context, Result};
 use uv_cache::{Cache, Refresh};
 use uv_client::BaseClientBuilder;
-use uv_configuration::{Concurrency, DependencyGroupsWithDefaults, DryRun};
+use uv_configuration::{Concurrency, DependencyGroupsWithDefaults, DryRun, RequireProvenance};
 use uv_preview::{Preview, PreviewFeature};
 use uv_python::{ConfigDiscovery, PythonDownloads, PythonPreference, PythonRequest};
 use uv_resolver::Metadata;
@@ -41,6 +41,7 @@ pub(crate) async fn metadata(
     install_mirrors: PythonInstallMirrors,
     malware_settings: MalwareCheckSettings,
     settings: ResolverSettings,
+    require_provenance: RequireProvenance,
     client_builder: BaseClientBuilder<'_>,
     script: Option<Pep723Script>,
     python_preference: PythonPreference,
@@ -234,6 +235,7 @@ pub(crate) async fn metadata(
                     install_target,
                     &environment,
                     &settings,
+                    &require_provenance,
                     &client_builder,
                     &state,
                     &concurrency,
@@ -1,4 +1,5 @@
 pub(crate) mod dir;
+pub(crate) mod exec;
 pub(crate) mod list;
 pub(crate) mod metadata;
 mod module_owners;
@@ -5,7 +5,7 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, DependencyGroups, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
-    ExtrasSpecificationWithDefaults, InstallOptions, Reinstall,
+    ExtrasSpecificationWithDefaults, InstallOptions, Reinstall, RequireProvenance,
 };
 use uv_distribution_types::{Dist, Name, ResolvedDist};
 use uv_fs::PortablePathBuf;
@@ -37,6 +37,7 @@ pub(crate) async fn collect_module_owners(
     target: InstallTarget<'_>,
     venv: &PythonEnvironment,
     settings: &ResolverSettings,
+    require_provenance: &RequireProvenance,
     client_builder: &BaseClientBuilder<'_>,
     state: &UniversalState,
     concurrency: &Concurrency,
@@ -68,6 +69,7 @@ pub(crate) async fn collect_module_owners(
             link_mode: settings.link_mode,
             compile_bytecode: false,
             reinstall: &reinstall,
+            require_provenance,
             build_options: &settings.build_options,
             sources: settings.sources.clone(),
         };
@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::ffi::OsString;
 use std::fmt::Write;
 use std::io::stdout;
+use std::net::SocketAddr;
 #[cfg(feature = "self-update")]
 use std::ops::Bound;
 use std::path::Path;
@@ -28,9 +29,10 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, CacheSizeOutputFormat, Cli, Commands, PipCommand, PipNamespace, ProjectCommand,
-    PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace,
-    TopLevelArgs, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs, options::ArgumentError,
+    CacheNamespace, CacheSizeOutputFormat, Cli, Commands, IndexCommand, IndexNamespace, PipCommand,
+    PipNamespace, ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace,
+    ToolCommand, ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace,
+    compat::CompatArgs, options::ArgumentError,
 };
 use uv_client::BaseClientBuilder;
 use uv_configuration::min_stack_size;
@@ -80,6 +82,8 @@ pub(crate) fn base_client_builder<'a>(globals: &GlobalSettings) -> BaseClientBui
         globals.network_settings.retries,
     )
     .cache_read_concurrency(globals.concurrency.cache_reads)
+    .git_mirrors(globals.network_settings.git_mirror.clone())
+    .git_allowed_signers(globals.network_settings.git_allowed_signers.clone())
     .http_proxy(globals.network_settings.http_proxy.clone())
     .https_proxy(globals.network_settings.https_proxy.clone())
     .no_proxy(globals.network_settings.no_proxy.clone());
@@ -611,7 +615,24 @@ async fn run_with_workspace_cache(
     if cache_settings.no_cache {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    if cache_settings.shared_cache.is_some()
+        && !globals.preview.is_enabled(PreviewFeature::SharedCache)
+    {
+        warn_user_once!(
+            "The `--shared-cache` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::SharedCache
+        );
+    }
+    if !globals.network_settings.git_mirror.is_empty()
+        && !globals.preview.is_enabled(PreviewFeature::GitMirror)
+    {
+        warn_user_once!(
+            "The `git-mirror` setting is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::GitMirror
+        );
+    }
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_shared_cache(cache_settings.shared_cache);
     // This check happens after the first (fallible) workspace discovery, which we need to resolve
     // the settings that go into the cache constructor, but the check happens before the first
     // workspace discovery that's used beyond settings discovery.
@@ -906,6 +927,7 @@ async fn run_with_workspace_cache(
                 &args.settings.extras,
                 &groups,
                 args.settings.reinstall,
+                &args.settings.require_provenance,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
@@ -1077,6 +1099,7 @@ async fn run_with_workspace_cache(
                 args.settings.keyring_provider,
                 &client_builder.subcommand(vec!["pip".to_owned(), "install".to_owned()]),
                 args.settings.reinstall,
+                &args.settings.require_provenance,
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
@@ -1304,12 +1327,50 @@ async fn run_with_workspace_cache(
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
         }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CachePruneSettings::resolve(args, filesystem.as_ref());
             show_settings!(args);
-            commands::cache_prune(args.ci, args.force, cache, printer, globals.preview).await
+            commands::cache_prune(
+                args.ci,
+                args.force,
+                args.max_size,
+                args.max_age,
+                cache,
+                printer,
+                globals.preview,
+            )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => commands::cache_dir(&cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            commands::cache_export(
+                &args.lock,
+                &args.output_file,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Import(args),
+        }) => commands::cache_import(&args.archive, cache, printer, globals.preview).await,
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => commands::cache_verify(args.quick, args.force, cache, printer, globals.preview).await,
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Stats(args),
+        }) => commands::cache_stats(
+            &cache,
+            args.top,
+            args.output_format,
+            printer,
+            globals.preview,
+        ),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
         }) => {
@@ -1356,6 +1417,9 @@ async fn run_with_workspace_cache(
                 args.gitignore,
                 args.force_pep517,
                 args.clear,
+                args.check,
+                args.verify_reproducible,
+                args.repair,
                 build_constraints,
                 args.build_constraints_from_workspace,
                 args.hash_checking,
@@ -1457,6 +1521,7 @@ async fn run_with_workspace_cache(
                         .preview
                         .is_enabled(PreviewFeature::RelocatableEnvsDefault)
                         && !args.no_relocatable),
+                &args.parents,
                 globals.preview,
             ))
             .await
@@ -1729,6 +1794,7 @@ async fn run_with_workspace_cache(
                 args.python_platform,
                 args.install_mirrors,
                 args.force,
+                args.shared,
                 args.options,
                 args.settings,
                 client_builder.subcommand(vec!["tool".to_owned(), "install".to_owned()]),
@@ -1761,6 +1827,7 @@ async fn run_with_workspace_cache(
                 args.show_with,
                 args.show_extras,
                 args.show_python,
+                args.show_environments,
                 args.outdated,
                 args.args,
                 args.filesystem,
@@ -1834,7 +1901,10 @@ async fn run_with_workspace_cache(
             let args = settings::ToolUninstallSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            commands::tool_uninstall(args.name, printer).await
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::tool_uninstall(args.name, &cache, printer).await
         }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::UpdateShell,
@@ -2064,12 +2134,18 @@ async fn run_with_workspace_cache(
                 password,
                 dry_run,
                 no_attestations,
+                attest,
+                fulcio_url,
+                rekor_url,
+                check,
                 direct,
+                output_format,
                 publish_url,
                 trusted_publishing,
                 keyring_provider,
                 check_url,
                 index,
+                to_directory,
                 index_locations,
             } = args;
 
@@ -2084,16 +2160,36 @@ async fn run_with_workspace_cache(
                 password,
                 check_url,
                 index,
+                to_directory,
                 index_locations,
                 dry_run,
                 no_attestations,
+                attest,
+                fulcio_url,
+                rekor_url,
+                check,
                 direct,
+                output_format,
                 globals.preview,
                 &cache,
                 printer,
             )
             .await
         }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Serve(args),
+        }) => {
+            commands::index_serve(
+                args.path.as_deref(),
+                SocketAddr::new(args.host, args.port),
+                args.username,
+                args.password,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
         Commands::Workspace(WorkspaceNamespace { command }) => match command {
             WorkspaceCommand::Metadata(args) => {
                 // Resolve the settings from the command-line arguments and workspace configuration.
@@ -2129,6 +2225,7 @@ async fn run_with_workspace_cache(
                     args.install_mirrors,
                     args.malware_settings,
                     args.settings,
+                    args.require_provenance,
                     client_builder.subcommand(vec!["workspace".to_owned(), "metadata".to_owned()]),
                     script,
                     globals.python_preference,
@@ -2164,6 +2261,50 @@ async fn run_with_workspace_cache(
                 )
                 .await
             }
+            WorkspaceCommand::Exec(args) => {
+                // Resolve the settings from the command-line arguments and workspace configuration.
+                let args = settings::WorkspaceExecSettings::resolve(args, filesystem, environment)?;
+                show_settings!(args);
+
+                // Check for conflicts between offline and refresh.
+                globals
+                    .network_settings
+                    .check_refresh_conflict(&args.refresh)?;
+
+                // Initialize the cache.
+                let cache = cache.init().await?.with_refresh(
+                    args.refresh
+                        .combine(Refresh::from(args.settings.reinstall.clone()))
+                        .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+                );
+
+                Box::pin(commands::exec(
+                    &project_dir,
+                    args.command,
+                    args.package,
+                    args.changed_since,
+                    args.parallel,
+                    args.lock_check,
+                    args.frozen,
+                    args.active,
+                    args.no_sync,
+                    args.python,
+                    args.install_mirrors,
+                    args.settings,
+                    client_builder.subcommand(vec!["workspace".to_owned(), "exec".to_owned()]),
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.installer_metadata,
+                    globals.concurrency,
+                    config_discovery,
+                    &cache,
+                    &workspace_cache,
+                    printer,
+                    globals.preview,
+                    args.malware_settings,
+                ))
+                .await
+            }
         },
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
@@ -2390,6 +2531,8 @@ async fn run_project(
                 workspace_cache,
                 printer,
                 args.env_file,
+                args.print_env,
+                args.list,
                 globals.preview,
                 args.max_recursion_depth,
                 args.malware_settings,
@@ -11,7 +11,7 @@ use rustc_hash::FxHashSet;
 
 use uv_audit::{VulnerabilityID, VulnerabilityServiceFormat};
 use uv_auth::Service;
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, Refresh, SharedCache};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     AddArgs, AuditArgs, AuditCommonArgs, AuditOutputFormat, AuthLoginArgs, AuthLogoutArgs,
@@ -22,11 +22,12 @@ use uv_cli::{
     PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat,
     ToolAuditArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs,
     TreeArgs, TreeFormat, UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec, VersionFormat,
+    WorkspaceExecArgs,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, BuildOptionsArgs, CheckArgs, ExcludeNewerArgs, ExportArgs, FormatArgs,
-    HashCheckingArgs, PackageExcludeNewerArgs, PublishArgs, PythonDirArgs, RegistryClientArgs,
-    ResolverArgs, ResolverInstallerArgs, ToolUpgradeArgs,
+    HashCheckingArgs, PackageExcludeNewerArgs, PruneArgs, PublishArgs, PublishFormat,
+    PythonDirArgs, RegistryClientArgs, ResolverArgs, ResolverInstallerArgs, ToolUpgradeArgs,
     options::{
         Flag, FlagSource, IntoPipOptions, check_conflicts, flag, resolve_flag, resolve_flag_pair,
         resolver_installer_options, resolver_options,
@@ -34,11 +35,12 @@ use uv_cli::{
 };
 use uv_client::{Certificates, Connectivity};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DevMode, DryRun, EditableMode,
-    EnvFile, ExcludeDependency, ExportFormat, ExtrasSpecification, GitLfsSetting, HashCheckingMode,
-    IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild, NoSources, Override,
-    PackageOverride, PipCompileFormat, ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion,
-    TargetTriple, TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+    BuildIsolation, BuildOptions, CacheAge, CacheSize, Concurrency, DependencyGroups, DevMode,
+    DryRun, EditableMode, EnvFile, ExcludeDependency, ExportFormat, ExtrasSpecification,
+    GitLfsSetting, GitMirror, HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType,
+    NoBinary, NoBuild, NoSources, Override, PackageOverride, PipCompileFormat, ProjectBuildBackend,
+    ProxyUrl, Reinstall, RequireProvenance, RequiredVersion, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
@@ -280,6 +282,8 @@ pub(crate) struct NetworkSettings {
     pub(super) https_proxy: Option<ProxyUrl>,
     pub(super) no_proxy: Option<Vec<String>>,
     pub(super) allow_insecure_host: Vec<TrustedHost>,
+    pub(super) git_mirror: Vec<GitMirror>,
+    pub(super) git_allowed_signers: Option<PathBuf>,
     pub(super) read_timeout: Duration,
     pub(super) connect_timeout: Duration,
     pub(super) retries: u32,
@@ -388,6 +392,11 @@ impl NetworkSettings {
                     .flatten(),
             )
             .collect();
+        let git_mirror = workspace
+            .and_then(|workspace| workspace.globals.git_mirror.clone())
+            .unwrap_or_default();
+        let git_allowed_signers =
+            workspace.and_then(|workspace| workspace.globals.git_allowed_signers.clone());
         let http_proxy = workspace.and_then(|workspace| workspace.globals.http_proxy.clone());
         let https_proxy = workspace.and_then(|workspace| workspace.globals.https_proxy.clone());
         let no_proxy = workspace.and_then(|workspace| workspace.globals.no_proxy.clone());
@@ -406,6 +415,8 @@ impl NetworkSettings {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            git_mirror,
+            git_allowed_signers,
             read_timeout: environment.http_read_timeout,
             connect_timeout: environment.http_connect_timeout,
             retries: environment.http_retries,
@@ -432,6 +443,7 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) shared_cache: Option<SharedCache>,
 }
 
 impl CacheSettings {
@@ -445,6 +457,40 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            shared_cache: args
+                .shared_cache
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.shared_cache.clone())),
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache prune` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CachePruneSettings {
+    pub(crate) ci: bool,
+    pub(crate) force: bool,
+    pub(crate) max_size: Option<CacheSize>,
+    pub(crate) max_age: Option<CacheAge>,
+}
+
+impl CachePruneSettings {
+    /// Resolve the [`CachePruneSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PruneArgs, filesystem: Option<&FilesystemOptions>) -> Self {
+        let PruneArgs {
+            ci,
+            max_size,
+            max_age,
+            force,
+        } = args;
+
+        Self {
+            ci,
+            force,
+            max_size: max_size
+                .or_else(|| filesystem.and_then(|filesystem| filesystem.globals.cache_max_size)),
+            max_age: max_age.or_else(|| {
+                filesystem.and_then(|filesystem| filesystem.globals.cache_max_age.clone())
+            }),
         }
     }
 }
@@ -679,6 +725,8 @@ pub(crate) struct RunSettings {
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: EnvFile,
+    pub(crate) print_env: bool,
+    pub(crate) list: bool,
     pub(crate) max_recursion_depth: u32,
     pub(crate) malware_settings: MalwareCheckSettings,
     #[cfg(unix)]
@@ -743,6 +791,8 @@ impl RunSettings {
             show_resolution,
             env_file,
             no_env_file,
+            print_env,
+            list,
             max_recursion_depth,
         } = args;
 
@@ -844,6 +894,8 @@ impl RunSettings {
                 &environment,
             )?,
             env_file: EnvFile::from_args(env_file, no_env_file),
+            print_env,
+            list,
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -1035,6 +1087,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) shared: bool,
     pub(crate) editable: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
@@ -1059,6 +1112,7 @@ impl ToolInstallSettings {
             excludes,
             build_constraints,
             lfs,
+            shared,
             installer,
             force,
             build,
@@ -1137,6 +1191,7 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             python_platform,
             force,
+            shared,
             editable,
             refresh: Refresh::try_from(refresh)?,
             options,
@@ -1250,6 +1305,7 @@ pub(crate) struct ToolListSettings {
     pub(crate) show_with: bool,
     pub(crate) show_extras: bool,
     pub(crate) show_python: bool,
+    pub(crate) show_environments: bool,
     pub(crate) outdated: bool,
     pub(crate) args: ResolverInstallerOptions,
     pub(crate) filesystem: ResolverInstallerOptions,
@@ -1267,6 +1323,7 @@ impl ToolListSettings {
             show_with,
             show_extras,
             show_python,
+            show_environments,
             outdated,
             no_outdated,
             exclude_newer:
@@ -1298,6 +1355,7 @@ impl ToolListSettings {
             show_with,
             show_extras,
             show_python,
+            show_environments,
             outdated: flag(outdated, no_outdated, "outdated")?.unwrap_or(false),
             args: ResolverInstallerOptions {
                 exclude_newer,
@@ -2165,6 +2223,7 @@ pub(crate) struct MetadataSettings {
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
+    pub(crate) require_provenance: RequireProvenance,
     pub(crate) malware_settings: MalwareCheckSettings,
 }
 
@@ -2202,6 +2261,18 @@ impl MetadataSettings {
         check_conflicts(locked, frozen)?;
 
         let malware_settings = MalwareCheckSettings::resolve(filesystem.as_ref(), &environment);
+        let require_provenance = filesystem
+            .as_ref()
+            .map(|fs| {
+                RequireProvenance::from_args(
+                    fs.top_level.require_provenance,
+                    fs.top_level
+                        .require_provenance_package
+                        .clone()
+                        .unwrap_or_default(),
+                )
+            })
+            .unwrap_or_default();
 
         Ok(Self {
             script,
@@ -2217,6 +2288,88 @@ impl MetadataSettings {
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::try_from(refresh)?,
             settings: ResolverSettings::resolve(resolver, build, filesystem, &environment)?,
+            require_provenance,
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+            malware_settings,
+        })
+    }
+}
+
+/// The resolved settings to use for a `workspace exec` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspaceExecSettings {
+    pub(crate) command: ExternalCommand,
+    pub(crate) package: Vec<PackageName>,
+    pub(crate) changed_since: Option<String>,
+    pub(crate) parallel: NonZeroUsize,
+    pub(crate) lock_check: LockCheck,
+    pub(crate) frozen: Option<FrozenSource>,
+    pub(crate) active: Option<bool>,
+    pub(crate) no_sync: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+    pub(crate) malware_settings: MalwareCheckSettings,
+}
+
+impl WorkspaceExecSettings {
+    /// Resolve the [`WorkspaceExecSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: Box<WorkspaceExecArgs>,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> anyhow::Result<Self> {
+        let WorkspaceExecArgs {
+            package,
+            changed_since,
+            parallel,
+            active,
+            no_active,
+            no_sync,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+            command,
+        } = *args;
+
+        let filesystem_install_mirrors = filesystem
+            .as_ref()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        // Resolve flags from CLI and environment variables.
+        let locked = resolve_flag(locked, "locked", environment.locked);
+        let frozen = resolve_flag(frozen, "frozen", environment.frozen);
+        let no_sync = resolve_flag(no_sync, "no-sync", environment.no_sync);
+
+        // Check for conflicts between locked and frozen.
+        check_conflicts(locked, frozen)?;
+
+        let malware_settings = MalwareCheckSettings::resolve(filesystem.as_ref(), &environment);
+
+        Ok(Self {
+            command,
+            package,
+            changed_since,
+            parallel,
+            lock_check: resolve_lock_check(locked),
+            frozen: resolve_frozen(frozen),
+            active: flag(active, no_active, "active")?,
+            no_sync: no_sync.is_enabled(),
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::try_from(refresh)?,
+            settings: ResolverInstallerSettings::resolve(
+                installer,
+                build,
+                filesystem,
+                &environment,
+            )?,
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -4146,10 +4299,13 @@ pub(crate) struct BuildSettings {
     pub(crate) gitignore: bool,
     pub(crate) force_pep517: bool,
     pub(crate) clear: bool,
+    pub(crate) check: bool,
+    pub(crate) verify_reproducible: bool,
+    pub(crate) repair: bool,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
-    pub(crate) python: Option<String>,
+    pub(crate) python: Vec<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -4172,6 +4328,9 @@ impl BuildSettings {
             list,
             force_pep517,
             clear,
+            check,
+            verify_reproducible,
+            repair,
             build_constraints,
             hash_checking:
                 HashCheckingArgs {
@@ -4218,6 +4377,9 @@ impl BuildSettings {
             build_logs: flag(build_logs, no_build_logs, "build-logs")?.unwrap_or(true),
             force_pep517,
             clear,
+            check,
+            verify_reproducible,
+            repair,
             gitignore: flag(create_gitignore, no_create_gitignore, "create-gitignore")?
                 .unwrap_or(true),
             build_constraints: build_constraints
@@ -4229,7 +4391,7 @@ impl BuildSettings {
                 flag(require_hashes, no_require_hashes, "require-hashes")?,
                 flag(verify_hashes, no_verify_hashes, "verify-hashes")?,
             ),
-            python: python.and_then(Maybe::into_option),
+            python: python.into_iter().filter_map(Maybe::into_option).collect(),
             refresh: Refresh::try_from(refresh)?,
             settings: ResolverSettings::resolve(resolver, build, filesystem, &environment)?,
             install_mirrors: environment
@@ -4252,6 +4414,7 @@ pub(crate) struct VenvSettings {
     pub(crate) system_site_packages: bool,
     pub(crate) relocatable: bool,
     pub(crate) no_relocatable: bool,
+    pub(crate) parents: Vec<PathBuf>,
     pub(crate) no_project: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
@@ -4278,6 +4441,7 @@ impl VenvSettings {
             system_site_packages,
             relocatable,
             no_relocatable,
+            parent,
             index_args,
             registry_client:
                 RegistryClientArgs {
@@ -4326,6 +4490,7 @@ impl VenvSettings {
             no_project,
             relocatable: relocatable.into(),
             no_relocatable: no_relocatable.into(),
+            parents: parent,
             refresh: Refresh::try_from(refresh)?,
             settings: PipSettings::combine(
                 PipOptions {
@@ -4365,6 +4530,7 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) reinstall: &'a Reinstall,
+    pub(crate) require_provenance: &'a RequireProvenance,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: NoSources,
 }
@@ -4529,6 +4695,7 @@ pub(crate) struct ResolverInstallerSettings {
     pub(crate) resolver: ResolverSettings,
     pub(crate) compile_bytecode: bool,
     pub(crate) reinstall: Reinstall,
+    pub(crate) require_provenance: RequireProvenance,
 }
 
 impl ResolverInstallerSettings {
@@ -4638,6 +4805,10 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
             },
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
             reinstall: value.reinstall.unwrap_or_default(),
+            require_provenance: RequireProvenance::from_args(
+                value.require_provenance,
+                value.require_provenance_package.unwrap_or_default(),
+            ),
         }
     }
 }
@@ -4699,6 +4870,7 @@ pub(crate) struct PipSettings {
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) upgrade: Upgrade,
     pub(crate) reinstall: Reinstall,
+    pub(crate) require_provenance: RequireProvenance,
 }
 
 impl PipSettings {
@@ -4807,6 +4979,8 @@ impl PipSettings {
             compile_bytecode: top_level_compile_bytecode,
             no_sources: top_level_no_sources,
             no_sources_package: top_level_no_sources_package,
+            require_provenance: top_level_require_provenance,
+            require_provenance_package: top_level_require_provenance_package,
             upgrade: top_level_upgrade,
             upgrade_package: top_level_upgrade_package,
             reinstall: top_level_reinstall,
@@ -5076,6 +5250,10 @@ impl PipSettings {
                     top_level_no_build_package.unwrap_or_default(),
                 )),
             ),
+            require_provenance: RequireProvenance::from_args(
+                top_level_require_provenance,
+                top_level_require_provenance_package.unwrap_or_default(),
+            ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -5099,6 +5277,7 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
             link_mode: settings.resolver.link_mode,
             compile_bytecode: settings.compile_bytecode,
             reinstall: &settings.reinstall,
+            require_provenance: &settings.require_provenance,
             build_options: &settings.resolver.build_options,
             sources: settings.resolver.sources.clone(),
         }
@@ -5112,10 +5291,16 @@ pub(crate) struct PublishSettings {
     pub(crate) files: Vec<String>,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
-    pub(crate) index: Option<String>,
+    pub(crate) index: Vec<String>,
+    pub(crate) to_directory: Option<PathBuf>,
     pub(crate) dry_run: bool,
     pub(crate) no_attestations: bool,
+    pub(crate) attest: bool,
+    pub(crate) fulcio_url: Option<DisplaySafeUrl>,
+    pub(crate) rekor_url: Option<DisplaySafeUrl>,
+    pub(crate) check: bool,
     pub(crate) direct: bool,
+    pub(crate) output_format: PublishFormat,
 
     // Both CLI and configuration.
     pub(crate) publish_url: DisplaySafeUrl,
@@ -5134,9 +5319,15 @@ impl fmt::Debug for PublishSettings {
             .field("username", &self.username)
             .field("password", &self.password.as_ref().map(|_| "****"))
             .field("index", &self.index)
+            .field("to_directory", &self.to_directory)
             .field("dry_run", &self.dry_run)
             .field("no_attestations", &self.no_attestations)
+            .field("attest", &self.attest)
+            .field("fulcio_url", &self.fulcio_url)
+            .field("rekor_url", &self.rekor_url)
+            .field("check", &self.check)
             .field("direct", &self.direct)
+            .field("output_format", &self.output_format)
             .field("publish_url", &self.publish_url)
             .field("trusted_publishing", &self.trusted_publishing)
             .field("keyring_provider", &self.keyring_provider)
@@ -5181,7 +5372,12 @@ impl PublishSettings {
             password,
             dry_run: args.dry_run,
             no_attestations: args.no_attestations,
+            attest: args.attest,
+            fulcio_url: args.fulcio_url,
+            rekor_url: args.rekor_url,
+            check: args.check,
             direct: args.direct,
+            output_format: args.output_format,
             publish_url: args
                 .publish_url
                 .combine(publish_url)
@@ -5195,6 +5391,7 @@ impl PublishSettings {
                 .unwrap_or_default(),
             check_url: args.check_url.combine(check_url),
             index: args.index,
+            to_directory: args.to_directory,
             index_locations: IndexLocations::new(
                 index
                     .into_iter()
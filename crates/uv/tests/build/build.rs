@@ -472,6 +472,125 @@ fn build_sdist_wheel() -> Result<()> {
     Ok(())
 }
 
+/// Build a pure-Python wheel with multiple interpreters; the identical wheels are kept once.
+#[test]
+fn build_matrix() -> Result<()> {
+    let context =
+        uv_test::test_context_with_versions!(&["3.11", "3.12"]).with_filter((r"\\\.", ""));
+
+    let project = context.temp_dir.child("project");
+
+    let pyproject_toml = project.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    project.child("README").touch()?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--preview-features")
+        .arg("build-matrix")
+        .arg("--python")
+        .arg("3.11")
+        .arg("--python")
+        .arg("3.12")
+        .current_dir(&project), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Building wheel from source distribution...
+    Successfully built dist/project-0.1.0.tar.gz
+    Successfully built dist/project-0.1.0-py3-none-any.whl
+    Built 1 wheel for Python 3.11, 3.12:
+      project-0.1.0-py3-none-any.whl (3.11, 3.12)
+    ");
+
+    project
+        .child("dist")
+        .child("project-0.1.0.tar.gz")
+        .assert(predicate::path::is_file());
+    project
+        .child("dist")
+        .child("project-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
+/// Wheels with the same filename must be identical across interpreters, as only one is kept.
+#[test]
+fn build_matrix_mismatch() -> Result<()> {
+    let context =
+        uv_test::test_context_with_versions!(&["3.11", "3.12"]).with_filter((r"\\\.", ""));
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    // A backend that embeds the interpreter version in an otherwise pure-Python wheel.
+    project.child("backend.py").write_str(indoc! {r#"
+        import os
+        import sys
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "project-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr("project.py", f"PYTHON = {sys.version_info[:2]!r}\n")
+                wheel.writestr(
+                    "project-0.1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.4\nName: project\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    "project-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr("project-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--preview-features")
+        .arg("build-matrix")
+        .arg("--wheel")
+        .arg("--python")
+        .arg("3.11")
+        .arg("--python")
+        .arg("3.12")
+        .current_dir(&project), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Building wheel...
+    Building wheel...
+    error: Failed to build `[TEMP_DIR]/project`
+      Caused by: The wheels built with Python `3.11` and `3.12` are both named `project-0.1.0-py3-none-any.whl`, but their contents differ
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn build_wheel_from_sdist() -> Result<()> {
     let context = uv_test::test_context!("3.12").with_filter((r"\\\.", ""));
@@ -2190,6 +2309,221 @@ fn build_fast_path_verbose() -> Result<()> {
     Ok(())
 }
 
+/// Build twice and compare the distributions, with the timestamp from `SOURCE_DATE_EPOCH`.
+#[test]
+fn build_verify_reproducible() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.5.15,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--preview-features")
+        .arg("reproducible-builds")
+        .arg("--verify-reproducible")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1700000000")
+        .current_dir(&project), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Building source distribution...
+    Building wheel from source distribution...
+    Successfully built dist/project-0.1.0.tar.gz
+    Successfully built dist/project-0.1.0-py3-none-any.whl
+    Verified that project-0.1.0.tar.gz is reproducible
+    Verified that project-0.1.0-py3-none-any.whl is reproducible
+    ");
+
+    // Only the distributions of the first build are kept.
+    let dist = fs_err::read_dir(project.child("dist").path())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<std::collections::BTreeSet<_>>>()?;
+    assert_eq!(
+        dist.into_iter().collect::<Vec<_>>(),
+        [
+            ".gitignore",
+            "project-0.1.0-py3-none-any.whl",
+            "project-0.1.0.tar.gz"
+        ]
+    );
+
+    Ok(())
+}
+
+/// A build that embeds the current time isn't reproducible; the differences are reported and the
+/// build fails.
+#[test]
+fn build_verify_reproducible_failure() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    project.child("backend.py").write_str(indoc! {r#"
+        import os
+        import time
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "project-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr("project.py", f"BUILT = {time.time_ns()}\n")
+                wheel.writestr(
+                    "project-0.1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.4\nName: project\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    "project-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr("project-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--preview-features")
+        .arg("reproducible-builds")
+        .arg("--verify-reproducible")
+        .arg("--wheel")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1700000000")
+        .current_dir(&project), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Building wheel...
+    Building wheel...
+    Successfully built dist/project-0.1.0-py3-none-any.whl
+    project-0.1.0-py3-none-any.whl is not reproducible:
+      `project.py`: the contents differ
+    ");
+
+    Ok(())
+}
+
+/// The uv build backend uses `SOURCE_DATE_EPOCH` as the modification time of all archive members,
+/// with both tar implementations.
+#[test]
+fn build_source_date_epoch() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.5.15,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+
+    let modification_times = indoc! {r#"
+        import tarfile
+        import zipfile
+
+        with tarfile.open("dist/project-0.1.0.tar.gz") as sdist:
+            print(sorted({member.mtime for member in sdist.getmembers()}))
+        with zipfile.ZipFile("dist/project-0.1.0-py3-none-any.whl") as wheel:
+            print(sorted({info.date_time for info in wheel.infolist()}))
+    "#};
+
+    context
+        .build()
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1700000000")
+        .current_dir(&project)
+        .assert()
+        .success();
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-c")
+        .arg(modification_times)
+        .current_dir(&project), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    [1700000000]
+    [(2023, 11, 14, 22, 13, 20)]
+    ");
+
+    context
+        .build()
+        .arg("--clear")
+        .arg("--preview-features")
+        .arg("tar-codec")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1700000000")
+        .current_dir(&project)
+        .assert()
+        .success();
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-c")
+        .arg(modification_times)
+        .current_dir(&project), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    [1700000000]
+    [(2023, 11, 14, 22, 13, 20)]
+    ");
+
+    Ok(())
+}
+
+/// Pure Python wheels don't need repairs and are kept as-is with `--repair`.
+#[test]
+fn build_repair_pure_python() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.5.15,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--preview-features")
+        .arg("wheel-repair")
+        .arg("--repair")
+        .current_dir(&project), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building source distribution...
+    Building wheel from source distribution...
+    Successfully built dist/project-0.1.0.tar.gz
+    Successfully built dist/project-0.1.0-py3-none-any.whl
+    ");
+
+    project
+        .child("dist")
+        .child("project-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
 /// Reject path-shaped script entry point names before writing wheel metadata.
 #[test]
 fn build_unsafe_script_entry_point_name() -> Result<()> {
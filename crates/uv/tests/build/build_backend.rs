@@ -1825,3 +1825,88 @@ fn rewrite_toml_1_1_by_default() -> Result<()> {
 
     Ok(())
 }
+
+/// Build a wheel with native artifacts through the `uv_build` PEP 517 hooks, which describe the
+/// interpreter to the build backend for the wheel tags.
+///
+/// Since we don't have a `uv_build` wheel, the hooks are loaded as an in-tree backend that calls
+/// the `uv` binary under test.
+#[test]
+#[cfg(target_os = "linux")]
+fn native_artifacts_pep517() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filter((r"linux_[a-z0-9_]+", "linux_[ARCH]"));
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = []
+        build-backend = "uv_build"
+        backend-path = ["_backend"]
+
+        [tool.uv.build-backend]
+        build-command = ["python", "-c", "open('src/project/_native.so', 'wb').close()"]
+        native-include = ["src/project/*.so"]
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+    let hooks = fs_err::read_to_string("../uv-build/python/uv_build/__init__.py")?
+        .replace("USE_UV_EXECUTABLE = False", "USE_UV_EXECUTABLE = True");
+    project
+        .child("_backend/uv_build/__init__.py")
+        .write_str(&hooks)?;
+
+    let uv_dir = uv_test::get_bin!().parent().unwrap().to_path_buf();
+    let path = std::env::join_paths(std::iter::once(uv_dir).chain(std::env::split_paths(
+        &std::env::var_os(EnvVars::PATH).unwrap_or_default(),
+    )))?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--wheel")
+        .env(EnvVars::UV_PREVIEW_FEATURES, "native-artifacts")
+        .env(EnvVars::PATH, path)
+        .current_dir(&project), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Building wheel...
+    warning: Expected `build-system.requires` to contain only `uv_build`, found ``
+    Successfully built dist/project-0.1.0-cp312-cp312-linux_[ARCH].whl
+    ");
+
+    context
+        .pip_install()
+        .arg(
+            fs_err::read_dir(project.child("dist").path())?
+                .next()
+                .unwrap()?
+                .path(),
+        )
+        .assert()
+        .success();
+    assert!(
+        context
+            .site_packages()
+            .join("project")
+            .join("_native.so")
+            .is_file()
+    );
+    let wheel = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("project-0.1.0.dist-info")
+            .join("WHEEL"),
+    )?;
+    let wheel = wheel.replace(uv_version::version(), "[VERSION]");
+    let wheel = regex::Regex::new(r"linux_[a-z0-9_]+")?.replace(&wheel, "linux_[ARCH]");
+    assert_snapshot!(wheel, @"
+    Wheel-Version: 1.0
+    Generator: uv [VERSION]
+    Root-Is-Purelib: false
+    Tag: cp312-cp312-linux_[ARCH]
+    ");
+
+    Ok(())
+}
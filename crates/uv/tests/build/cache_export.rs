@@ -0,0 +1,78 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+
+use uv_fs::Simplified;
+use uv_test::uv_snapshot;
+
+/// Export the cache entries for a lockfile, then import them into an empty cache and sync offline.
+#[test]
+fn cache_export_import() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (
+                r"(Exported|Imported) \d+ files \([^)]+\)",
+                "$1 [N] files ([SIZE])",
+            ),
+            (r"\(\d+ already present\)", "([N] already present)"),
+        ])
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["{}"]
+        "#,
+        context.workspace_root.join("test/links").portable_display()
+    })?;
+
+    // Populate the cache.
+    context.sync().assert().success();
+
+    uv_snapshot!(filters, context.cache_export().arg("--preview-features").arg("cache-export").arg("-o").arg("cache.tar.zst"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Exported [N] files ([SIZE]) to: cache.tar.zst
+    ");
+
+    // Start from an empty cache and environment.
+    context.clean().assert().success();
+    fs_err::remove_dir_all(context.temp_dir.child(".venv"))?;
+
+    uv_snapshot!(filters, context.cache_import().arg("--preview-features").arg("cache-export").arg("cache.tar.zst"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Imported [N] files ([SIZE]) into: [CACHE_DIR]/
+    ");
+
+    // The imported entries are sufficient to sync without network access.
+    uv_snapshot!(filters, context.sync().arg("--offline"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+
+    // Importing the same archive again retains the existing entries.
+    uv_snapshot!(filters, context.cache_import().arg("--preview-features").arg("cache-export").arg("cache.tar.zst"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Imported [N] files ([SIZE]) into: [CACHE_DIR]/ ([N] already present)
+    ");
+
+    Ok(())
+}
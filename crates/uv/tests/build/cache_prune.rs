@@ -468,3 +468,178 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --max-age` should evict unused archives, but retain any archives that are
+/// linked into an environment.
+#[cfg(unix)]
+#[test]
+fn prune_max_age() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_exclude_newer("2025-01-01T00:00Z")
+        .with_filtered_file_counts()
+        .with_filtered_sizes_and_units();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("hardlink")
+        .assert()
+        .success();
+
+    // Mark every archive as last used 40 days ago.
+    let last_used = filetime::FileTime::from_system_time(
+        std::time::SystemTime::now() - std::time::Duration::from_hours(40 * 24),
+    );
+    for entry in fs_err::read_dir(context.cache_dir.child("archive-v0").path())? {
+        filetime::set_file_mtime(entry?.path(), last_used)?;
+    }
+
+    // The archive is linked into the environment, so it should be retained.
+    uv_snapshot!(context.filters(), context.prune().arg("--max-age").arg("30d"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    warning: Size- and age-bounded cache eviction is experimental and may change without warning. Pass `--preview-features cache-eviction` to disable this warning.
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    ");
+
+    // Once the environment is removed, the archive should be evicted.
+    context.venv().arg("--clear").assert().success();
+
+    uv_snapshot!(context.filters(), context.prune().arg("--max-age").arg("30d").arg("--preview-features").arg("cache-eviction"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    // Reinstalling the package should require a download, since we evicted the wheel.
+    uv_snapshot!(context.filters(), context.pip_install().arg("-r").arg("requirements.txt").arg("--offline"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because all versions of iniconfig need to be downloaded from a registry and you require iniconfig, we can conclude that your requirements are unsatisfiable.
+
+    hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+    ");
+
+    Ok(())
+}
+
+/// `cache prune --max-age` should retain archives that are symlinked into an environment, which
+/// can't be detected from the archive itself.
+#[cfg(unix)]
+#[test]
+fn prune_max_age_symlink() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_file_counts()
+        .with_filtered_sizes_and_units();
+
+    let wheel = context
+        .workspace_root
+        .join("test/links/ok-1.0.0-py3-none-any.whl");
+
+    // Install a local wheel, to populate the cache.
+    context
+        .pip_install()
+        .arg(&wheel)
+        .arg("--link-mode")
+        .arg("symlink")
+        .assert()
+        .success();
+
+    // Mark every archive as last used 40 days ago.
+    let last_used = filetime::FileTime::from_system_time(
+        std::time::SystemTime::now() - std::time::Duration::from_hours(40 * 24),
+    );
+    for entry in fs_err::read_dir(context.cache_dir.child("archive-v0").path())? {
+        let path = entry?.path();
+        if path.is_dir() {
+            filetime::set_file_mtime(path, last_used)?;
+        }
+    }
+
+    // The archive is symlinked into the environment, so it should be retained.
+    uv_snapshot!(context.filters(), context.prune().arg("--max-age").arg("30d").arg("--preview-features").arg("cache-eviction"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    ");
+
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import ok")
+        .assert()
+        .success();
+
+    // Once the environment is removed, the archive should be evicted.
+    context.venv().arg("--clear").assert().success();
+
+    uv_snapshot!(context.filters(), context.prune().arg("--max-age").arg("30d").arg("--preview-features").arg("cache-eviction"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    assert!(
+        fs_err::read_dir(context.cache_dir.child("archive-v0").path())?
+            .next()
+            .is_none()
+    );
+
+    Ok(())
+}
+
+/// `cache prune --max-size` should evict the least-recently-used archives until the cache fits.
+#[test]
+fn prune_max_size() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_exclude_newer("2025-01-01T00:00Z")
+        .with_filtered_file_counts()
+        .with_filtered_sizes_and_units();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache, then remove the environment.
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+    context.venv().arg("--clear").assert().success();
+
+    // A generous limit should leave the cache untouched.
+    uv_snapshot!(context.filters(), context.prune().arg("--max-size").arg("1G").arg("--preview-features").arg("cache-eviction"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    ");
+
+    // A limit of zero should evict every unused archive.
+    uv_snapshot!(context.filters(), context.prune().env(EnvVars::UV_CACHE_MAX_SIZE, "0").arg("--preview-features").arg("cache-eviction"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    assert!(
+        fs_err::read_dir(context.cache_dir.child("archive-v0").path())?
+            .next()
+            .is_none()
+    );
+
+    Ok(())
+}
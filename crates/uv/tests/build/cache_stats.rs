@@ -0,0 +1,67 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use uv_test::uv_snapshot;
+
+/// `cache stats` on a missing cache reports an empty cache.
+#[test]
+fn cache_stats_empty() {
+    let context = uv_test::test_context!("3.12").with_cache_dir("missing-cache");
+
+    uv_snapshot!(context.filters(), context.cache_stats().arg("--preview-features").arg("cache-stats"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Cache at [CACHE_DIR]/: 0B in 0 files (0B reclaimable)
+    ");
+
+    context.cache_dir.assert(predicates::path::missing());
+}
+
+/// `cache stats` attributes the downloaded and unzipped wheels for a package to that package.
+#[test]
+fn cache_stats_by_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let assert = context
+        .cache_stats()
+        .arg("--preview-features")
+        .arg("cache-stats")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--top")
+        .arg("1")
+        .assert()
+        .success();
+    let report: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout)?;
+
+    // The package is listed, and includes the unzipped wheel.
+    let packages = report["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0]["name"], "iniconfig");
+    let archive = report["buckets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|bucket| bucket["bucket"] == "archive-v0")
+        .expect("the archive bucket to be reported");
+    assert!(
+        packages[0]["logical_bytes"].as_u64().unwrap() > archive["logical_bytes"].as_u64().unwrap()
+    );
+
+    // The unzipped wheel was used within the past day.
+    assert_eq!(report["archive_ages"][0]["max_age_days"], 1);
+    assert_eq!(report["archive_ages"][0]["num_archives"], 1);
+
+    Ok(())
+}
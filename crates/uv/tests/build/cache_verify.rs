@@ -0,0 +1,90 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use uv_test::uv_snapshot;
+
+/// `cache verify` removes unzipped wheels that no longer match their recorded hash, such that
+/// they're re-downloaded on next use.
+#[test]
+fn verify_corrupt_archive() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"archive-v0/[A-Za-z0-9_-]+", "archive-v0/[ARCHIVE]"),
+            (r"`[0-9a-f]{64}`", "`[HASH]`"),
+            (r"\d+ metadata entries", "[N] metadata entries"),
+            (r"corrupt entry \([^)]+\)", "corrupt entry ([SIZE])"),
+        ])
+        .collect::<Vec<_>>();
+
+    let links = context.workspace_root.join("test/links");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("ok==1.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links)
+        .assert()
+        .success();
+
+    uv_snapshot!(filters, context.cache_verify().arg("--preview-features").arg("cache-verify"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Checked 1 archive and [N] metadata entries
+    No corrupt entries found
+    ");
+
+    // Simulate a partially written file in the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.path().join("archive-v0"))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .find(|path| path.is_dir())
+        .expect("the unzipped wheel to be in the cache");
+    fs_err::write(archive.join("ok").join("__init__.py"), "")?;
+
+    // The structure of the archive is intact, so a quick check doesn't detect the corruption.
+    uv_snapshot!(filters, context.cache_verify().arg("--quick").arg("--preview-features").arg("cache-verify"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Checked 1 archive and [N] metadata entries
+    No corrupt entries found
+    ");
+
+    uv_snapshot!(filters, context.cache_verify().arg("--preview-features").arg("cache-verify"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Checked 1 archive and [N] metadata entries
+    Removing corrupt cache entry: archive-v0/[HASH] (expected dirhash `[HASH]`, found `[HASH]`)
+    Removed 1 corrupt entry ([SIZE])
+    ");
+    assert!(!archive.exists());
+
+    // The wheel is unzipped again on next use.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links)
+        .arg("--reinstall"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ ok==1.0.0
+    ");
+
+    Ok(())
+}
@@ -18,10 +18,19 @@ mod cache;
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod cache_export;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_prune;
 
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod cache_size;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod cache_stats;
+
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod cache_verify;
+
 mod extract;
@@ -54,6 +54,7 @@ fn help() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
       self                       Manage the uv executable
@@ -61,9 +62,13 @@ fn help() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -135,15 +140,20 @@ fn help_flag() {
       venv       Create a virtual environment
       build      Build Python packages into source distributions and wheels
       publish    Upload distributions to an index
+      index      Manage package indexes
       workspace  Inspect uv workspaces
       cache      Manage uv's cache
       self       Manage the uv executable
       help       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -215,15 +225,20 @@ fn help_short_flag() {
       venv       Create a virtual environment
       build      Build Python packages into source distributions and wheels
       publish    Upload distributions to an index
+      index      Manage package indexes
       workspace  Inspect uv workspaces
       cache      Manage uv's cache
       self       Manage the uv executable
       help       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -279,11 +294,16 @@ fn help_flag_workspace() {
       metadata  View metadata about the current workspace
       dir       Display the path of a workspace member
       list      List the members of a workspace
+      exec      Run a command in each member of a workspace
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -400,6 +420,15 @@ fn help_subcommand() {
 
               [env: UV_CACHE_DIR=]
 
+          --shared-cache <LOCATION>
+              A shared cache for wheels built from source, consulted before building and populated after
+              a successful build.
+
+              Accepts either a directory (e.g., on a network file system) or an HTTP(S) URL that
+              supports `GET` and `PUT` requests.
+
+              [env: UV_SHARED_CACHE=]
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -683,6 +712,15 @@ fn help_subsubcommand() {
 
               [env: UV_CACHE_DIR=]
 
+          --shared-cache <LOCATION>
+              A shared cache for wheels built from source, consulted before building and populated after
+              a successful build.
+
+              Accepts either a directory (e.g., on a network file system) or an HTTP(S) URL that
+              supports `GET` and `PUT` requests.
+
+              [env: UV_SHARED_CACHE=]
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -834,9 +872,13 @@ fn help_flag_subcommand() {
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -915,9 +957,13 @@ fn help_flag_subsubcommand() {
               UV_COMPILE_BYTECODE=]
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -981,6 +1027,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        index
         workspace
         cache
         self
@@ -1010,6 +1057,7 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        index
         workspace
         cache
         self
@@ -1067,6 +1115,7 @@ fn help_with_global_option() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
       self                       Manage the uv executable
@@ -1074,9 +1123,13 @@ fn help_with_global_option() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -1183,6 +1236,7 @@ fn help_with_no_pager() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
       self                       Manage the uv executable
@@ -1190,9 +1244,13 @@ fn help_with_no_pager() {
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                 Avoid reading from or writing to the cache, instead using a
+                                     temporary directory for the duration of the operation [env:
+                                     UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]    Path to the cache directory [env: UV_CACHE_DIR=]
+          --shared-cache <LOCATION>  A shared cache for wheels built from source, consulted before
+                                     building and populated after a successful build [env:
+                                     UV_SHARED_CACHE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
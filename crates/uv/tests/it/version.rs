@@ -3,6 +3,7 @@ use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use insta::assert_snapshot;
+use std::process::Command;
 
 use uv_static::EnvVars;
 use uv_test::{apply_filters, uv_snapshot};
@@ -2067,6 +2068,74 @@ fn version_set_dynamic() -> Result<()> {
     Ok(())
 }
 
+/// A version derived from Git tags by the uv build backend can be read, but not set.
+#[test]
+fn version_dynamic_git() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filter((r"\+g[0-9a-f]{7}", "+g[COMMIT]"));
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "myproject"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+
+        [tool.uv.build-backend]
+        version-source = "git"
+    "#})?;
+
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&context.temp_dir)
+            .env("GIT_AUTHOR_NAME", "ferris")
+            .env("GIT_AUTHOR_EMAIL", "ferris@example.com")
+            .env("GIT_COMMITTER_NAME", "ferris")
+            .env("GIT_COMMITTER_EMAIL", "ferris@example.com")
+            .assert()
+            .success();
+    };
+    git(&["init"]);
+    git(&["add", "pyproject.toml"]);
+    git(&["commit", "-m", "Initial commit"]);
+    git(&["tag", "v1.2.0"]);
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--preview-features")
+        .arg("dynamic-version"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    myproject 1.2.0
+    ");
+
+    // After the tag, we're on a development version of the next release.
+    git(&["commit", "--allow-empty", "-m", "Second commit"]);
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("--short")
+        .arg("--preview-features")
+        .arg("dynamic-version"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    1.2.1.dev1+g[COMMIT]
+    ");
+
+    uv_snapshot!(context.filters(), context.version()
+        .arg("1.3.0")
+        .arg("--preview-features")
+        .arg("dynamic-version"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: The version of `myproject` is derived from Git tags (`tool.uv.build-backend.version-source`), create a Git tag to set it instead: pyproject.toml
+    ");
+
+    Ok(())
+}
+
 /// Previously would fallback to `uv --version` if this pyproject.toml isn't usable for whatever reason
 /// (In this case, because tool.uv.managed = false)
 #[test]
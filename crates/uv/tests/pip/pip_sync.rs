@@ -8,7 +8,7 @@ use fs_err as fs;
 use indoc::{formatdoc, indoc};
 use predicates::Predicate;
 use url::Url;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{method, path, path_regex};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use uv_fs::{Simplified, copy_dir_all};
@@ -1362,6 +1362,276 @@ fn install_local_source_distribution() -> Result<()> {
     Ok(())
 }
 
+/// Share a wheel built from a local source distribution via a shared cache directory, such that
+/// it can be installed with a cold local cache, without building (and thus, offline).
+#[test]
+fn install_local_source_distribution_shared_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Download a source distribution.
+    let archive = context.temp_dir.child("wheel-0.42.0.tar.gz");
+    download_to_disk(
+        "https://files.pythonhosted.org/packages/b0/b4/bc2baae3970c282fae6c2cb8e0f179923dceb7eaffb0e76170628f9af97b/wheel-0.42.0.tar.gz",
+        &archive,
+    );
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "wheel @ {}",
+        Url::from_file_path(archive.path()).unwrap()
+    ))?;
+
+    let shared = context.temp_dir.child("shared");
+
+    // Build the wheel, populating the shared cache.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--shared-cache")
+        .arg(shared.path())
+        .arg("--preview-features")
+        .arg("shared-cache"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + wheel==0.42.0 (from file://[TEMP_DIR]/wheel-0.42.0.tar.gz)
+    "
+    );
+
+    shared
+        .child("built-wheels-v0")
+        .child("sha256")
+        .assert(predicates::path::is_dir());
+
+    // Clear the local cache, and reinstall offline; the wheel is fetched from the shared cache
+    // rather than built.
+    context.clean().assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--offline")
+        .arg("--shared-cache")
+        .arg(shared.path())
+        .arg("--preview-features")
+        .arg("shared-cache"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ wheel==0.42.0 (from file://[TEMP_DIR]/wheel-0.42.0.tar.gz)
+    "
+    );
+
+    context.assert_command("import wheel").success();
+
+    Ok(())
+}
+
+/// Write a project with an in-tree build backend that records each build in the `BUILD_LOG` file.
+fn logging_backend_project(context: &uv_test::TestContext) -> Result<ChildPath> {
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    project.child("backend.py").write_str(indoc! {r#"
+        import os
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            with open(os.environ["BUILD_LOG"], "a") as log:
+                log.write("build_wheel\n")
+            filename = "project-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr("project.py", "")
+                wheel.writestr(
+                    "project-0.1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.4\nName: project\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    "project-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr("project-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#})?;
+    context
+        .temp_dir
+        .child("requirements.txt")
+        .write_str(&format!(
+            "project @ {}",
+            Url::from_directory_path(project.path()).unwrap()
+        ))?;
+    Ok(project)
+}
+
+/// Share a wheel built from a local directory via a shared cache directory, such that it can be
+/// installed with a cold local cache without building.
+#[test]
+fn install_source_tree_shared_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    logging_backend_project(&context)?;
+    let build_log = context.temp_dir.child("build.log");
+    let shared = context.temp_dir.child("shared");
+
+    // Build the wheel, populating the shared cache.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--shared-cache")
+        .arg(shared.path())
+        .arg("--preview-features")
+        .arg("shared-cache")
+        .env("BUILD_LOG", build_log.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    ");
+
+    shared
+        .child("built-wheels-v0")
+        .child("dirhash")
+        .assert(predicates::path::is_dir());
+
+    // Clear the local cache, and reinstall; the wheel is fetched from the shared cache rather
+    // than built.
+    context.clean().assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--shared-cache")
+        .arg(shared.path())
+        .arg("--preview-features")
+        .arg("shared-cache")
+        .env("BUILD_LOG", build_log.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/project)
+    ");
+
+    assert_eq!(fs::read_to_string(build_log.path())?, "build_wheel\n");
+
+    Ok(())
+}
+
+/// Share a wheel built from a local directory via a shared cache server, which is read with `GET`
+/// requests and populated with `PUT` requests.
+#[tokio::test]
+async fn install_source_tree_shared_cache_http() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    logging_backend_project(&context)?;
+    let build_log = context.temp_dir.child("build.log");
+
+    let entry = r"^/uv/built-wheels-v0/dirhash/[0-9a-f]{64}/cp312-cp312-[a-z0-9_]+";
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("{entry}/filename$")))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path_regex(format!(
+            "{entry}/(filename|project-0.1.0-py3-none-any.whl)$"
+        )))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(2)
+        .mount(&server)
+        .await;
+    let shared = format!("{}/uv/", server.uri());
+
+    // Build the wheel, and upload it to the shared cache.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--shared-cache")
+        .arg(&shared)
+        .arg("--preview-features")
+        .arg("shared-cache")
+        .env("BUILD_LOG", build_log.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    ");
+
+    // The wheel is uploaded before the pointer to it.
+    let requests = server.received_requests().await.unwrap();
+    let uploads = requests
+        .iter()
+        .filter(|request| request.method == wiremock::http::Method::PUT)
+        .collect::<Vec<_>>();
+    let [wheel, pointer] = uploads.as_slice() else {
+        panic!("Expected two uploads, found: {uploads:?}");
+    };
+    assert!(
+        wheel
+            .url
+            .path()
+            .ends_with("/project-0.1.0-py3-none-any.whl")
+    );
+    assert!(pointer.url.path().ends_with("/filename"));
+    assert_eq!(pointer.body, b"project-0.1.0-py3-none-any.whl");
+    server.verify().await;
+
+    // Serve the uploaded wheel, then clear the local cache and reinstall; the wheel is downloaded
+    // from the shared cache rather than built.
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path(pointer.url.path()))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(pointer.body.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(wheel.url.path()))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel.body.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+    context.clean().assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .arg("--shared-cache")
+        .arg(&shared)
+        .arg("--preview-features")
+        .arg("shared-cache")
+        .env("BUILD_LOG", build_log.path()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/project)
+    ");
+
+    assert_eq!(fs::read_to_string(build_log.path())?, "build_wheel\n");
+    context.assert_command("import project").success();
+
+    Ok(())
+}
+
 /// This package includes a `[build-system]`, but no `build-backend`.
 ///
 /// It lists some explicit build requirements that are necessary to build the distribution:
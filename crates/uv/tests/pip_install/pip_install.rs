@@ -1,6 +1,7 @@
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
 
 use anyhow::{Context, Result, anyhow};
 use assert_cmd::prelude::*;
@@ -33,6 +34,7 @@ use uv_test::archive::write_tar_gz;
 use uv_test::decode_token;
 use uv_test::find_links::FindLinksServer;
 use uv_test::packse::PackseServer;
+use uv_test::simple_index::SimpleIndexServer;
 use uv_test::{
     DEFAULT_PYTHON_VERSION, TestContext, apply_filters, download_to_disk, get_bin, uv_snapshot,
     venv_bin_path,
@@ -2794,6 +2796,189 @@ fn install_git_full_commit_ignores_same_named_branch() -> Result<()> {
     Ok(())
 }
 
+/// Install packages from subdirectories of a local Git repository, which should use a partial
+/// clone and a sparse checkout; widen the checkout for a second subdirectory; and fetch the
+/// remaining objects before a full checkout, such that later checkouts work offline.
+#[test]
+#[cfg(feature = "test-git")]
+fn install_git_subdirectory_sparse_checkout() -> Result<()> {
+    let context = uv_test::test_context!(DEFAULT_PYTHON_VERSION);
+
+    let repository = context.temp_dir.child("repository");
+    for (directory, name) in [(".", "root"), ("packages/a", "a"), ("packages/b", "b")] {
+        let package = repository.child(directory);
+        package.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+
+            [build-system]
+            requires = []
+            build-backend = "backend"
+            backend-path = ["."]
+        "#})?;
+        package.child("backend.py").write_str(&formatdoc! {r#"
+            import os
+            import zipfile
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                filename = "{name}-0.1.0-py3-none-any.whl"
+                with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                    wheel.writestr("{name}.py", "")
+                    wheel.writestr(
+                        "{name}-0.1.0.dist-info/METADATA",
+                        "Metadata-Version: 2.4\nName: {name}\nVersion: 0.1.0\n",
+                    )
+                    wheel.writestr(
+                        "{name}-0.1.0.dist-info/WHEEL",
+                        "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                    )
+                    wheel.writestr("{name}-0.1.0.dist-info/RECORD", "")
+                return filename
+        "#})?;
+    }
+    repository.child("data/blob.txt").write_str("data")?;
+
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(repository.path())
+            .args(args)
+            .env("GIT_AUTHOR_DATE", "2000-01-01T00:00:00Z")
+            .env("GIT_COMMITTER_DATE", "2000-01-01T00:00:00Z")
+            .assert()
+            .success()
+    };
+    git(&["init"]);
+    git(&["config", "uploadpack.allowFilter", "true"]);
+    git(&["add", "."]);
+    git(&[
+        "-c",
+        "user.name=ferris",
+        "-c",
+        "user.email=ferris@example.com",
+        "commit",
+        "-m",
+        "Initial commit",
+    ]);
+    let commit = String::from_utf8(git(&["rev-parse", "HEAD"]).get_output().stdout.clone())?;
+    let commit = commit.trim();
+
+    let url = format!(
+        "git+{}@{commit}",
+        Url::from_directory_path(repository.path())
+            .unwrap()
+            .as_str()
+            .trim_end_matches('/')
+    );
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"@[0-9a-f]{40}", "@[COMMIT]")])
+        .collect::<Vec<_>>();
+
+    // Returns the single entry in the given cache directory.
+    let entry = |path: PathBuf| -> Result<PathBuf> {
+        let mut entries = fs::read_dir(path)?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(entries.len(), 1);
+        Ok(entries.remove(0).path())
+    };
+    let is_partial = |db: &Path| {
+        Command::new("git")
+            .arg("-C")
+            .arg(db)
+            .args([
+                "config",
+                "--get-regexp",
+                r"^remote\..*\.partialclonefilter$",
+            ])
+            .output()
+            .unwrap()
+            .status
+            .success()
+    };
+    let git_cache = context.cache_dir.child("git-v0");
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg(format!("a @ {url}#subdirectory=packages/a")), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + a==0.1.0 (from git+file://[TEMP_DIR]/repository@[COMMIT]#subdirectory=packages/a)
+    ");
+
+    // Only the requested subdirectory (and the files at the root) should be checked out.
+    let db = entry(git_cache.join("db"))?;
+    let checkout = entry(entry(git_cache.join("checkouts"))?)?;
+    assert!(is_partial(&db));
+    assert!(checkout.join("packages/a/backend.py").is_file());
+    assert!(checkout.join("backend.py").is_file());
+    assert!(!checkout.join("packages/b/backend.py").exists());
+    assert!(!checkout.join("data").exists());
+
+    // Installing a second subdirectory should widen the existing checkout.
+    uv_snapshot!(filters, context.pip_install()
+        .arg(format!("b @ {url}#subdirectory=packages/b")), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + b==0.1.0 (from git+file://[TEMP_DIR]/repository@[COMMIT]#subdirectory=packages/b)
+    ");
+
+    assert!(is_partial(&db));
+    assert!(checkout.join("packages/a/backend.py").is_file());
+    assert!(checkout.join("packages/b/backend.py").is_file());
+    assert!(!checkout.join("data").exists());
+
+    // Installing the repository root should fetch the remaining objects, and check out the
+    // entire repository.
+    uv_snapshot!(filters, context.pip_install()
+        .arg(format!("root @ {url}")), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + root==0.1.0 (from git+file://[TEMP_DIR]/repository@[COMMIT])
+    ");
+
+    assert!(!is_partial(&db));
+    assert!(checkout.join("data/blob.txt").is_file());
+
+    // Remove the repository, the checkouts, and the built wheels; the database is complete, so
+    // the packages can be checked out and rebuilt offline.
+    fs::remove_dir_all(&repository)?;
+    for entry in fs::read_dir(context.cache_dir.path())? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() && entry.file_name() != "git-v0" {
+            fs::remove_dir_all(entry.path())?;
+        }
+    }
+    fs::remove_dir_all(git_cache.join("checkouts"))?;
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("--offline")
+        .arg("--reinstall")
+        .arg(format!("a @ {url}#subdirectory=packages/a"))
+        .arg(format!("b @ {url}#subdirectory=packages/b")), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     ~ a==0.1.0 (from git+file://[TEMP_DIR]/repository@[COMMIT]#subdirectory=packages/a)
+     ~ b==0.1.0 (from git+file://[TEMP_DIR]/repository@[COMMIT]#subdirectory=packages/b)
+    ");
+
+    Ok(())
+}
+
 /// Install a package from a public GitHub repository, omitting the `git+` prefix
 #[test]
 #[cfg(feature = "test-git")]
@@ -6821,34 +7006,100 @@ async fn install_package_basic_auth_from_netrc_default() -> Result<()> {
 }
 
 /// Install a package from an index that requires authentication
-#[tokio::test]
-async fn install_package_basic_auth_from_netrc() -> Result<()> {
+#[test]
+fn install_package_basic_auth_from_netrc() -> Result<()> {
     let context = uv_test::test_context!("3.12");
-    let proxy = crate::pypi_proxy::start().await;
+
+    let dist = context.temp_dir.child("dist");
+    dist.create_dir_all()?;
+    fs_err::copy(
+        context.workspace_root.join("test/links/ok-1.0.0-py3-none-any.whl"),
+        dist.child("ok-1.0.0-py3-none-any.whl"),
+    )?;
+    let server = SimpleIndexServer::with_credentials(&dist, "public", "heron");
+    let index_url = Url::parse(server.index_url())?;
+
     let netrc = context.temp_dir.child(".netrc");
     netrc.write_str(&format!(
         "machine {} login public password heron",
-        proxy.host()
+        index_url.host_str().unwrap()
     ))?;
 
     uv_snapshot!(context.filters(), context.pip_install()
-        .arg("anyio")
+        .arg("ok")
         .arg("--index-url")
-        .arg(proxy.url("/basic-auth/simple"))
+        .arg(server.index_url())
         .env(EnvVars::NETRC, netrc.to_str().unwrap())
-        .arg("--strict"), @"
-    exit_code: 0 (success)
-    ----- stderr -----
-    Resolved 3 packages in [TIME]
-    Prepared 3 packages in [TIME]
-    Installed 3 packages in [TIME]
-     + anyio==4.3.0
-     + idna==3.6
-     + sniffio==1.3.1
-    "
-    );
+        .arg("--strict"), @"");
 
-    context.assert_command("import anyio").success();
+    context.assert_command("import ok").success();
+
+    Ok(())
+}
+
+/// Install a package from an index served by `uv index serve`, with the password read from the
+/// environment.
+#[test]
+fn install_package_from_index_serve() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let dist = context.temp_dir.child("dist");
+    dist.create_dir_all()?;
+    for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context.workspace_root.join("test/links").join(filename),
+            dist.child(filename),
+        )?;
+    }
+
+    let mut server = context
+        .command()
+        .arg("index")
+        .arg("serve")
+        .arg(dist.path())
+        .arg("--port")
+        .arg("0")
+        .arg("--username")
+        .arg("public")
+        .arg("--preview-features")
+        .arg("index-serve")
+        .env(EnvVars::UV_SERVE_PASSWORD, "heron")
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Wait for the server to report the URL it's listening on.
+    let mut stderr = BufReader::new(server.stderr.take().unwrap());
+    let mut line = String::new();
+    stderr.read_line(&mut line)?;
+    let index_url = line
+        .trim()
+        .rsplit_once(" at: ")
+        .map(|(_, url)| url.to_string())
+        .with_context(|| format!("Unexpected output from `uv index serve`: {line}"))?;
+
+    // Without credentials, the index rejects the request.
+    let output = context
+        .pip_install()
+        .arg("ok")
+        .arg("--index-url")
+        .arg(&index_url)
+        .output()?;
+    assert!(!output.status.success());
+
+    let mut authenticated = Url::parse(&index_url)?;
+    authenticated.set_username("public").unwrap();
+    authenticated.set_password(Some("heron")).unwrap();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok")
+        .arg("--index-url")
+        .arg(authenticated.as_str())
+        .arg("--strict"), @"");
+
+    context.assert_command("import ok").success();
+
+    server.kill()?;
+    server.wait()?;
 
     Ok(())
 }
@@ -16749,3 +17000,78 @@ fn compile_bytecode_excludes_stdlib() -> Result<()> {
 
     Ok(())
 }
+
+/// Packages provided by a parent environment are treated as installed, unless a different version
+/// is requested.
+#[test]
+fn install_satisfied_by_parent_environment() {
+    let context = uv_test::test_context!("3.12");
+    let links = context.workspace_root.join("test/links");
+
+    // Install a package into a parent environment.
+    context
+        .venv()
+        .arg("base")
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("--python")
+        .arg("base")
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links)
+        .assert()
+        .success();
+
+    // Layer the environment on top of the parent.
+    context
+        .venv()
+        .arg("--clear")
+        .arg("--parent")
+        .arg("base")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("layered-environments")
+        .assert()
+        .success();
+
+    // The parent provides the requested version, so nothing is installed.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    ");
+
+    // A different version is installed into the environment itself, shadowing the parent.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("ok==2.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    // The parent environment is left untouched.
+    uv_snapshot!(context.filters(), context.pip_freeze().arg("--python").arg("base"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    ok==1.0.0
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: base
+    ");
+}
@@ -5084,6 +5084,242 @@ fn run_with_not_existing_env_file() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_with_project_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.environment]
+        variables = { REBEL_1 = "leia_organa", REBEL_2 = "obi_wan_kenobi" }
+        path-prepend = ["tools"]
+        env-files = [".env", ".env.missing"]
+        "#
+    })?;
+
+    context.temp_dir.child(".env").write_str(indoc! { "
+        REBEL_2=han_solo
+        REBEL_3=C3PO
+       "
+    })?;
+
+    context.temp_dir.child("test.py").write_str(indoc! { "
+        import os
+        print(os.environ.get('REBEL_1'))
+        print(os.environ.get('REBEL_2'))
+        print(os.environ.get('REBEL_3'))
+        print(os.environ['PATH'].split(os.pathsep)[1] == os.path.abspath('tools'))
+       "
+    })?;
+
+    // Static variables take precedence over those read from environment files.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("project-environment-variables").arg("test.py"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    leia_organa
+    obi_wan_kenobi
+    C3PO
+    True
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // Variables set in the calling environment take precedence.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("project-environment-variables").arg("test.py").env("REBEL_1", "luke_skywalker"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    luke_skywalker
+    obi_wan_kenobi
+    C3PO
+    True
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // The variables are written into the activation scripts.
+    let activate = fs_err::read_to_string(uv_test::venv_bin_path(&context.venv).join("activate"))?;
+    assert!(activate.contains("REBEL_1='leia_organa'"));
+
+    Ok(())
+}
+
+#[test]
+fn run_task() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = ["iniconfig==2.0.0"]
+
+        [tool.uv.scripts]
+        greet = "python -c 'import sys; print(\"hello\", *sys.argv[1:])'"
+        test = { cmd = ["python", "-c", "import iniconfig, os; print(os.environ['MODE'])"], env = { MODE = "testing" }, groups = ["test"], depends-on = ["greet"] }
+        check = { depends-on = ["greet", "fail", "test"], help = "Run all checks" }
+        fail = "python -c 'raise SystemExit(3)'"
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("project-tasks").arg("--list"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    check  Run all checks
+    fail   python -c 'raise SystemExit(3)'
+    greet  python -c 'import sys; print(\"hello\", *sys.argv[1:])'
+    test   python -c \"import iniconfig, os; print(os.environ['MODE'])\"
+    ");
+
+    // Additional arguments are forwarded to the task.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("project-tasks").arg("greet").arg("world"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    hello world
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    ");
+
+    // The task's dependencies run first, and its dependency groups are synced.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("project-tasks").arg("test"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    hello
+    testing
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The first failing task stops the invocation.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("project-tasks").arg("check"), @"
+    exit_code: 3 (failure)
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    ");
+
+    // Tasks without a command can't accept arguments.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("project-tasks").arg("check").arg("-x"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: Task `check` doesn't define a command, so it can't be run with additional arguments
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn run_print_env() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.environment]
+        variables = { REBEL_1 = "leia_organa" }
+        path-prepend = ["tools"]
+        "#
+    })?;
+
+    context.temp_dir.child(".env").write_str(indoc! { "
+        REBEL_2=han_solo
+       "
+    })?;
+
+    // Only the leading `PATH` entries are set by uv.
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"(?m)^(PATH=[^:]*:[^:]*):.*$", "$1:[PATH]")])
+        .collect::<Vec<_>>();
+
+    // The variables from `--env-file` and `[tool.uv.environment]` are printed, along with `PATH`
+    // and `VIRTUAL_ENV`, and the command is not run.
+    uv_snapshot!(filters, context.run()
+        .arg("--preview-features")
+        .arg("project-environment-variables")
+        .arg("--env-file")
+        .arg(".env")
+        .arg("--print-env")
+        .arg("python")
+        .arg("-c")
+        .arg("raise SystemExit(1)"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    REBEL_2=han_solo
+    REBEL_1=leia_organa
+    PATH=[VENV]/bin:[TEMP_DIR]/tools:[PATH]
+    VIRTUAL_ENV=[VENV]/
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Activation scripts are only rewritten for projects that declare `[tool.uv.environment]`.
+#[test]
+fn run_without_project_environment_preserves_activation_scripts() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    // Customize the activation script.
+    let activate = uv_test::venv_bin_path(&context.venv).join("activate");
+    let contents = format!("{}\n# customized\n", fs_err::read_to_string(&activate)?);
+    fs_err::write(&activate, &contents)?;
+
+    context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("pass")
+        .assert()
+        .success();
+
+    assert_eq!(fs_err::read_to_string(&activate)?, contents);
+
+    Ok(())
+}
+
 #[test]
 fn run_with_extra_conflict() -> Result<()> {
     let context = uv_test::test_context!("3.12");
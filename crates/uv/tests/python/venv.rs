@@ -2380,3 +2380,108 @@ fn no_clear_conflicts_with_allow_existing() {
     "
     );
 }
+
+/// Layer a virtual environment on top of a parent environment with `--parent`.
+#[test]
+fn create_venv_with_parent() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12", "3.11"]);
+
+    // Create a parent environment with a package installed.
+    context
+        .venv()
+        .arg("base")
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("--python")
+        .arg("base")
+        .arg(
+            context
+                .workspace_root
+                .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        )
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--parent")
+        .arg("base")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("layered-environments"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    ");
+
+    // The parent is recorded in `pyvenv.cfg`, and its `site-packages` is appended to `sys.path`.
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    let parents = pyvenv_cfg
+        .lines()
+        .find(|line| line.starts_with("parent-environments"))
+        .unwrap();
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(parents, @"parent-environments = [TEMP_DIR]/base");
+    });
+    assert!(
+        site_packages_path(&context.venv, "python3.12")
+            .join("_uv_parents.pth")
+            .is_file()
+    );
+
+    // Packages in the parent environment are importable from the child.
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-c")
+        .arg("import ok; print(ok.__file__)"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    [TEMP_DIR]/base/[PYTHON-LIB]/site-packages/ok/__init__.py
+    ");
+
+    // The parent must use the same Python minor version.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("child")
+        .arg("--parent")
+        .arg("base")
+        .arg("--python")
+        .arg("3.11")
+        .arg("--preview-features")
+        .arg("layered-environments"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtual environment at: child
+    error: Failed to create virtual environment
+      Caused by: Parent environment at base uses Python 3.12, but the virtual environment uses Python 3.11
+    ");
+
+    // The parent must be a virtual environment.
+    context.temp_dir.child("not-a-venv").create_dir_all()?;
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("child")
+        .arg("--clear")
+        .arg("--parent")
+        .arg("not-a-venv")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("layered-environments"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: child
+    error: Failed to create virtual environment
+      Caused by: Parent environment is not a virtual environment: not-a-venv
+    ");
+
+    Ok(())
+}
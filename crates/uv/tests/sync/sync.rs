@@ -11025,6 +11025,338 @@ fn sync_git_metadata_archive_dependency() -> Result<()> {
     Ok(())
 }
 
+/// Lock a Git source fetched from a mirror, and sync it from the original repository without the
+/// mirror.
+#[test]
+#[cfg(feature = "test-git")]
+fn sync_git_mirror() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filter((r"@[0-9a-f]{40}", "@[COMMIT]"))
+        .with_filter((r"#[0-9a-f]{40}", "#[COMMIT]"));
+
+    let repository = context.temp_dir.child("canonical/pkg");
+    repository.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "pkg"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    repository.child("backend.py").write_str(indoc! {r#"
+        import os
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "pkg-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr("pkg.py", "")
+                wheel.writestr(
+                    "pkg-0.1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.4\nName: pkg\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    "pkg-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr("pkg-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#})?;
+
+    Command::new("git")
+        .arg("init")
+        .arg(repository.path())
+        .assert()
+        .success();
+    Command::new("git")
+        .arg("-C")
+        .arg(repository.path())
+        .arg("add")
+        .arg(".")
+        .assert()
+        .success();
+    Command::new("git")
+        .arg("-C")
+        .arg(repository.path())
+        .arg("-c")
+        .arg("user.name=Example")
+        .arg("-c")
+        .arg("user.email=example@example.com")
+        .arg("commit")
+        .arg("-m")
+        .arg("Initial commit")
+        .env("GIT_AUTHOR_DATE", "2000-01-01T00:00:00Z")
+        .env("GIT_COMMITTER_DATE", "2000-01-01T00:00:00Z")
+        .assert()
+        .success();
+
+    // Mirror the repository, and make the original unreachable.
+    let mirror = context.temp_dir.child("mirror/pkg");
+    Command::new("git")
+        .arg("clone")
+        .arg("--bare")
+        .arg(repository.path())
+        .arg(mirror.path())
+        .assert()
+        .success();
+    let unreachable = context.temp_dir.child("unreachable");
+    fs_err::rename(context.temp_dir.child("canonical"), &unreachable)?;
+
+    let canonical_url = Url::from_directory_path(context.temp_dir.child("canonical").path())
+        .map_err(|()| anyhow!("failed to convert repository path to file URL"))?;
+    let mirror_url = Url::from_directory_path(context.temp_dir.child("mirror").path())
+        .map_err(|()| anyhow!("failed to convert repository path to file URL"))?;
+    let pyproject_toml = formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["pkg"]
+
+        [tool.uv.sources]
+        pkg = {{ git = "{canonical_url}pkg" }}
+    "#};
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+        {pyproject_toml}
+        [[tool.uv.git-mirror]]
+        url = "{canonical_url}"
+        mirror = "{mirror_url}"
+    "#})?;
+
+    // The repository is fetched from the mirror, but the lockfile retains the original URL.
+    uv_snapshot!(context.filters(), context.lock().arg("--preview-features").arg("git-mirror"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!(
+        {
+            filters => context.filters(),
+        },
+        {
+            assert_snapshot!(
+                lock, @r#"
+            version = 1
+            revision = 3
+            requires-python = ">=3.12"
+
+            [options]
+            exclude-newer = "2024-03-25T00:00:00Z"
+
+            [[package]]
+            name = "pkg"
+            version = "0.1.0"
+            source = { git = "file://[TEMP_DIR]/canonical/pkg#[COMMIT]" }
+
+            [[package]]
+            name = "project"
+            version = "0.1.0"
+            source = { virtual = "." }
+            dependencies = [
+                { name = "pkg" },
+            ]
+
+            [package.metadata]
+            requires-dist = [{ name = "pkg", git = "file://[TEMP_DIR]/canonical/pkg" }]
+            "#
+            );
+        }
+    );
+
+    // Restore the original repository, and remove the mirror; the lockfile is usable without it.
+    fs_err::rename(&unreachable, context.temp_dir.child("canonical"))?;
+    fs_err::remove_dir_all(context.temp_dir.child("mirror"))?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&pyproject_toml)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--locked").arg("--no-cache"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + pkg==0.1.0 (from git+file://[TEMP_DIR]/canonical/pkg@[COMMIT])
+    ");
+
+    Ok(())
+}
+
+/// Verify the signature of a Git source against the trusted keys of the source, rather than the
+/// global `git-allowed-signers` setting.
+#[test]
+#[cfg(all(feature = "test-git", unix))]
+fn sync_git_source_allowed_signers() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filter((r"@[0-9a-f]{40}", "@[COMMIT]"))
+        .with_filter((r"#[0-9a-f]{40}", "#[COMMIT]"));
+
+    // Generate the signing key, and trust it for the source.
+    let key = context.temp_dir.child("key");
+    Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-C", "example", "-f"])
+        .arg(key.path())
+        .assert()
+        .success();
+    let public_key = fs_err::read_to_string(context.temp_dir.child("key.pub"))?;
+    let allowed_signers = context.temp_dir.child("keys/allowed_signers");
+    allowed_signers.write_str(&format!("example@example.com {public_key}"))?;
+
+    let repository = context.temp_dir.child("repository");
+    repository.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "pkg"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    repository.child("backend.py").write_str(indoc! {r#"
+        import os
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "pkg-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr("pkg.py", "")
+                wheel.writestr(
+                    "pkg-0.1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.4\nName: pkg\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    "pkg-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr("pkg-0.1.0.dist-info/RECORD", "")
+            return filename
+    "#})?;
+
+    Command::new("git")
+        .arg("init")
+        .arg(repository.path())
+        .assert()
+        .success();
+    Command::new("git")
+        .arg("-C")
+        .arg(repository.path())
+        .arg("add")
+        .arg(".")
+        .assert()
+        .success();
+    Command::new("git")
+        .arg("-C")
+        .arg(repository.path())
+        .arg("-c")
+        .arg("user.name=Example")
+        .arg("-c")
+        .arg("user.email=example@example.com")
+        .arg("-c")
+        .arg("gpg.format=ssh")
+        .arg("-c")
+        .arg(format!("user.signingkey={}", key.path().display()))
+        .arg("commit")
+        .arg("-S")
+        .arg("-m")
+        .arg("Initial commit")
+        .env("GIT_AUTHOR_DATE", "2000-01-01T00:00:00Z")
+        .env("GIT_COMMITTER_DATE", "2000-01-01T00:00:00Z")
+        .assert()
+        .success();
+
+    let repository_url = Url::from_directory_path(repository.path())
+        .map_err(|()| anyhow!("failed to convert repository path to file URL"))?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["pkg"]
+
+        [tool.uv.sources]
+        pkg = {{ git = "{repository_url}", verify-signature = true, allowed-signers = "keys/allowed_signers" }}
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The path to the trusted keys is retained in the lockfile, relative to the project.
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!(
+        {
+            filters => context.filters(),
+        },
+        {
+            assert_snapshot!(
+                lock, @r#"
+            version = 1
+            revision = 3
+            requires-python = ">=3.12"
+
+            [options]
+            exclude-newer = "2024-03-25T00:00:00Z"
+
+            [[package]]
+            name = "pkg"
+            version = "0.1.0"
+            source = { git = "file://[TEMP_DIR]/repository/?verify-signature=true&allowed-signers=keys%2Fallowed_signers#[COMMIT]" }
+
+            [[package]]
+            name = "project"
+            version = "0.1.0"
+            source = { virtual = "." }
+            dependencies = [
+                { name = "pkg" },
+            ]
+
+            [package.metadata]
+            requires-dist = [{ name = "pkg", git = "file://[TEMP_DIR]/repository/?verify-signature=true&allowed-signers=keys%2Fallowed_signers" }]
+            "#
+            );
+        }
+    );
+
+    uv_snapshot!(context.filters(), context.sync().arg("--locked").arg("--no-cache"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + pkg==0.1.0 (from git+file://[TEMP_DIR]/repository/@[COMMIT])
+    ");
+
+    // Without the trusted key, the signature can't be verified.
+    allowed_signers.write_str("")?;
+
+    context
+        .sync()
+        .arg("--locked")
+        .arg("--no-cache")
+        .arg("--reinstall")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not signed by a trusted key"));
+
+    Ok(())
+}
+
 /// Sync a package with multiple wheels at the same version, differing only in the build tag. We
 /// should choose the wheel with the highest build tag.
 #[test]
@@ -16535,3 +16867,100 @@ fn sync_frozen_workspace_member_git_credentials() -> Result<()> {
 
     Ok(())
 }
+
+/// Packages provided by a parent environment are treated as installed when syncing, as long as
+/// they match the locked version.
+#[test]
+fn sync_satisfied_by_parent_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let links = context.workspace_root.join("test/links");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0"]
+        "#,
+    )?;
+
+    // Install the locked version into a parent environment.
+    context
+        .venv()
+        .arg("base")
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("--python")
+        .arg("base")
+        .arg("ok==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links)
+        .assert()
+        .success();
+
+    // Layer the project environment on top of the parent.
+    context
+        .venv()
+        .arg("--clear")
+        .arg("--parent")
+        .arg("base")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--preview-features")
+        .arg("layered-environments")
+        .assert()
+        .success();
+
+    // The parent provides the locked version, so nothing is installed.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Checked 1 package in [TIME]
+    ");
+
+    // After changing the requirement, the locked version is installed into the project
+    // environment, shadowing the parent.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==2.0.0"]
+        "#,
+    )?;
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    // The parent environment is left untouched.
+    uv_snapshot!(context.filters(), context.pip_freeze().arg("--python").arg("base"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    ok==1.0.0
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: base
+    ");
+
+    Ok(())
+}
@@ -15,10 +15,15 @@ use assert_fs::{
 use indoc::indoc;
 use insta::assert_snapshot;
 use predicates::prelude::predicate;
+use serde_json::json;
 #[cfg(windows)]
 use uv_fs::Simplified;
 use uv_fs::copy_dir_all;
 use uv_static::EnvVars;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
 
 use uv_test::uv_snapshot;
 
@@ -6272,3 +6277,408 @@ fn tool_install_lock_revalidates_changed_constraints() -> Result<()> {
 
     Ok(())
 }
+
+/// Install tools with compatible requirements into a shared environment.
+#[test]
+fn tool_install_shared() {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black` into a new shared environment.
+    context
+        .tool_install()
+        .arg("black")
+        .arg("--shared")
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // `flask` requires the same version of `click`, so it's installed into the same environment,
+    // reusing the installed `click`.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("flask")
+        .arg("--shared")
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + blinker==1.7.0
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    Installed 1 executable: flask
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        // The receipt records the shared environment.
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("flask").join("uv-receipt.toml")).unwrap(), @r#"
+        [tool]
+        requirements = [{ name = "flask" }]
+        shared-environment = "1"
+        entrypoints = [
+            { name = "flask", install-path = "[TEMP_DIR]/bin/flask", from = "flask" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "#);
+    });
+
+    // The tool directory only holds the receipt.
+    tool_dir
+        .child("flask")
+        .child("pyvenv.cfg")
+        .assert(predicate::path::missing());
+
+    // The shared environment is retained while any tool is installed into it.
+    context
+        .tool_uninstall()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+    tool_dir
+        .child(".shared")
+        .child("1")
+        .assert(predicate::path::is_dir());
+
+    context
+        .tool_uninstall()
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+    tool_dir
+        .child(".shared")
+        .child("1")
+        .assert(predicate::path::missing());
+}
+
+/// Reinstalling a tool into its shared environment is a no-op when the environment is satisfied.
+#[test]
+fn tool_install_shared_reinstall() {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix()
+        .with_tool_dirs();
+    let find_links = context.workspace_root.join("test").join("links");
+
+    context
+        .tool_install()
+        .arg("simple-launcher")
+        .arg("--with")
+        .arg("ok==1.0.0")
+        .arg("--shared")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("simple-launcher")
+        .arg("--with")
+        .arg("ok==1.0.0")
+        .arg("--shared")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    `simple-launcher` is already installed
+    ");
+
+    // Changing the requirements updates the shared environment in place.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("simple-launcher")
+        .arg("--with")
+        .arg("ok==2.0.0")
+        .arg("--shared")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - ok==1.0.0
+     + ok==2.0.0
+    Installed 1 executable: simple_launcher
+    ");
+
+    context
+        .temp_dir
+        .child("tools")
+        .child(".shared")
+        .child("2")
+        .assert(predicate::path::missing());
+}
+
+/// Mount a minimal package index for `simple-launcher`, advertising the given hashes.
+async fn mount_simple_launcher_index(server: &MockServer, hashes: serde_json::Value, wheel: &[u8]) {
+    let wheel_filename = "simple_launcher-0.1.0-py3-none-any.whl";
+    let simple_index = json!({
+        "meta": {
+            "api-version": "1.1"
+        },
+        "name": "simple-launcher",
+        "files": [{
+            "filename": wheel_filename,
+            "url": format!("{}/files/{wheel_filename}", server.uri()),
+            "hashes": hashes,
+            "upload-time": "2024-03-24T00:00:00Z"
+        }]
+    });
+    Mock::given(method("GET"))
+        .and(path("/simple/simple-launcher/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            simple_index.to_string(),
+            "application/vnd.pypi.simple.v1+json",
+        ))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/files/{wheel_filename}")))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel.to_vec()))
+        .mount(server)
+        .await;
+}
+
+/// `require-provenance` refuses to install a tool whose index doesn't serve provenance for it.
+#[tokio::test]
+async fn tool_install_require_provenance_missing() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_exe_suffix()
+        .with_tool_dirs();
+    let wheel = fs_err::read(
+        context
+            .workspace_root
+            .join("test/links/simple_launcher-0.1.0-py3-none-any.whl"),
+    )?;
+    let server = MockServer::start().await;
+    mount_simple_launcher_index(
+        &server,
+        json!({ "sha256": "5327e0bb67cdb46800999de6dcf034bf0a5335702883494af0d8b7f6ca48cee4" }),
+        &wheel,
+    )
+    .await;
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("require-provenance = true")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("simple-launcher")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--config-file")
+        .arg(context.temp_dir.child("uv.toml").as_os_str())
+        .env(EnvVars::UV_PREVIEW_FEATURES, "provenance-verification")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to fetch the provenance of `simple_launcher-0.1.0-py3-none-any.whl`
+      Caused by: Failed to fetch provenance from: http://[LOCALHOST]/integrity/simple-launcher/0.1.0/simple_launcher-0.1.0-py3-none-any.whl/provenance
+      Caused by: Failed to fetch: `http://[LOCALHOST]/integrity/simple-launcher/0.1.0/simple_launcher-0.1.0-py3-none-any.whl/provenance`
+      Caused by: HTTP status client error (404 Not Found) for url (http://[LOCALHOST]/integrity/simple-launcher/0.1.0/simple_launcher-0.1.0-py3-none-any.whl/provenance)
+    ");
+
+    context
+        .temp_dir
+        .child("tools")
+        .child("simple-launcher")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// `require-provenance` refuses to install a tool whose provenance doesn't attest to it.
+#[tokio::test]
+async fn tool_install_require_provenance_invalid() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_exe_suffix()
+        .with_tool_dirs();
+    let wheel = fs_err::read(
+        context
+            .workspace_root
+            .join("test/links/simple_launcher-0.1.0-py3-none-any.whl"),
+    )?;
+    let server = MockServer::start().await;
+    mount_simple_launcher_index(
+        &server,
+        json!({ "sha256": "5327e0bb67cdb46800999de6dcf034bf0a5335702883494af0d8b7f6ca48cee4" }),
+        &wheel,
+    )
+    .await;
+
+    // An attestation for a different digest of the wheel.
+    let statement = "eyJfdHlwZSI6Imh0dHBzOi8vaW4tdG90by5pby9TdGF0ZW1lbnQvdjEiLCJzdWJqZWN0IjpbeyJuYW1lIjoic2ltcGxlX2xhdW5jaGVyLTAuMS4wLXB5My1ub25lLWFueS53aGwiLCJkaWdlc3QiOnsic2hhMjU2IjoiMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMCJ9fV0sInByZWRpY2F0ZVR5cGUiOiJodHRwczovL2RvY3MucHlwaS5vcmcvYXR0ZXN0YXRpb25zL3B1Ymxpc2gvdjEiLCJwcmVkaWNhdGUiOm51bGx9";
+    Mock::given(method("GET"))
+        .and(path(
+            "/integrity/simple-launcher/0.1.0/simple_launcher-0.1.0-py3-none-any.whl/provenance",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "version": 1,
+            "attestation_bundles": [{
+                "publisher": {
+                    "kind": "GitHub",
+                    "repository": "astral-sh/simple-launcher",
+                    "workflow": "release.yml"
+                },
+                "attestations": [{
+                    "version": 1,
+                    "verification_material": {
+                        "certificate": "",
+                        "transparency_entries": []
+                    },
+                    "envelope": {
+                        "statement": statement,
+                        "signature": ""
+                    }
+                }]
+            }]
+        })))
+        .mount(&server)
+        .await;
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("require-provenance = true")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("simple-launcher")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--config-file")
+        .arg(context.temp_dir.child("uv.toml").as_os_str())
+        .env(EnvVars::UV_PREVIEW_FEATURES, "provenance-verification")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to verify the provenance of `simple_launcher-0.1.0-py3-none-any.whl`
+      Caused by: The attested SHA-256 digest `0000000000000000000000000000000000000000000000000000000000000000` doesn't match the expected digest `5327e0bb67cdb46800999de6dcf034bf0a5335702883494af0d8b7f6ca48cee4`
+    ");
+
+    context
+        .temp_dir
+        .child("tools")
+        .child("simple-launcher")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// `require-provenance` refuses to install a tool if the index doesn't report the digest that its
+/// provenance would attest to.
+#[tokio::test]
+async fn tool_install_require_provenance_missing_digest() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_exe_suffix()
+        .with_tool_dirs();
+    let wheel = fs_err::read(
+        context
+            .workspace_root
+            .join("test/links/simple_launcher-0.1.0-py3-none-any.whl"),
+    )?;
+    let server = MockServer::start().await;
+    mount_simple_launcher_index(&server, json!({}), &wheel).await;
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("require-provenance-package = [\"simple-launcher\"]")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("simple-launcher")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri()))
+        .arg("--config-file")
+        .arg(context.temp_dir.child("uv.toml").as_os_str())
+        .env(EnvVars::UV_PREVIEW_FEATURES, "provenance-verification")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str()), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The index didn't provide a SHA-256 digest for `simple_launcher-0.1.0-py3-none-any.whl`, which is required to verify its provenance
+    ");
+
+    context
+        .temp_dir
+        .child("tools")
+        .child("simple-launcher")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// The `require-provenance` settings are persisted in the tool receipt, such that they're
+/// enforced on upgrade.
+#[test]
+fn tool_install_require_provenance_receipt() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_exe_suffix()
+        .with_tool_dirs();
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("require-provenance-package = [\"ok\"]")?;
+
+    context
+        .tool_install()
+        .arg("simple-launcher")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--config-file")
+        .arg(context.temp_dir.child("uv.toml").as_os_str())
+        .env(EnvVars::UV_PREVIEW_FEATURES, "provenance-verification")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str())
+        .assert()
+        .success();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(context.temp_dir.join("tools").join("simple-launcher").join("uv-receipt.toml")).unwrap(), @r#"
+        [tool]
+        requirements = [{ name = "simple-launcher" }]
+        entrypoints = [
+            { name = "simple_launcher", install-path = "[TEMP_DIR]/bin/simple_launcher", from = "simple-launcher" },
+        ]
+
+        [tool.options]
+        no-index = true
+        find-links = ["file://[WORKSPACE]/test/links"]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        require-provenance-package = ["ok"]
+        "#);
+    });
+
+    Ok(())
+}
@@ -62,6 +62,43 @@ fn tool_list_paths() {
     ");
 }
 
+#[test]
+fn tool_list_show_environments() {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black` into a shared environment, and `flask` into its own environment.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .arg("--shared")
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+    context
+        .tool_install()
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_list().arg("--show-environments")
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    black v24.2.0 [environment: [TEMP_DIR]/tools/.shared/1]
+    - black
+    - blackd
+    flask v3.0.2 [environment: [TEMP_DIR]/tools/flask]
+    - flask
+    ");
+}
+
 #[cfg(windows)]
 #[test]
 fn tool_list_paths_windows() {
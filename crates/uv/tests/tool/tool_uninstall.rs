@@ -170,3 +170,51 @@ fn tool_uninstall_all_missing_receipt() {
     Removed dangling environment for `black`
     ");
 }
+
+/// Uninstalling a tool removes its packages from the shared environment.
+#[test]
+fn tool_uninstall_shared() {
+    let context = uv_test::test_context!("3.13")
+        .with_filtered_exe_suffix()
+        .with_tool_dirs();
+    let find_links = context.workspace_root.join("test").join("links");
+    let shared_dir = context.temp_dir.child("tools").child(".shared").child("1");
+
+    context
+        .tool_install()
+        .arg("simple-launcher")
+        .arg("--with")
+        .arg("ok")
+        .arg("--shared")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .assert()
+        .success();
+    context
+        .tool_install()
+        .arg("basic-app")
+        .arg("--shared")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_uninstall().arg("simple-launcher"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Uninstalled 1 executable: simple_launcher
+    ");
+
+    // Only `basic-app` remains in the shared environment.
+    uv_snapshot!(context.filters(), context.pip_list().arg("--python").arg(shared_dir.as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Package   Version
+    --------- -------
+    basic-app 0.1.0
+    ");
+}
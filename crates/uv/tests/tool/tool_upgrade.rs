@@ -1774,3 +1774,109 @@ fn tool_upgrade_lock_uses_requested_python() -> Result<()> {
 
     Ok(())
 }
+
+/// Upgrading a tool whose requirements conflict with its shared environment moves it into another
+/// shared environment.
+#[test]
+fn tool_upgrade_shared_conflict() {
+    let context = uv_test::test_context!("3.13")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix()
+        .with_tool_dirs();
+    let tool_dir = context.temp_dir.child("tools");
+    let links = context.temp_dir.child("links");
+    links.create_dir_all().unwrap();
+    let find_links = context.workspace_root.join("test").join("links");
+    for wheel in [
+        "simple_launcher-0.1.0-py3-none-any.whl",
+        "basic_app-0.1.0-py3-none-any.whl",
+        "ok-1.0.0-py3-none-any.whl",
+    ] {
+        fs_err::copy(find_links.join(wheel), links.join(wheel)).unwrap();
+    }
+
+    // Both tools are installed into the same shared environment, with `ok==1.0.0`.
+    context
+        .tool_install()
+        .arg("simple-launcher")
+        .arg("--with")
+        .arg("ok")
+        .arg("--shared")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.as_os_str())
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .assert()
+        .success();
+    context
+        .tool_install()
+        .arg("basic-app")
+        .arg("--with")
+        .arg("ok<2")
+        .arg("--shared")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.as_os_str())
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .assert()
+        .success();
+
+    // A newer `ok` is published, which `basic-app` can't use.
+    fs_err::copy(
+        find_links.join("ok-2.0.0-py3-none-any.whl"),
+        links.join("ok-2.0.0-py3-none-any.whl"),
+    )
+    .unwrap();
+
+    uv_snapshot!(context.filters(), context.tool_upgrade()
+        .arg("simple-launcher")
+        .env(EnvVars::UV_PREVIEW_FEATURES, "tool-shared-environments")
+        .env(EnvVars::PATH, context.temp_dir.child("bin").as_os_str()), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + ok==2.0.0
+     + simple-launcher==0.1.0
+    Installed 1 executable: simple_launcher
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("simple-launcher").join("uv-receipt.toml")).unwrap(), @r#"
+        [tool]
+        requirements = [
+            { name = "simple-launcher" },
+            { name = "ok" },
+        ]
+        shared-environment = "2"
+        entrypoints = [
+            { name = "simple_launcher", install-path = "[TEMP_DIR]/bin/simple_launcher", from = "simple-launcher" },
+        ]
+
+        [tool.options]
+        no-index = true
+        find-links = ["file://[TEMP_DIR]/links"]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "#);
+    });
+
+    // The original environment is left with the packages required by `basic-app`.
+    uv_snapshot!(context.filters(), context.pip_list().arg("--python").arg(tool_dir.join(".shared").join("1")), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Package   Version
+    --------- -------
+    basic-app 0.1.0
+    ok        1.0.0
+    ");
+    uv_snapshot!(context.filters(), context.pip_list().arg("--python").arg(tool_dir.join(".shared").join("2")), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    Package         Version
+    --------------- -------
+    ok              2.0.0
+    simple-launcher 0.1.0
+    ");
+}
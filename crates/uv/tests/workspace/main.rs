@@ -4,6 +4,8 @@ mod workspace;
 
 mod workspace_dir;
 
+mod workspace_exec;
+
 mod workspace_list;
 
 mod workspace_metadata;
@@ -0,0 +1,274 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::fixture::{FileWriteStr, PathChild, PathCreateDir};
+use indoc::indoc;
+
+use uv_test::uv_snapshot;
+
+/// Create a workspace with a virtual root and two members, `alpha` and `beta`.
+fn workspace(context: &uv_test::TestContext) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#
+        })?;
+
+    let alpha = context.temp_dir.child("packages").child("alpha");
+    alpha.create_dir_all()?;
+    alpha.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "alpha"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#
+    })?;
+
+    let beta = context.temp_dir.child("packages").child("beta");
+    beta.create_dir_all()?;
+    beta.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "beta"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    Ok(())
+}
+
+/// Run a command in each workspace member, with the member directory as the working directory.
+#[test]
+fn workspace_exec() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    workspace(&context)?;
+
+    uv_snapshot!(context.filters(), context.workspace_exec()
+        .arg("--preview-features").arg("workspace-exec")
+        .arg("--")
+        .arg("python").arg("-c").arg("import os; print(os.path.basename(os.getcwd()))"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    alpha
+    beta
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Running `python -c import os; print(os.path.basename(os.getcwd()))` in `alpha`
+    Running `python -c import os; print(os.path.basename(os.getcwd()))` in `beta`
+
+    Ran `python -c import os; print(os.path.basename(os.getcwd()))` in 2 members: 2 succeeded, 0 failed
+      alpha  ok
+      beta   ok
+    ");
+
+    Ok(())
+}
+
+/// Failures in one member don't prevent the command from running in the others, and are reported
+/// in the summary.
+#[test]
+fn workspace_exec_failure() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    workspace(&context)?;
+
+    // With `--parallel`, runs complete in any order.
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"in `(alpha|beta)`\n", "in `[MEMBER]`\n")])
+        .collect();
+
+    uv_snapshot!(filters, context.workspace_exec()
+        .arg("--preview-features").arg("workspace-exec")
+        .arg("--parallel").arg("2")
+        .arg("--")
+        .arg("python").arg("-c").arg("import os, sys; sys.exit(3 if os.getcwd().endswith('alpha') else 0)"), @"
+    exit_code: 1 (failure)
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Ran `python -c import os, sys; sys.exit(3 if os.getcwd().endswith('alpha') else 0)` in `[MEMBER]`
+    Ran `python -c import os, sys; sys.exit(3 if os.getcwd().endswith('alpha') else 0)` in `[MEMBER]`
+
+    Ran `python -c import os, sys; sys.exit(3 if os.getcwd().endswith('alpha') else 0)` in 2 members: 1 succeeded, 1 failed
+      alpha  failed (exit code 3)
+      beta   ok
+    ");
+
+    Ok(())
+}
+
+/// Select workspace members with `--package`.
+#[test]
+fn workspace_exec_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    workspace(&context)?;
+
+    uv_snapshot!(context.filters(), context.workspace_exec()
+        .arg("--preview-features").arg("workspace-exec")
+        .arg("--package").arg("beta")
+        .arg("--")
+        .arg("python").arg("-c").arg("import importlib.util; print(importlib.util.find_spec('iniconfig') is None)"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    True
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited in [TIME]
+    Running `python -c import importlib.util; print(importlib.util.find_spec('iniconfig') is None)` in `beta`
+
+    Ran `python -c import importlib.util; print(importlib.util.find_spec('iniconfig') is None)` in 1 member: 1 succeeded, 0 failed
+      beta  ok
+    ");
+
+    uv_snapshot!(context.filters(), context.workspace_exec()
+        .arg("--preview-features").arg("workspace-exec")
+        .arg("--package").arg("gamma")
+        .arg("--")
+        .arg("python").arg("--version"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: Package `gamma` is not a member of the workspace
+    ");
+
+    Ok(())
+}
+
+/// Run a Git command in the test directory.
+fn git(context: &uv_test::TestContext, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(&context.temp_dir)
+        .env("GIT_AUTHOR_NAME", "ferris")
+        .env("GIT_AUTHOR_EMAIL", "ferris@example.com")
+        .env("GIT_COMMITTER_NAME", "ferris")
+        .env("GIT_COMMITTER_EMAIL", "ferris@example.com")
+        .assert()
+        .success();
+}
+
+/// Select the workspace members with changes since a Git reference with `--changed-since`.
+#[test]
+fn workspace_exec_changed_since() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    workspace(&context)?;
+    context.temp_dir.child(".gitignore").write_str(".venv\n")?;
+    git(&context, &["init"]);
+    git(&context, &["add", "."]);
+    git(&context, &["commit", "-m", "Initial commit"]);
+
+    // Skip syncing, which would require network access to install `iniconfig`.
+    let command = |reference: &str| {
+        let mut command = context.workspace_exec();
+        command
+            .arg("--preview-features")
+            .arg("workspace-exec")
+            .arg("--no-sync")
+            .arg("--changed-since")
+            .arg(reference)
+            .arg("--")
+            .arg("python")
+            .arg("-c")
+            .arg("import os; print(os.path.basename(os.getcwd()))");
+        command
+    };
+
+    uv_snapshot!(context.filters(), command("HEAD"), @"
+    exit_code: 0 (success)
+    ----- stderr -----
+    No workspace members have changed since `HEAD`
+    ");
+
+    // A modified file is attributed to the member that contains it.
+    context
+        .temp_dir
+        .child("packages")
+        .child("beta")
+        .child("README.md")
+        .write_str("# beta")?;
+    git(&context, &["add", "."]);
+    git(&context, &["commit", "-m", "Update beta"]);
+    uv_snapshot!(context.filters(), command("HEAD~1"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    beta
+
+    ----- stderr -----
+    Running `python -c import os; print(os.path.basename(os.getcwd()))` in `beta`
+
+    Ran `python -c import os; print(os.path.basename(os.getcwd()))` in 1 member: 1 succeeded, 0 failed
+      beta  ok
+    ");
+
+    // Untracked files are included too.
+    context
+        .temp_dir
+        .child("packages")
+        .child("alpha")
+        .child("notes.txt")
+        .write_str("notes")?;
+    uv_snapshot!(context.filters(), command("HEAD"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    alpha
+
+    ----- stderr -----
+    Running `python -c import os; print(os.path.basename(os.getcwd()))` in `alpha`
+
+    Ran `python -c import os; print(os.path.basename(os.getcwd()))` in 1 member: 1 succeeded, 0 failed
+      alpha  ok
+    ");
+
+    // Unknown references are reported.
+    uv_snapshot!(context.filters(), command("does-not-exist"), @"
+    exit_code: 2 (failure)
+    ----- stderr -----
+    error: `git diff --name-only --relative does-not-exist --` failed:
+    fatal: bad revision 'does-not-exist'
+    ");
+
+    Ok(())
+}
+
+/// With `--quiet`, only the output of the command itself is shown.
+#[test]
+fn workspace_exec_parallel_quiet() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    workspace(&context)?;
+
+    uv_snapshot!(context.filters(), context.workspace_exec()
+        .arg("--preview-features").arg("workspace-exec")
+        .arg("--no-sync")
+        .arg("--quiet")
+        .arg("--parallel").arg("2")
+        .arg("--")
+        .arg("python").arg("-c").arg("print('hello')"), @"
+    exit_code: 0 (success)
+    ----- stdout -----
+    hello
+    hello
+    ");
+
+    uv_snapshot!(context.filters(), context.workspace_exec()
+        .arg("--preview-features").arg("workspace-exec")
+        .arg("--no-sync")
+        .arg("-qq")
+        .arg("--parallel").arg("2")
+        .arg("--")
+        .arg("python").arg("-c").arg("print('hello')"), @"exit_code: 0 (success)");
+
+    Ok(())
+}